@@ -1,10 +1,16 @@
 use criterion::{black_box, criterion_group, criterion_main, Criterion};
 use num_traits::Pow;
 use perplex_num::{HyperbolicPolar, Perplex, PerplexMatrixForm};
-criterion_group!(benches, bench_multiplication);
+criterion_group!(
+    benches,
+    bench_multiplication,
+    bench_const_power,
+    bench_signed_power
+);
 criterion_main!(benches);
 
 const POW_EXP: u32 = 100;
+const SMALL_POW_EXP: u32 = 13;
 const TIME: f64 = 0.123;
 const SPACE: f64 = 4.321;
 // for z=Perplex(TIME, SPACE) t becomes inf at z^476
@@ -63,3 +69,38 @@ fn bench_multiplication(c: &mut Criterion) {
     });
     group.finish();
 }
+fn bench_const_power(c: &mut Criterion) {
+    let mut group = c.benchmark_group("Constant power");
+    group.bench_function("Perplex exponentiation by squaring", |b| {
+        b.iter(|| {
+            let z = Perplex::new(TIME, SPACE);
+            let _ = black_box(squaring_multiplication(
+                black_box(z),
+                black_box(SMALL_POW_EXP),
+            ));
+        })
+    });
+    group.bench_function("Perplex unrolled constant exponent", |b| {
+        b.iter(|| {
+            let z = Perplex::new(TIME, SPACE);
+            let _ = black_box(black_box(z).pow_const::<SMALL_POW_EXP>());
+        })
+    });
+    group.finish();
+}
+fn bench_signed_power(c: &mut Criterion) {
+    let mut group = c.benchmark_group("Signed power");
+    group.bench_function("Perplex checked negative power", |b| {
+        b.iter(|| {
+            let z = Perplex::new(SPACE, TIME);
+            let _ = black_box(black_box(z).powi(-black_box(SMALL_POW_EXP as i32)));
+        })
+    });
+    group.bench_function("Perplex unchecked negative power", |b| {
+        b.iter(|| {
+            let z = Perplex::new(SPACE, TIME);
+            let _ = black_box(black_box(z).powi_unchecked(-black_box(SMALL_POW_EXP as i32)));
+        })
+    });
+    group.finish();
+}
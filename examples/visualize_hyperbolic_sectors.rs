@@ -34,14 +34,14 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     };
     let label_offset = (10, 0);
     let klein_sector = [
-        ("1 Right", e),
-        ("h Up", h),
-        ("-1 Left", -e),
-        ("-h Down", -h),
-        ("z (√2, 1)", z),
-        ("h z (1, √2)", h * z),
-        ("-z (-√2, -1)", -z),
-        ("-h z (-1, -√2)", -h * z),
+        (format!("1 {}", e.sector()), e),
+        (format!("h {}", h.sector()), h),
+        (format!("-1 {}", (-e).sector()), -e),
+        (format!("-h {}", (-h).sector()), -h),
+        ("z (√2, 1)".to_string(), z),
+        ("h z (1, √2)".to_string(), h * z),
+        ("-z (-√2, -1)".to_string(), -z),
+        ("-h z (-1, -√2)".to_string(), -h * z),
     ];
     // Draw a diagonal line where x = y
     chart
@@ -91,7 +91,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     for (hyperbola, color, legend_d) in hyperbolas {
         let points = hyperbola
             .into_iter()
-            .filter(|&(_t, x)| (x <= x_max as f64 && x >= x_min as f64));
+            .filter(|&(_t, x)| x <= x_max as f64 && x >= x_min as f64);
         let draw_result = chart.draw_series(LineSeries::new(points, color))?;
         let legend = match legend_d {
             1 => format!("Hyperbola defined by t²-x²={:.1}", d),
@@ -0,0 +1,101 @@
+//! # Perplex Num Derive
+//!
+//! Procedural macros for the [perplex_num](https://docs.rs/perplex_num) crate, which are re-exported by its optional feature `derive`.
+//!
+//! ## Features
+//! - `lift_to_perplex`: Lifts a real function `fn f(x: T) -> T` to the perplex function `fn f_perplex(z: Perplex<T>) -> Perplex<T>` by applying `f` to the null components, see `Perplex::lift`.
+
+use proc_macro::TokenStream;
+use quote::{format_ident, quote};
+use syn::{parse_macro_input, Expr, FnArg, ItemFn, ReturnType};
+
+/// Generates the perplex lift `f_perplex` of the annotated real function `f`, which takes one argument and returns a value of the same type.
+///
+/// The generated function applies `f` to the null components `u = t + x` and `v = t - x` by `Perplex::lift`.
+/// With the argument `domain = <predicate>`, the generated function checks that both null components satisfy the predicate, e.g., a closure like `|x| x > 0.0`, and returns an `Option`.
+///
+/// ```ignore
+/// use perplex_num::{lift_to_perplex, Perplex};
+///
+/// #[lift_to_perplex(domain = |x| x >= 0.0)]
+/// fn root(x: f64) -> f64 {
+///     x.sqrt()
+/// }
+///
+/// let z = Perplex::new(2.5, 1.5);
+/// assert_eq!(root_perplex(z), z.sqrt());
+/// ```
+#[proc_macro_attribute]
+pub fn lift_to_perplex(attr: TokenStream, item: TokenStream) -> TokenStream {
+    let mut domain: Option<Expr> = None;
+    let parser = syn::meta::parser(|meta| {
+        if meta.path.is_ident("domain") {
+            domain = Some(meta.value()?.parse()?);
+            Ok(())
+        } else {
+            Err(meta.error("expected `domain = <predicate>`"))
+        }
+    });
+    parse_macro_input!(attr with parser);
+    let function = parse_macro_input!(item as ItemFn);
+    let signature = &function.sig;
+    let argument_type = match (signature.inputs.len(), signature.inputs.first()) {
+        (1, Some(FnArg::Typed(argument))) => &argument.ty,
+        _ => {
+            return syn::Error::new_spanned(
+                &signature.inputs,
+                "expected exactly one argument like `x: f64`",
+            )
+            .to_compile_error()
+            .into()
+        }
+    };
+    let ReturnType::Type(_, return_type) = &signature.output else {
+        return syn::Error::new_spanned(signature, "expected a return type like `-> f64`")
+            .to_compile_error()
+            .into();
+    };
+    if quote!(#argument_type).to_string() != quote!(#return_type).to_string()
+        || !signature.generics.params.is_empty()
+    {
+        return syn::Error::new_spanned(
+            signature,
+            "expected a non-generic function with equal argument and return types",
+        )
+        .to_compile_error()
+        .into();
+    }
+    let name = &signature.ident;
+    let lifted = format_ident!("{}_perplex", name);
+    let visibility = &function.vis;
+    let doc = format!("The lift of `{name}` to perplex numbers by its application to the null components, see `Perplex::lift`.");
+    let expanded = match domain {
+        None => quote! {
+            #function
+            #[doc = #doc]
+            #[inline]
+            #visibility fn #lifted(z: ::perplex_num::Perplex<#argument_type>) -> ::perplex_num::Perplex<#argument_type> {
+                z.lift(#name)
+            }
+        },
+        Some(domain) => {
+            let doc_domain = "Returns `None` if a null component is outside of the domain.";
+            quote! {
+                #function
+                #[doc = #doc]
+                #[doc = ""]
+                #[doc = #doc_domain]
+                #[inline]
+                #visibility fn #lifted(z: ::perplex_num::Perplex<#argument_type>) -> ::core::option::Option<::perplex_num::Perplex<#argument_type>> {
+                    let domain: fn(#argument_type) -> bool = #domain;
+                    if domain(z.t + z.x) && domain(z.t - z.x) {
+                        ::core::option::Option::Some(z.lift(#name))
+                    } else {
+                        ::core::option::Option::None
+                    }
+                }
+            }
+        }
+    };
+    expanded.into()
+}
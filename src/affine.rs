@@ -0,0 +1,272 @@
+//! # Affine Module
+//!
+//! This module provides the newtypes `PerplexPoint` and `PerplexVector`, which distinguish events of the Minkowski plane from displacements between events in spacetime-diagram code.
+//! Events form an affine space without a distinguished origin: the difference of two events is a displacement, and a displacement moves an event to another event, whereas the sum of two events is not defined.
+//! A Lorentz boost is linear, hence it acts on displacements by multiplication, but on events only about a chosen origin event. Boosting absolute coordinates as if they were displacements silently boosts about the origin of the coordinate system, which the types turn into a compile error.
+//!
+//! ## Features
+//! - `PerplexPoint - PerplexPoint = PerplexVector`, `PerplexPoint ± PerplexVector = PerplexPoint`, and the vector space operations of `PerplexVector`.
+//! - `UnitPerplex * PerplexVector`: The boost of a displacement.
+//! - `PerplexPoint::boost_about`: The boost of an event about an origin event.
+//! - `PerplexVector::squared_interval`: The invariant squared interval `dt^2 - dx^2` of a displacement.
+//!
+//! ## Example
+//! ```
+//! use approx::assert_abs_diff_eq;
+//! use perplex_num::affine::{PerplexPoint, PerplexVector};
+//! use perplex_num::UnitPerplex;
+//! let (emission, absorption) = (PerplexPoint::new(5.0, 2.0), PerplexPoint::new(7.0, 3.0));
+//! let boost = UnitPerplex::from_velocity(0.6).unwrap();
+//! // the displacement between the events is boosted, the events are boosted about the emission
+//! let displacement = boost * (absorption - emission);
+//! assert_abs_diff_eq!(displacement.squared_interval(), 3.0, epsilon = 1e-14);
+//! let boosted = absorption.boost_about(emission, boost);
+//! assert_eq!(boosted, emission + displacement);
+//! assert_eq!(emission + PerplexVector::new(2.0, 1.0), absorption);
+//! ```
+
+use super::{Perplex, UnitPerplex};
+use core::ops::{Add, AddAssign, Div, Mul, Neg, Sub, SubAssign};
+use num_traits::{Float, Num, NumAssign};
+
+/// An event of the Minkowski plane with the coordinates `t + x h` in a chosen frame, i.e., a point of the affine space of events.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub struct PerplexPoint<T>(pub Perplex<T>);
+
+/// A displacement `dt + dx h` between two events, i.e., a vector of the linear space of displacements.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub struct PerplexVector<T>(pub Perplex<T>);
+
+impl<T: Copy + Num> PerplexPoint<T> {
+    /// Creates the event with the time coordinate `t` and the space coordinate `x`.
+    #[inline]
+    pub fn new(t: T, x: T) -> Self {
+        Self(Perplex::new(t, x))
+    }
+    /// Returns the origin event of the frame.
+    #[inline]
+    pub fn origin() -> Self {
+        Self::new(T::zero(), T::zero())
+    }
+    /// Returns the coordinates of the event as a perplex number.
+    #[inline]
+    pub fn coords(&self) -> Perplex<T> {
+        self.0
+    }
+    /// Returns the displacement of the event from the origin of the frame, i.e., its position vector.
+    #[inline]
+    pub fn to_vector(&self) -> PerplexVector<T> {
+        PerplexVector(self.0)
+    }
+    /// Returns the event `self + s (other - self)` on the straight line through both events, e.g., the midpoint for `s = 1/2`.
+    #[inline]
+    pub fn lerp(&self, other: Self, s: T) -> Self {
+        *self + (other - *self) * s
+    }
+}
+
+impl<T: Copy + Float> PerplexPoint<T> {
+    /// Returns the event boosted by `boost` about the event `origin`, i.e., `origin + boost (self - origin)`.
+    ///
+    /// The origin is invariant, and the boost about the origin of the frame is `boost.apply(self.coords())`.
+    #[inline]
+    pub fn boost_about(&self, origin: Self, boost: UnitPerplex<T>) -> Self {
+        origin + boost * (*self - origin)
+    }
+}
+
+impl<T: Copy + Num> PerplexVector<T> {
+    /// Creates the displacement with the time component `dt` and the space component `dx`.
+    #[inline]
+    pub fn new(dt: T, dx: T) -> Self {
+        Self(Perplex::new(dt, dx))
+    }
+    /// Returns the zero displacement.
+    #[inline]
+    pub fn zero() -> Self {
+        Self::new(T::zero(), T::zero())
+    }
+    /// Returns the components of the displacement as a perplex number.
+    #[inline]
+    pub fn components(&self) -> Perplex<T> {
+        self.0
+    }
+    /// Returns the invariant squared interval `dt^2 - dx^2`, which is positive for time-like, zero for light-like and negative for space-like displacements.
+    #[inline]
+    pub fn squared_interval(&self) -> T {
+        self.0.squared_distance()
+    }
+}
+
+impl<T: Copy + Num> Sub for PerplexPoint<T> {
+    type Output = PerplexVector<T>;
+    /// Returns the displacement from `rhs` to `self`.
+    #[inline]
+    fn sub(self, rhs: Self) -> Self::Output {
+        PerplexVector(self.0 - rhs.0)
+    }
+}
+
+impl<T: Copy + Num> Add<PerplexVector<T>> for PerplexPoint<T> {
+    type Output = Self;
+    #[inline]
+    fn add(self, rhs: PerplexVector<T>) -> Self::Output {
+        Self(self.0 + rhs.0)
+    }
+}
+
+impl<T: Copy + Num> Sub<PerplexVector<T>> for PerplexPoint<T> {
+    type Output = Self;
+    #[inline]
+    fn sub(self, rhs: PerplexVector<T>) -> Self::Output {
+        Self(self.0 - rhs.0)
+    }
+}
+
+impl<T: Copy + NumAssign> AddAssign<PerplexVector<T>> for PerplexPoint<T> {
+    #[inline]
+    fn add_assign(&mut self, rhs: PerplexVector<T>) {
+        self.0 += rhs.0;
+    }
+}
+
+impl<T: Copy + NumAssign> SubAssign<PerplexVector<T>> for PerplexPoint<T> {
+    #[inline]
+    fn sub_assign(&mut self, rhs: PerplexVector<T>) {
+        self.0 -= rhs.0;
+    }
+}
+
+impl<T: Copy + Num> Add for PerplexVector<T> {
+    type Output = Self;
+    #[inline]
+    fn add(self, rhs: Self) -> Self::Output {
+        Self(self.0 + rhs.0)
+    }
+}
+
+impl<T: Copy + Num> Add<PerplexPoint<T>> for PerplexVector<T> {
+    type Output = PerplexPoint<T>;
+    #[inline]
+    fn add(self, rhs: PerplexPoint<T>) -> Self::Output {
+        rhs + self
+    }
+}
+
+impl<T: Copy + Num> Sub for PerplexVector<T> {
+    type Output = Self;
+    #[inline]
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self(self.0 - rhs.0)
+    }
+}
+
+impl<T: Copy + NumAssign> AddAssign for PerplexVector<T> {
+    #[inline]
+    fn add_assign(&mut self, rhs: Self) {
+        self.0 += rhs.0;
+    }
+}
+
+impl<T: Copy + NumAssign> SubAssign for PerplexVector<T> {
+    #[inline]
+    fn sub_assign(&mut self, rhs: Self) {
+        self.0 -= rhs.0;
+    }
+}
+
+impl<T: Copy + Num + Neg<Output = T>> Neg for PerplexVector<T> {
+    type Output = Self;
+    #[inline]
+    fn neg(self) -> Self::Output {
+        Self(-self.0)
+    }
+}
+
+impl<T: Copy + Num> Mul<T> for PerplexVector<T> {
+    type Output = Self;
+    #[inline]
+    fn mul(self, rhs: T) -> Self::Output {
+        Self(self.0 * rhs)
+    }
+}
+
+impl<T: Copy + Num> Div<T> for PerplexVector<T> {
+    type Output = Self;
+    #[inline]
+    fn div(self, rhs: T) -> Self::Output {
+        Self(self.0 / rhs)
+    }
+}
+
+impl<T: Copy + Float> Mul<PerplexVector<T>> for UnitPerplex<T> {
+    type Output = PerplexVector<T>;
+    /// Boosts the displacement `rhs`, which preserves its squared interval.
+    #[inline]
+    fn mul(self, rhs: PerplexVector<T>) -> Self::Output {
+        PerplexVector(self.apply(rhs.0))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_abs_diff_eq;
+    #[test]
+    fn test_affine_operations() {
+        let (a, b) = (PerplexPoint::new(1.0, -2.0), PerplexPoint::new(4.0, 0.5));
+        let v = b - a;
+        assert_eq!(v, PerplexVector::new(3.0, 2.5));
+        assert_eq!(a + v, b);
+        assert_eq!(v + a, b);
+        assert_eq!(b - v, a);
+        assert_eq!(-v, a - b);
+        assert_eq!(v * 2.0 - v, v + PerplexVector::zero());
+        assert_eq!(v / 2.0, PerplexVector::new(1.5, 1.25));
+        assert_eq!(a.lerp(b, 0.5), PerplexPoint::new(2.5, -0.75), "Midpoint!");
+        let mut c = a;
+        c += v;
+        assert_eq!(c, b);
+        c -= v;
+        assert_eq!(c, a);
+        let mut w = v;
+        w += v;
+        w -= PerplexVector::new(3.0, 0.0);
+        assert_eq!(w.components(), Perplex::new(3.0, 5.0));
+        assert_eq!(b.to_vector(), b - PerplexPoint::origin());
+        assert_eq!(b.coords(), Perplex::new(4.0, 0.5));
+    }
+    #[test]
+    fn test_boosts() {
+        let boost = UnitPerplex::from_rapidity(0.7);
+        let (a, b) = (PerplexPoint::new(3.0, 1.0), PerplexPoint::new(5.0, -0.5));
+        // the boost of the displacement is independent of the origin of the frame
+        let shift = PerplexVector::new(-10.0, 20.0);
+        let (a2, b2) = (a + shift, b + shift);
+        assert_abs_diff_eq!(
+            (boost * (b2 - a2)).components(),
+            (boost * (b - a)).components(),
+            epsilon = 1e-14
+        );
+        assert_abs_diff_eq!(
+            (boost * (b - a)).squared_interval(),
+            (b - a).squared_interval(),
+            epsilon = 1e-12
+        );
+        // events are boosted about an origin, which is invariant
+        assert_eq!(a.boost_about(a, boost), a);
+        let boosted = b.boost_about(a, boost);
+        assert_abs_diff_eq!(
+            (boosted - a).squared_interval(),
+            (b - a).squared_interval(),
+            epsilon = 1e-12
+        );
+        assert_eq!(
+            b.boost_about(PerplexPoint::origin(), boost).coords(),
+            boost.apply(b.coords()),
+            "Boost about the origin of the frame!"
+        );
+        // boosting the coordinates as displacements is the boost about the origin of the frame
+        assert!((boosted.coords() - boost.apply(b.coords())).l1_norm() > 1.0);
+    }
+}
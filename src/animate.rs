@@ -0,0 +1,255 @@
+//! # Animate Module
+//!
+//! This module generates the frames of spacetime-diagram animations by Lorentz boosts.
+//! The frames interpolate the rapidity and the logarithm of the modulus instead of the components, such that intermediate events move along hyperbolas and each step between frames is a boost followed by a scaling, see `UnitPerplex`.
+//!
+//! ## Features
+//! - `Easing`: Timing curves of the animation, which map the linear progress in `[0, 1]` to the eased progress.
+//! - `boost_between`: The frames between two events of the same sector.
+//! - `boost_between_sets` and `boost_frames`: The frames of whole point sets at once, stored in a `Frames` buffer.
+//!
+//! ## Example
+//! ```
+//! use approx::assert_abs_diff_eq;
+//! use perplex_num::animate::{boost_between, boost_frames, Easing};
+//! use perplex_num::Perplex;
+//! let (start, end) = (Perplex::new(1.0, 0.0), Perplex::cis(1.0));
+//! let frames = boost_between(start, end, Easing::Linear, 5).unwrap();
+//! assert_eq!(frames.len(), 5);
+//! assert_abs_diff_eq!(frames[2], Perplex::cis(0.5), epsilon = 1e-15);
+//! // a boost of a point set from the rapidity 0 to 2
+//! let events = [Perplex::new(1.0, 0.0), Perplex::new(2.0, 1.0), Perplex::new(0.0, 1.0)];
+//! let buffer = boost_frames(&events, 2.0, Easing::EaseInOut, 30);
+//! assert_eq!(buffer.frame(0), &events);
+//! assert_abs_diff_eq!(buffer.frame(29)[0], Perplex::cis(2.0), epsilon = 1e-12);
+//! ```
+
+use super::{HyperbolicPolar, HyperbolicSector, Perplex, UnitPerplex};
+use num_traits::Float;
+
+/// The timing curve of an animation.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug, Default)]
+pub enum Easing {
+    /// The constant speed `s`.
+    #[default]
+    Linear,
+    /// The quadratic acceleration from rest `s^2`.
+    EaseIn,
+    /// The quadratic deceleration to rest `1 - (1 - s)^2`.
+    EaseOut,
+    /// The smoothstep `3 s^2 - 2 s^3`, which starts and ends at rest.
+    EaseInOut,
+}
+
+impl Easing {
+    /// Maps the linear progress `s` in `[0, 1]` to the eased progress, whereby `0` and `1` are fixed points.
+    #[inline]
+    pub fn apply<T: Copy + Float>(&self, s: T) -> T {
+        let (one, two) = (T::one(), T::one() + T::one());
+        match self {
+            Self::Linear => s,
+            Self::EaseIn => s * s,
+            Self::EaseOut => one - (one - s) * (one - s),
+            Self::EaseInOut => s * s * (two + one - two * s),
+        }
+    }
+    /// Returns the eased progress of the frames `0, ..., n_frames - 1`, whereby the first frame is at `0` and the last frame at `1`.
+    fn progress<T: Copy + Float>(self, n_frames: usize) -> impl Iterator<Item = T> {
+        let last = T::from(n_frames.saturating_sub(1).max(1)).unwrap();
+        (0..n_frames).map(move |k| self.apply(T::from(k).unwrap() / last))
+    }
+}
+
+/// A buffer of the frames of an animated point set, stored frame by frame.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Frames<T> {
+    /// The number of points per frame.
+    pub points: usize,
+    /// The points of all frames, whereby frame `k` occupies `data[k * points..(k + 1) * points]`.
+    pub data: Vec<Perplex<T>>,
+}
+
+impl<T> Frames<T> {
+    /// Returns the number of frames, which is zero for frames without points.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.data.len().checked_div(self.points).unwrap_or(0)
+    }
+    /// Checks if the buffer contains no frames.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+    /// Returns the points of the frame `k`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `k` is not smaller than the number of frames.
+    #[inline]
+    pub fn frame(&self, k: usize) -> &[Perplex<T>] {
+        &self.data[k * self.points..(k + 1) * self.points]
+    }
+    /// Returns an iterator over the frames.
+    #[inline]
+    pub fn iter(&self) -> impl Iterator<Item = &[Perplex<T>]> {
+        self.data.chunks(self.points.max(1))
+    }
+}
+
+/// Returns `n_frames` events from `z_start` to `z_end`, both included, which interpolate the rapidity linearly and the modulus geometrically in the eased progress.
+///
+/// Returns `None` if the events are light-like or lie in different sectors, since no boost and scaling maps one to the other.
+pub fn boost_between<T: Copy + Float>(
+    z_start: Perplex<T>,
+    z_end: Perplex<T>,
+    easing: Easing,
+    n_frames: usize,
+) -> Option<Vec<Perplex<T>>> {
+    let (start, end) = (z_start.polar(), z_end.polar());
+    if matches!(start.sector, HyperbolicSector::Diagonal(_)) || start.sector != end.sector {
+        return None;
+    }
+    let (ln_rho, d_ln_rho) = (start.rho.ln(), end.rho.ln() - start.rho.ln());
+    let d_theta = end.theta - start.theta;
+    let frames = easing
+        .progress::<T>(n_frames)
+        .map(|s| {
+            HyperbolicPolar {
+                rho: (ln_rho + s * d_ln_rho).exp(),
+                theta: start.theta + s * d_theta,
+                sector: start.sector,
+            }
+            .into()
+        })
+        .collect();
+    Some(frames)
+}
+
+/// Returns the frames of the pairwise interpolations of `boost_between` from the events `starts` to the events `ends`.
+///
+/// Returns `None` if the lengths differ or a pair cannot be interpolated.
+pub fn boost_between_sets<T: Copy + Float>(
+    starts: &[Perplex<T>],
+    ends: &[Perplex<T>],
+    easing: Easing,
+    n_frames: usize,
+) -> Option<Frames<T>> {
+    if starts.len() != ends.len() {
+        return None;
+    }
+    let paths = starts
+        .iter()
+        .zip(ends)
+        .map(|(&start, &end)| boost_between(start, end, easing, n_frames))
+        .collect::<Option<Vec<_>>>()?;
+    let data = (0..n_frames)
+        .flat_map(|k| paths.iter().map(move |path| path[k]))
+        .collect();
+    Some(Frames {
+        points: starts.len(),
+        data,
+    })
+}
+
+/// Returns the frames of the events boosted with the rapidity eased from zero in the first frame to `rapidity` in the last frame.
+///
+/// All events are boosted by the same `UnitPerplex` per frame, which preserves the light cone, including light-like events, and the causal structure of the set.
+pub fn boost_frames<T: Copy + Float>(
+    events: &[Perplex<T>],
+    rapidity: T,
+    easing: Easing,
+    n_frames: usize,
+) -> Frames<T> {
+    let data = easing
+        .progress::<T>(n_frames)
+        .flat_map(|s| {
+            let boost = UnitPerplex::from_rapidity(s * rapidity);
+            events.iter().map(move |&z| boost.apply(z))
+        })
+        .collect();
+    Frames {
+        points: events.len(),
+        data,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_abs_diff_eq;
+    #[test]
+    fn test_easing() {
+        for easing in [
+            Easing::Linear,
+            Easing::EaseIn,
+            Easing::EaseOut,
+            Easing::EaseInOut,
+        ] {
+            assert_eq!(easing.apply(0.0), 0.0);
+            assert_eq!(easing.apply(1.0), 1.0);
+            let progress: Vec<f64> = easing.progress(11).collect();
+            assert!(progress.windows(2).all(|w| w[0] < w[1]), "Monotonic!");
+        }
+        assert_eq!(Easing::EaseInOut.apply(0.5), 0.5);
+        assert_eq!(Easing::EaseIn.apply(0.5), 0.25);
+        assert_eq!(Easing::EaseOut.apply(0.5), 0.75);
+        assert_eq!(Easing::Linear.progress::<f64>(1).collect::<Vec<_>>(), [0.0]);
+    }
+    #[test]
+    fn test_boost_between() {
+        let (start, end) = (Perplex::new(-1.0, 0.5), Perplex::new(-4.0, -3.0));
+        let frames = boost_between(start, end, Easing::EaseIn, 7).unwrap();
+        assert_abs_diff_eq!(frames[0], start, epsilon = 1e-12);
+        assert_abs_diff_eq!(frames[6], end, epsilon = 1e-12);
+        let (rho_0, rho_1) = (start.modulus(), end.modulus());
+        for (k, z) in frames.iter().enumerate() {
+            let s = Easing::EaseIn.apply(k as f64 / 6.0);
+            assert_eq!(z.sector(), HyperbolicSector::Left);
+            assert_abs_diff_eq!(
+                z.arg(),
+                (1.0 - s) * start.arg() + s * end.arg(),
+                epsilon = 1e-12
+            );
+            assert_abs_diff_eq!(
+                z.modulus(),
+                rho_0.powf(1.0 - s) * rho_1.powf(s),
+                epsilon = 1e-12
+            );
+        }
+        assert!(boost_between(start, Perplex::new(1.0, 0.0), Easing::Linear, 3).is_none());
+        assert!(boost_between(start, Perplex::new(-1.0, 1.0), Easing::Linear, 3).is_none());
+        assert!(boost_between(start, end, Easing::Linear, 0)
+            .unwrap()
+            .is_empty());
+    }
+    #[test]
+    fn test_frames() {
+        let starts = [Perplex::new(1.0, 0.0), Perplex::new(0.5, 2.0)];
+        let ends = [Perplex::new(2.0, 1.0), Perplex::new(-0.5, 3.0)];
+        let frames = boost_between_sets(&starts, &ends, Easing::EaseOut, 4).unwrap();
+        assert_eq!(frames.len(), 4);
+        assert_eq!(frames.iter().count(), 4);
+        let path = boost_between(starts[1], ends[1], Easing::EaseOut, 4).unwrap();
+        for (frame, z) in frames.iter().zip(path) {
+            assert_eq!(frame[1], z);
+        }
+        assert!(boost_between_sets(&starts, &ends[..1], Easing::Linear, 4).is_none());
+        let events = [Perplex::new(1.0, 1.0), Perplex::new(3.0, -1.0)];
+        let boosted = boost_frames(&events, -1.5, Easing::Linear, 3);
+        assert_eq!(boosted.len(), 3);
+        let boost = UnitPerplex::from_rapidity(-0.75);
+        assert_eq!(
+            boosted.frame(1),
+            &[boost.apply(events[0]), boost.apply(events[1])]
+        );
+        for frame in boosted.iter() {
+            assert_eq!(
+                frame[0].t, frame[0].x,
+                "Light-like events stay on the light cone!"
+            );
+            assert_abs_diff_eq!(frame[1].squared_distance(), 8.0, epsilon = 1e-12);
+        }
+        let empty = boost_frames::<f64>(&[], 1.0, Easing::Linear, 5);
+        assert!(empty.is_empty() && empty.iter().next().is_none());
+    }
+}
@@ -5,9 +5,10 @@
 //! The module includes implementations for basic arithmetic operations between `Perplex` structs, such as:
 //! - `Add`: Trait for the addition operator.
 //! - `Sub`: Trait for the subtraction operator.
-//! - `Mul`: Trait for the multiplication operator.
+//! - `Mul`: Trait for the multiplication operator, which rounds the products of the components separately, see below.
 //! - `Div`: Trait for the division operator.
-//! - Tertiary operation `MulAdd` from the `num_traits` crate.
+//! - `Rem`: Trait for the remainder operator of the division truncated towards zero.
+//! - Tertiary operation `MulAdd` from the `num_traits` crate, which fuses the multiplications and the addition for floating-point components.
 //!
 //! Additionally, it supports assignment variants of these operations for mutable references of `Perplex` structs, which are:
 //! - `AddAssign`: Trait for addition assignment.
@@ -16,167 +17,418 @@
 //! - `DivAssign`: Trait for division assignment.
 //! - Tertiary operation `MulAddAssign` from the `num_traits` crate.
 //!
-//! The module also includes implementations for interactions between `Perplex` structs and the generic floating point type (`f32` or `f64`).
+//! The module also includes implementations for interactions between `Perplex` structs and their scalar component type, with the scalar on either side, e.g., `2.0 * z` and `z * 2.0`. Since the orphan rule forbids a generic implementation with the scalar on the left side, these operators are implemented for the floating-point types `f32` and `f64` only.
+//!
+//! All binary and assignment operators are also implemented for references and mixed owned and borrowed operands, e.g., `&z + &w`, `z * &w`, `&z / 2.0` and `z += &w`, which clone the borrowed operands like the operators of `num-complex`.
+//!
+//! `Mul` is generic over components with `Num` only, e.g., integers, rationals and nested perplex numbers, hence it cannot fuse the products for floating-point components without a `MulAdd` bound on every component type and without the specialization of trait implementations.
+//! The fused products are provided by `MulAdd` and by the methods `Perplex::mul_fused` and `Perplex::squared_distance_fused`, which are accurate under cancellation, e.g., for nearly light-like factors.
+//!
+//! Since the division by light-like numbers is undefined, `Div` and `Rem` yield infinite or NaN components for floating-point numbers and panic for integers like the division by zero, whereas `CheckedDiv` of the `num_traits` crate returns `None`.
+//! Together with the parsing of `Perplex::from_str_radix`, `Perplex` implements the `Num` trait, e.g., for generic numeric code or the nesting `Perplex<Perplex<T>>`.
+//!
+//! With the optional feature `nan-debug`, all operations record the origin of NaN components, see the `debug` module.
 
 use super::Perplex;
-use num_traits::{MulAdd, MulAddAssign, Num, NumAssign};
-use std::ops::{Add, Div, Mul, Sub};
-use std::ops::{AddAssign, DivAssign, MulAssign, SubAssign};
+use core::ops::{Add, Div, Mul, Rem, Sub};
+use core::ops::{AddAssign, DivAssign, MulAssign, SubAssign};
+use num_traits::{CheckedDiv, MulAdd, MulAddAssign, Num, NumAssign};
 
 // binary between Perplex and Perplex
-impl<T: Copy + Num> Add for Perplex<T> {
+impl<T: Clone + Num> Add for Perplex<T> {
     type Output = Self;
     #[inline]
     fn add(self, rhs: Self) -> Self::Output {
-        Self::new(self.t + rhs.t, self.x + rhs.x)
+        #[cfg(feature = "nan-debug")]
+        let operands = [self.clone(), rhs.clone()];
+        let result = Self::new(self.t + rhs.t, self.x + rhs.x);
+        #[cfg(feature = "nan-debug")]
+        crate::debug::record("add", &operands, result.clone());
+        result
     }
 }
-impl<T: Copy + NumAssign> AddAssign for Perplex<T> {
+impl<T: Clone + NumAssign> AddAssign for Perplex<T> {
     fn add_assign(&mut self, rhs: Self) {
+        #[cfg(feature = "nan-debug")]
+        let operands = [self.clone(), rhs.clone()];
         self.t += rhs.t;
         self.x += rhs.x;
+        #[cfg(feature = "nan-debug")]
+        crate::debug::record("add_assign", &operands, self.clone());
     }
 }
 
-impl<T: Copy + Num> Sub for Perplex<T> {
+impl<T: Clone + Num> Sub for Perplex<T> {
     type Output = Self;
     #[inline]
     fn sub(self, rhs: Self) -> Self::Output {
-        Self::new(self.t - rhs.t, self.x - rhs.x)
+        #[cfg(feature = "nan-debug")]
+        let operands = [self.clone(), rhs.clone()];
+        let result = Self::new(self.t - rhs.t, self.x - rhs.x);
+        #[cfg(feature = "nan-debug")]
+        crate::debug::record("sub", &operands, result.clone());
+        result
     }
 }
-impl<T: Copy + NumAssign> SubAssign for Perplex<T> {
+impl<T: Clone + NumAssign> SubAssign for Perplex<T> {
     fn sub_assign(&mut self, rhs: Self) {
+        #[cfg(feature = "nan-debug")]
+        let operands = [self.clone(), rhs.clone()];
         self.t -= rhs.t;
         self.x -= rhs.x;
+        #[cfg(feature = "nan-debug")]
+        crate::debug::record("sub_assign", &operands, self.clone());
     }
 }
 
-impl<T: Copy + Num> Mul for Perplex<T> {
+impl<T: Clone + Num> Mul for Perplex<T> {
     type Output = Self;
+    /// Multiplies `self` by `rhs` with separately rounded products, see `Perplex::mul_fused` for the fused products of floating-point components.
     #[inline]
     fn mul(self, rhs: Self) -> Self::Output {
-        Self::new(
-            self.t * rhs.t + self.x * rhs.x,
-            rhs.t * self.x + self.t * rhs.x,
-        )
+        #[cfg(feature = "nan-debug")]
+        let operands = [self.clone(), rhs.clone()];
+        let (Self { t: t1, x: x1 }, Self { t: t2, x: x2 }) = (self, rhs);
+        let result = Self::new(
+            t1.clone() * t2.clone() + x1.clone() * x2.clone(),
+            t2 * x1 + t1 * x2,
+        );
+        #[cfg(feature = "nan-debug")]
+        crate::debug::record("mul", &operands, result.clone());
+        result
     }
 }
-impl<T: Copy + NumAssign> MulAssign for Perplex<T> {
+impl<T: Clone + NumAssign> MulAssign for Perplex<T> {
     fn mul_assign(&mut self, rhs: Self) {
-        let t = self.t;
-        self.t *= rhs.t;
-        self.t += self.x * rhs.x;
-        self.x *= rhs.t;
-        self.x += t * rhs.x;
+        #[cfg(feature = "nan-debug")]
+        let operands = [self.clone(), rhs.clone()];
+        let Self { t: t2, x: x2 } = rhs;
+        let t = self.t.clone();
+        self.t *= t2.clone();
+        self.t += self.x.clone() * x2.clone();
+        self.x *= t2;
+        self.x += t * x2;
+        #[cfg(feature = "nan-debug")]
+        crate::debug::record("mul_assign", &operands, self.clone());
     }
 }
 
-impl<T: Copy + Num> Div for Perplex<T> {
-    type Output = Option<Self>;
-    /// Divides `self` by `rhs`. Division by a light-like number yields `None`, otherwise `Some(self / rhs)`.
+impl<T: Clone + Num> Div for Perplex<T> {
+    type Output = Self;
+    /// Divides `self` by `rhs`. Division by a light-like number yields infinite or NaN components for floating-point numbers and panics for integers like the division by zero, see `checked_div` for a fallible division.
     #[inline]
     fn div(self, rhs: Self) -> Self::Output {
-        let Self { t: t2, x: x2 } = rhs;
-        let norm_squared_2 = t2 * t2 - x2 * x2;
-        if norm_squared_2 == T::zero() {
+        #[cfg(feature = "nan-debug")]
+        let operands = [self.clone(), rhs.clone()];
+        let norm_squared_2 = rhs.squared_distance();
+        let (Self { t: t1, x: x1 }, Self { t: t2, x: x2 }) = (self, rhs);
+        let t_new = (t1.clone() * t2.clone() - x1.clone() * x2.clone()) / norm_squared_2.clone();
+        let x_new = (t2 * x1 - t1 * x2) / norm_squared_2;
+        let result = Self::new(t_new, x_new);
+        #[cfg(feature = "nan-debug")]
+        crate::debug::record("div", &operands, result.clone());
+        result
+    }
+}
+impl<T: Clone + Num> CheckedDiv for Perplex<T> {
+    /// Divides `self` by `v`. Division by a light-like number yields `None`, otherwise `Some(self / v)`.
+    #[inline]
+    fn checked_div(&self, v: &Self) -> Option<Self> {
+        if v.squared_distance().is_zero() {
             // light-like
             None
         } else {
-            let Self { t: t1, x: x1 } = self;
-            let t_new = (t1 * t2 - x1 * x2) / norm_squared_2;
-            let x_new = (t2 * x1 - t1 * x2) / norm_squared_2;
-            Some(Self::new(t_new, x_new))
+            Some(self.clone() / v.clone())
         }
     }
 }
-impl<T: Copy + NumAssign> DivAssign for Perplex<T> {
+impl<T: Clone + NumAssign> DivAssign for Perplex<T> {
     /// Divides `self` by `rhs` in place. Division by a light-like number yields a Perplex number with NaN components.
     fn div_assign(&mut self, rhs: Self) {
+        #[cfg(feature = "nan-debug")]
+        let operands = [self.clone(), rhs.clone()];
+        let norm_squared_2 = rhs.squared_distance();
         let Self { t: t2, x: x2 } = rhs;
-        let norm_squared_2 = t2 * t2 - x2 * x2;
-        let t = self.t;
-        self.t *= t2;
-        self.t -= self.x * x2;
-        self.t /= norm_squared_2;
+        let t = self.t.clone();
+        self.t *= t2.clone();
+        self.t -= self.x.clone() * x2.clone();
+        self.t /= norm_squared_2.clone();
         self.x *= t2;
         self.x -= t * x2;
         self.x /= norm_squared_2;
+        #[cfg(feature = "nan-debug")]
+        crate::debug::record("div_assign", &operands, self.clone());
+    }
+}
+
+impl<T: Clone + Num> Rem for Perplex<T> {
+    type Output = Self;
+    /// Computes the remainder `self - rhs * q` of the quotient `q = self / rhs` with components truncated towards zero like `%` of `T`, i.e., like the `Rem` of `Complex` of the `num-complex` crate.
+    /// Hence, the remainder of integer components is exact, and the remainder by a light-like number yields non-finite components for floating-point numbers and panics for integers like `Div`.
+    #[inline]
+    fn rem(self, rhs: Self) -> Self::Output {
+        #[cfg(feature = "nan-debug")]
+        let operands = [self.clone(), rhs.clone()];
+        let Self { t, x } = self.clone() / rhs.clone();
+        // the quotient with integer components
+        let q = Self::new(t.clone() - t % T::one(), x.clone() - x % T::one());
+        let result = self - rhs * q;
+        #[cfg(feature = "nan-debug")]
+        crate::debug::record("rem", &operands, result.clone());
+        result
     }
 }
 
 // binary between Perplex and T
-impl<T: Copy + Num> Add<T> for Perplex<T> {
+impl<T: Clone + Num> Add<T> for Perplex<T> {
     type Output = Perplex<T>;
     #[inline]
     fn add(self, other: T) -> Self::Output {
-        Self::Output::new(self.t + other, self.x)
+        #[cfg(feature = "nan-debug")]
+        let operands = [self.clone(), Perplex::new(other.clone(), T::zero())];
+        let result = Self::Output::new(self.t + other, self.x);
+        #[cfg(feature = "nan-debug")]
+        crate::debug::record("add_scalar", &operands, result.clone());
+        result
     }
 }
-impl<T: Copy + NumAssign> AddAssign<T> for Perplex<T> {
+impl<T: Clone + NumAssign> AddAssign<T> for Perplex<T> {
     fn add_assign(&mut self, rhs: T) {
+        #[cfg(feature = "nan-debug")]
+        let operands = [self.clone(), Perplex::new(rhs.clone(), T::zero())];
         self.t += rhs;
+        #[cfg(feature = "nan-debug")]
+        crate::debug::record("add_assign_scalar", &operands, self.clone());
     }
 }
 
-impl<T: Copy + Num> Sub<T> for Perplex<T> {
+impl<T: Clone + Num> Sub<T> for Perplex<T> {
     type Output = Perplex<T>;
     #[inline]
     fn sub(self, rhs: T) -> Self::Output {
-        Self::Output::new(self.t - rhs, self.x)
+        #[cfg(feature = "nan-debug")]
+        let operands = [self.clone(), Perplex::new(rhs.clone(), T::zero())];
+        let result = Self::Output::new(self.t - rhs, self.x);
+        #[cfg(feature = "nan-debug")]
+        crate::debug::record("sub_scalar", &operands, result.clone());
+        result
     }
 }
-impl<T: Copy + NumAssign> SubAssign<T> for Perplex<T> {
+impl<T: Clone + NumAssign> SubAssign<T> for Perplex<T> {
     fn sub_assign(&mut self, rhs: T) {
+        #[cfg(feature = "nan-debug")]
+        let operands = [self.clone(), Perplex::new(rhs.clone(), T::zero())];
         self.t -= rhs;
+        #[cfg(feature = "nan-debug")]
+        crate::debug::record("sub_assign_scalar", &operands, self.clone());
     }
 }
 
-impl<T: Copy + Num> Mul<T> for Perplex<T> {
+impl<T: Clone + Num> Mul<T> for Perplex<T> {
     type Output = Perplex<T>;
     #[inline]
     fn mul(self, rhs: T) -> Self::Output {
-        Self::Output::new(self.t * rhs, self.x * rhs)
+        #[cfg(feature = "nan-debug")]
+        let operands = [self.clone(), Perplex::new(rhs.clone(), T::zero())];
+        let result = Self::Output::new(self.t * rhs.clone(), self.x * rhs);
+        #[cfg(feature = "nan-debug")]
+        crate::debug::record("mul_scalar", &operands, result.clone());
+        result
     }
 }
-impl<T: Copy + NumAssign> MulAssign<T> for Perplex<T> {
+impl<T: Clone + NumAssign> MulAssign<T> for Perplex<T> {
     fn mul_assign(&mut self, rhs: T) {
-        self.t *= rhs;
+        #[cfg(feature = "nan-debug")]
+        let operands = [self.clone(), Perplex::new(rhs.clone(), T::zero())];
+        self.t *= rhs.clone();
         self.x *= rhs;
+        #[cfg(feature = "nan-debug")]
+        crate::debug::record("mul_assign_scalar", &operands, self.clone());
     }
 }
 
-impl<T: Copy + Num> Div<T> for Perplex<T> {
+impl<T: Clone + Num> Div<T> for Perplex<T> {
     type Output = Self;
     #[inline]
     fn div(self, rhs: T) -> Self::Output {
-        Self::Output::new(self.t / rhs, self.x / rhs)
+        #[cfg(feature = "nan-debug")]
+        let operands = [self.clone(), Perplex::new(rhs.clone(), T::zero())];
+        let result = Self::Output::new(self.t / rhs.clone(), self.x / rhs);
+        #[cfg(feature = "nan-debug")]
+        crate::debug::record("div_scalar", &operands, result.clone());
+        result
     }
 }
-impl<T: Copy + NumAssign> DivAssign<T> for Perplex<T> {
+impl<T: Clone + NumAssign> DivAssign<T> for Perplex<T> {
     fn div_assign(&mut self, rhs: T) {
-        self.t /= rhs;
+        #[cfg(feature = "nan-debug")]
+        let operands = [self.clone(), Perplex::new(rhs.clone(), T::zero())];
+        self.t /= rhs.clone();
         self.x /= rhs;
+        #[cfg(feature = "nan-debug")]
+        crate::debug::record("div_assign_scalar", &operands, self.clone());
     }
 }
 
+// binary between references of Perplex and T, which clone the operands
+macro_rules! forward_ref_binop {
+    ($imp:ident, $method:ident, $rhs:ty, $output:ty, [$($bound:tt)*]) => {
+        impl<'a, 'b, T: $($bound)*> $imp<&'b $rhs> for &'a Perplex<T> {
+            type Output = $output;
+            #[inline]
+            fn $method(self, rhs: &'b $rhs) -> Self::Output {
+                self.clone().$method(rhs.clone())
+            }
+        }
+        impl<'b, T: $($bound)*> $imp<&'b $rhs> for Perplex<T> {
+            type Output = $output;
+            #[inline]
+            fn $method(self, rhs: &'b $rhs) -> Self::Output {
+                self.$method(rhs.clone())
+            }
+        }
+        impl<'a, T: $($bound)*> $imp<$rhs> for &'a Perplex<T> {
+            type Output = $output;
+            #[inline]
+            fn $method(self, rhs: $rhs) -> Self::Output {
+                self.clone().$method(rhs)
+            }
+        }
+    };
+}
+
+forward_ref_binop!(Add, add, Perplex<T>, Perplex<T>, [Clone + Num]);
+forward_ref_binop!(Sub, sub, Perplex<T>, Perplex<T>, [Clone + Num]);
+forward_ref_binop!(Mul, mul, Perplex<T>, Perplex<T>, [Clone + Num]);
+forward_ref_binop!(Div, div, Perplex<T>, Perplex<T>, [Clone + Num]);
+forward_ref_binop!(Rem, rem, Perplex<T>, Perplex<T>, [Clone + Num]);
+forward_ref_binop!(Add, add, T, Perplex<T>, [Clone + Num]);
+forward_ref_binop!(Sub, sub, T, Perplex<T>, [Clone + Num]);
+forward_ref_binop!(Mul, mul, T, Perplex<T>, [Clone + Num]);
+forward_ref_binop!(Div, div, T, Perplex<T>, [Clone + Num]);
+
+// assignment by references of Perplex and T, which clone the operand
+macro_rules! forward_ref_op_assign {
+    ($($imp:ident, $method:ident);*) => {$(
+        impl<'a, T: Clone + NumAssign> $imp<&'a Perplex<T>> for Perplex<T> {
+            #[inline]
+            fn $method(&mut self, rhs: &'a Perplex<T>) {
+                self.$method(rhs.clone())
+            }
+        }
+        impl<'a, T: Clone + NumAssign> $imp<&'a T> for Perplex<T> {
+            #[inline]
+            fn $method(&mut self, rhs: &'a T) {
+                self.$method(rhs.clone())
+            }
+        }
+    )*};
+}
+
+forward_ref_op_assign!(
+    AddAssign, add_assign;
+    SubAssign, sub_assign;
+    MulAssign, mul_assign;
+    DivAssign, div_assign
+);
+
+// binary between T and Perplex
+macro_rules! scalar_lhs_ops {
+    ($($real:ty),*) => {$(
+        impl Add<Perplex<$real>> for $real {
+            type Output = Perplex<$real>;
+            #[inline]
+            fn add(self, rhs: Perplex<$real>) -> Self::Output {
+                rhs + self
+            }
+        }
+        impl Sub<Perplex<$real>> for $real {
+            type Output = Perplex<$real>;
+            #[inline]
+            fn sub(self, rhs: Perplex<$real>) -> Self::Output {
+                Perplex::new(self, 0.0) - rhs
+            }
+        }
+        impl Mul<Perplex<$real>> for $real {
+            type Output = Perplex<$real>;
+            #[inline]
+            fn mul(self, rhs: Perplex<$real>) -> Self::Output {
+                rhs * self
+            }
+        }
+        impl Div<Perplex<$real>> for $real {
+            type Output = Perplex<$real>;
+            /// Divides the scalar `self` by `rhs`. Division by a light-like number yields non-finite components like the division of perplex numbers.
+            #[inline]
+            fn div(self, rhs: Perplex<$real>) -> Self::Output {
+                Perplex::new(self, 0.0) / rhs
+            }
+        }
+        scalar_lhs_ref_ops!($real; Add, add, Perplex<$real>; Sub, sub, Perplex<$real>; Mul, mul, Perplex<$real>; Div, div, Perplex<$real>);
+    )*};
+}
+
+// binary between references of T and Perplex
+macro_rules! scalar_lhs_ref_ops {
+    ($real:ty; $($imp:ident, $method:ident, $output:ty);*) => {$(
+        impl<'a, 'b> $imp<&'b Perplex<$real>> for &'a $real {
+            type Output = $output;
+            #[inline]
+            fn $method(self, rhs: &'b Perplex<$real>) -> Self::Output {
+                (*self).$method(*rhs)
+            }
+        }
+        impl<'b> $imp<&'b Perplex<$real>> for $real {
+            type Output = $output;
+            #[inline]
+            fn $method(self, rhs: &'b Perplex<$real>) -> Self::Output {
+                self.$method(*rhs)
+            }
+        }
+        impl<'a> $imp<Perplex<$real>> for &'a $real {
+            type Output = $output;
+            #[inline]
+            fn $method(self, rhs: Perplex<$real>) -> Self::Output {
+                (*self).$method(rhs)
+            }
+        }
+    )*};
+}
+
+scalar_lhs_ops!(f32, f64);
+
 // tertiary ops between three Perplex
-impl<T: Copy + Num + MulAdd<Output = T>> MulAdd<Perplex<T>> for Perplex<T> {
+impl<T: Clone + Num + MulAdd<Output = T>> MulAdd<Perplex<T>> for Perplex<T> {
     type Output = Perplex<T>;
     #[inline]
     fn mul_add(self, other: Perplex<T>, add: Perplex<T>) -> Self {
-        let t = self.t * other.t + self.x * other.x + add.t;
-        let x = other.t * self.x + self.t * other.x + add.x;
-        Self::new(t, x)
+        #[cfg(feature = "nan-debug")]
+        let operands = [self.clone(), other.clone(), add.clone()];
+        let (Self { t: t1, x: x1 }, Self { t: t2, x: x2 }) = (self, other);
+        // fused for floating-point components, whose products are rounded only once with the summand
+        let t = t1
+            .clone()
+            .mul_add(t2.clone(), x1.clone().mul_add(x2.clone(), add.t));
+        let x = t1.mul_add(x2, x1.mul_add(t2, add.x));
+        let result = Self::new(t, x);
+        #[cfg(feature = "nan-debug")]
+        crate::debug::record("mul_add", &operands, result.clone());
+        result
     }
 }
-impl<T: Copy + NumAssign + MulAddAssign> MulAddAssign for Perplex<T> {
+impl<T: Clone + NumAssign + MulAddAssign> MulAddAssign for Perplex<T> {
     fn mul_add_assign(&mut self, other: Self, add: Self) {
-        let t = self.t;
-        self.t *= other.t;
-        self.t += self.x * other.x + add.t;
-        self.x *= other.t;
-        self.x += t * other.x + add.x;
+        #[cfg(feature = "nan-debug")]
+        let operands = [self.clone(), other.clone(), add.clone()];
+        let Self { t: t2, x: x2 } = other;
+        let mut t = self.x.clone();
+        t.mul_add_assign(x2.clone(), add.t);
+        let mut x = self.t.clone();
+        x.mul_add_assign(x2, add.x);
+        self.t.mul_add_assign(t2.clone(), t);
+        self.x.mul_add_assign(t2, x);
+        #[cfg(feature = "nan-debug")]
+        crate::debug::record("mul_add_assign", &operands, self.clone());
     }
 }
 
@@ -247,24 +499,24 @@ mod tests {
         let one = Perplex::one();
         let zero = Perplex::zero();
         assert_eq!(
-            (z1 / one).unwrap(),
+            z1 / one,
             z1,
             "Division of neutral element of multiplication yields same element!"
         );
         assert!(
-            (z1 / zero).is_none(),
+            z1.checked_div(&zero).is_none(),
             "Division of neutral element of addition yields none!"
         );
         let z2 = Perplex::new(-1.0, 2.0);
         let mut z12 = z1 * z2;
         let div_result = z12 / z2;
-        assert!(
-            div_result.is_some(),
+        assert_eq!(
+            z12.checked_div(&z2),
+            Some(div_result),
             "Division of product by multiplier is valid!"
         );
         assert_eq!(
-            div_result.unwrap(),
-            z1,
+            div_result, z1,
             "Division of product by multiplier gives multiplicand."
         );
         z12 /= z2;
@@ -275,9 +527,14 @@ mod tests {
         assert_eq!(z12, Perplex::new(1.0, -1.0), "Multiplication formula!");
         assert!(z2.is_light_like(), "-1 + j is light-like!");
         assert!(
-            (z12 / z2).is_none(),
+            z12.checked_div(&z2).is_none(),
             "Division is not defined for light-like numbers!"
         );
+        let div_result = z12 / z2;
+        assert!(
+            div_result.t.is_nan() && div_result.x.is_nan(),
+            "Div for light-like number yields NaN!"
+        );
         z12 /= z2;
         assert!(
             z12.t.is_nan() && z12.x.is_nan(),
@@ -285,6 +542,41 @@ mod tests {
         );
     }
     #[test]
+    fn test_rem() {
+        let (z, w) = (Perplex::new(7.5, 1.0), Perplex::new(2.0, 0.5));
+        let r = z % w;
+        // the quotient 3.87 - 0.47 h truncated to 3
+        assert_eq!(r, Perplex::new(1.5, -0.5));
+        assert_eq!((z - r) / w, Perplex::new(3.0, 0.0), "Integer quotient!");
+        assert!(
+            !(z % Perplex::new(1.0, 1.0)).is_finite(),
+            "Light-like divisor!"
+        );
+        let negative = Perplex::new(-3.0, 0.0) % Perplex::new(2.0, 0.0);
+        assert_eq!(negative, Perplex::new(-1.0, 0.0), "Sign of the dividend!");
+        // exact remainder of integer components
+        let (z, w) = (Perplex::new(7, 3), Perplex::new(2, 1));
+        assert_eq!(z % w, Perplex::new(1, 0));
+        assert_eq!(z / w * w + z % w, z, "Division with remainder!");
+        assert_eq!(Perplex::new(-7, 3) % w, Perplex::new(-1, 0));
+    }
+    #[test]
+    fn test_num() {
+        fn remainder_of_parsed<N: Num>(a: &str, b: &str) -> N {
+            let (a, b) = (N::from_str_radix(a, 10), N::from_str_radix(b, 10));
+            a.ok().unwrap() % b.ok().unwrap()
+        }
+        let r: Perplex<i64> = remainder_of_parsed("7 + 3 h", "2 + 1 h");
+        assert_eq!(r, Perplex::new(1, 0));
+        // nesting of perplex numbers
+        let (z, w) = (
+            Perplex::new(Perplex::new(1.0, 2.0), Perplex::new(0.5, 0.0)),
+            Perplex::new(Perplex::new(3.0, 0.0), Perplex::new(1.0, 1.0)),
+        );
+        assert_eq!(z * w / w, z);
+        assert_eq!(Perplex::<Perplex<f64>>::one() * z, z);
+    }
+    #[test]
     fn test_scalar() {
         let z1 = Perplex::new(1.0, 2.0);
         assert_eq!(
@@ -309,6 +601,23 @@ mod tests {
         );
     }
     #[test]
+    fn test_scalar_lhs() {
+        let z = Perplex::new(1.0, 2.0);
+        assert_eq!(2.0 + z, z + 2.0, "Addition commutes!");
+        assert_eq!(2.0 - z, Perplex::new(1.0, -2.0));
+        assert_eq!(2.0 * z, z * 2.0, "Multiplication commutes!");
+        assert_eq!(3.0 / z, Perplex::new(-1.0, 2.0));
+        assert!(
+            !(1.0 / Perplex::new(1.0f64, -1.0)).is_finite(),
+            "Light-like divisor!"
+        );
+        let w = Perplex::new(0.5f32, 0.25);
+        assert_eq!(
+            1.0 - 2.0 * w + w * w,
+            (Perplex::new(1.0, 0.0) - w) * (1.0 - w)
+        );
+    }
+    #[test]
     fn test_scalar_assign() {
         let mut z1 = Perplex::new(1.0, 2.0);
         z1 += 2.0;
@@ -350,4 +659,75 @@ mod tests {
         );
         assert_eq!(z, z1, "MulAddAssign yields same result as MulAdd!");
     }
+    #[test]
+    fn test_heap_components() {
+        use num_rational::BigRational;
+        let r = |n: i64, d: i64| BigRational::new(n.into(), d.into());
+        let (z, w) = (
+            Perplex::new(r(1, 3), r(-1, 2)),
+            Perplex::new(r(7, 5), r(2, 9)),
+        );
+        let product = &z * &w;
+        assert_eq!(&product / &w, z, "Exact division!");
+        assert_eq!(&z + &w - w.clone(), z);
+        let mut z2 = z.clone();
+        z2 *= w.clone();
+        assert_eq!(z2, product, "MulAssign yields same result as Mul!");
+        z2 /= w.clone();
+        assert_eq!(z2, z);
+        z2 += r(1, 1);
+        assert_eq!(z2, &z + &Perplex::one());
+        assert_eq!(z.clone() * r(3, 1), Perplex::new(r(1, 1), r(-3, 2)));
+        assert_eq!(-z.clone() + z.clone(), Perplex::zero());
+        assert_eq!(z.powi(-2).unwrap() * z.powu(2), Perplex::one());
+        assert_eq!(z.pow_const::<3>(), &(&z * &z) * &z);
+        assert_eq!(z.to_string(), "1/3 - 1/2 h");
+        // exact beyond the range of primitive types
+        let large = Perplex::new(r(1, 1), r(1, 3)).powu(200);
+        assert_eq!(large.squared_distance(), r(8, 9).pow(200));
+        assert!(large.is_time_like());
+        let mut z3 = z.clone();
+        z3 -= &w;
+        z3 *= &r(2, 1);
+        assert_eq!(z3, (&z - &w) * &r(2, 1), "Assignment by reference!");
+    }
+    #[test]
+    #[allow(clippy::op_ref)]
+    fn test_references() {
+        let (z, w) = (Perplex::new(1.5, -0.5), Perplex::new(2.0, 1.0));
+        let values = [z, w, Perplex::new(-0.25, 3.0)];
+        // operators within iterators without dereferencing
+        let sum = values.iter().fold(Perplex::zero(), |sum, z| sum + z);
+        assert_eq!(sum, z + w + values[2]);
+        let products: Vec<_> = values
+            .iter()
+            .zip(&values[1..])
+            .map(|(a, b)| a * b)
+            .collect();
+        assert_eq!(products, vec![z * w, w * values[2]]);
+        assert_eq!(&z + &w, z + w);
+        assert_eq!(&z - w, z - w);
+        assert_eq!(z * &w, z * w);
+        assert_eq!(&z / &w, z / w);
+        assert_eq!(&z % &w, z % w);
+        assert_eq!(&z + &2.0, z + 2.0);
+        assert_eq!(&z - 2.0, z - 2.0);
+        assert_eq!(z * &2.0, z * 2.0);
+        assert_eq!(&z / 2.0, z / 2.0);
+        assert_eq!(&2.0 + &z, 2.0 + z);
+        assert_eq!(2.0 - &z, 2.0 - z);
+        assert_eq!(&2.0 * z, 2.0 * z);
+        assert_eq!(
+            &2.0f32 / &Perplex::new(1.0f32, 0.5),
+            2.0f32 / Perplex::new(1.0, 0.5)
+        );
+        assert_eq!(-&z, -z);
+        assert_eq!(Inv::inv(&w), w.inv());
+        let mut z2 = z;
+        z2 += &w;
+        z2 -= &1.0;
+        z2 *= &w;
+        z2 /= &4.0;
+        assert_eq!(z2, (z + w - 1.0) * w / 4.0, "Assignment by reference!");
+    }
 }
@@ -0,0 +1,231 @@
+//! # Blaschke Module
+//!
+//! This module provides the hyperbolic analogues of Blaschke factors and Blaschke products for experiments with the function theory of the perplex numbers.
+//! The role of the unit disc is played by the unit square `D = {z : |t| + |x| < 1}`, i.e., the product of the open unit intervals of the null coordinates `u = t + x` and `v = t - x`, whose boundary consists of the four light-like segments `u = ±1` and `v = ±1`.
+//! The Blaschke factor `(z - a) / (1 - a z)` with a zero `a` in `D` acts as the real Blaschke factor `(u - a_u) / (1 - a_u u)` of the unit interval on both null coordinates, see `Mobius::blaschke`, and hence maps `D` onto itself and its boundary onto its boundary.
+//! Products of these factors are the inner functions of `D` in the sense that their null coordinates are real inner functions of the unit interval: both map `±1` to `±1`.
+//!
+//! ## Features
+//! - `in_unit_square`: The membership test of `D` in the null coordinates, which is exact for rational components.
+//! - `pseudo_hyperbolic`: The perplex pseudo-hyperbolic distance `(z - w) / (1 - z w)`, whose null coordinates are invariant up to sign under Blaschke factors.
+//! - `BlaschkeProduct`: A finite Blaschke product `e ∏ (z - a_k) / (1 - a_k z)` with a unit `e` in `{±1, ±h}`, its factors, evaluation and multiplication.
+//! - `boundary_defect`: The deviation of a function from an inner function on sampled points of the boundary of `D`.
+//!
+//! ## Example
+//! ```
+//! use approx::assert_abs_diff_eq;
+//! use perplex_num::blaschke::{boundary_defect, BlaschkeProduct};
+//! use perplex_num::Perplex;
+//! let b = BlaschkeProduct::from_zeros(vec![Perplex::new(0.25, 0.5), Perplex::new(-0.5, 0.0)]).unwrap();
+//! assert_eq!(b.evaluate(Perplex::new(0.25, 0.5)), Some(Perplex::new(0.0, 0.0)));
+//! // the boundary of the unit square is mapped onto itself
+//! let defect = boundary_defect(|z| b.evaluate(z), 16).unwrap();
+//! assert_abs_diff_eq!(defect, 0.0, epsilon = 1e-14);
+//! ```
+
+use super::null_basis::NullBasis;
+use super::{Mobius, Perplex};
+use core::ops::{Mul, Neg};
+use num_traits::{Float, Num, One};
+
+/// Checks if `z` is in the open unit square `|t| + |x| < 1`, i.e., if both null coordinates are in the open unit interval.
+#[inline]
+pub fn in_unit_square<T: Copy + Num + Neg<Output = T> + PartialOrd>(z: Perplex<T>) -> bool {
+    let NullBasis { plus, minus } = NullBasis::from(z);
+    let one = T::one();
+    -one < plus && plus < one && -one < minus && minus < one
+}
+
+/// Returns the pseudo-hyperbolic distance `(z - w) / (1 - z w)`, or `None` if `1 - z w` is light-like.
+///
+/// The null coordinates are the real pseudo-hyperbolic distances of the null coordinates of `z` and `w`, whose absolute values are invariant under Blaschke factors, i.e., the perplex Schwarz-Pick lemma holds with equality.
+#[inline]
+pub fn pseudo_hyperbolic<T: Copy + Num + Neg<Output = T>>(
+    z: Perplex<T>,
+    w: Perplex<T>,
+) -> Option<Perplex<T>> {
+    Some((z - w) * (Perplex::<T>::one() - z * w).try_inverse()?)
+}
+
+/// A finite Blaschke product `e ∏ (z - a_k) / (1 - a_k z)` with zeros `a_k` in the unit square and a unit `e` in `{±1, ±h}`.
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+pub struct BlaschkeProduct<T> {
+    unit: Perplex<T>,
+    zeros: Vec<Perplex<T>>,
+}
+
+impl<T: Copy + Num + Neg<Output = T> + PartialOrd> BlaschkeProduct<T> {
+    /// Creates the Blaschke product with the unit `unit` and the zeros `zeros`, or returns `None` if `unit` is not in `{±1, ±h}` or a zero is not in the unit square.
+    ///
+    /// The unit `h` reflects the null coordinate `v` of the values, and `-h` reflects `u`.
+    #[inline]
+    pub fn new(unit: Perplex<T>, zeros: Vec<Perplex<T>>) -> Option<Self> {
+        let NullBasis { plus, minus } = NullBasis::from(unit);
+        let is_sign = |w: T| w * w == T::one();
+        if !(is_sign(plus) && is_sign(minus) && zeros.iter().all(|&a| in_unit_square(a))) {
+            return None;
+        }
+        Some(Self { unit, zeros })
+    }
+    /// Creates the Blaschke product with the unit `1` and the zeros `zeros`, see `new`.
+    #[inline]
+    pub fn from_zeros(zeros: Vec<Perplex<T>>) -> Option<Self> {
+        Self::new(Perplex::one(), zeros)
+    }
+    /// Returns the unit `e`.
+    #[inline]
+    pub fn unit(&self) -> Perplex<T> {
+        self.unit
+    }
+    /// Returns the zeros `a_k` with multiplicity.
+    #[inline]
+    pub fn zeros(&self) -> &[Perplex<T>] {
+        &self.zeros
+    }
+    /// Returns the number of factors, which is the degree of the real Blaschke products of both null coordinates.
+    #[inline]
+    pub fn degree(&self) -> usize {
+        self.zeros.len()
+    }
+    /// Returns the Blaschke factors `(z - a_k) / (1 - a_k z)` as Möbius transformations.
+    #[inline]
+    pub fn factors(&self) -> impl Iterator<Item = Mobius<T>> + '_ {
+        self.zeros
+            .iter()
+            .map(|&a| Mobius::new(Perplex::one(), -a, -a, Perplex::one()))
+    }
+    /// Evaluates the product at `z`, or returns `None` if a denominator `1 - a_k z` is light-like, which does not occur on the closed unit square.
+    #[inline]
+    pub fn evaluate(&self, z: Perplex<T>) -> Option<Perplex<T>> {
+        self.factors().try_fold(self.unit, |product, factor| {
+            Some(product * factor.apply(z)?)
+        })
+    }
+}
+
+impl<T: Copy + Num + Neg<Output = T> + PartialOrd> Mul for BlaschkeProduct<T> {
+    type Output = Self;
+    /// Returns the pointwise product, which is the Blaschke product with the product of the units and the zeros of both.
+    #[inline]
+    fn mul(mut self, rhs: Self) -> Self::Output {
+        self.unit = self.unit * rhs.unit;
+        self.zeros.extend(rhs.zeros);
+        self
+    }
+}
+
+/// Returns the maximal deviation `||f_u(z)| - 1|` on the sides `u = ±1` and `||f_v(z)| - 1|` on the sides `v = ±1` of the unit square, where `f_u` and `f_v` are the null coordinates of `f(z)`, or `None` if `f` is undefined at a sampled point.
+///
+/// Each side is sampled at the midpoints of `samples` intervals of equal length, and the defect of an inner function of the unit square, e.g., a `BlaschkeProduct`, is zero up to rounding.
+pub fn boundary_defect<T, F>(f: F, samples: usize) -> Option<T>
+where
+    T: Float,
+    F: Fn(Perplex<T>) -> Option<Perplex<T>>,
+{
+    let one = T::one();
+    let two = one + one;
+    let n = T::from(samples)?;
+    let mut defect = T::zero();
+    for k in 0..samples {
+        let s = -one + (two * T::from(k)? + one) / n;
+        for side in [one, -one] {
+            let value = NullBasis::from(f(Perplex::from(NullBasis::new(side, s)))?);
+            defect = defect.max((value.plus.abs() - one).abs());
+            let value = NullBasis::from(f(Perplex::from(NullBasis::new(s, side)))?);
+            defect = defect.max((value.minus.abs() - one).abs());
+        }
+    }
+    Some(defect)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_abs_diff_eq;
+    use num_rational::Ratio;
+    #[test]
+    fn test_unit_square() {
+        assert!(in_unit_square(Perplex::new(0.5, 0.25)));
+        assert!(in_unit_square(Perplex::new(-0.25, 0.7)));
+        assert!(!in_unit_square(Perplex::new(0.5, 0.5)), "Boundary!");
+        assert!(!in_unit_square(Perplex::new(0.0, -1.5)));
+        assert!(Mobius::blaschke(Perplex::new(0.5, -0.5)).is_none());
+        let m = Mobius::blaschke(Perplex::new(0.25, 0.5)).unwrap();
+        assert_eq!(
+            m.apply(Perplex::new(0.25, 0.5)),
+            Some(Perplex::new(0.0, 0.0))
+        );
+        // the corners of the unit square are fixed, and the boundary is preserved
+        for corner in [(1.0, 0.0), (-1.0, 0.0), (0.0, 1.0), (0.0, -1.0)] {
+            let corner = Perplex::new(corner.0, corner.1);
+            assert_abs_diff_eq!(m.apply(corner).unwrap(), corner, epsilon = 1e-15);
+        }
+        assert!(boundary_defect(|z| m.apply(z), 32).unwrap() < 1e-15);
+        assert!(boundary_defect(|z| Some(z * 0.5), 4).unwrap() == 0.5);
+    }
+    #[test]
+    fn test_pseudo_hyperbolic() {
+        let (z, w) = (Perplex::new(0.3, -0.4), Perplex::new(-0.1, 0.6));
+        let m = Mobius::blaschke(Perplex::new(0.2, 0.1)).unwrap();
+        let before = NullBasis::from(pseudo_hyperbolic(z, w).unwrap());
+        let after =
+            NullBasis::from(pseudo_hyperbolic(m.apply(z).unwrap(), m.apply(w).unwrap()).unwrap());
+        assert_abs_diff_eq!(before.plus.abs(), after.plus.abs(), epsilon = 1e-15);
+        assert_abs_diff_eq!(before.minus.abs(), after.minus.abs(), epsilon = 1e-15);
+        assert!(pseudo_hyperbolic(Perplex::new(1.0, 0.0), Perplex::new(1.0, 0.0)).is_none());
+    }
+    #[test]
+    fn test_blaschke_product() {
+        let half = Ratio::new(1, 2);
+        let quarter = Ratio::new(1, 4);
+        let zeros = vec![
+            Perplex::new(quarter, half),
+            Perplex::new(-half, Ratio::from(0)),
+        ];
+        let b = BlaschkeProduct::from_zeros(zeros.clone()).unwrap();
+        assert_eq!(b.degree(), 2);
+        assert_eq!(b.zeros(), zeros.as_slice());
+        for &a in &zeros {
+            assert_eq!(b.evaluate(a), Some(Perplex::new(0.into(), 0.into())));
+        }
+        // the exact values agree with the factors of the null coordinates
+        let z = Perplex::new(Ratio::new(1, 3), Ratio::new(-1, 5));
+        let real = |a: Ratio<i64>, u: Ratio<i64>| (u - a) / (Ratio::from(1) - a * u);
+        let (nz, n0, n1) = (
+            NullBasis::from(z),
+            NullBasis::from(zeros[0]),
+            NullBasis::from(zeros[1]),
+        );
+        let expected = NullBasis::new(
+            real(n0.plus, nz.plus) * real(n1.plus, nz.plus),
+            real(n0.minus, nz.minus) * real(n1.minus, nz.minus),
+        );
+        assert_eq!(NullBasis::from(b.evaluate(z).unwrap()), expected);
+        // units and products
+        let h = Perplex::new(0.into(), 1.into());
+        let c = BlaschkeProduct::new(h, vec![z]).unwrap();
+        let product = b.clone() * c.clone();
+        assert_eq!(product.unit(), h);
+        assert_eq!(product.degree(), 3);
+        assert_eq!(
+            product.evaluate(z.conj()),
+            Some(b.evaluate(z.conj()).unwrap() * c.evaluate(z.conj()).unwrap())
+        );
+        assert!(BlaschkeProduct::new(Perplex::new(half, half), vec![]).is_none());
+        assert!(BlaschkeProduct::from_zeros(vec![Perplex::new(half, half)]).is_none());
+    }
+    #[test]
+    fn test_inner() {
+        let zeros = vec![
+            Perplex::new(0.1, 0.7),
+            Perplex::new(-0.4, -0.3),
+            Perplex::new(0.0, 0.0),
+        ];
+        let b = BlaschkeProduct::new(Perplex::new(0.0, -1.0), zeros).unwrap();
+        assert!(boundary_defect(|z| b.evaluate(z), 64).unwrap() < 1e-14);
+        // the unit square is mapped into itself
+        for (t, x) in [(0.3, 0.2), (-0.6, 0.3), (0.05, -0.9)] {
+            assert!(in_unit_square(b.evaluate(Perplex::new(t, x)).unwrap()));
+        }
+    }
+}
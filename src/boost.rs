@@ -0,0 +1,315 @@
+//! # Boost Module
+//!
+//! This module provides the `UnitPerplex` type for perplex numbers on the right branch of the unit hyperbola `t^2 - x^2 = 1, t > 0`, i.e., `cosh(theta) + h sinh(theta)` with the rapidity `theta`.
+//! Multiplication by a unit perplex number is a Lorentz boost of the Minkowski plane, see Sec. 3.2 in [The Mathematics of Minkowski Space-Time](https://doi.org/10.1007/978-3-7643-8614-6). The type ties the perplex unit hyperbola to the standard parameterizations of special relativity in natural units (`c = 1`): the rapidity `theta`, the velocity `beta = tanh(theta)` and the Lorentz factor `gamma = cosh(theta)`.
+//!
+//! ## Features
+//! - Constructors from the rapidity, the velocity and the pair `(gamma, beta)`, whereby the latter two validate their domains and return `None` for invalid inputs.
+//! - Composition of boosts by multiplication, which realizes the relativistic addition of velocities.
+//! - `compose_boosts`: The product of long kinematic chains with periodic renormalization to the unit hyperbola.
+//! - `hyperbolic_mean`: The intrinsic (Karcher) mean of boosts, which averages the rapidities instead of the components.
+//!
+//! ## Example
+//! ```
+//! use approx::assert_abs_diff_eq;
+//! use perplex_num::{Perplex, UnitPerplex};
+//! let boost = UnitPerplex::from_velocity(0.6).unwrap();
+//! assert_abs_diff_eq!(boost.gamma(), 1.25, epsilon = 1e-15);
+//! assert_abs_diff_eq!((boost * boost).velocity(), 1.2 / 1.36, epsilon = 1e-15);
+//! // the boost of an event at rest in the origin of space
+//! assert_abs_diff_eq!(boost.apply(Perplex::new(1.0, 0.0)), Perplex::new(1.25, 0.75), epsilon = 1e-15);
+//! assert!(UnitPerplex::from_velocity(1.0).is_none());
+//! ```
+
+use super::Perplex;
+use core::ops::Mul;
+use num_traits::Float;
+
+/// A perplex number `cosh(theta) + h sinh(theta)` on the right branch of the unit hyperbola, which represents a Lorentz boost with the rapidity `theta`.
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct UnitPerplex<T> {
+    z: Perplex<T>,
+}
+
+impl<T: Copy + Float> UnitPerplex<T> {
+    /// Creates the boost with the rapidity `rapidity`.
+    #[inline]
+    pub fn from_rapidity(rapidity: T) -> Self {
+        Self {
+            z: Perplex::new(rapidity.cosh(), rapidity.sinh()),
+        }
+    }
+    /// Creates the boost with the velocity `velocity` in units of the speed of light, or returns `None` unless `|velocity| < 1`.
+    #[inline]
+    pub fn from_velocity(velocity: T) -> Option<Self> {
+        if velocity.abs() < T::one() {
+            // (1 - v)(1 + v) is exact close to |v| = 1
+            let gamma = ((T::one() - velocity) * (T::one() + velocity))
+                .sqrt()
+                .recip();
+            Some(Self {
+                z: Perplex::new(gamma, gamma * velocity),
+            })
+        } else {
+            None
+        }
+    }
+    /// Creates the boost with the Lorentz factor `gamma` and the velocity `beta`, or returns `None` unless `|beta| < 1` and `gamma = 1 / sqrt(1 - beta^2)` up to a relative tolerance of the square root of the machine epsilon.
+    #[inline]
+    pub fn from_gamma_beta(gamma: T, beta: T) -> Option<Self> {
+        let boost = Self::from_velocity(beta)?;
+        if (gamma - boost.gamma()).abs() <= T::epsilon().sqrt() * boost.gamma() {
+            Some(boost)
+        } else {
+            None
+        }
+    }
+    /// Normalizes a time-like number of the right sector to the unit hyperbola, or returns `None` if `z` is not in the right sector.
+    #[inline]
+    pub fn from_perplex(z: Perplex<T>) -> Option<Self> {
+        if z.t > z.x.abs() {
+            let modulus = z.modulus();
+            Some(Self {
+                z: Perplex::new(z.t / modulus, z.x / modulus),
+            })
+        } else {
+            None
+        }
+    }
+    /// Returns the boost with the rapidity zero.
+    #[inline]
+    pub fn identity() -> Self {
+        Self {
+            z: Perplex::new(T::one(), T::zero()),
+        }
+    }
+    /// Returns the rapidity `theta = asinh(gamma beta)`, which is equal to `atanh(beta)` but does not round to infinity for velocities close to one.
+    #[inline]
+    pub fn rapidity(&self) -> T {
+        self.z.x.asinh()
+    }
+    /// Returns the velocity `beta = tanh(theta)` in units of the speed of light.
+    #[inline]
+    pub fn velocity(&self) -> T {
+        self.z.x / self.z.t
+    }
+    /// Returns the Lorentz factor `gamma = cosh(theta)`.
+    #[inline]
+    pub fn gamma(&self) -> T {
+        self.z.t
+    }
+    /// Returns the perplex number `gamma + h gamma beta`.
+    #[inline]
+    pub fn perplex(&self) -> Perplex<T> {
+        self.z
+    }
+    /// Returns the inverse boost, i.e., the hyperbolic conjugate with the negated rapidity.
+    #[inline]
+    pub fn inverse(&self) -> Self {
+        Self { z: self.z.conj() }
+    }
+    /// Boosts the event `z` by multiplication.
+    #[inline]
+    pub fn apply(&self, z: Perplex<T>) -> Perplex<T> {
+        self.z * z
+    }
+}
+
+impl<T: Copy + Float> Mul for UnitPerplex<T> {
+    type Output = Self;
+    /// Composes two boosts, whose rapidities add.
+    #[inline]
+    fn mul(self, rhs: Self) -> Self::Output {
+        Self { z: self.z * rhs.z }
+    }
+}
+
+impl<T: Copy + Float> From<T> for UnitPerplex<T> {
+    /// Creates the boost with the rapidity `rapidity`.
+    #[inline]
+    fn from(rapidity: T) -> Self {
+        Self::from_rapidity(rapidity)
+    }
+}
+
+impl<T> From<UnitPerplex<T>> for Perplex<T> {
+    #[inline]
+    fn from(boost: UnitPerplex<T>) -> Self {
+        boost.z
+    }
+}
+
+/// Composes the chain of boosts, given as `UnitPerplex` or as rapidities, by multiplication and returns the composite boost together with an estimate of the accumulated drift.
+///
+/// The product is accumulated in the null basis `u = t + x = e^theta` and `v = t - x = e^-theta`, in which the factors multiply componentwise and the unit hyperbola is `u v = 1`.
+/// The null component of each factor whose sum `t + |x|` does not cancel is the larger one, and the other one is its reciprocal, such that large rapidities do not cancel in the difference `t - |x|`.
+/// Rounding errors move the product of many factors off the unit hyperbola. Hence, the product is divided by its modulus `sqrt(u v)` after every `renormalize_every` factors and at the end of the chain, whereby the drift `|u v - 1|` of the squared distance before each renormalization is accumulated.
+/// The value zero of `renormalize_every` only renormalizes at the end of the chain.
+///
+/// # Examples
+///
+/// ```
+/// use approx::assert_abs_diff_eq;
+/// use perplex_num::boost::compose_boosts;
+/// let (boost, drift) = compose_boosts(vec![0.01; 10_000], 64);
+/// assert_abs_diff_eq!(boost.rapidity(), 100.0, epsilon = 1e-9);
+/// assert!(drift < 1e-9);
+/// ```
+pub fn compose_boosts<T, I>(boosts: I, renormalize_every: usize) -> (UnitPerplex<T>, T)
+where
+    T: Copy + Float,
+    I: IntoIterator,
+    I::Item: Into<UnitPerplex<T>>,
+{
+    let (mut u, mut v) = (T::one(), T::one());
+    let mut drift = T::zero();
+    let mut renormalize = |u: &mut T, v: &mut T| {
+        let squared_distance = *u * *v;
+        drift = drift + (squared_distance - T::one()).abs();
+        let modulus = squared_distance.sqrt();
+        *u = *u / modulus;
+        *v = *v / modulus;
+    };
+    for (i, boost) in boosts.into_iter().enumerate() {
+        let Perplex { t, x } = boost.into().z;
+        let larger = t + x.abs();
+        let (u_i, v_i) = if x.is_sign_negative() {
+            (larger.recip(), larger)
+        } else {
+            (larger, larger.recip())
+        };
+        u = u * u_i;
+        v = v * v_i;
+        if renormalize_every > 0 && (i + 1) % renormalize_every == 0 {
+            renormalize(&mut u, &mut v);
+        }
+    }
+    renormalize(&mut u, &mut v);
+    let half = (T::one() + T::one()).recip();
+    let z = Perplex::new((u + v) * half, (u - v) * half);
+    (UnitPerplex { z }, drift)
+}
+
+/// Computes the intrinsic (Karcher) mean of the boosts `samples`, i.e., the boost `m` minimizing the sum of the squared hyperbolic distances `|theta(m^-1 z)|^2` to the samples, or returns `None` if there are no samples.
+///
+/// The arithmetic mean of the components of unit perplex numbers is not on the unit hyperbola, and its normalization is biased towards samples with large rapidities.
+/// Since the unit hyperbola is isometric to the real line of rapidities, i.e., `theta(m^-1 z) = theta(z) - theta(m)`, the intrinsic mean is the boost with the mean rapidity, which is computed directly instead of by a fixed-point iteration.
+///
+/// # Examples
+///
+/// ```
+/// use approx::assert_abs_diff_eq;
+/// use perplex_num::boost::hyperbolic_mean;
+/// use perplex_num::UnitPerplex;
+/// let samples = [UnitPerplex::from_rapidity(0.0), UnitPerplex::from_rapidity(3.0)];
+/// let mean = hyperbolic_mean(&samples).unwrap();
+/// assert_abs_diff_eq!(mean.rapidity(), 1.5, epsilon = 1e-12);
+/// ```
+pub fn hyperbolic_mean<T: Copy + Float>(samples: &[UnitPerplex<T>]) -> Option<UnitPerplex<T>> {
+    let first = samples.first()?.rapidity();
+    let sum = samples[1..].iter().fold(first, |sum, z| sum + z.rapidity());
+    let n = T::from(samples.len()).unwrap();
+    Some(UnitPerplex::from_rapidity(sum / n))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_abs_diff_eq;
+    #[test]
+    fn test_parameterizations() {
+        let boost = UnitPerplex::from_rapidity(0.5);
+        assert_abs_diff_eq!(boost.rapidity(), 0.5, epsilon = 1e-15);
+        assert_abs_diff_eq!(boost.velocity(), f64::tanh(0.5), epsilon = 1e-15);
+        assert_abs_diff_eq!(boost.gamma(), f64::cosh(0.5), epsilon = 1e-15);
+        assert_abs_diff_eq!(boost.perplex().squared_distance(), 1.0, epsilon = 1e-15);
+        let from_velocity = UnitPerplex::from_velocity(boost.velocity()).unwrap();
+        assert_abs_diff_eq!(from_velocity.perplex(), boost.perplex(), epsilon = 1e-15);
+        let from_gamma_beta = UnitPerplex::from_gamma_beta(boost.gamma(), boost.velocity());
+        assert_eq!(from_gamma_beta, Some(from_velocity));
+        let from_perplex = UnitPerplex::from_perplex(Perplex::new(2.5, 1.5)).unwrap();
+        assert_eq!(from_perplex.perplex(), Perplex::new(1.25, 0.75));
+        assert_eq!(Perplex::from(from_perplex), Perplex::new(1.25, 0.75));
+    }
+    #[test]
+    fn test_domains() {
+        assert!(UnitPerplex::from_velocity(-1.0).is_none(), "Light speed!");
+        assert!(UnitPerplex::from_velocity(1.5).is_none());
+        assert!(UnitPerplex::from_velocity(f64::NAN).is_none());
+        assert!(
+            UnitPerplex::from_gamma_beta(2.0, 0.6).is_none(),
+            "Inconsistent gamma!"
+        );
+        assert!(UnitPerplex::from_gamma_beta(1.25, 1.0).is_none());
+        assert!(UnitPerplex::from_gamma_beta(f64::NAN, 0.6).is_none());
+        assert!(UnitPerplex::from_perplex(Perplex::new(-2.0, 1.0)).is_none());
+        assert!(UnitPerplex::from_perplex(Perplex::new(1.0, 1.0)).is_none());
+        let fast = UnitPerplex::from_velocity(1.0 - 1e-12).unwrap();
+        assert!(fast.gamma().is_finite() && fast.rapidity().is_finite());
+    }
+    #[test]
+    fn test_compose_boosts() {
+        let rapidities: Vec<f64> = (0..5000).map(|i| 0.01 * f64::sin(i as f64)).collect();
+        let expected: f64 = rapidities.iter().sum();
+        let (boost, drift) = compose_boosts(rapidities.iter().copied(), 32);
+        assert_abs_diff_eq!(boost.rapidity(), expected, epsilon = 1e-12);
+        assert_abs_diff_eq!(boost.perplex().squared_distance(), 1.0, epsilon = 1e-15);
+        assert!(drift < 1e-12, "Renormalization keeps the drift small!");
+        let boosts = rapidities.iter().map(|&r| UnitPerplex::from_rapidity(r));
+        let (unnormalized, _) = compose_boosts(boosts, 0);
+        assert_abs_diff_eq!(unnormalized.rapidity(), expected, epsilon = 1e-9);
+        let (identity, drift) = compose_boosts(Vec::<f64>::new(), 4);
+        assert_eq!((identity, drift), (UnitPerplex::identity(), 0.0));
+        // large rapidities, for which t - |x| cancels to zero
+        let (boost, drift) = compose_boosts(vec![40.0, -39.0], 0);
+        assert_abs_diff_eq!(boost.rapidity(), 1.0, epsilon = 1e-12);
+        assert!(drift < 1e-15);
+        let (boost, drift) = compose_boosts(vec![UnitPerplex::from_rapidity(30.0)], 1);
+        assert_abs_diff_eq!(boost.rapidity(), 30.0, epsilon = 1e-12);
+        assert!(drift < 1e-15);
+        let (boost, _) = compose_boosts(vec![-300.0, 100.0, -100.0, 250.0], 2);
+        assert_abs_diff_eq!(boost.rapidity(), -50.0, epsilon = 1e-12);
+    }
+    #[test]
+    fn test_hyperbolic_mean() {
+        let rapidities = [-1.0, 0.5, 2.0, 30.0];
+        let samples: Vec<_> = rapidities.iter().map(|&r| UnitPerplex::from(r)).collect();
+        let mean = hyperbolic_mean(&samples).unwrap();
+        assert_abs_diff_eq!(mean.rapidity(), 31.5 / 4.0, epsilon = 1e-12);
+        let arithmetic = samples
+            .iter()
+            .fold(Perplex::new(0.0, 0.0), |sum, z| sum + z.perplex());
+        let normalized = UnitPerplex::from_perplex(arithmetic).unwrap();
+        assert!(
+            normalized.rapidity() > 10.0,
+            "The normalized arithmetic mean is dominated by the largest rapidity!"
+        );
+        let single = [UnitPerplex::from_rapidity(-2.5f32)];
+        assert_eq!(hyperbolic_mean(&single), Some(single[0]));
+        assert!(hyperbolic_mean::<f64>(&[]).is_none());
+    }
+    #[test]
+    fn test_composition() {
+        let (u, v) = (0.6, -0.3);
+        let composed =
+            UnitPerplex::from_velocity(u).unwrap() * UnitPerplex::from_velocity(v).unwrap();
+        assert_abs_diff_eq!(
+            composed.velocity(),
+            (u + v) / (1.0 + u * v),
+            epsilon = 1e-15
+        );
+        let boost = UnitPerplex::from_rapidity(1.2);
+        let identity = boost * boost.inverse();
+        assert_abs_diff_eq!(
+            identity.perplex(),
+            UnitPerplex::identity().perplex(),
+            epsilon = 1e-14
+        );
+        let z = Perplex::new(0.5, -2.0);
+        assert_abs_diff_eq!(
+            boost.apply(z).squared_distance(),
+            z.squared_distance(),
+            epsilon = 1e-14
+        );
+    }
+}
@@ -0,0 +1,537 @@
+//! # Calculus Module
+//!
+//! This module provides numerical differentiation of perplex functions and a small optimizer over perplex parameters.
+//!
+//! ## Differentiation
+//! A function `f` is h-holomorphic if its Jacobian in (t, x) coordinates is the matrix form of a perplex number, the h-derivative `f'(z)`, see Sec. 7.2 in [The Mathematics of Minkowski Space-Time](https://doi.org/10.1007/978-3-7643-8614-6).
+//! - `derivative`: The h-derivative by a central difference along the time axis.
+//! - `jacobian`: The 2x2 Jacobian `[[dt'/dt, dt'/dx], [dx'/dt, dx'/dx]]` of an arbitrary map of the plane by central differences, as used by `dynamics::lyapunov_jacobian`.
+//! - `gradient`: The gradient `(dF/dt, dF/dx)` of a real function by central differences.
+//! - `derivative_via_dual`: The exact derivative of a real function by its evaluation over `Dual` numbers, the analogue of the complex-step differentiation.
+//! - `derivative_via_perplex`: The symmetric difference quotient of a real function by a single evaluation of its perplex extension, since `f(x + s h) = (f(x + s) + f(x - s)) / 2 + (f(x + s) - f(x - s)) / 2 h` for functions lifted to the null components.
+//!
+//! ## Optimization
+//! The optimizers minimize objectives of a slice of perplex parameters, whose components are differentiated numerically.
+//! - `minimize`: Gradient descent with a backtracking line search for real objectives.
+//! - `levenberg_marquardt`: The Levenberg–Marquardt algorithm for the sum of the squared L2 norms of perplex residuals.
+//!
+//! Both optimizers control the step for the light cone: steps which yield a non-finite objective (e.g., the logarithm of a light-like number) are rejected, and with `OptimizerOptions::preserve_sectors` steps are shortened until no parameter crosses a light cone into another sector.
+//!
+//! ## Example
+//! ```
+//! use approx::assert_abs_diff_eq;
+//! use perplex_num::calculus::{levenberg_marquardt, OptimizerOptions};
+//! use perplex_num::Perplex;
+//! // fit the boost and scale exp(p) mapping the points z to the points w
+//! let p = Perplex::new(0.5, -0.3);
+//! let z = [Perplex::new(1.0, 0.0), Perplex::new(0.5, 2.0), Perplex::new(-1.0, 0.25)];
+//! let w: Vec<_> = z.iter().map(|&z| p.exp() * z).collect();
+//! let residuals = |params: &[Perplex<f64>]| z.iter().zip(&w).map(|(&z, &w)| params[0].exp() * z - w).collect();
+//! let result = levenberg_marquardt(residuals, &[Perplex::new(0.0, 0.0)], &OptimizerOptions::default());
+//! assert!(result.converged);
+//! assert_abs_diff_eq!(result.params[0], p, epsilon = 1e-8);
+//! ```
+
+use super::dual::Dual;
+use super::Perplex;
+use num_traits::Float;
+
+/// Returns the h-derivative of the h-holomorphic function `f` at `z` by a central difference with the step `step` along the time axis.
+#[inline]
+pub fn derivative<T, F>(f: F, z: Perplex<T>, step: T) -> Perplex<T>
+where
+    T: Copy + Float,
+    F: Fn(Perplex<T>) -> Perplex<T>,
+{
+    let delta = Perplex::new(step, T::zero());
+    let two_step = step + step;
+    let difference = f(z + delta) - f(z - delta);
+    Perplex::new(difference.t / two_step, difference.x / two_step)
+}
+
+/// Returns the Jacobian `[[dt'/dt, dt'/dx], [dx'/dt, dx'/dx]]` of the map `f` at `z` by central differences with the step `step`.
+#[inline]
+pub fn jacobian<T, F>(f: F, z: Perplex<T>, step: T) -> [[T; 2]; 2]
+where
+    T: Copy + Float,
+    F: Fn(Perplex<T>) -> Perplex<T>,
+{
+    let two_step = step + step;
+    let d_t = f(z + Perplex::new(step, T::zero())) - f(z - Perplex::new(step, T::zero()));
+    let d_x = f(z + Perplex::new(T::zero(), step)) - f(z - Perplex::new(T::zero(), step));
+    [
+        [d_t.t / two_step, d_x.t / two_step],
+        [d_t.x / two_step, d_x.x / two_step],
+    ]
+}
+
+/// Returns the gradient `(dF/dt, dF/dx)` of the real function `f` at `z` by central differences with the step `step`.
+#[inline]
+pub fn gradient<T, F>(f: F, z: Perplex<T>, step: T) -> Perplex<T>
+where
+    T: Copy + Float,
+    F: Fn(Perplex<T>) -> T,
+{
+    let two_step = step + step;
+    let d_t = f(z + Perplex::new(step, T::zero())) - f(z - Perplex::new(step, T::zero()));
+    let d_x = f(z + Perplex::new(T::zero(), step)) - f(z - Perplex::new(T::zero(), step));
+    Perplex::new(d_t / two_step, d_x / two_step)
+}
+
+/// Returns the exact derivative `f'(x)` of the real function `f` by the dual part of `f(x + ε)`, where `f` is written over `Dual` numbers.
+///
+/// In contrast to finite differences, there is neither a step size nor a truncation or cancellation error.
+#[inline]
+pub fn derivative_via_dual<T, F>(f: F, x: T) -> T
+where
+    T: Copy + Float,
+    F: Fn(Dual<T>) -> Dual<T>,
+{
+    f(Dual::variable(x)).eps
+}
+
+/// Returns the symmetric difference quotient `(f(x + step) - f(x - step)) / (2 step)` of a real function by the space component of `f(x + step h) / step`, where `f` is the perplex extension of the real function.
+///
+/// The perplex extension by the null components (see `Perplex::lift`) evaluates `f(x + step)` and `f(x - step)` simultaneously, hence the quotient has the truncation error `O(step^2)` and the cancellation error of a central difference.
+/// Use `derivative_via_dual` for exact derivatives.
+#[inline]
+pub fn derivative_via_perplex<T, F>(f: F, x: T, step: T) -> T
+where
+    T: Copy + Float,
+    F: Fn(Perplex<T>) -> Perplex<T>,
+{
+    f(Perplex::new(x, step)).x / step
+}
+
+/// The options of the optimizers `minimize` and `levenberg_marquardt`.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct OptimizerOptions<T> {
+    /// The maximum number of iterations.
+    pub max_iterations: usize,
+    /// The optimization has converged if the maximum norm of the gradient or of the step falls below the tolerance.
+    pub tolerance: T,
+    /// The step of the central differences.
+    pub step: T,
+    /// Shortens steps which move a parameter across a light cone into another sector.
+    pub preserve_sectors: bool,
+}
+
+impl<T: Copy + Float> Default for OptimizerOptions<T> {
+    /// Defaults to 200 iterations, the square root of the machine epsilon as tolerance, its cube root as difference step, and preserved sectors.
+    fn default() -> Self {
+        Self {
+            max_iterations: 200,
+            tolerance: T::epsilon().sqrt(),
+            step: T::epsilon().cbrt(),
+            preserve_sectors: true,
+        }
+    }
+}
+
+/// The result of the optimizers `minimize` and `levenberg_marquardt`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct OptimizationResult<T> {
+    /// The optimized parameters.
+    pub params: Vec<Perplex<T>>,
+    /// The objective at the optimized parameters.
+    pub cost: T,
+    /// The number of performed iterations.
+    pub iterations: usize,
+    /// Indicates whether the tolerance has been reached.
+    pub converged: bool,
+}
+
+/// Maximum number of step reductions within one iteration.
+const MAX_STEP_REDUCTIONS: usize = 40;
+
+/// Returns `true` if `candidate` lies in the same sector as `current`, whereby light-like numbers may move into any sector.
+fn same_sector<T: Copy + Float>(current: Perplex<T>, candidate: Perplex<T>) -> bool {
+    current
+        .klein()
+        .map_or(true, |klein| candidate.klein() == Some(klein))
+}
+
+/// Returns `params + scale * direction`, whose components are stored consecutively as `(t, x)` in `direction`.
+fn shifted<T: Copy + Float>(params: &[Perplex<T>], direction: &[T], scale: T) -> Vec<Perplex<T>> {
+    params
+        .iter()
+        .zip(direction.chunks_exact(2))
+        .map(|(z, d)| Perplex::new(z.t + scale * d[0], z.x + scale * d[1]))
+        .collect()
+}
+
+/// Returns the largest scale of at most one for which `params + scale * direction` stays in the sectors of `params`, or `None` if the sectors are violated after `MAX_STEP_REDUCTIONS` halvings.
+fn sector_scale<T: Copy + Float>(
+    params: &[Perplex<T>],
+    direction: &[T],
+    options: &OptimizerOptions<T>,
+) -> Option<T> {
+    let two = T::one() + T::one();
+    let mut scale = T::one();
+    for _ in 0..MAX_STEP_REDUCTIONS {
+        let candidate = shifted(params, direction, scale);
+        if !options.preserve_sectors
+            || params
+                .iter()
+                .zip(&candidate)
+                .all(|(&z, &c)| same_sector(z, c))
+        {
+            return Some(scale);
+        }
+        scale = scale / two;
+    }
+    None
+}
+
+/// Returns the maximum absolute value of `values`.
+fn max_abs<T: Copy + Float>(values: &[T]) -> T {
+    values.iter().fold(T::zero(), |max, v| max.max(v.abs()))
+}
+
+/// Minimizes the real `objective` of perplex parameters by gradient descent, starting at `initial`.
+///
+/// The step along the negative numerical gradient is determined by a backtracking line search with the Armijo condition. The initial step length of each iteration is twice the accepted step length of the previous iteration.
+pub fn minimize<T, F>(
+    objective: F,
+    initial: &[Perplex<T>],
+    options: &OptimizerOptions<T>,
+) -> OptimizationResult<T>
+where
+    T: Copy + Float,
+    F: Fn(&[Perplex<T>]) -> T,
+{
+    let two = T::one() + T::one();
+    let armijo = T::from(1e-4).unwrap();
+    let mut params = initial.to_vec();
+    let mut cost = objective(&params);
+    let mut step_length = T::one();
+    let mut converged = false;
+    let mut iterations = 0;
+    while iterations < options.max_iterations && cost.is_finite() {
+        iterations += 1;
+        let mut gradient = Vec::with_capacity(2 * params.len());
+        for i in 0..params.len() {
+            let g = self::gradient(
+                |z| {
+                    let mut shifted = params.clone();
+                    shifted[i] = z;
+                    objective(&shifted)
+                },
+                params[i],
+                options.step,
+            );
+            gradient.extend([g.t, g.x]);
+        }
+        if max_abs(&gradient) <= options.tolerance {
+            converged = true;
+            break;
+        }
+        let descent: Vec<T> = gradient.iter().map(|&g| -g).collect();
+        let squared_norm = gradient.iter().fold(T::zero(), |sum, &g| sum + g * g);
+        let Some(mut scale) = sector_scale(&params, &descent, options).map(|s| s * step_length)
+        else {
+            break;
+        };
+        let mut accepted = None;
+        for _ in 0..MAX_STEP_REDUCTIONS {
+            let candidate = shifted(&params, &descent, scale);
+            let candidate_cost = objective(&candidate);
+            if candidate_cost.is_finite() && candidate_cost <= cost - armijo * scale * squared_norm
+            {
+                accepted = Some((candidate, candidate_cost));
+                break;
+            }
+            scale = scale / two;
+        }
+        let Some((candidate, candidate_cost)) = accepted else {
+            break;
+        };
+        let step_size = scale * max_abs(&descent);
+        (params, cost, step_length) = (candidate, candidate_cost, two * scale);
+        if step_size <= options.tolerance * options.tolerance {
+            converged = true;
+            break;
+        }
+    }
+    OptimizationResult {
+        params,
+        cost,
+        iterations,
+        converged,
+    }
+}
+
+/// Minimizes the sum of the squared L2 norms of the perplex `residuals` of perplex parameters by the Levenberg–Marquardt algorithm, starting at `initial`.
+///
+/// The Jacobian of the residuals with respect to the parameter components is computed by central differences. The damping of the normal equations is scaled by their diagonal, decreased after accepted steps and increased after rejected steps.
+/// The reported cost is half the sum of the squared L2 norms of the residuals.
+pub fn levenberg_marquardt<T, F>(
+    residuals: F,
+    initial: &[Perplex<T>],
+    options: &OptimizerOptions<T>,
+) -> OptimizationResult<T>
+where
+    T: Copy + Float,
+    F: Fn(&[Perplex<T>]) -> Vec<Perplex<T>>,
+{
+    let two = T::one() + T::one();
+    let (decrease, increase) = (T::from(3).unwrap(), T::from(4).unwrap());
+    let half_squared_norm = |r: &[Perplex<T>]| {
+        r.iter()
+            .fold(T::zero(), |sum, z| sum + (z.t * z.t + z.x * z.x) / two)
+    };
+    let mut params = initial.to_vec();
+    let mut r = residuals(&params);
+    let mut cost = half_squared_norm(&r);
+    let mut damping = T::from(1e-3).unwrap();
+    let mut converged = false;
+    let mut iterations = 0;
+    let n = 2 * params.len();
+    while iterations < options.max_iterations && cost.is_finite() {
+        iterations += 1;
+        // columns of the Jacobian of the flattened residuals
+        let columns: Vec<Vec<T>> = (0..n)
+            .map(|k| {
+                let mut direction = vec![T::zero(); n];
+                direction[k] = T::one();
+                let forward = residuals(&shifted(&params, &direction, options.step));
+                let backward = residuals(&shifted(&params, &direction, -options.step));
+                forward
+                    .iter()
+                    .zip(&backward)
+                    .flat_map(|(f, b)| {
+                        [
+                            (f.t - b.t) / (two * options.step),
+                            (f.x - b.x) / (two * options.step),
+                        ]
+                    })
+                    .collect()
+            })
+            .collect();
+        let flat: Vec<T> = r.iter().flat_map(|z| [z.t, z.x]).collect();
+        let dot = |a: &[T], b: &[T]| a.iter().zip(b).fold(T::zero(), |sum, (&a, &b)| sum + a * b);
+        let gradient: Vec<T> = columns.iter().map(|c| dot(c, &flat)).collect();
+        if max_abs(&gradient) <= options.tolerance {
+            converged = true;
+            break;
+        }
+        let normal: Vec<Vec<T>> = columns
+            .iter()
+            .map(|a| columns.iter().map(|b| dot(a, b)).collect())
+            .collect();
+        let mut accepted = None;
+        for _ in 0..MAX_STEP_REDUCTIONS {
+            let mut damped = normal.clone();
+            for (k, row) in damped.iter_mut().enumerate() {
+                row[k] = row[k] + damping * normal[k][k].max(T::epsilon());
+            }
+            let rhs: Vec<T> = gradient.iter().map(|&g| -g).collect();
+            if let Some(step) = solve(damped, rhs) {
+                if let Some(scale) = sector_scale(&params, &step, options) {
+                    let candidate = shifted(&params, &step, scale);
+                    let candidate_r = residuals(&candidate);
+                    let candidate_cost = half_squared_norm(&candidate_r);
+                    if candidate_cost.is_finite() && candidate_cost < cost {
+                        accepted = Some((
+                            candidate,
+                            candidate_r,
+                            candidate_cost,
+                            scale * max_abs(&step),
+                        ));
+                        break;
+                    }
+                }
+            }
+            damping = damping * increase;
+        }
+        let Some((candidate, candidate_r, candidate_cost, step_size)) = accepted else {
+            break;
+        };
+        let scale = candidate
+            .iter()
+            .fold(T::zero(), |max, z| max.max(z.max_norm()));
+        (params, r, cost, damping) = (candidate, candidate_r, candidate_cost, damping / decrease);
+        if step_size <= options.tolerance * (scale + options.tolerance) {
+            converged = true;
+            break;
+        }
+    }
+    OptimizationResult {
+        params,
+        cost,
+        iterations,
+        converged,
+    }
+}
+
+/// Solves the linear system `a y = b` by Gaussian elimination with partial pivoting, or returns `None` for a singular matrix.
+fn solve<T: Copy + Float>(mut a: Vec<Vec<T>>, mut b: Vec<T>) -> Option<Vec<T>> {
+    let n = b.len();
+    for col in 0..n {
+        let pivot = (col..n).max_by(|&i, &j| {
+            a[i][col]
+                .abs()
+                .partial_cmp(&a[j][col].abs())
+                .unwrap_or(std::cmp::Ordering::Equal)
+        })?;
+        if a[pivot][col].is_zero() || !a[pivot][col].is_finite() {
+            return None;
+        }
+        a.swap(col, pivot);
+        b.swap(col, pivot);
+        let (upper, lower) = a.split_at_mut(col + 1);
+        let pivot_row = &upper[col];
+        for (offset, row) in lower.iter_mut().enumerate() {
+            let factor = row[col] / pivot_row[col];
+            for (entry, &p) in row.iter_mut().zip(pivot_row).skip(col) {
+                *entry = *entry - factor * p;
+            }
+            b[col + 1 + offset] = b[col + 1 + offset] - factor * b[col];
+        }
+    }
+    let mut y = vec![T::zero(); n];
+    for row in (0..n).rev() {
+        let sum = (row + 1..n).fold(b[row], |sum, k| sum - a[row][k] * y[k]);
+        y[row] = sum / a[row][row];
+    }
+    Some(y)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_abs_diff_eq;
+    #[test]
+    fn test_derivative() {
+        let z = Perplex::new(0.8, -0.3);
+        assert_abs_diff_eq!(derivative(|z| z.exp(), z, 1e-5), z.exp(), epsilon = 1e-9);
+        assert_abs_diff_eq!(
+            derivative(|z| z * z * z, z, 1e-5),
+            (z * z).scale(3.0),
+            epsilon = 1e-9
+        );
+        let [[a, b], [c, d]] = jacobian(|z| z * z, z, 1e-5);
+        let expected = z.scale(2.0);
+        assert_abs_diff_eq!(Perplex::new(a, c), expected, epsilon = 1e-9);
+        assert_abs_diff_eq!(Perplex::new(d, b), expected, epsilon = 1e-9);
+        let [[_, b], [c, _]] = jacobian(|z| z.conj(), z, 1e-5);
+        assert_eq!(
+            (b, c),
+            (0.0, 0.0),
+            "Conjugation is not h-holomorphic but linear!"
+        );
+        let g = gradient(|z| z.squared_distance(), z, 1e-5);
+        assert_abs_diff_eq!(g, Perplex::new(2.0 * z.t, -2.0 * z.x), epsilon = 1e-9);
+    }
+    #[test]
+    fn test_derivative_tricks() {
+        let x = 0.4;
+        let f = |y: Dual<f64>| (y.sin() * y).exp();
+        let exact = (x * x.sin()).exp() * (x.sin() + x * x.cos());
+        assert_abs_diff_eq!(derivative_via_dual(f, x), exact, epsilon = 1e-15);
+        assert_eq!(
+            derivative_via_dual(|y| y.powi(3), 2.0),
+            12.0,
+            "Exact without step size!"
+        );
+        let step = 1e-4;
+        let central = ((x + step).exp() - (x - step).exp()) / (2.0 * step);
+        assert_abs_diff_eq!(
+            derivative_via_perplex(|z| z.exp(), x, step),
+            central,
+            epsilon = 1e-10
+        );
+        assert_abs_diff_eq!(
+            derivative_via_perplex(|z| z.sin(), x, step),
+            x.cos(),
+            epsilon = 1e-8
+        );
+        let cubic = derivative_via_perplex(|z| z * z * z, 2.0, 0.5);
+        assert_abs_diff_eq!(cubic, 12.25, epsilon = 1e-12);
+    }
+    #[test]
+    fn test_solve() {
+        let a = vec![vec![0.0, 2.0], vec![1.0, 1.0]];
+        assert_eq!(
+            solve(a, vec![4.0, 3.0]).unwrap(),
+            vec![1.0, 2.0],
+            "Pivoting!"
+        );
+        assert!(solve(vec![vec![1.0, 2.0], vec![2.0, 4.0]], vec![1.0, 1.0]).is_none());
+    }
+    #[test]
+    fn test_minimize() {
+        let (c1, c2) = (Perplex::new(1.0, -2.0), Perplex::new(0.5, 0.25));
+        let objective = |p: &[Perplex<f64>]| {
+            (p[0] - c1).l2_norm().powi(2) + 3.0 * (p[1] - c2).l2_norm().powi(2)
+        };
+        let options = OptimizerOptions {
+            preserve_sectors: false,
+            ..Default::default()
+        };
+        let result = minimize(objective, &[Perplex::new(0.0, 0.0); 2], &options);
+        assert!(result.converged);
+        assert_abs_diff_eq!(result.params[0], c1, epsilon = 1e-6);
+        assert_abs_diff_eq!(result.params[1], c2, epsilon = 1e-6);
+        assert!(result.cost < 1e-10);
+    }
+    #[test]
+    fn test_levenberg_marquardt() {
+        // boost with rapidity 0.4 and scale 2
+        let p = Perplex::new(f64::ln(2.0), 0.4);
+        let z: Vec<_> = (0..6)
+            .map(|k| Perplex::new(1.0 + 0.1 * k as f64, 0.3 * k as f64 - 0.5))
+            .collect();
+        let w: Vec<_> = z.iter().map(|&z| p.exp() * z).collect();
+        let residuals = |params: &[Perplex<f64>]| {
+            z.iter()
+                .zip(&w)
+                .map(|(&z, &w)| params[0].exp() * z - w)
+                .collect()
+        };
+        let result = levenberg_marquardt(
+            residuals,
+            &[Perplex::new(0.0, 0.0)],
+            &OptimizerOptions::default(),
+        );
+        assert!(result.converged);
+        assert!(result.iterations < 50);
+        assert_abs_diff_eq!(result.params[0], p, epsilon = 1e-8);
+        assert!(result.cost < 1e-16);
+    }
+    #[test]
+    fn test_sector_control() {
+        // the minimum of |ln(p) - 1|^2 in the right sector is e, whereas ln is undefined on the light cone
+        let target = Perplex::new(1.0, 0.0);
+        let residuals = |params: &[Perplex<f64>]| {
+            vec![params[0]
+                .ln()
+                .map_or(Perplex::new(f64::NAN, f64::NAN), |l| l - target)]
+        };
+        let result = levenberg_marquardt(
+            residuals,
+            &[Perplex::new(0.2, 0.1)],
+            &OptimizerOptions::default(),
+        );
+        assert!(result.converged);
+        assert_abs_diff_eq!(
+            result.params[0],
+            Perplex::new(std::f64::consts::E, 0.0),
+            epsilon = 1e-6
+        );
+        // starting in the left sector, the parameter stays in the left sector
+        let objective = |p: &[Perplex<f64>]| (p[0] - Perplex::new(0.0, 5.0)).l2_norm().powi(2);
+        let result = minimize(
+            objective,
+            &[Perplex::new(-2.0, 0.5)],
+            &OptimizerOptions::default(),
+        );
+        assert_eq!(result.params[0].klein(), Some(-Perplex::new(1.0, 0.0)));
+        let free = OptimizerOptions {
+            preserve_sectors: false,
+            ..Default::default()
+        };
+        let result = minimize(objective, &[Perplex::new(-2.0, 0.5)], &free);
+        assert_abs_diff_eq!(result.params[0], Perplex::new(0.0, 5.0), epsilon = 1e-6);
+    }
+}
@@ -0,0 +1,297 @@
+//! # Conformance Module
+//!
+//! This module provides a harness (optional feature `testing`) which sweeps structured grids of `f32` inputs and measures the deviation of reduced-precision implementations of perplex functions from `f64` references in units in the last place (ulps).
+//! The crate runs it for its own `f32` implementations of all unary functions, and downstream scalar backends, e.g., fixed-point or `f16` components, reuse it by wrapping their implementation as a function of `Perplex<f32>` and describing their precision by a `Format`.
+//! The deviation of a value is measured normwise, i.e., the maximum norm of the error in ulps of the maximum norm of the reference, since a component of a perplex number which cancels to zero has no meaningful relative accuracy on its own.
+//!
+//! ## Features
+//! - `Format`: The precision and the exponent range of a floating-point format, e.g., `Format::F32` and `Format::F16`.
+//! - `structured_grid`: Inputs of all sectors with magnitudes over a range of binary exponents, including zero components and nearly light-like numbers.
+//! - `sweep`: The comparison of an arbitrary candidate with a reference over a grid, summarized in a `ConformanceReport`.
+//! - `unary_functions` and `sweep_unary_functions`: The unary functions of the crate and their reports for the `f32` implementations.
+//!
+//! ## Example
+//! ```
+//! use perplex_num::conformance::{structured_grid, sweep, Format};
+//! use perplex_num::Perplex;
+//! let grid = structured_grid(4);
+//! // a candidate which evaluates the hyperbolic tangent in f32
+//! let report = sweep("tanh", &grid, |z| Some(z.tanh()), |z| Some(z.tanh()), Format::F32);
+//! assert!(report.max_ulps < 8.0, "{report:?}");
+//! assert_eq!(report.domain_mismatches, 0);
+//! ```
+
+use super::Perplex;
+
+/// The precision and the exponent range of a binary floating-point format with the conventions of `f32::MANTISSA_DIGITS`, `f32::MIN_EXP` and `f32::MAX_EXP`.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub struct Format {
+    /// The number of significant binary digits including the implicit leading bit.
+    pub mantissa_digits: u32,
+    /// One more than the smallest normal binary exponent, i.e., the smallest normal number is `2^(min_exp - 1)`.
+    pub min_exp: i32,
+    /// One more than the largest binary exponent, i.e., all finite numbers are below `2^max_exp`.
+    pub max_exp: i32,
+}
+
+impl Format {
+    /// The IEEE 754 half precision of `f16`.
+    pub const F16: Self = Self {
+        mantissa_digits: 11,
+        min_exp: -13,
+        max_exp: 16,
+    };
+    /// The IEEE 754 single precision of `f32`.
+    pub const F32: Self = Self {
+        mantissa_digits: f32::MANTISSA_DIGITS,
+        min_exp: f32::MIN_EXP,
+        max_exp: f32::MAX_EXP,
+    };
+    /// Returns the distance of the numbers of the format adjacent to `v`, i.e., one ulp, which is constant below the smallest normal number.
+    #[inline]
+    pub fn ulp(&self, v: f64) -> f64 {
+        // the exponent e with 2^(e - 1) <= |v| < 2^e
+        let exponent = if v == 0.0 {
+            self.min_exp
+        } else {
+            (v.abs().log2().floor() as i32 + 1).max(self.min_exp)
+        };
+        2f64.powi(exponent - self.mantissa_digits as i32)
+    }
+    /// Checks if `v` is within the range of the finite numbers of the format.
+    #[inline]
+    pub fn in_range(&self, v: f64) -> bool {
+        v.abs() < 2f64.powi(self.max_exp)
+    }
+}
+
+/// The summary of the comparison of a candidate with a reference over a grid of inputs.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct ConformanceReport {
+    /// The name of the compared function.
+    pub function: &'static str,
+    /// The number of inputs for which both the candidate and the reference are defined and the reference is within the range of the format.
+    pub samples: usize,
+    /// The maximal normwise deviation in ulps of the format, which is infinite if the candidate is not finite for a finite reference.
+    pub max_ulps: f64,
+    /// The input with the maximal deviation, or `None` if there are no samples.
+    pub worst_input: Option<Perplex<f32>>,
+    /// The number of inputs for which only one of the candidate and the reference is defined, e.g., due to the rounding of the null components close to a domain boundary.
+    pub domain_mismatches: usize,
+    /// The number of inputs which are skipped since the reference is out of the range of the format or not finite.
+    pub skipped: usize,
+}
+
+/// Returns the inputs `t + x h` with components `0` and `±2^k` for `k` in `-levels..=levels`, and the nearly light-like numbers `m (±1 ± (1 - 2^-j) h)` for the magnitudes `m = 2^k` and `j` in `[4, 12, 23]`.
+///
+/// The grid covers all sectors symmetrically, both light-like lines and the subnormal-free range of `f32` for `levels` up to `125`.
+pub fn structured_grid(levels: u32) -> Vec<Perplex<f32>> {
+    let levels = levels.min(125) as i32;
+    let magnitudes: Vec<f32> = (-levels..=levels).map(|k| 2f32.powi(k)).collect();
+    let mut components = vec![0.0];
+    for &m in &magnitudes {
+        components.extend([m, -m]);
+    }
+    let mut grid = Vec::with_capacity(components.len().pow(2) + 24 * magnitudes.len());
+    for &t in &components {
+        for &x in &components {
+            grid.push(Perplex::new(t, x));
+        }
+    }
+    for &m in &magnitudes {
+        for j in [4, 12, 23] {
+            let near = m * (1.0 - 2f32.powi(-j));
+            for (t, x) in [(m, near), (near, m)] {
+                for (s_t, s_x) in [(1.0, 1.0), (1.0, -1.0), (-1.0, 1.0), (-1.0, -1.0)] {
+                    grid.push(Perplex::new(s_t * t, s_x * x));
+                }
+            }
+        }
+    }
+    grid
+}
+
+/// Compares `candidate` with `reference` at all inputs of `grid`, whereby the inputs are converted exactly to `f64` for the reference, and measures the deviations in ulps of `format`.
+pub fn sweep<C, R>(
+    function: &'static str,
+    grid: &[Perplex<f32>],
+    candidate: C,
+    reference: R,
+    format: Format,
+) -> ConformanceReport
+where
+    C: Fn(Perplex<f32>) -> Option<Perplex<f32>>,
+    R: Fn(Perplex<f64>) -> Option<Perplex<f64>>,
+{
+    let mut report = ConformanceReport {
+        function,
+        samples: 0,
+        max_ulps: 0.0,
+        worst_input: None,
+        domain_mismatches: 0,
+        skipped: 0,
+    };
+    for &z in grid {
+        let exact = Perplex::new(z.t as f64, z.x as f64);
+        match (candidate(z), reference(exact)) {
+            (Some(c), Some(r)) => {
+                let magnitude = r.max_norm();
+                if !(magnitude.is_finite() && format.in_range(magnitude)) {
+                    report.skipped += 1;
+                    continue;
+                }
+                let error = Perplex::new(c.t as f64 - r.t, c.x as f64 - r.x).max_norm();
+                let ulps = if error.is_finite() {
+                    error / format.ulp(magnitude)
+                } else {
+                    f64::INFINITY
+                };
+                report.samples += 1;
+                // the first input is recorded even for a zero deviation
+                if report.worst_input.is_none() || ulps > report.max_ulps {
+                    report.max_ulps = report.max_ulps.max(ulps);
+                    report.worst_input = Some(z);
+                }
+            }
+            (None, None) => {}
+            _ => report.domain_mismatches += 1,
+        }
+    }
+    report
+}
+
+/// A unary function of the crate with its implementations for `f32` and `f64` components.
+#[derive(Copy, Clone, Debug)]
+pub struct UnaryFunction {
+    /// The name of the method of `Perplex`.
+    pub name: &'static str,
+    /// The implementation for `f32` components, which returns `None` outside of the domain.
+    pub f32: fn(Perplex<f32>) -> Option<Perplex<f32>>,
+    /// The implementation for `f64` components, which returns `None` outside of the domain.
+    pub f64: fn(Perplex<f64>) -> Option<Perplex<f64>>,
+}
+
+/// Collects the unary functions into `UnaryFunction`s, whereby total functions are wrapped into `Some`.
+macro_rules! unary_functions {
+    ($($name:ident: $wrap:ident),* $(,)?) => {
+        [$(UnaryFunction {
+            name: stringify!($name),
+            f32: |z| unary_functions!(@$wrap z.$name()),
+            f64: |z| unary_functions!(@$wrap z.$name()),
+        }),*]
+    };
+    (@total $value:expr) => {
+        Some($value)
+    };
+    (@partial $value:expr) => {
+        $value
+    };
+}
+
+/// Returns the unary functions of the crate, i.e., the elementary functions and their inverses, the inverse and the hyperbolic conjugate.
+pub fn unary_functions() -> Vec<UnaryFunction> {
+    unary_functions!(
+        conj: total,
+        try_inverse: partial,
+        exp: total,
+        exp2: total,
+        ln: partial,
+        log2: partial,
+        log10: partial,
+        sqrt: partial,
+        sin: total,
+        cos: total,
+        tan: partial,
+        asin: partial,
+        acos: partial,
+        atan: total,
+        sinh: total,
+        cosh: total,
+        tanh: total,
+        asinh: total,
+        acosh: partial,
+        atanh: partial,
+    )
+    .to_vec()
+}
+
+/// Returns the reports of the `f32` implementations of all `unary_functions` compared with the `f64` implementations over `grid`.
+pub fn sweep_unary_functions(grid: &[Perplex<f32>]) -> Vec<ConformanceReport> {
+    unary_functions()
+        .into_iter()
+        .map(|f| sweep(f.name, grid, f.f32, f.f64, Format::F32))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[test]
+    fn test_format() {
+        assert_eq!(Format::F32.ulp(1.0), f32::EPSILON as f64);
+        assert_eq!(Format::F32.ulp(1.5), f32::EPSILON as f64);
+        assert_eq!(Format::F32.ulp(-2.0), 2.0 * f32::EPSILON as f64);
+        assert_eq!(Format::F32.ulp(0.0), 2f64.powi(-149), "Subnormal spacing!");
+        assert_eq!(Format::F16.ulp(1.0), 2f64.powi(-10));
+        assert!(Format::F32.in_range(f32::MAX as f64));
+        assert!(!Format::F32.in_range(2f64.powi(128)));
+    }
+    #[test]
+    fn test_sweep() {
+        let grid = structured_grid(6);
+        assert_eq!(grid.len(), 27 * 27 + 24 * 13);
+        // the candidate agrees with the reference up to the rounding to f32
+        let report = sweep(
+            "exact",
+            &grid,
+            |z| Some(z * z),
+            |z| Some(z * z),
+            Format::F32,
+        );
+        assert!(report.max_ulps <= 1.0, "{report:?}");
+        assert_eq!((report.samples, report.skipped), (grid.len(), 0));
+        // a candidate of half precision deviates by many ulps of f32, but few of f16
+        let half = |z: Perplex<f32>| {
+            let round = |v: f32| {
+                let ulp = Format::F16.ulp(v as f64) as f32;
+                (v / ulp).round() * ulp
+            };
+            Some(Perplex::new(round(z.t.sin()), round(z.x.sin())))
+        };
+        let reference = |z: Perplex<f64>| Some(Perplex::new(z.t.sin(), z.x.sin()));
+        let f32_report = sweep("sin", &grid, half, reference, Format::F32);
+        let f16_report = sweep("sin", &grid, half, reference, Format::F16);
+        assert!(f32_report.max_ulps > 100.0);
+        assert!(f16_report.max_ulps <= 0.5 + 1e-3, "{f16_report:?}");
+        // mismatches of the domain and overflows are counted separately
+        let report = sweep(
+            "mismatch",
+            &grid,
+            Some,
+            |z| (z.t > 0.0).then_some(z * 1e300),
+            Format::F32,
+        );
+        assert!(report.domain_mismatches > 0 && report.skipped > 0);
+        assert_eq!(report.samples, 0);
+        assert_eq!(report.worst_input, None);
+    }
+    #[test]
+    fn test_unary_functions() {
+        let grid = structured_grid(6);
+        let reports = sweep_unary_functions(&grid);
+        assert_eq!(reports.len(), unary_functions().len());
+        for report in &reports {
+            assert!(report.samples > 0, "{report:?}");
+            let bound = match report.function {
+                "conj" => 0.0,
+                // the rounding of the argument scaled by ln(2) is amplified by the derivative
+                "exp2" => 64.0,
+                // the deviations close to the light-like lines grow with the cancellation of t^2 - x^2
+                "ln" | "log2" | "log10" => 512.0,
+                "atanh" => 1e6,
+                _ => 4.0,
+            };
+            assert!(report.max_ulps <= bound, "{report:?}");
+        }
+    }
+}
@@ -0,0 +1,246 @@
+//! # Consts Module
+//!
+//! This module provides constants as `Perplex` values in the submodules `f64` and `f32`, analogous to `std::f64::consts` and `std::f32::consts`.
+//!
+//! ## Features
+//! - The mathematical constants of the standard library like `E`, `PI` and `LN_2` lifted to the time component.
+//! - `ZERO`, `ONE` and the hyperbolic unit `H`.
+//! - The idempotents `IDEMPOTENT_PLUS = (1 + h) / 2` and `IDEMPOTENT_MINUS = (1 - h) / 2` of the null basis. Every perplex number decomposes into `z = u IDEMPOTENT_PLUS + v IDEMPOTENT_MINUS` with the null components `u = t + x` and `v = t - x`, see Sec. 4.1 in [The Mathematics of Minkowski Space-Time](https://doi.org/10.1007/978-3-7643-8614-6).
+//! - `CIS_TABLE`: The boosts `cis(k CIS_STEP)` on the unit hyperbola, which are evaluated and verified at compile time, and `cis_lookup` for fast boosts in inner loops on targets with slow transcendental functions.
+//! - `cis_table!`: The compile-time table for another step and length, which is evaluated by `cis_lookup_with`.
+//!
+//! ## Example
+//! ```
+//! use perplex_num::consts::f64::{IDEMPOTENT_MINUS, IDEMPOTENT_PLUS};
+//! use perplex_num::Perplex;
+//! let z = Perplex::new(2.0, 0.5);
+//! let (u, v) = (z.t + z.x, z.t - z.x);
+//! assert_eq!(IDEMPOTENT_PLUS.scale(u) + IDEMPOTENT_MINUS.scale(v), z);
+//! assert_eq!(IDEMPOTENT_PLUS * IDEMPOTENT_PLUS, IDEMPOTENT_PLUS);
+//! ```
+//!
+//! The table lookup replaces `cosh` and `sinh` by a multiplication with a table entry and a short polynomial:
+//! ```
+//! use perplex_num::consts::{cis_lookup, cis_lookup_with};
+//! use perplex_num::Perplex;
+//! let theta = 1.2345;
+//! let error = (cis_lookup(theta) - Perplex::cis(theta)).max_norm();
+//! assert!(error <= 1e-15 * theta.cosh());
+//! // a coarser table with the step 1 / 4 for arguments up to 4
+//! const TABLE: [Perplex<f64>; 17] = perplex_num::cis_table!(17, 0.25);
+//! let error = (cis_lookup_with(&TABLE, 0.25, -theta) - Perplex::cis(-theta)).max_norm();
+//! assert!(error <= 1e-11);
+//! ```
+
+use crate::Perplex;
+use num_traits::Float;
+
+/// Evaluates the table `[cis(0), cis(step), ..., cis((len - 1) step)]` of `Perplex<f64>` values for a positive `step` in a constant context.
+///
+/// The entries sum the Taylor series of `cosh` and `sinh`, whose terms are positive, such that they are accurate to a few ulps without calling transcendental functions at runtime.
+///
+/// # Example
+/// ```
+/// use perplex_num::Perplex;
+/// const TABLE: [Perplex<f64>; 5] = perplex_num::cis_table!(5, 0.5);
+/// assert!((TABLE[3] - Perplex::cis(1.5)).max_norm() <= 1e-15);
+/// ```
+#[macro_export]
+macro_rules! cis_table {
+    ($len:expr, $step:expr) => {{
+        let step: f64 = $step;
+        let mut table = [$crate::Perplex::new(1.0, 0.0); $len];
+        let mut k = 1;
+        while k < $len {
+            let theta = k as f64 * step;
+            let (mut cosh, mut sinh, mut term, mut n) = (1.0, 0.0, 1.0, 1.0);
+            while term > cosh * f64::EPSILON {
+                term *= theta / n;
+                sinh += term;
+                term *= theta / (n + 1.0);
+                cosh += term;
+                n += 2.0;
+            }
+            table[k] = $crate::Perplex::new(cosh, sinh);
+            k += 1;
+        }
+        table
+    }};
+}
+
+/// The step `1 / 32` of the arguments of `CIS_TABLE`.
+pub const CIS_STEP: f64 = 0.03125;
+
+/// The boosts `cis(k CIS_STEP)` for `k = 0, ..., 256`, i.e., for the arguments in `[0, 8]`.
+pub const CIS_TABLE: [Perplex<f64>; 257] = cis_table!(257, CIS_STEP);
+
+// verifies at compile time that all entries are on the unit hyperbola up to the rounding of the squares
+const _: () = {
+    let mut k = 0;
+    while k < CIS_TABLE.len() {
+        let z = CIS_TABLE[k];
+        let defect = z.t * z.t - z.x * z.x - 1.0;
+        let tolerance = 16.0 * f64::EPSILON * z.t * z.t;
+        assert!(-tolerance <= defect && defect <= tolerance);
+        assert!(k == 0 || CIS_TABLE[k - 1].x < z.x);
+        k += 1;
+    }
+};
+
+/// Returns `cis(theta)` by `CIS_TABLE`, which is accurate to a few ulps relative to `cosh(theta)`, see `cis_lookup_with`.
+#[inline]
+pub fn cis_lookup(theta: f64) -> Perplex<f64> {
+    cis_lookup_with(&CIS_TABLE, CIS_STEP, theta)
+}
+
+/// Returns `cis(theta)` by a table of `cis(k step)`, e.g., of `cis_table!`, or by `Perplex::cis` if `|theta|` is beyond the table.
+///
+/// The lookup interpolates between the entries by the addition theorem `cis(k step + r) = cis(k step) cis(r)` for the nearest entry `k`, whereby `cis(r)` with `|r| <= step / 2` is the Taylor polynomial of degree seven. Its relative truncation error `(step / 2)^8 / 8!` is below the rounding for steps up to `1 / 8`. Negative arguments are mapped to the conjugate of `cis(-theta)`.
+#[inline]
+pub fn cis_lookup_with(table: &[Perplex<f64>], step: f64, theta: f64) -> Perplex<f64> {
+    let magnitude = Float::abs(theta);
+    let k = Float::round(magnitude / step);
+    // NaN arguments are also computed by `Perplex::cis`
+    if k.is_nan() || k >= table.len() as f64 {
+        return Perplex::cis(theta);
+    }
+    let r = magnitude - k * step;
+    let r2 = r * r;
+    let cosh = 1.0 + r2 * (1.0 / 2.0 + r2 * (1.0 / 24.0 + r2 / 720.0));
+    let sinh = r * (1.0 + r2 * (1.0 / 6.0 + r2 * (1.0 / 120.0 + r2 / 5040.0)));
+    let z = table[k as usize] * Perplex::new(cosh, sinh);
+    if theta < 0.0 {
+        z.conj()
+    } else {
+        z
+    }
+}
+
+macro_rules! perplex_consts {
+    ($float:ident, $($name:ident),*) => {
+        use crate::Perplex;
+        $(
+            #[doc = concat!("The constant `", stringify!($name), "` of `std::", stringify!($float), "::consts` as time component.")]
+            pub const $name: Perplex<$float> = Perplex::new(core::$float::consts::$name, 0.0);
+        )*
+        /// The additive identity `0`.
+        pub const ZERO: Perplex<$float> = Perplex::new(0.0, 0.0);
+        /// The multiplicative identity `1`.
+        pub const ONE: Perplex<$float> = Perplex::new(1.0, 0.0);
+        /// The hyperbolic unit `h` with `h^2 = 1`.
+        pub const H: Perplex<$float> = Perplex::new(0.0, 1.0);
+        /// The idempotent `(1 + h) / 2` projecting onto the null component `u = t + x`.
+        pub const IDEMPOTENT_PLUS: Perplex<$float> = Perplex::new(0.5, 0.5);
+        /// The idempotent `(1 - h) / 2` projecting onto the null component `v = t - x`.
+        pub const IDEMPOTENT_MINUS: Perplex<$float> = Perplex::new(0.5, -0.5);
+    };
+}
+
+/// Perplex constants for `f64`.
+pub mod f64 {
+    perplex_consts!(
+        f64,
+        E,
+        PI,
+        TAU,
+        SQRT_2,
+        FRAC_1_SQRT_2,
+        LN_2,
+        LN_10,
+        LOG2_E,
+        LOG2_10,
+        LOG10_E,
+        LOG10_2,
+        FRAC_PI_2,
+        FRAC_PI_3,
+        FRAC_PI_4,
+        FRAC_PI_6,
+        FRAC_PI_8,
+        FRAC_1_PI,
+        FRAC_2_PI,
+        FRAC_2_SQRT_PI
+    );
+}
+
+/// Perplex constants for `f32`.
+pub mod f32 {
+    perplex_consts!(
+        f32,
+        E,
+        PI,
+        TAU,
+        SQRT_2,
+        FRAC_1_SQRT_2,
+        LN_2,
+        LN_10,
+        LOG2_E,
+        LOG2_10,
+        LOG10_E,
+        LOG10_2,
+        FRAC_PI_2,
+        FRAC_PI_3,
+        FRAC_PI_4,
+        FRAC_PI_6,
+        FRAC_PI_8,
+        FRAC_1_PI,
+        FRAC_2_PI,
+        FRAC_2_SQRT_PI
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::f64::*;
+    use crate::Perplex;
+    use num_traits::{One, Zero};
+    #[test]
+    fn test_consts() {
+        assert_eq!(ZERO, Perplex::zero());
+        assert_eq!(ONE, Perplex::one());
+        assert_eq!(H, Perplex::h());
+        assert_eq!(H * H, ONE);
+        assert_eq!(E, Perplex::new(std::f64::consts::E, 0.0));
+        assert_eq!(LN_2.exp().t, 2.0);
+        assert_eq!(super::f32::PI, Perplex::new(std::f32::consts::PI, 0.0));
+    }
+    #[test]
+    fn test_cis_table() {
+        use super::{cis_lookup, cis_lookup_with, CIS_STEP, CIS_TABLE};
+        for (k, z) in CIS_TABLE.iter().enumerate() {
+            let expected = Perplex::cis(k as f64 * CIS_STEP);
+            assert!((*z - expected).max_norm() <= 4.0 * f64::EPSILON * expected.t);
+        }
+        for i in -1000..=1000 {
+            let theta = i as f64 * 0.00917;
+            let expected = Perplex::cis(theta);
+            let error = (cis_lookup(theta) - expected).max_norm();
+            assert!(
+                error <= 4.0 * f64::EPSILON * expected.t,
+                "Lookup of {theta}!"
+            );
+        }
+        assert_eq!(cis_lookup(0.0), ONE);
+        assert_eq!(cis_lookup(9.0), Perplex::cis(9.0), "Beyond the table!");
+        assert!(cis_lookup(f64::NAN).t.is_nan());
+        const TABLE: [Perplex<f64>; 9] = crate::cis_table!(9, 0.125);
+        assert_eq!(
+            TABLE[..],
+            CIS_TABLE[..33]
+                .iter()
+                .step_by(4)
+                .copied()
+                .collect::<Vec<_>>()[..]
+        );
+        let error = (cis_lookup_with(&TABLE, 0.125, 0.9) - Perplex::cis(0.9)).max_norm();
+        assert!(error <= 1e-15);
+    }
+    #[test]
+    fn test_idempotents() {
+        assert_eq!(IDEMPOTENT_PLUS * IDEMPOTENT_PLUS, IDEMPOTENT_PLUS);
+        assert_eq!(IDEMPOTENT_MINUS * IDEMPOTENT_MINUS, IDEMPOTENT_MINUS);
+        assert_eq!(IDEMPOTENT_PLUS * IDEMPOTENT_MINUS, ZERO, "Zero divisors!");
+        assert_eq!(IDEMPOTENT_PLUS + IDEMPOTENT_MINUS, ONE);
+        assert_eq!(IDEMPOTENT_PLUS - IDEMPOTENT_MINUS, H);
+        assert!(IDEMPOTENT_PLUS.is_light_like() && IDEMPOTENT_MINUS.is_light_like());
+    }
+}
@@ -0,0 +1,271 @@
+//! # Cordic Module
+//!
+//! This module provides the hyperbolic CORDIC algorithm, which computes `cosh`, `sinh` and `atanh` by shifts and additions only, for scalar types that implement `Num`, `PartialOrd` and the right shift `Shr<u32>` as the division by a power of two, e.g., the fixed-point types of embedded targets without a floating-point unit.
+//! The iterations `i = 1, 2, ...` rotate by `±atanh(2^-i)`, whereby the iterations `4, 13, 40, ...` with `k -> 3 k + 1` are repeated to ensure the convergence, see [A unified algorithm for elementary functions](https://doi.org/10.1145/1478786.1478840).
+//! The constants `atanh(2^-i)` and the gain of the rotations are evaluated by series in the scalar type, hence the algorithm needs no floating-point constants and its precision is set by the scalar type and the number of iterations.
+//!
+//! ## Features
+//! - `exp_hyperbolic`: The pair `(cosh(theta), sinh(theta))`, i.e., the components of `Perplex::cis(theta)`, whereby arguments beyond the convergence range are halved and the results are doubled by the double-argument formulas.
+//! - `atanh`: The inverse hyperbolic tangent within the convergence range `|y| <= tanh(range(iterations))`.
+//! - `range`: The convergence range `sum atanh(2^-i)` of the rotations, which is about `1.118` for many iterations.
+//!
+//! ## Example
+//! ```
+//! use perplex_num::cordic::{atanh, exp_hyperbolic};
+//! # use core::ops::{Add, Div, Mul, Rem, Shr, Sub};
+//! # /// A floating-point scalar whose right shift divides by a power of two.
+//! # #[derive(Copy, Clone, Debug, PartialEq, PartialOrd)]
+//! # struct Scalar(f64);
+//! # macro_rules! ops { ($($imp:ident $method:ident),*) => {$(
+//! #     impl $imp for Scalar { type Output = Self; fn $method(self, rhs: Self) -> Self { Scalar(self.0.$method(rhs.0)) } }
+//! # )*}; }
+//! # ops!(Add add, Sub sub, Mul mul, Div div, Rem rem);
+//! # impl Shr<u32> for Scalar { type Output = Self; fn shr(self, n: u32) -> Self { Scalar(self.0 / 2f64.powi(n as i32)) } }
+//! # impl num_traits::Zero for Scalar { fn zero() -> Self { Scalar(0.0) } fn is_zero(&self) -> bool { self.0 == 0.0 } }
+//! # impl num_traits::One for Scalar { fn one() -> Self { Scalar(1.0) } }
+//! # impl num_traits::Num for Scalar {
+//! #     type FromStrRadixErr = num_traits::ParseFloatError;
+//! #     fn from_str_radix(s: &str, radix: u32) -> Result<Self, Self::FromStrRadixErr> { <f64 as num_traits::Num>::from_str_radix(s, radix).map(Scalar) }
+//! # }
+//! let (cosh, sinh) = exp_hyperbolic(Scalar(0.75), 48);
+//! assert!((cosh.0 - 0.75f64.cosh()).abs() < 1e-13 && (sinh.0 - 0.75f64.sinh()).abs() < 1e-13);
+//! let theta = atanh(Scalar(0.5), 48).unwrap();
+//! assert!((theta.0 - 0.5f64.atanh()).abs() < 1e-13);
+//! ```
+
+use core::ops::Shr;
+use num_traits::Num;
+
+/// Returns the shift exponents `1, 2, ..., iterations` of the rotations, whereby `4, 13, 40, ...` are repeated.
+#[inline]
+fn shifts(iterations: u32) -> impl Iterator<Item = u32> {
+    let mut repeat = 4;
+    (1..=iterations).flat_map(move |i| {
+        let count = if i == repeat {
+            repeat = 3 * repeat + 1;
+            2
+        } else {
+            1
+        };
+        core::iter::repeat(i).take(count)
+    })
+}
+
+/// Returns `atanh(2^-i)` by its series `sum 2^(-i (2k + 1)) / (2k + 1)`, which is summed until the terms vanish in the scalar type.
+#[inline]
+fn atanh_pow2<T: Copy + Num + Shr<u32, Output = T>>(i: u32) -> T {
+    let two = T::one() + T::one();
+    let (mut power, mut odd, mut sum) = (T::one() >> i, T::one(), T::zero());
+    loop {
+        let next = sum + power / odd;
+        if next == sum {
+            return sum;
+        }
+        sum = next;
+        power = (power >> i) >> i;
+        odd = odd + two;
+    }
+}
+
+/// Returns the convergence range `sum atanh(2^-i)` over the rotations of `iterations`, i.e., the arguments `|theta| <= range(iterations)` of `exp_hyperbolic` are computed without halving.
+#[inline]
+pub fn range<T: Copy + Num + Shr<u32, Output = T>>(iterations: u32) -> T {
+    shifts(iterations).fold(T::zero(), |sum, i| sum + atanh_pow2(i))
+}
+
+/// Returns the inverse of the gain `prod sqrt(1 - 2^(-2 i))` of the rotations of `iterations`.
+///
+/// The squared gain is a product of shifts, and its inverse square root is found by the Newton iteration `y -> y (3 - g y^2) / 2` from `1.25`, which converges quadratically to about `1.2075`.
+#[inline]
+fn inverse_gain<T: Copy + Num + Shr<u32, Output = T>>(iterations: u32) -> T {
+    let squared = shifts(iterations).fold(T::one(), |g, i| g - ((g >> i) >> i));
+    let three = T::one() + T::one() + T::one();
+    (0..8).fold(T::one() + (T::one() >> 2), |y, _| {
+        (y * (three - squared * y * y)) >> 1
+    })
+}
+
+/// Returns `(cosh(theta), sinh(theta))` by `iterations` rotations of the hyperbolic CORDIC algorithm in rotation mode, whose error is about `2^-iterations` plus the rounding of the scalar type.
+///
+/// Arguments beyond `range(iterations)` are halved until they converge, and the results are doubled by `cosh(2 a) = cosh(a)^2 + sinh(a)^2` and `sinh(2 a) = 2 sinh(a) cosh(a)`, which amplifies the rounding errors by the number of halvings.
+pub fn exp_hyperbolic<T: Copy + Num + PartialOrd + Shr<u32, Output = T>>(
+    theta: T,
+    iterations: u32,
+) -> (T, T) {
+    let limit = range::<T>(iterations);
+    let (mut theta, mut halvings) = (theta, 0);
+    while theta > limit || theta < T::zero() - limit {
+        theta = theta >> 1;
+        halvings += 1;
+    }
+    let (mut x, mut y, mut z) = (inverse_gain::<T>(iterations), T::zero(), theta);
+    for i in shifts(iterations) {
+        let (dx, dy, dz) = (y >> i, x >> i, atanh_pow2::<T>(i));
+        if z < T::zero() {
+            (x, y, z) = (x - dx, y - dy, z + dz);
+        } else {
+            (x, y, z) = (x + dx, y + dy, z - dz);
+        }
+    }
+    for _ in 0..halvings {
+        let product = x * y;
+        (x, y) = (x * x + y * y, product + product);
+    }
+    (x, y)
+}
+
+/// Returns `atanh(y)` by `iterations` rotations of the hyperbolic CORDIC algorithm in vectoring mode, which rotate `1 + y h` onto the time axis, or `None` if `|y|` exceeds `tanh(range(iterations))`, which is about `0.8068` for many iterations.
+///
+/// Larger arguments are reduced by the caller, e.g., by `atanh(y) = ln((1 + y) / (1 - y)) / 2` with a logarithm of the scalar type.
+pub fn atanh<T: Copy + Num + PartialOrd + Shr<u32, Output = T>>(
+    y: T,
+    iterations: u32,
+) -> Option<T> {
+    let (cosh, sinh) = exp_hyperbolic(range::<T>(iterations), iterations);
+    // |y| <= sinh / cosh without division
+    if y * cosh > sinh || y * cosh < T::zero() - sinh {
+        return None;
+    }
+    let (mut x, mut y, mut z) = (T::one(), y, T::zero());
+    for i in shifts(iterations) {
+        let (dx, dy, dz) = (y >> i, x >> i, atanh_pow2::<T>(i));
+        if y < T::zero() {
+            (x, y, z) = (x + dx, y + dy, z - dz);
+        } else {
+            (x, y, z) = (x - dx, y - dy, z + dz);
+        }
+    }
+    Some(z)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::ops::{Add, Div, Mul, Rem, Sub};
+    use num_traits::{One, Zero};
+
+    /// A fixed-point scalar with 32 fractional bits.
+    #[derive(Copy, Clone, Debug, PartialEq, PartialOrd)]
+    struct Q32(i64);
+
+    const FRAC: f64 = (1u64 << 32) as f64;
+
+    impl Q32 {
+        fn from_f64(v: f64) -> Self {
+            Self((v * FRAC).round() as i64)
+        }
+        fn to_f64(self) -> f64 {
+            self.0 as f64 / FRAC
+        }
+    }
+    impl Add for Q32 {
+        type Output = Self;
+        fn add(self, rhs: Self) -> Self {
+            Self(self.0 + rhs.0)
+        }
+    }
+    impl Sub for Q32 {
+        type Output = Self;
+        fn sub(self, rhs: Self) -> Self {
+            Self(self.0 - rhs.0)
+        }
+    }
+    impl Mul for Q32 {
+        type Output = Self;
+        fn mul(self, rhs: Self) -> Self {
+            Self(((self.0 as i128 * rhs.0 as i128) >> 32) as i64)
+        }
+    }
+    impl Div for Q32 {
+        type Output = Self;
+        fn div(self, rhs: Self) -> Self {
+            Self((((self.0 as i128) << 32) / rhs.0 as i128) as i64)
+        }
+    }
+    impl Rem for Q32 {
+        type Output = Self;
+        fn rem(self, rhs: Self) -> Self {
+            Self(self.0 % rhs.0)
+        }
+    }
+    impl Shr<u32> for Q32 {
+        type Output = Self;
+        fn shr(self, n: u32) -> Self {
+            Self(self.0 >> n)
+        }
+    }
+    impl Zero for Q32 {
+        fn zero() -> Self {
+            Self(0)
+        }
+        fn is_zero(&self) -> bool {
+            self.0 == 0
+        }
+    }
+    impl One for Q32 {
+        fn one() -> Self {
+            Self(1 << 32)
+        }
+    }
+    impl Num for Q32 {
+        type FromStrRadixErr = core::num::ParseIntError;
+        fn from_str_radix(s: &str, radix: u32) -> Result<Self, Self::FromStrRadixErr> {
+            i64::from_str_radix(s, radix).map(|v| Self(v << 32))
+        }
+    }
+
+    #[test]
+    fn test_constants() {
+        assert_eq!(
+            shifts(14).collect::<Vec<_>>().len(),
+            16,
+            "Repeated 4 and 13!"
+        );
+        for i in 1..20 {
+            let expected = (0.5f64.powi(i as i32)).atanh();
+            // the truncation of each term costs up to one unit of 2^-32
+            assert!((atanh_pow2::<Q32>(i).to_f64() - expected).abs() < 1e-8);
+        }
+        assert!((range::<Q32>(32).to_f64() - 1.118173).abs() < 1e-6);
+        assert!((inverse_gain::<Q32>(32).to_f64() - 1.207497).abs() < 1e-6);
+    }
+    #[test]
+    fn test_exp_hyperbolic() {
+        for k in -40..=40 {
+            let theta = k as f64 * 0.1;
+            let (cosh, sinh) = exp_hyperbolic(Q32::from_f64(theta), 32);
+            let tolerance = 1e-7 * theta.cosh();
+            assert!(
+                (cosh.to_f64() - theta.cosh()).abs() < tolerance,
+                "cosh({theta})!"
+            );
+            assert!(
+                (sinh.to_f64() - theta.sinh()).abs() < tolerance,
+                "sinh({theta})!"
+            );
+        }
+        // the precision is limited by the number of iterations
+        let (cosh, _) = exp_hyperbolic(Q32::from_f64(0.5), 8);
+        assert!((cosh.to_f64() - 0.5f64.cosh()).abs() < 1e-2);
+        assert!(exp_hyperbolic(Q32::zero(), 32).1.to_f64().abs() < 1e-9);
+    }
+    #[test]
+    fn test_atanh() {
+        for k in -16..=16 {
+            let y = k as f64 * 0.05;
+            let z = atanh(Q32::from_f64(y), 32).unwrap();
+            assert!((z.to_f64() - y.atanh()).abs() < 1e-8, "atanh({y})!");
+        }
+        assert!(
+            atanh(Q32::from_f64(0.81), 32).is_none(),
+            "Beyond the range!"
+        );
+        assert!(
+            atanh(Q32::from_f64(-0.9), 32).is_none(),
+            "Beyond the range!"
+        );
+        let (cosh, sinh) = exp_hyperbolic(Q32::from_f64(0.3), 32);
+        let z = atanh(sinh / cosh, 32).unwrap();
+        assert!((z.to_f64() - 0.3).abs() < 1e-8);
+    }
+}
@@ -0,0 +1,290 @@
+//! # Debug Module
+//!
+//! This module helps to track down the origin of NaN components in long pipelines of perplex operations (optional feature `nan-debug`).
+//! With the feature, the arithmetic operators of `binary_ops` check their results, and an operation that produces a NaN component from operands without NaN components is recorded as the origin of the NaN in a thread-local `NanEvent`.
+//! Operations on operands, which already contain NaN components, only propagate the NaN and are not recorded, such that the event points at the first operation of the pipeline that created a NaN.
+//! The checks cost a comparison per component of each operation, hence the feature is meant for debugging only.
+//! The elementary functions of floating-point perplex numbers, e.g., `exp`, `ln`, `sqrt` and `atanh`, are recorded like the operators, and after the operators they consist of, such that the event names the function instead of its inner operation.
+//! The event contains the operands converted to `f64`, whereby the conversion only uses the arithmetic of `Num`, since the operators are generic over the component type without conversion bounds.
+//! The conversion is exact for `f32` and `f64` components and approximates other components, e.g., rationals, by 64 significant bits.
+//!
+//! ## Example
+//! ```
+//! use perplex_num::{debug, Perplex};
+//! debug::clear_nan_event();
+//! let z = Perplex::new(f64::INFINITY, 1.0);
+//! let w = (z * Perplex::new(0.0, 1.0)) + Perplex::new(1.0, 0.0);
+//! assert!(w.is_nan());
+//! let event = debug::last_nan_event().unwrap();
+//! assert_eq!(event.operation, "mul");
+//! assert_eq!(event.type_name, "f64");
+//! assert_eq!(event.operands, vec![z, Perplex::new(0.0, 1.0)]);
+//! ```
+
+use super::Perplex;
+use num_traits::Num;
+use std::any::type_name;
+use std::cell::RefCell;
+use std::vec::Vec;
+
+/// The record of an operation which produced a NaN component from operands without NaN components.
+#[derive(Clone, Debug, PartialEq)]
+pub struct NanEvent {
+    /// The name of the operation, e.g., `"mul"` or `"div_assign"`.
+    pub operation: &'static str,
+    /// The name of the component type of the operands for diagnostic purposes, see `std::any::type_name`.
+    pub type_name: &'static str,
+    /// The operands converted to `f64`, e.g., the factors of `"mul"` or the argument of `"ln"`.
+    pub operands: Vec<Perplex<f64>>,
+}
+
+thread_local! {
+    static LAST_NAN_EVENT: RefCell<Option<NanEvent>> = const { RefCell::new(None) };
+}
+
+/// Returns the last operation of the current thread which produced a NaN component from operands without NaN components, or `None` if there is none since the last call of `clear_nan_event`.
+pub fn last_nan_event() -> Option<NanEvent> {
+    LAST_NAN_EVENT.with(|event| event.borrow().clone())
+}
+
+/// Removes the recorded event of the current thread.
+pub fn clear_nan_event() {
+    LAST_NAN_EVENT.with(|event| event.borrow_mut().take());
+}
+
+/// Records `operation` if `result` has a NaN component and none of the `operands` has a NaN component.
+#[inline]
+pub(crate) fn record<T: Clone + Num>(
+    operation: &'static str,
+    operands: &[Perplex<T>],
+    result: Perplex<T>,
+) {
+    if !has_nan(&result) || operands.iter().any(has_nan) {
+        return;
+    }
+    let event = NanEvent {
+        operation,
+        type_name: type_name::<T>(),
+        operands: operands
+            .iter()
+            .map(|z| Perplex::new(to_f64(&z.t), to_f64(&z.x)))
+            .collect(),
+    };
+    LAST_NAN_EVENT.with(|last| *last.borrow_mut() = Some(event));
+}
+
+/// Checks for NaN components, which are the only values not equal to themselves.
+#[inline]
+#[allow(clippy::eq_op)]
+fn has_nan<T: Clone + Num>(z: &Perplex<T>) -> bool {
+    z.t != z.t || z.x != z.x
+}
+
+/// Converts `a` to `f64` by the arithmetic of `Num`, i.e., by the binary digits of `a`, which are exact for binary floating-point and integer types.
+/// Components with more than 64 significant bits are truncated, and magnitudes beyond the range of `f64` saturate to zero or infinity.
+fn to_f64<T: Clone + Num>(a: &T) -> f64 {
+    // more halvings or doublings than needed for the range of f64 including subnormals
+    const MAX_SCALINGS: i32 = 1100;
+    let one = T::one();
+    let two = one.clone() + one.clone();
+    let trunc = |z: &T| z.clone() - z.clone() % one.clone();
+    if a.is_zero() {
+        return 0.0;
+    }
+    if !(a.clone() - a.clone()).is_zero() {
+        // a NaN or an infinity, since `a - a` of a finite number is zero
+        if has_nan(&Perplex::new(a.clone(), one.clone())) {
+            return f64::NAN;
+        }
+        let negative = T::from_str_radix("-inf", 10).is_ok_and(|inf| inf == *a);
+        return if negative {
+            f64::NEG_INFINITY
+        } else {
+            f64::INFINITY
+        };
+    }
+    if (one.clone() / two.clone()).is_zero() {
+        // integer division, hence the binary digits are the remainders of the division by two
+        let (mut n, mut value, mut scale) = (a.clone(), 0.0, 1.0f64);
+        while !n.is_zero() {
+            let bit = n.clone() % two.clone();
+            if bit == one {
+                value += scale;
+            } else if !bit.is_zero() {
+                value -= scale;
+            }
+            n = (n - bit) / two.clone();
+            scale *= 2.0;
+            if scale.is_infinite() && !n.is_zero() {
+                return if value < 0.0 {
+                    f64::NEG_INFINITY
+                } else {
+                    f64::INFINITY
+                };
+            }
+        }
+        return value;
+    }
+    // normalize to 1 <= |m| < 2 with a = m 2^exponent
+    let (mut m, mut exponent) = (a.clone(), 0);
+    while !trunc(&(m.clone() / two.clone())).is_zero() {
+        m = m / two.clone();
+        exponent += 1;
+        if exponent > MAX_SCALINGS {
+            return if trunc(&m) == one {
+                f64::INFINITY
+            } else {
+                f64::NEG_INFINITY
+            };
+        }
+    }
+    while trunc(&m).is_zero() {
+        m = m * two.clone();
+        exponent -= 1;
+        if exponent < -MAX_SCALINGS {
+            return 0.0;
+        }
+    }
+    let negative = trunc(&m) != one;
+    let mut mantissa = 0u64;
+    for _ in 0..u64::BITS {
+        let bit = trunc(&m);
+        mantissa = mantissa << 1 | u64::from(!bit.is_zero());
+        m = (m - bit) * two.clone();
+    }
+    // at most one rounding, since a mantissa of 53 bits is exact and 2^-1022 m is normal
+    let magnitude = (mantissa as f64) * pow2(1 - u64::BITS as i32);
+    let value = if exponent >= f64::MAX_EXP {
+        f64::INFINITY
+    } else if exponent >= f64::MIN_EXP - 1 {
+        magnitude * pow2(exponent)
+    } else if exponent >= 2 * (f64::MIN_EXP - 1) {
+        magnitude * pow2(f64::MIN_EXP - 1) * pow2(exponent - f64::MIN_EXP + 1)
+    } else {
+        0.0
+    };
+    if negative {
+        -value
+    } else {
+        value
+    }
+}
+
+/// Returns `2^exp` for a normal exponent `exp`, i.e., for `-1022 <= exp <= 1023`.
+fn pow2(exp: i32) -> f64 {
+    f64::from_bits(((exp + f64::MAX_EXP - 1) as u64) << (f64::MANTISSA_DIGITS - 1))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[test]
+    fn test_nan_origin() {
+        clear_nan_event();
+        let (z, w) = (Perplex::new(1.0, 2.0), Perplex::new(0.5, -1.0));
+        let _ = (z * w + z) / w;
+        assert_eq!(last_nan_event(), None, "No NaN is produced!");
+        let mut v = Perplex::new(1.0, 1.0);
+        v /= Perplex::new(2.0, 2.0);
+        let _ = v + z;
+        let event = last_nan_event().unwrap();
+        assert_eq!(
+            event.operation, "div_assign",
+            "The propagation is not recorded!"
+        );
+        assert_eq!(event.type_name, "f64");
+        clear_nan_event();
+        let _ = Perplex::new(f32::INFINITY, 0.0) * 0.0f32;
+        let event = last_nan_event().unwrap();
+        assert_eq!(event.operation, "mul_scalar");
+        assert_eq!(event.type_name, "f32");
+        clear_nan_event();
+        let _ = Perplex::new(1u8, 2u8) + Perplex::new(3u8, 4u8);
+        assert_eq!(last_nan_event(), None, "Integers are never NaN!");
+    }
+    #[test]
+    fn test_thread_local() {
+        clear_nan_event();
+        let _ = Perplex::new(f64::INFINITY, 0.0) - Perplex::new(f64::INFINITY, 0.0);
+        std::thread::spawn(|| assert_eq!(last_nan_event(), None))
+            .join()
+            .unwrap();
+        assert_eq!(last_nan_event().unwrap().operation, "sub");
+    }
+    #[test]
+    fn test_operands() {
+        clear_nan_event();
+        let (z, w) = (Perplex::new(f64::INFINITY, -2.5), Perplex::new(0.0, 1e-300));
+        let _ = z * w;
+        let event = last_nan_event().unwrap();
+        assert_eq!((event.operation, event.operands), ("mul", vec![z, w]));
+        let _ = Perplex::new(f32::INFINITY, 0.1f32) - Perplex::new(f32::INFINITY, 0.0);
+        let event = last_nan_event().unwrap();
+        assert_eq!(
+            event.operands,
+            vec![
+                Perplex::new(f64::INFINITY, 0.1f32 as f64),
+                Perplex::new(f64::INFINITY, 0.0)
+            ],
+            "The conversion of f32 is exact!"
+        );
+    }
+    #[test]
+    fn test_functions() {
+        clear_nan_event();
+        let z = Perplex::new(f64::INFINITY, 0.0);
+        assert!(z.exp().is_nan());
+        let event = last_nan_event().unwrap();
+        assert_eq!(
+            (event.operation, event.operands),
+            ("exp", vec![z]),
+            "The function is recorded after its inner operations!"
+        );
+        assert!(z.sinh().is_nan());
+        assert_eq!(last_nan_event().unwrap().operation, "sinh");
+        let _ = z.sinh().atanh();
+        assert_eq!(
+            last_nan_event().unwrap().operation,
+            "sinh",
+            "The propagation is not recorded!"
+        );
+        let w = Perplex::new(f64::INFINITY, f64::INFINITY);
+        assert!(w.tanh().is_nan());
+        let event = last_nan_event().unwrap();
+        assert_eq!(
+            (event.operation, event.operands),
+            ("tanh", vec![w]),
+            "The null component t - x is NaN!"
+        );
+    }
+    #[test]
+    fn test_to_f64() {
+        for a in [
+            1.0,
+            -2.75,
+            0.1,
+            1e300,
+            -1e-310,
+            f64::MAX,
+            f64::MIN,
+            f64::MIN_POSITIVE,
+            f64::EPSILON,
+            5e-324,
+            f64::INFINITY,
+            f64::NEG_INFINITY,
+        ] {
+            assert_eq!(to_f64(&a), a, "The conversion of {a} is exact!");
+        }
+        assert!(to_f64(&f64::NAN).is_nan());
+        assert_eq!(to_f64(&f32::MIN_POSITIVE), f32::MIN_POSITIVE as f64);
+        assert_eq!(to_f64(&-1e-40f32), -1e-40f32 as f64, "Subnormal!");
+        assert_eq!(to_f64(&i64::MIN), i64::MIN as f64);
+        assert_eq!(to_f64(&-13i8), -13.0);
+        assert_eq!(to_f64(&u64::MAX), u64::MAX as f64);
+        let huge = num_traits::pow(num_bigint::BigInt::from(10), 400);
+        assert_eq!(to_f64(&huge), f64::INFINITY);
+        assert_eq!(to_f64(&-huge), f64::NEG_INFINITY);
+        let third = num_rational::Ratio::new(-1i64, 3);
+        assert_eq!(to_f64(&third), -1.0 / 3.0);
+    }
+}
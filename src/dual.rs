@@ -0,0 +1,199 @@
+//! # Dual Module
+//!
+//! This module provides the `Dual` numbers `a + b ε` with `ε^2 = 0`, the parabolic sibling of the complex (`i^2 = -1`) and perplex (`h^2 = 1`) numbers among the two-dimensional real algebras, see Sec. 4.1 in [The Mathematics of Minkowski Space-Time](https://doi.org/10.1007/978-3-7643-8614-6).
+//! Since `f(a + b ε) = f(a) + f'(a) b ε` holds for every differentiable real function, the evaluation of a function at `x + ε` yields the exact first derivative in the dual part, without a step size or cancellation error, see `calculus::derivative_via_dual`.
+//!
+//! ## Features
+//! - Arithmetic operations `Add`, `Sub`, `Mul`, `Neg`, and `Div` with `None` for a divisor with zero real part, like `checked_div` of `Perplex`.
+//! - Elementary functions by the chain rule, and `lift` for arbitrary functions with known derivatives.
+//!
+//! ## Example
+//! ```
+//! use perplex_num::dual::Dual;
+//! let x = Dual::variable(2.0);
+//! let y = x * x * x + x.exp();
+//! assert_eq!(y.re, 8.0 + 2f64.exp());
+//! assert_eq!(y.eps, 12.0 + 2f64.exp(), "Exact derivative!");
+//! ```
+
+use core::ops::{Add, Div, Mul, Neg, Sub};
+use num_traits::{Float, Num};
+
+/// A dual number `re + eps ε` with the nilpotent unit `ε^2 = 0`.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug, Default)]
+pub struct Dual<T> {
+    /// The real part.
+    pub re: T,
+    /// The dual part, which carries the derivative.
+    pub eps: T,
+}
+
+impl<T: Copy + Num> Dual<T> {
+    /// Creates the dual number `re + eps ε`.
+    #[inline]
+    pub fn new(re: T, eps: T) -> Self {
+        Self { re, eps }
+    }
+    /// Creates the constant `c + 0 ε`, whose derivative is zero.
+    #[inline]
+    pub fn constant(c: T) -> Self {
+        Self::new(c, T::zero())
+    }
+    /// Creates the variable `x + 1 ε` of the differentiation.
+    #[inline]
+    pub fn variable(x: T) -> Self {
+        Self::new(x, T::one())
+    }
+    /// Multiplies both parts with the real factor `factor`.
+    #[inline]
+    pub fn scale(&self, factor: T) -> Self {
+        Self::new(self.re * factor, self.eps * factor)
+    }
+    /// Returns `f(re) + df(re) eps ε` of the real function `f` with the derivative `df`.
+    #[inline]
+    pub fn lift<F: Fn(T) -> T, D: Fn(T) -> T>(self, f: F, df: D) -> Self {
+        Self::new(f(self.re), df(self.re) * self.eps)
+    }
+}
+
+impl<T: Copy + Float> Dual<T> {
+    /// Returns the exponential function `exp(re) (1 + eps ε)`.
+    #[inline]
+    pub fn exp(self) -> Self {
+        let exp = self.re.exp();
+        Self::new(exp, exp * self.eps)
+    }
+    /// Returns the natural logarithm, which is NaN for a non-positive real part like `ln` of `T`.
+    #[inline]
+    pub fn ln(self) -> Self {
+        self.lift(T::ln, T::recip)
+    }
+    /// Returns the square root, whose dual part is infinite for a zero real part.
+    #[inline]
+    pub fn sqrt(self) -> Self {
+        let sqrt = self.re.sqrt();
+        Self::new(sqrt, self.eps / (sqrt + sqrt))
+    }
+    /// Returns the integer power `re^n + n re^(n - 1) eps ε`.
+    #[inline]
+    pub fn powi(self, n: i32) -> Self {
+        let n_t = T::from(n).unwrap();
+        Self::new(self.re.powi(n), n_t * self.re.powi(n - 1) * self.eps)
+    }
+    /// Returns the sine.
+    #[inline]
+    pub fn sin(self) -> Self {
+        self.lift(T::sin, T::cos)
+    }
+    /// Returns the cosine.
+    #[inline]
+    pub fn cos(self) -> Self {
+        self.lift(T::cos, |x| -x.sin())
+    }
+    /// Returns the hyperbolic sine.
+    #[inline]
+    pub fn sinh(self) -> Self {
+        self.lift(T::sinh, T::cosh)
+    }
+    /// Returns the hyperbolic cosine.
+    #[inline]
+    pub fn cosh(self) -> Self {
+        self.lift(T::cosh, T::sinh)
+    }
+}
+
+impl<T: Copy + Num> From<T> for Dual<T> {
+    /// See `Dual::constant`.
+    #[inline]
+    fn from(c: T) -> Self {
+        Self::constant(c)
+    }
+}
+
+impl<T: Copy + Num> Add for Dual<T> {
+    type Output = Self;
+    #[inline]
+    fn add(self, rhs: Self) -> Self::Output {
+        Self::new(self.re + rhs.re, self.eps + rhs.eps)
+    }
+}
+
+impl<T: Copy + Num> Sub for Dual<T> {
+    type Output = Self;
+    #[inline]
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self::new(self.re - rhs.re, self.eps - rhs.eps)
+    }
+}
+
+impl<T: Copy + Num> Mul for Dual<T> {
+    type Output = Self;
+    /// Multiplies by the product rule, since `ε^2 = 0`.
+    #[inline]
+    fn mul(self, rhs: Self) -> Self::Output {
+        Self::new(self.re * rhs.re, self.re * rhs.eps + self.eps * rhs.re)
+    }
+}
+
+impl<T: Copy + Num> Div for Dual<T> {
+    type Output = Option<Self>;
+    /// Divides by the quotient rule. Division by a number with zero real part, i.e., a zero divisor `b ε`, yields `None`.
+    #[inline]
+    fn div(self, rhs: Self) -> Self::Output {
+        if rhs.re.is_zero() {
+            None
+        } else {
+            let re = self.re / rhs.re;
+            Some(Self::new(re, (self.eps - re * rhs.eps) / rhs.re))
+        }
+    }
+}
+
+impl<T: Copy + Num + Neg<Output = T>> Neg for Dual<T> {
+    type Output = Self;
+    #[inline]
+    fn neg(self) -> Self::Output {
+        Self::new(-self.re, -self.eps)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_abs_diff_eq;
+    #[test]
+    fn test_arithmetic() {
+        let (a, b) = (Dual::new(2.0, 3.0), Dual::new(-1.0, 0.5));
+        assert_eq!(a + b, Dual::new(1.0, 3.5));
+        assert_eq!(a - b, Dual::new(3.0, 2.5));
+        assert_eq!(a * b, Dual::new(-2.0, -2.0));
+        assert_eq!(-a, Dual::new(-2.0, -3.0));
+        assert_eq!((a * b / b).unwrap(), a);
+        assert_eq!(a / Dual::new(0.0, 1.0), None, "ε is a zero divisor!");
+        let eps = Dual::new(0, 1);
+        assert_eq!(eps * eps, Dual::constant(0), "ε is nilpotent!");
+    }
+    #[test]
+    fn test_functions() {
+        let x = Dual::variable(0.7);
+        let cases = [
+            (x.exp(), 0.7f64.exp()),
+            (x.ln(), 1.0 / 0.7),
+            (x.sqrt(), 0.5 / 0.7f64.sqrt()),
+            (x.powi(-2), -2.0 / 0.343),
+            (x.sin(), 0.7f64.cos()),
+            (x.cos(), -0.7f64.sin()),
+            (x.sinh(), 0.7f64.cosh()),
+            (x.cosh(), 0.7f64.sinh()),
+        ];
+        for (y, derivative) in cases {
+            assert_abs_diff_eq!(y.eps, derivative, epsilon = 1e-14);
+        }
+        let chain = x.sin().exp().scale(2.0);
+        assert_abs_diff_eq!(
+            chain.eps,
+            2.0 * 0.7f64.sin().exp() * 0.7f64.cos(),
+            epsilon = 1e-14
+        );
+    }
+}
@@ -0,0 +1,484 @@
+//! # Dynamics Module
+//!
+//! This module provides tools for the analysis of discrete dynamical systems `z_{k+1} = f(z_k)` whose state is a perplex number.
+//!
+//! ## Lyapunov Exponents
+//! The largest Lyapunov exponent measures the mean exponential rate at which nearby orbits separate. It is estimated with the tangent-vector method of [Benettin et al.](https://doi.org/10.1007/BF02128236):
+//! A tangent vector is propagated along the orbit by the linearization of `f` and renormalized after each step, whereby the logarithms of the growth factors are accumulated.
+//! - `lyapunov`: For h-holomorphic maps, the linearization is the multiplication by the h-derivative `f'(z)`.
+//! - `lyapunov_jacobian`: For general maps of the plane, the linearization is given by the 2x2 Jacobian in (t, x) coordinates.
+//!
+//! ## Newton Fractals
+//! `newton_fractal` iterates Newton's method `z <- z - p(z) / p'(z)` for a `PerplexPolynomial` from each point of a grid over a `PerplexBox` and labels the point by the root it converges to.
+//! In contrast to the complex case, the iteration may also fail since the derivative becomes light-like, i.e., not invertible. Since the perplex product acts componentwise in the null basis, the basins are products of the real basins of the null components.
+//!
+//! ## Iterated Exponentials
+//! `iterated_exp` and `power_tower` iterate the sector-extended exponential function, whose orbits depend strikingly on the sector: e.g., the iterated exponential stays on the axis of `h` for the start `h`.
+//! Since the iterates grow like towers of exponentials, the logarithm of the maximum norm of the next iterate is computed in advance, and the iteration terminates before an overflow with a `TowerStatus::Overflow` that reports the logarithm.
+//!
+//! ## Example
+//! ```
+//! use perplex_num::{dynamics, Perplex};
+//! let c = Perplex::new(2.0, 1.0);
+//! // linear map z -> c z with constant h-derivative c
+//! let lambda = dynamics::lyapunov(|z| c * z, |_| c, Perplex::new(0.1, 0.2), 1000);
+//! // the separation grows with the larger null component t + x = 3 of c
+//! approx::assert_abs_diff_eq!(lambda, f64::ln(3.0), epsilon = 1e-3);
+//! ```
+
+use super::{Perplex, PerplexBox, PerplexPolynomial};
+use num_traits::{CheckedDiv, Float};
+
+/// Estimates the largest Lyapunov exponent of the iterated perplex map `f` starting at `z0` from `n` iterations.
+///
+/// The tangent vector is multiplied by the h-derivative `df(z_k)` at each point of the orbit and renormalized to unit L2 norm after each step.
+/// The result is the average of the logarithmic growth factors. A tangent vector that collapses onto the origin, e.g., due to a light-like h-derivative, yields negative infinity, and the estimate for `n = 0` iterations is NaN.
+#[inline]
+pub fn lyapunov<T, F, D>(f: F, df: D, z0: Perplex<T>, n: usize) -> T
+where
+    T: Copy + Float,
+    F: Fn(Perplex<T>) -> Perplex<T>,
+    D: Fn(Perplex<T>) -> Perplex<T>,
+{
+    let mut z = z0;
+    let mut tangent = Perplex::new(T::one(), T::zero());
+    let mut log_growth = T::zero();
+    for _ in 0..n {
+        tangent = df(z) * tangent;
+        let growth = tangent.l2_norm();
+        if growth.is_zero() || !growth.is_finite() {
+            // the renormalization is undefined, hence the logarithm of the growth determines the estimate
+            return growth.ln();
+        }
+        log_growth = log_growth + growth.ln();
+        tangent = tangent / growth;
+        z = f(z);
+    }
+    if n == 0 {
+        return T::nan();
+    }
+    log_growth / T::from(n).unwrap()
+}
+
+/// Estimates the largest Lyapunov exponent of an arbitrary map `f` of the hyperbolic plane starting at `z0` from `n` iterations.
+///
+/// The closure `jacobian` returns the 2x2 Jacobian `[[dt'/dt, dt'/dx], [dx'/dt, dx'/dx]]` of `f` in (t, x) coordinates.
+/// The tangent vector is renormalized to unit L2 norm after each step. As for `lyapunov`, a collapsed tangent vector yields negative infinity and `n = 0` yields NaN.
+#[inline]
+pub fn lyapunov_jacobian<T, F, J>(f: F, jacobian: J, z0: Perplex<T>, n: usize) -> T
+where
+    T: Copy + Float,
+    F: Fn(Perplex<T>) -> Perplex<T>,
+    J: Fn(Perplex<T>) -> [[T; 2]; 2],
+{
+    let mut z = z0;
+    let mut tangent = Perplex::new(T::one(), T::zero());
+    let mut log_growth = T::zero();
+    for _ in 0..n {
+        let [[a, b], [c, d]] = jacobian(z);
+        tangent = Perplex::new(a * tangent.t + b * tangent.x, c * tangent.t + d * tangent.x);
+        let growth = tangent.l2_norm();
+        if growth.is_zero() || !growth.is_finite() {
+            // the renormalization is undefined, hence the logarithm of the growth determines the estimate
+            return growth.ln();
+        }
+        log_growth = log_growth + growth.ln();
+        tangent = tangent / growth;
+        z = f(z);
+    }
+    if n == 0 {
+        return T::nan();
+    }
+    log_growth / T::from(n).unwrap()
+}
+
+/// The options of `newton_fractal`.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct NewtonOptions<T> {
+    /// The maximum number of Newton steps per grid point.
+    pub max_iterations: usize,
+    /// The iteration has converged if the maximum norm of the Newton step falls below the tolerance.
+    pub tolerance: T,
+    /// The iteration has diverged if the maximum norm of the iterate exceeds the bound.
+    pub bound: T,
+}
+
+impl<T: Copy + Float> Default for NewtonOptions<T> {
+    /// Defaults to 50 iterations, the square root of the machine epsilon as tolerance, and the bound `1e10`.
+    fn default() -> Self {
+        Self {
+            max_iterations: 50,
+            tolerance: T::epsilon().sqrt(),
+            bound: T::from(1e10).unwrap(),
+        }
+    }
+}
+
+/// The outcome of Newton's method from a grid point of `newton_fractal`.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub enum NewtonLabel {
+    /// The iteration has converged to the root of the given index in `NewtonFractal::roots`.
+    Root(usize),
+    /// The iterate has left the bound or is not finite.
+    Diverged,
+    /// The derivative has become light-like, such that the Newton step is undefined.
+    LightLikeDerivative,
+    /// The maximum number of iterations has been reached.
+    Unconverged,
+}
+
+/// The result of `newton_fractal` with row-major buffers of the grid points, whereby the rows ascend in the space component and the columns ascend in the time component.
+#[derive(Clone, Debug, PartialEq)]
+pub struct NewtonFractal<T> {
+    /// The number of columns, i.e., of grid points along the time axis.
+    pub width: usize,
+    /// The number of rows, i.e., of grid points along the space axis.
+    pub height: usize,
+    /// The roots referenced by `NewtonLabel::Root`, i.e., the roots of `PerplexPolynomial::roots` followed by further limits of the iteration in the order of their discovery.
+    pub roots: Vec<Perplex<T>>,
+    /// The outcome of each grid point.
+    pub labels: Vec<NewtonLabel>,
+    /// The number of performed Newton steps of each grid point.
+    pub iterations: Vec<usize>,
+}
+
+/// Iterates Newton's method for the polynomial `p` from the centers of a `width x height` grid of cells over `region` and labels each point by its outcome.
+///
+/// A converged iterate is assigned to the first known root within ten times the tolerance in the maximum norm. Limits which are no roots of `PerplexPolynomial::roots`, e.g., for null components of degree greater than two, are appended to the roots.
+///
+/// # Examples
+///
+/// ```
+/// use perplex_num::dynamics::{newton_fractal, NewtonLabel, NewtonOptions};
+/// use perplex_num::{Perplex, PerplexBox, PerplexPolynomial};
+/// // p(z) = z^2 - 1 with the roots 1, -1, h and -h
+/// let one = Perplex::new(1.0, 0.0);
+/// let p = PerplexPolynomial::new(vec![-one, Perplex::new(0.0, 0.0), one]);
+/// let region = PerplexBox::new(Perplex::new(-2.0, -2.0), Perplex::new(2.0, 2.0)).unwrap();
+/// let fractal = newton_fractal(&p, region, 64, 64, NewtonOptions::default());
+/// assert_eq!(fractal.roots.len(), 4);
+/// assert!(fractal.labels.iter().any(|&label| label == NewtonLabel::Root(0)));
+/// ```
+pub fn newton_fractal<T: Copy + Float>(
+    p: &PerplexPolynomial<T>,
+    region: PerplexBox<T>,
+    width: usize,
+    height: usize,
+    options: NewtonOptions<T>,
+) -> NewtonFractal<T> {
+    let derivative = p.derivative();
+    let mut roots = p.roots().unwrap_or_default();
+    let mut labels = Vec::with_capacity(width * height);
+    let mut iterations = Vec::with_capacity(width * height);
+    let two = T::one() + T::one();
+    let cell = |min: T, max: T, i: usize, n: usize| {
+        min + (max - min) * (T::from(i).unwrap() + T::one() / two) / T::from(n).unwrap()
+    };
+    let match_radius = T::from(10).unwrap() * options.tolerance;
+    for row in 0..height {
+        let x = cell(region.min.x, region.max.x, row, height);
+        for column in 0..width {
+            let mut z = Perplex::new(cell(region.min.t, region.max.t, column, width), x);
+            let mut label = NewtonLabel::Unconverged;
+            let mut steps = 0;
+            while steps < options.max_iterations {
+                let Some(step) = p.eval(z).checked_div(&derivative.eval(z)) else {
+                    label = NewtonLabel::LightLikeDerivative;
+                    break;
+                };
+                z = z - step;
+                steps += 1;
+                let size = z.max_norm();
+                if !size.is_finite() || size > options.bound {
+                    label = NewtonLabel::Diverged;
+                    break;
+                }
+                if step.max_norm() <= options.tolerance {
+                    let index = roots
+                        .iter()
+                        .position(|&root| (root - z).max_norm() <= match_radius)
+                        .unwrap_or_else(|| {
+                            roots.push(z);
+                            roots.len() - 1
+                        });
+                    label = NewtonLabel::Root(index);
+                    break;
+                }
+            }
+            labels.push(label);
+            iterations.push(steps);
+        }
+    }
+    NewtonFractal {
+        width,
+        height,
+        roots,
+        labels,
+        iterations,
+    }
+}
+
+/// The reason for the termination of `iterated_exp` and `power_tower`.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub enum TowerStatus {
+    /// All exponentiations have been performed.
+    Completed,
+    /// The next exponentiation would overflow or is not finite, see `TowerResult::log_magnitude`.
+    Overflow,
+    /// The base of the power tower is light-like, hence its logarithm is undefined.
+    UndefinedLogarithm,
+}
+
+/// The result of `iterated_exp` and `power_tower`.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct TowerResult<T> {
+    /// The last finite iterate.
+    pub value: Perplex<T>,
+    /// The number of performed exponentiations.
+    pub iterations: usize,
+    /// The reason for the termination.
+    pub status: TowerStatus,
+    /// The natural logarithm of the maximum norm of `value`, or of the overflowing next iterate for `TowerStatus::Overflow`.
+    pub log_magnitude: T,
+}
+
+/// Returns the `n`-fold exponential `exp(exp(...exp(z)))` with the sector-extended `Perplex::exp`, or the last finite iterate if an exponentiation would overflow.
+///
+/// # Examples
+///
+/// ```
+/// use perplex_num::dynamics::{iterated_exp, TowerStatus};
+/// use perplex_num::Perplex;
+/// let result = iterated_exp(Perplex::new(0.0, 0.0), 10);
+/// assert_eq!(result.status, TowerStatus::Overflow);
+/// assert_eq!(result.iterations, 4);
+/// // the fifth iterate exp(exp(e^e)) is about 10^(1.66 * 10^6)
+/// assert!(result.log_magnitude > 3.8e6);
+/// ```
+pub fn iterated_exp<T: Copy + Float>(z: Perplex<T>, n: usize) -> TowerResult<T> {
+    iterate_exp(z, n, |w| w)
+}
+
+/// Returns the power tower `z^z^...^z` of height `n`, which is evaluated from the top by `z^w = exp(w ln(z))` (see `Perplex::powc`), or the last finite iterate if an exponentiation would overflow.
+///
+/// The tower of height zero is one. For a light-like base of a tower with a height greater than one, the logarithm is undefined and `z` is returned with `TowerStatus::UndefinedLogarithm`.
+///
+/// # Examples
+///
+/// ```
+/// use approx::assert_abs_diff_eq;
+/// use perplex_num::dynamics::power_tower;
+/// use perplex_num::Perplex;
+/// // the infinite tower of sqrt(2) converges to 2
+/// let result = power_tower(Perplex::new(2f64.sqrt(), 0.0), 200);
+/// assert_abs_diff_eq!(result.value, Perplex::new(2.0, 0.0), epsilon = 1e-10);
+/// ```
+pub fn power_tower<T: Copy + Float>(z: Perplex<T>, n: usize) -> TowerResult<T> {
+    if n < 2 {
+        let value = if n == 0 {
+            Perplex::new(T::one(), T::zero())
+        } else {
+            z
+        };
+        return TowerResult {
+            value,
+            iterations: 0,
+            status: TowerStatus::Completed,
+            log_magnitude: value.max_norm().ln(),
+        };
+    }
+    match z.ln() {
+        Some(ln_z) => iterate_exp(z, n - 1, |w| w * ln_z),
+        None => TowerResult {
+            value: z,
+            iterations: 0,
+            status: TowerStatus::UndefinedLogarithm,
+            log_magnitude: z.max_norm().ln(),
+        },
+    }
+}
+
+/// Iterates `w <- exp(argument(w))` `n` times from `start` and terminates before an overflow.
+fn iterate_exp<T, F>(start: Perplex<T>, n: usize, argument: F) -> TowerResult<T>
+where
+    T: Copy + Float,
+    F: Fn(Perplex<T>) -> Perplex<T>,
+{
+    let ln_max = T::max_value().ln();
+    let mut value = start;
+    for iteration in 0..n {
+        let w = argument(value);
+        let log_magnitude = exp_log_magnitude(w);
+        if !(log_magnitude.is_finite() && log_magnitude < ln_max) {
+            return TowerResult {
+                value,
+                iterations: iteration,
+                status: TowerStatus::Overflow,
+                log_magnitude,
+            };
+        }
+        value = w.exp();
+    }
+    TowerResult {
+        value,
+        iterations: n,
+        status: TowerStatus::Completed,
+        log_magnitude: value.max_norm().ln(),
+    }
+}
+
+/// Returns the natural logarithm of the maximum norm of `w.exp()` without its evaluation.
+///
+/// The Klein index `k` maps `w` to the right sector, where `exp(k w) = e^t (cosh(x) + h sinh(x))` has the maximum norm `e^t cosh(x)`, and the multiplication by `k` preserves the maximum norm. Light-like `w` is evaluated by the formula of the right sector like `Perplex::exp`.
+fn exp_log_magnitude<T: Copy + Float>(w: Perplex<T>) -> T {
+    let Perplex { t, x } = w.klein_index().map_or(w, |k| Perplex::<T>::from(k) * w);
+    let x_abs = x.abs();
+    let two = T::one() + T::one();
+    // ln(cosh(x)) = |x| + ln((1 + e^(-2|x|)) / 2)
+    t + x_abs + (-two * x_abs).exp().ln_1p() - two.ln()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_abs_diff_eq;
+    #[test]
+    fn test_lyapunov() {
+        let c = Perplex::new(0.5, 0.25);
+        let z0 = Perplex::new(1.0, -0.5);
+        let lambda = lyapunov(|z| c * z, |_| c, z0, 10000);
+        assert_abs_diff_eq!(lambda, f64::ln(0.75), epsilon = 1e-4);
+        let c = Perplex::new(0.0, 0.5); // h-derivative with null components 0.5 and -0.5
+        let lambda = lyapunov(|z| c * z, |_| c, z0, 200);
+        assert_abs_diff_eq!(lambda, f64::ln(0.5), epsilon = 1e-10);
+        let c = Perplex::new(1.0, 1.0); // light-like derivative contracts along t - x
+        let lambda = lyapunov(|z| c * z, |_| c, z0, 10000);
+        assert_abs_diff_eq!(lambda, f64::ln(2.0), epsilon = 1e-4);
+        // the light-like h-derivatives 1 + h and 1 - h annihilate each other's null direction
+        let df = |z: Perplex<f64>| Perplex::new(1.0, if z.t < 0.5 { 1.0 } else { -1.0 });
+        let shift = |z: Perplex<f64>| z + Perplex::new(1.0, 0.0);
+        let lambda = lyapunov(shift, df, Perplex::new(0.0, 0.0), 10);
+        assert_eq!(lambda, f64::NEG_INFINITY, "Collapsed tangent vector!");
+        assert!(lyapunov(|z| c * z, |_| c, z0, 0).is_nan());
+    }
+    #[test]
+    fn test_lyapunov_jacobian() {
+        // z -> z^2 has h-derivative 2z, the Jacobian is the matrix form of 2z
+        let z0 = Perplex::new(0.9, 0.1);
+        let lambda_h = lyapunov(|z| z * z, |z| z.scale(2.0), z0, 30);
+        let lambda_jacobian = lyapunov_jacobian(
+            |z| z * z,
+            |z| [[2.0 * z.t, 2.0 * z.x], [2.0 * z.x, 2.0 * z.t]],
+            z0,
+            30,
+        );
+        assert_abs_diff_eq!(lambda_h, lambda_jacobian, epsilon = 1e-10);
+        // a shear map has a vanishing Lyapunov exponent
+        let lambda = lyapunov_jacobian(
+            |z| Perplex::new(z.t + z.x, z.x),
+            |_| [[1.0, 1.0], [0.0, 1.0]],
+            z0,
+            10000,
+        );
+        assert!(lambda.abs() < 0.01, "Shear map grows only linearly!");
+        let zero = |_: Perplex<f64>| [[0.0, 0.0], [0.0, 0.0]];
+        assert_eq!(lyapunov_jacobian(|z| z, zero, z0, 5), f64::NEG_INFINITY);
+        assert!(lyapunov_jacobian(|z| z, zero, z0, 0).is_nan());
+    }
+    #[test]
+    fn test_newton_fractal() {
+        let one = Perplex::new(1.0, 0.0);
+        let zero = Perplex::new(0.0, 0.0);
+        let p = PerplexPolynomial::new(vec![-one, zero, one]);
+        let region = PerplexBox::new(Perplex::new(-2.0, -2.0), Perplex::new(2.0, 2.0)).unwrap();
+        let fractal = newton_fractal(&p, region, 40, 30, NewtonOptions::default());
+        assert_eq!(fractal.labels.len(), 40 * 30);
+        assert_eq!(fractal.iterations.len(), 40 * 30);
+        assert_eq!(fractal.roots, p.roots().unwrap(), "No additional limits!");
+        // the real basins of u^2 - 1 are u > 0 and u < 0, hence the quadrants of the null components
+        for (i, label) in fractal.labels.iter().enumerate() {
+            let z = Perplex::new(
+                -2.0 + (i % 40) as f64 * 0.1 + 0.05,
+                -2.0 + (i / 40) as f64 * (4.0 / 30.0) + 2.0 / 30.0,
+            );
+            let expected = Perplex::new(
+                ((z.t + z.x).signum() + (z.t - z.x).signum()) / 2.0,
+                ((z.t + z.x).signum() - (z.t - z.x).signum()) / 2.0,
+            );
+            match label {
+                NewtonLabel::Root(index) => {
+                    assert_abs_diff_eq!(fractal.roots[*index], expected, epsilon = 1e-9)
+                }
+                other => panic!("Unexpected outcome {other:?} at {z}!"),
+            }
+        }
+        // the derivative 2z is light-like on the diagonals
+        let diagonal = PerplexBox::new(Perplex::new(0.5, 0.5), Perplex::new(0.5, 0.5)).unwrap();
+        let fractal = newton_fractal(&p, diagonal, 1, 1, NewtonOptions::default());
+        assert_eq!(fractal.labels, vec![NewtonLabel::LightLikeDerivative]);
+        assert_eq!(fractal.iterations, vec![0]);
+        // z^3 - z has null components of degree three, whose roots are discovered
+        let cubic = PerplexPolynomial::new(vec![zero, -one, zero, one]);
+        let fractal = newton_fractal(&cubic, region, 16, 16, NewtonOptions::default());
+        assert_eq!(
+            fractal.roots.len(),
+            9,
+            "All 3 x 3 combinations of the real roots!"
+        );
+        let options = NewtonOptions {
+            max_iterations: 1,
+            ..NewtonOptions::default()
+        };
+        let fractal = newton_fractal(&p, region, 4, 4, options);
+        assert!(fractal.labels.contains(&NewtonLabel::Unconverged));
+    }
+    #[test]
+    fn test_iterated_exp() {
+        let e = f64::exp(1.0);
+        let result = iterated_exp(Perplex::new(0.0, 0.0), 3);
+        assert_eq!(result.status, TowerStatus::Completed);
+        assert_abs_diff_eq!(result.value, Perplex::new(e.exp(), 0.0), epsilon = 1e-12);
+        assert_abs_diff_eq!(result.log_magnitude, e, epsilon = 1e-12);
+        let result = iterated_exp(Perplex::new(0.0, 0.0), 5);
+        assert_eq!(
+            (result.status, result.iterations),
+            (TowerStatus::Overflow, 4)
+        );
+        assert_abs_diff_eq!(result.log_magnitude, e.exp().exp(), epsilon = 1e-6);
+        assert!(result.value.t.is_finite(), "The last finite iterate!");
+        // the iterates of h stay on the axis of h
+        let result = iterated_exp(Perplex::new(0.0, 1.0), 2);
+        assert_abs_diff_eq!(result.value, Perplex::new(0.0, e.exp()), epsilon = 1e-12);
+        for z in [Perplex::new(1.5, -0.5), Perplex::new(-0.25, 2.0)] {
+            let magnitude = z.exp().max_norm().ln();
+            assert_abs_diff_eq!(exp_log_magnitude(z), magnitude, epsilon = 1e-14);
+        }
+        let nan = iterated_exp(Perplex::new(f64::NAN, 0.0), 2);
+        assert_eq!((nan.status, nan.iterations), (TowerStatus::Overflow, 0));
+    }
+    #[test]
+    fn test_power_tower() {
+        let two = Perplex::new(2.0, 0.0);
+        assert_eq!(power_tower(two, 0).value, Perplex::new(1.0, 0.0));
+        assert_eq!(power_tower(two, 1).value, two);
+        let result = power_tower(two, 4);
+        assert_eq!(result.status, TowerStatus::Completed);
+        assert_abs_diff_eq!(result.value, Perplex::new(65536.0, 0.0), epsilon = 1e-8);
+        let result = power_tower(two, 6);
+        assert_eq!(
+            (result.status, result.iterations),
+            (TowerStatus::Overflow, 3)
+        );
+        assert_abs_diff_eq!(result.log_magnitude, 65536.0 * f64::ln(2.0), epsilon = 1e-6);
+        let light_like = power_tower(Perplex::new(1.0, -1.0), 3);
+        assert_eq!(light_like.status, TowerStatus::UndefinedLogarithm);
+        assert_eq!(
+            power_tower(Perplex::new(1.0, -1.0), 1).status,
+            TowerStatus::Completed
+        );
+        // null components 1.2 and 1.4 lie below e^(1/e), hence both real towers converge
+        let z = Perplex::new(1.3, 0.1);
+        let limit = power_tower(z, 500).value;
+        assert_abs_diff_eq!(z.powc(limit).unwrap(), limit, epsilon = 1e-10);
+    }
+}
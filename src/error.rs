@@ -0,0 +1,188 @@
+//! # Error Module
+//!
+//! This module provides the crate-wide `PerplexError` and the `Result`-based counterparts of the fallible operations, whose `Option` outputs do not tell why an operation failed.
+//! The `_checked` methods follow `Perplex::from_arg_modulus_checked`: they return the same values as their `Option` counterparts and distinguish light-like operands, poles and arguments outside of the domain in the error.
+//! The specific errors of parsing, polar forms and Rindler coordinates convert into `PerplexError`, such that all fallible operations of the crate compose with `?` in one function.
+//! The hyperbolic tangent `tanh` is defined on the whole plane, see `Perplex::tanh`, and has no checked counterpart.
+//!
+//! ## Features
+//! - `div_checked`, `inverse_checked` and `powi_checked`: Fail with `LightLike` for a light-like divisor.
+//! - `ln_checked`, `sqrt_checked` and `tan_checked`: Fail with `LightLike`, `OutOfDomain` and `Pole`, respectively.
+//!
+//! ## Example
+//! ```
+//! use perplex_num::{Perplex, PerplexError};
+//! fn geometric_mean(a: Perplex<f64>, b: Perplex<f64>) -> Result<Perplex<f64>, PerplexError> {
+//!     let quotient = a.div_checked(b)?;
+//!     Ok(b * quotient.sqrt_checked()?.powi_checked(1)?)
+//! }
+//! assert!(geometric_mean(Perplex::new(4.0, 0.0), Perplex::new(1.0, 0.0)).is_ok());
+//! assert_eq!(
+//!     geometric_mean(Perplex::new(4.0, 0.0), Perplex::new(1.0, 1.0)),
+//!     Err(PerplexError::LightLike)
+//! );
+//! assert_eq!("1 + x h".parse::<Perplex<f64>>().map_err(PerplexError::from), Err(PerplexError::Parse(perplex_num::ParsePerplexError::InvalidComponent)));
+//! ```
+
+use super::rindler::RindlerError;
+use super::{ParsePerplexError, Perplex, PolarError};
+use core::fmt;
+use core::ops::Neg;
+use num_traits::{CheckedDiv, Float, Num};
+
+/// The error of the fallible operations of the crate.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub enum PerplexError {
+    /// The operand is light-like, e.g., the divisor of a division or the argument of the logarithm.
+    LightLike,
+    /// The operand is outside of the domain of the function, e.g., the square root outside of the right sector.
+    OutOfDomain,
+    /// The operand is a pole of the function, e.g., the tangent where the cosine is light-like.
+    Pole,
+    /// A string cannot be parsed as a perplex number.
+    Parse(ParsePerplexError),
+    /// A polar form cannot originate from a perplex number.
+    Polar(PolarError),
+    /// A conversion from or to Rindler coordinates failed.
+    Rindler(RindlerError),
+}
+
+impl fmt::Display for PerplexError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::LightLike => write!(f, "operand must not be light-like"),
+            Self::OutOfDomain => write!(f, "operand is outside of the domain of the function"),
+            Self::Pole => write!(f, "operand is a pole of the function"),
+            Self::Parse(e) => e.fmt(f),
+            Self::Polar(e) => e.fmt(f),
+            Self::Rindler(e) => e.fmt(f),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for PerplexError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Parse(e) => Some(e),
+            Self::Polar(e) => Some(e),
+            Self::Rindler(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<ParsePerplexError> for PerplexError {
+    #[inline]
+    fn from(e: ParsePerplexError) -> Self {
+        Self::Parse(e)
+    }
+}
+
+impl From<PolarError> for PerplexError {
+    #[inline]
+    fn from(e: PolarError) -> Self {
+        Self::Polar(e)
+    }
+}
+
+impl From<RindlerError> for PerplexError {
+    #[inline]
+    fn from(e: RindlerError) -> Self {
+        Self::Rindler(e)
+    }
+}
+
+impl<T: Clone + Num> Perplex<T> {
+    /// Divides `self` by `rhs` like `checked_div`, or returns `PerplexError::LightLike` if `rhs` is light-like.
+    #[inline]
+    pub fn div_checked(self, rhs: Self) -> Result<Self, PerplexError> {
+        self.checked_div(&rhs).ok_or(PerplexError::LightLike)
+    }
+}
+
+impl<T: Clone + Num + Neg<Output = T>> Perplex<T> {
+    /// Returns the multiplicative inverse like `try_inverse`, or `PerplexError::LightLike` if `self` is light-like.
+    #[inline]
+    pub fn inverse_checked(&self) -> Result<Self, PerplexError> {
+        self.try_inverse().ok_or(PerplexError::LightLike)
+    }
+    /// Raises `self` to a signed integer power like `powi`, or returns `PerplexError::LightLike` if the exponent is negative and `self` is light-like.
+    #[inline]
+    pub fn powi_checked(&self, exp: i32) -> Result<Self, PerplexError> {
+        self.powi(exp).ok_or(PerplexError::LightLike)
+    }
+}
+
+impl<T: Copy + Float> Perplex<T> {
+    /// Computes the natural logarithm like `ln`, or returns `PerplexError::LightLike` if `self` is light-like.
+    #[inline]
+    pub fn ln_checked(self) -> Result<Self, PerplexError> {
+        self.ln().ok_or(PerplexError::LightLike)
+    }
+    /// Computes the square root like `sqrt`, or returns `PerplexError::OutOfDomain` if `self` is not in the closed right sector.
+    #[inline]
+    pub fn sqrt_checked(self) -> Result<Self, PerplexError> {
+        self.sqrt().ok_or(PerplexError::OutOfDomain)
+    }
+    /// Computes the tangens like `tan`, or returns `PerplexError::Pole` if `cos(self)` is light-like.
+    #[inline]
+    pub fn tan_checked(self) -> Result<Self, PerplexError> {
+        self.tan().ok_or(PerplexError::Pole)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::f64::consts::FRAC_PI_2;
+    #[test]
+    fn test_checked() {
+        let (z, light) = (Perplex::new(2.0, 1.0), Perplex::new(1.0, -1.0));
+        assert_eq!(z.div_checked(z), Ok(Perplex::new(1.0, 0.0)));
+        assert_eq!(z.div_checked(light), Err(PerplexError::LightLike));
+        assert_eq!(z.inverse_checked().ok(), z.try_inverse());
+        assert_eq!(light.inverse_checked(), Err(PerplexError::LightLike));
+        assert_eq!(z.powi_checked(-2).ok(), z.powi(-2));
+        assert_eq!(light.powi_checked(2), Ok(light * light));
+        assert_eq!(light.powi_checked(-1), Err(PerplexError::LightLike));
+        assert_eq!(z.ln_checked().ok(), z.ln());
+        assert_eq!(light.ln_checked(), Err(PerplexError::LightLike));
+        assert_eq!(z.sqrt_checked().ok(), z.sqrt());
+        assert_eq!(
+            Perplex::new(1.0, 2.0).sqrt_checked(),
+            Err(PerplexError::OutOfDomain)
+        );
+        assert_eq!(z.tan_checked().ok(), z.tan());
+        // cos(pi / 2) rounds to a non-zero value, hence the pole is not hit exactly
+        assert!(Perplex::new(FRAC_PI_2, 0.0).tan_checked().is_ok());
+        assert_eq!(
+            Perplex::new(0, 3).div_checked(Perplex::new(2, 2)),
+            Err(PerplexError::LightLike)
+        );
+    }
+    #[test]
+    fn test_conversions() -> Result<(), PerplexError> {
+        let z: Perplex<f64> = "2 + 1 h".parse()?;
+        assert_eq!(z.inverse_checked()?.inverse_checked()?, z);
+        let parse = |s: &str| -> Result<Perplex<f64>, PerplexError> { Ok(s.parse()?) };
+        assert_eq!(
+            parse(""),
+            Err(PerplexError::Parse(ParsePerplexError::Empty))
+        );
+        let rindler = |z: Perplex<f64>| -> Result<f64, PerplexError> { Ok(z.to_rindler()?.rho) };
+        assert_eq!(
+            rindler(z),
+            Err(PerplexError::Rindler(RindlerError::OutsideWedge))
+        );
+        assert_eq!(
+            PerplexError::from(PolarError::InvalidModulus).to_string(),
+            PolarError::InvalidModulus.to_string()
+        );
+        let error: Box<dyn std::error::Error> =
+            Box::new(PerplexError::from(RindlerError::OutsideWedge));
+        assert!(error.source().is_some());
+        assert!(PerplexError::Pole.to_string().contains("pole"));
+        Ok(())
+    }
+}
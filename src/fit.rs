@@ -0,0 +1,485 @@
+//! # Fit Module
+//!
+//! This module estimates transformations of the hyperbolic plane from point correspondences, e.g., for the registration of spacetime diagrams.
+//! The estimation exploits the null basis `u = t + x` and `v = t - x`, in which a boost by the rapidity `theta` scales `u` by `e^theta` and `v` by `e^-theta`, and a perplex Möbius transformation acts as two independent real Möbius transformations.
+//!
+//! ## Features
+//! - `boost`: Least-squares estimation of a boost `z -> e^(h theta) z + c` with rapidity `theta` and translation `c` under a `PlaneMetric`.
+//! - `mobius`: Least-squares estimation of a `Mobius` transformation from three or more (e.g., four) point correspondences.
+//!
+//! ## Example
+//! ```
+//! use approx::assert_abs_diff_eq;
+//! use perplex_num::{fit, Perplex, PlaneMetric};
+//! let src = [Perplex::new(0.0, 0.0), Perplex::new(1.0, 0.0), Perplex::new(0.0, 2.0)];
+//! let dst: Vec<_> = src.iter().map(|&z| Perplex::cis(0.5) * z + Perplex::new(1.0, -1.0)).collect();
+//! let boost = fit::boost(&src, &dst, PlaneMetric::Euclidean).unwrap();
+//! assert_abs_diff_eq!(boost.rapidity, 0.5, epsilon = 1e-12);
+//! assert_abs_diff_eq!(boost.translation, Perplex::new(1.0, -1.0), epsilon = 1e-12);
+//! ```
+
+use super::{Mobius, Perplex, PlaneMetric};
+use num_traits::Float;
+
+/// Maximum number of iterations of the pattern search for the metrics `Max` and `Interval`.
+const MAX_SEARCH_ITERATIONS: usize = 10_000;
+
+/// A boost `z -> e^(h rapidity) z + translation` estimated by `boost`.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct BoostFit<T> {
+    /// The rapidity (hyperbolic angle) of the boost.
+    pub rapidity: T,
+    /// The translation after the boost.
+    pub translation: Perplex<T>,
+    /// The root mean square of the distances between the transformed source points and the destination points.
+    pub residual: T,
+}
+
+impl<T: Copy + Float> BoostFit<T> {
+    /// Applies the boost followed by the translation to `z`.
+    #[inline]
+    pub fn apply(&self, z: Perplex<T>) -> Perplex<T> {
+        Perplex::cis(self.rapidity) * z + self.translation
+    }
+}
+
+/// Estimates the boost `z -> e^(h theta) z + c` which minimizes the sum of the squared distances between the transformed `src` points and the `dst` points under `metric`.
+///
+/// For the `Euclidean` metric, the minimum is computed exactly: the optimal translation aligns the centroids and the optimal `lambda = e^theta` is a positive root of the quartic `S_uu lambda^4 - S_uU lambda^3 + S_vV lambda - S_vv`, with the sums of products of the centered null components of source and destination.
+/// For the `Max` and `Interval` metrics, the Euclidean solution is refined by a pattern search over rapidity and translation. Since all light-like residuals have an interval of zero, the `Interval` objective may admit many minima.
+///
+/// Returns `None` if fewer than two correspondences are given or the rapidity is not determined, e.g., if all source points coincide.
+///
+/// # Panics
+/// Panics if `src` and `dst` differ in length.
+pub fn boost<T: Copy + Float>(
+    src: &[Perplex<T>],
+    dst: &[Perplex<T>],
+    metric: PlaneMetric,
+) -> Option<BoostFit<T>> {
+    assert_eq!(src.len(), dst.len(), "Point sets differ in length!");
+    if src.len() < 2 {
+        return None;
+    }
+    let two = T::one() + T::one();
+    let n = T::from(src.len()).unwrap();
+    let null = |z: &Perplex<T>| (z.t + z.x, z.t - z.x);
+    let mean = |points: &[Perplex<T>]| {
+        points.iter().fold((T::zero(), T::zero()), |(u, v), z| {
+            let (z_u, z_v) = null(z);
+            (u + z_u / n, v + z_v / n)
+        })
+    };
+    let ((src_u, src_v), (dst_u, dst_v)) = (mean(src), mean(dst));
+    let (mut s_uu, mut s_uu_dst, mut s_vv, mut s_vv_dst) =
+        (T::zero(), T::zero(), T::zero(), T::zero());
+    for (p, q) in src.iter().zip(dst) {
+        let ((p_u, p_v), (q_u, q_v)) = (null(p), null(q));
+        let (p_u, p_v, q_u, q_v) = (p_u - src_u, p_v - src_v, q_u - dst_u, q_v - dst_v);
+        s_uu = s_uu + p_u * p_u;
+        s_uu_dst = s_uu_dst + p_u * q_u;
+        s_vv = s_vv + p_v * p_v;
+        s_vv_dst = s_vv_dst + p_v * q_v;
+    }
+    // the least-squares objective up to a constant as a function of lambda = e^theta
+    let objective = |lambda: T| {
+        lambda * lambda * s_uu - two * lambda * s_uu_dst + s_vv / (lambda * lambda)
+            - two * s_vv_dst / lambda
+    };
+    let quartic = [-s_vv, s_vv_dst, T::zero(), -s_uu_dst, s_uu];
+    let lambda = positive_roots(&quartic)
+        .into_iter()
+        .filter(|lambda| lambda.is_finite())
+        .min_by(|a, b| {
+            objective(*a)
+                .partial_cmp(&objective(*b))
+                .unwrap_or(std::cmp::Ordering::Equal)
+        })?;
+    let translation_u = dst_u - lambda * src_u;
+    let translation_v = dst_v - src_v / lambda;
+    let mut params = [
+        lambda.ln(),
+        (translation_u + translation_v) / two,
+        (translation_u - translation_v) / two,
+    ];
+    let cost = |params: &[T; 3]| {
+        let fit = BoostFit {
+            rapidity: params[0],
+            translation: Perplex::new(params[1], params[2]),
+            residual: T::zero(),
+        };
+        src.iter().zip(dst).fold(T::zero(), |sum, (&p, &q)| {
+            let d = metric.distance(fit.apply(p), q);
+            sum + d * d
+        })
+    };
+    if metric != PlaneMetric::Euclidean {
+        let scale = dst
+            .iter()
+            .fold(T::one(), |scale, z| scale.max(z.max_norm()));
+        params = pattern_search(cost, params, [T::one(), scale, scale]);
+    }
+    Some(BoostFit {
+        rapidity: params[0],
+        translation: Perplex::new(params[1], params[2]),
+        residual: (cost(&params) / n).sqrt(),
+    })
+}
+
+/// Minimizes `cost` by a compass search starting at `params` with the initial step sizes `steps`, which are halved whenever no coordinate step decreases the cost.
+fn pattern_search<T: Copy + Float>(
+    cost: impl Fn(&[T; 3]) -> T,
+    mut params: [T; 3],
+    mut steps: [T; 3],
+) -> [T; 3] {
+    let two = T::one() + T::one();
+    let mut best = cost(&params);
+    for _ in 0..MAX_SEARCH_ITERATIONS {
+        let mut improved = false;
+        for i in 0..3 {
+            for direction in [T::one(), -T::one()] {
+                let mut candidate = params;
+                candidate[i] = candidate[i] + direction * steps[i];
+                let candidate_cost = cost(&candidate);
+                if candidate_cost < best {
+                    (params, best, improved) = (candidate, candidate_cost, true);
+                }
+            }
+        }
+        if !improved {
+            steps = steps.map(|s| s / two);
+            if steps
+                .iter()
+                .zip(params)
+                .all(|(&s, p)| s <= T::epsilon() * p.abs().max(T::one()))
+            {
+                break;
+            }
+        }
+    }
+    params
+}
+
+/// Returns the positive real roots of a real polynomial with coefficients in ascending order.
+///
+/// The roots are isolated recursively by the roots of the derivative, between which the polynomial is monotone, and refined by bisection.
+fn positive_roots<T: Copy + Float>(coefficients: &[T]) -> Vec<T> {
+    let mut coefficients = coefficients.to_vec();
+    while coefficients.last().is_some_and(|c| c.is_zero()) {
+        coefficients.pop();
+    }
+    let Some(&leading) = coefficients.last() else {
+        return Vec::new();
+    };
+    // Cauchy bound of the absolute values of the roots
+    let bound = T::one()
+        + coefficients
+            .iter()
+            .fold(T::zero(), |bound, c| bound.max((*c / leading).abs()));
+    roots_in(&coefficients, T::zero(), bound)
+}
+
+/// Returns the roots in the interval `(lower, upper]` of a real polynomial without trailing zero coefficients.
+fn roots_in<T: Copy + Float>(coefficients: &[T], lower: T, upper: T) -> Vec<T> {
+    if coefficients.len() < 2 {
+        return Vec::new();
+    }
+    let eval = |s: T| {
+        coefficients
+            .iter()
+            .rev()
+            .fold(T::zero(), |acc, &c| acc * s + c)
+    };
+    let mut k = T::zero();
+    let derivative: Vec<T> = coefficients
+        .iter()
+        .skip(1)
+        .map(|&c| {
+            k = k + T::one();
+            c * k
+        })
+        .collect();
+    let mut bounds = vec![lower];
+    bounds.extend(roots_in(&derivative, lower, upper));
+    bounds.push(upper);
+    let two = T::one() + T::one();
+    let mut roots: Vec<T> = Vec::new();
+    for interval in bounds.windows(2) {
+        let (mut a, mut b) = (interval[0], interval[1]);
+        let (f_a, f_b) = (eval(a), eval(b));
+        if f_b.is_zero() {
+            if b > lower {
+                roots.push(b);
+            }
+            continue;
+        }
+        if f_a.is_zero() || f_a.signum() == f_b.signum() {
+            continue;
+        }
+        // the polynomial is monotone between consecutive roots of the derivative
+        loop {
+            let mid = (a + b) / two;
+            if mid <= a || mid >= b {
+                break;
+            }
+            if eval(mid).signum() == f_a.signum() {
+                a = mid;
+            } else {
+                b = mid;
+            }
+        }
+        roots.push(b);
+    }
+    roots.dedup();
+    roots
+}
+
+/// Estimates the `Mobius` transformation which maps the `src` points to the `dst` points in the least-squares sense of the linearized equations `a z + b - w (c z + d) = 0`.
+///
+/// In each null component, the real coefficients are the right singular vector of the smallest singular value of the linear system, computed as the eigenvector of the smallest eigenvalue of its normal matrix.
+/// Three correspondences determine the transformation exactly, four or more are fitted in the least-squares sense.
+///
+/// Returns `None` if fewer than three correspondences are given or the estimated transformation is not invertible.
+///
+/// # Panics
+/// Panics if `src` and `dst` differ in length.
+///
+/// # Examples
+///
+/// ```
+/// use approx::assert_abs_diff_eq;
+/// use perplex_num::{fit, Mobius, Perplex};
+/// let m = Mobius::new(Perplex::new(1.0, 0.5), Perplex::new(-2.0, 0.0), Perplex::new(0.25, 0.5), Perplex::new(3.0, 1.0));
+/// let src = [Perplex::new(0.0, 0.0), Perplex::new(1.0, 0.0), Perplex::new(0.0, 1.5), Perplex::new(-1.0, 0.5)];
+/// let dst: Vec<_> = src.iter().map(|&z| m.apply(z).unwrap()).collect();
+/// let fitted = fit::mobius(&src, &dst).unwrap();
+/// let z = Perplex::new(0.3, 0.2);
+/// assert_abs_diff_eq!(fitted.apply(z).unwrap(), m.apply(z).unwrap(), epsilon = 1e-9);
+/// ```
+pub fn mobius<T: Copy + Float>(src: &[Perplex<T>], dst: &[Perplex<T>]) -> Option<Mobius<T>> {
+    assert_eq!(src.len(), dst.len(), "Point sets differ in length!");
+    if src.len() < 3 {
+        return None;
+    }
+    let u = real_mobius(src.iter().zip(dst).map(|(p, q)| (p.t + p.x, q.t + q.x)))?;
+    let v = real_mobius(src.iter().zip(dst).map(|(p, q)| (p.t - p.x, q.t - q.x)))?;
+    let two = T::one() + T::one();
+    let join = |u: T, v: T| Perplex::new((u + v) / two, (u - v) / two);
+    let m = Mobius::new(
+        join(u[0], v[0]),
+        join(u[1], v[1]),
+        join(u[2], v[2]),
+        join(u[3], v[3]),
+    );
+    m.inverse().map(|_| m)
+}
+
+/// Estimates the real coefficients `[a, b, c, d]` of `f(s) = (a s + b) / (c s + d)` from pairs `(s, f(s))`, or returns `None` for a degenerate transformation.
+fn real_mobius<T: Copy + Float>(pairs: impl Iterator<Item = (T, T)>) -> Option<[T; 4]> {
+    // normal matrix of the rows (s, 1, -w s, -w)
+    let mut normal = [[T::zero(); 4]; 4];
+    for (s, w) in pairs {
+        let row = [s, T::one(), -w * s, -w];
+        for i in 0..4 {
+            for j in 0..4 {
+                normal[i][j] = normal[i][j] + row[i] * row[j];
+            }
+        }
+    }
+    let coefficients = smallest_eigenvector(normal);
+    let determinant = coefficients[0] * coefficients[3] - coefficients[1] * coefficients[2];
+    let norm = coefficients.iter().fold(T::zero(), |sum, c| sum + *c * *c);
+    if determinant.abs() <= T::epsilon().sqrt() * norm {
+        return None;
+    }
+    Some(coefficients)
+}
+
+/// Returns a unit eigenvector of the smallest eigenvalue of a symmetric 4x4 matrix, computed by the cyclic Jacobi eigenvalue algorithm.
+fn smallest_eigenvector<T: Copy + Float>(mut a: [[T; 4]; 4]) -> [T; 4] {
+    let mut vectors = [[T::zero(); 4]; 4];
+    for (i, row) in vectors.iter_mut().enumerate() {
+        row[i] = T::one();
+    }
+    let two = T::one() + T::one();
+    for _ in 0..100 {
+        let off_diagonal = (0..4)
+            .flat_map(|i| (0..4).filter(move |&j| j != i).map(move |j| (i, j)))
+            .fold(T::zero(), |sum, (i, j)| sum + a[i][j] * a[i][j]);
+        let diagonal = (0..4).fold(T::zero(), |sum, i| sum + a[i][i] * a[i][i]);
+        if off_diagonal <= T::epsilon() * T::epsilon() * diagonal {
+            break;
+        }
+        for p in 0..3 {
+            for q in p + 1..4 {
+                if a[p][q].is_zero() {
+                    continue;
+                }
+                // rotation which annihilates a[p][q]
+                let tau = (a[q][q] - a[p][p]) / (two * a[p][q]);
+                let t = tau.signum() / (tau.abs() + (T::one() + tau * tau).sqrt());
+                let c = (T::one() + t * t).sqrt().recip();
+                let s = t * c;
+                for row in a.iter_mut() {
+                    let (a_kp, a_kq) = (row[p], row[q]);
+                    row[p] = c * a_kp - s * a_kq;
+                    row[q] = s * a_kp + c * a_kq;
+                }
+                let (row_p, row_q) = (a[p], a[q]);
+                a[p] = std::array::from_fn(|k| c * row_p[k] - s * row_q[k]);
+                a[q] = std::array::from_fn(|k| s * row_p[k] + c * row_q[k]);
+                for row in vectors.iter_mut() {
+                    let (v_p, v_q) = (row[p], row[q]);
+                    row[p] = c * v_p - s * v_q;
+                    row[q] = s * v_p + c * v_q;
+                }
+            }
+        }
+    }
+    let smallest = (1..4).fold(0, |min, i| if a[i][i] < a[min][min] { i } else { min });
+    [
+        vectors[0][smallest],
+        vectors[1][smallest],
+        vectors[2][smallest],
+        vectors[3][smallest],
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_abs_diff_eq;
+    fn points() -> Vec<Perplex<f64>> {
+        vec![
+            Perplex::new(0.0, 0.0),
+            Perplex::new(1.0, 0.2),
+            Perplex::new(-0.5, 1.5),
+            Perplex::new(2.0, -1.0),
+            Perplex::new(0.3, 0.9),
+        ]
+    }
+    #[test]
+    fn test_positive_roots() {
+        // (s - 1)(s - 2)(s + 3) = s^3 - 7 s + 6
+        let roots = positive_roots(&[6.0, -7.0, 0.0, 1.0]);
+        assert_eq!(roots.len(), 2);
+        assert_abs_diff_eq!(roots[0], 1.0, epsilon = 1e-14);
+        assert_abs_diff_eq!(roots[1], 2.0, epsilon = 1e-14);
+        assert!(positive_roots(&[1.0, 0.0, 1.0]).is_empty());
+        assert_eq!(
+            positive_roots(&[0.0, 1.0]),
+            Vec::<f64>::new(),
+            "Zero is not positive!"
+        );
+        assert!(positive_roots::<f64>(&[0.0]).is_empty());
+    }
+    #[test]
+    fn test_boost() {
+        let (theta, c) = (-0.7, Perplex::new(0.5, 2.0));
+        let src = points();
+        let dst: Vec<_> = src.iter().map(|&z| Perplex::cis(theta) * z + c).collect();
+        for metric in [
+            PlaneMetric::Euclidean,
+            PlaneMetric::Max,
+            PlaneMetric::Interval,
+        ] {
+            let fit = boost(&src, &dst, metric).unwrap();
+            assert_abs_diff_eq!(fit.rapidity, theta, epsilon = 1e-10);
+            assert_abs_diff_eq!(fit.translation, c, epsilon = 1e-10);
+            assert_abs_diff_eq!(fit.residual, 0.0, epsilon = 1e-10);
+            assert_abs_diff_eq!(fit.apply(src[3]), dst[3], epsilon = 1e-10);
+        }
+        assert!(boost(&src[..1], &dst[..1], PlaneMetric::Euclidean).is_none());
+        let same = vec![Perplex::new(1.0, 1.0); 3];
+        assert!(
+            boost(&same, &dst[..3], PlaneMetric::Euclidean).is_none(),
+            "Rapidity is not determined!"
+        );
+    }
+    #[test]
+    fn test_boost_noisy() {
+        let (theta, c) = (0.3, Perplex::new(-1.0, 0.5));
+        let src = points();
+        let noise = [0.01, -0.02, 0.015, 0.0, -0.01];
+        let dst: Vec<_> = src
+            .iter()
+            .zip(noise)
+            .map(|(&z, e)| Perplex::cis(theta) * z + c + Perplex::new(e, -e))
+            .collect();
+        let fit = boost(&src, &dst, PlaneMetric::Euclidean).unwrap();
+        assert_abs_diff_eq!(fit.rapidity, theta, epsilon = 0.05);
+        assert!(fit.residual > 0.0);
+        // the exact least-squares solution is a local minimum
+        let cost = |theta: f64, c: Perplex<f64>| {
+            let fit = BoostFit {
+                rapidity: theta,
+                translation: c,
+                residual: 0.0,
+            };
+            src.iter()
+                .zip(&dst)
+                .map(|(&p, &q)| (fit.apply(p) - q).l2_norm().powi(2))
+                .sum::<f64>()
+        };
+        let optimum = cost(fit.rapidity, fit.translation);
+        for delta in [1e-4, -1e-4] {
+            assert!(cost(fit.rapidity + delta, fit.translation) > optimum);
+            assert!(cost(fit.rapidity, fit.translation + Perplex::new(delta, delta)) > optimum);
+        }
+        let max_residual = |fit: &BoostFit<f64>| {
+            let sum: f64 = src
+                .iter()
+                .zip(&dst)
+                .map(|(&p, &q)| PlaneMetric::Max.distance(fit.apply(p), q).powi(2))
+                .sum();
+            (sum / 5.0).sqrt()
+        };
+        let max_fit = boost(&src, &dst, PlaneMetric::Max).unwrap();
+        assert_abs_diff_eq!(max_fit.residual, max_residual(&max_fit), epsilon = 1e-15);
+        assert!(
+            max_fit.residual <= max_residual(&fit),
+            "Pattern search does not increase the cost!"
+        );
+    }
+    #[test]
+    fn test_mobius() {
+        let m = Mobius::new(
+            Perplex::new(2.0, -0.5),
+            Perplex::new(1.0, 1.5),
+            Perplex::new(0.1, 0.3),
+            Perplex::new(1.0, 0.0),
+        );
+        let src = points();
+        let dst: Vec<_> = src.iter().map(|&z| m.apply(z).unwrap()).collect();
+        for n in [3, 4, 5] {
+            let fitted = mobius(&src[..n], &dst[..n]).unwrap();
+            for (&p, &q) in src.iter().zip(&dst) {
+                assert_abs_diff_eq!(fitted.apply(p).unwrap(), q, epsilon = 1e-9);
+            }
+        }
+        assert!(mobius(&src[..2], &dst[..2]).is_none());
+        let constant = vec![Perplex::new(1.0, 0.0); 4];
+        assert!(
+            mobius(&src[..4], &constant).is_none(),
+            "Constant maps are not invertible!"
+        );
+    }
+    #[test]
+    fn test_smallest_eigenvector() {
+        let a = [
+            [4.0, 1.0, 0.0, 0.0],
+            [1.0, 3.0, 0.0, 0.0],
+            [0.0, 0.0, 2.0, 0.5],
+            [0.0, 0.0, 0.5, 0.5],
+        ];
+        let v = smallest_eigenvector(a);
+        let av: Vec<f64> = (0..4)
+            .map(|i| (0..4).map(|j| a[i][j] * v[j]).sum())
+            .collect();
+        let lambda: f64 = (0..4).map(|i| av[i] * v[i]).sum();
+        assert_abs_diff_eq!(lambda, 1.25 - f64::sqrt(0.5625 + 0.25), epsilon = 1e-12);
+        for i in 0..4 {
+            assert_abs_diff_eq!(av[i], lambda * v[i], epsilon = 1e-12);
+        }
+    }
+}
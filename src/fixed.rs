@@ -0,0 +1,251 @@
+//! # Fixed Module
+//!
+//! This module converts perplex numbers with `f64` components to a fixed-point representation with integer mantissas and a shared binary exponent, i.e., `z = (t + x h) 2^exponent` with `t, x` of type `i64`.
+//! Integer components are stored and transmitted bit-exactly and independent of the platform, which is required for deterministic storage and the network synchronization of lockstep simulations.
+//! Since the scaling by a power of two is exact, the conversion is lossless for all components which are multiples of `2^exponent`, and otherwise rounds with an explicit `Rounding` mode.
+//!
+//! ## Features
+//! - `Perplex::to_fixed_repr`: The rounded fixed-point representation, or `None` if a mantissa is not representable.
+//! - `Perplex::from_fixed_repr`: The conversion back, which is exact for mantissas up to `2^53` in magnitude.
+//! - `FixedPerplex::to_le_bytes` and `FixedPerplex::from_le_bytes`: The canonical little-endian bytes.
+//!
+//! ## Example
+//! ```
+//! use perplex_num::fixed::{FixedPerplex, Rounding};
+//! use perplex_num::Perplex;
+//! let z = Perplex::new(1.75, -0.3);
+//! let fixed = z.to_fixed_repr(-4, Rounding::Nearest).unwrap();
+//! assert_eq!((fixed.t, fixed.x, fixed.exponent), (28, -5, -4));
+//! assert_eq!(Perplex::from_fixed_repr(fixed), Perplex::new(1.75, -0.3125));
+//! assert_eq!(FixedPerplex::from_le_bytes(fixed.to_le_bytes()), fixed);
+//! ```
+
+use super::Perplex;
+use num_traits::{Float, ToPrimitive};
+
+/// The rounding of components which are not multiples of the scale `2^exponent`.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug, Default)]
+pub enum Rounding {
+    /// Rounds to the nearest mantissa, whereby ties are rounded away from zero.
+    #[default]
+    Nearest,
+    /// Rounds towards zero, i.e., truncates.
+    TowardZero,
+    /// Rounds towards negative infinity.
+    Floor,
+    /// Rounds towards positive infinity.
+    Ceil,
+}
+
+impl Rounding {
+    /// Rounds `v` to an integer.
+    #[inline]
+    pub(crate) fn apply(&self, v: f64) -> f64 {
+        match self {
+            Self::Nearest => Float::round(v),
+            Self::TowardZero => Float::trunc(v),
+            Self::Floor => Float::floor(v),
+            Self::Ceil => Float::ceil(v),
+        }
+    }
+}
+
+/// The fixed-point representation `(t + x h) 2^exponent` of a perplex number with integer mantissas.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug, Default)]
+pub struct FixedPerplex {
+    /// The mantissa of the time component.
+    pub t: i64,
+    /// The mantissa of the space component.
+    pub x: i64,
+    /// The binary exponent of the scale shared by both components.
+    pub exponent: i32,
+}
+
+impl FixedPerplex {
+    /// The number of bytes of `to_le_bytes`.
+    pub const BYTES: usize = 20;
+    /// Creates the fixed-point representation `(t + x h) 2^exponent`.
+    #[inline]
+    pub fn new(t: i64, x: i64, exponent: i32) -> Self {
+        Self { t, x, exponent }
+    }
+    /// Returns the little-endian bytes of the mantissas `t` and `x` followed by the exponent.
+    #[inline]
+    pub fn to_le_bytes(&self) -> [u8; Self::BYTES] {
+        let mut bytes = [0; Self::BYTES];
+        bytes[..8].copy_from_slice(&self.t.to_le_bytes());
+        bytes[8..16].copy_from_slice(&self.x.to_le_bytes());
+        bytes[16..].copy_from_slice(&self.exponent.to_le_bytes());
+        bytes
+    }
+    /// Creates the fixed-point representation from the bytes of `to_le_bytes`.
+    #[inline]
+    pub fn from_le_bytes(bytes: [u8; Self::BYTES]) -> Self {
+        let mut t = [0; 8];
+        let mut x = [0; 8];
+        let mut exponent = [0; 4];
+        t.copy_from_slice(&bytes[..8]);
+        x.copy_from_slice(&bytes[8..16]);
+        exponent.copy_from_slice(&bytes[16..]);
+        Self::new(
+            i64::from_le_bytes(t),
+            i64::from_le_bytes(x),
+            i32::from_le_bytes(exponent),
+        )
+    }
+}
+
+impl Perplex<f64> {
+    /// Returns the fixed-point representation with the scale `2^exponent`, whose mantissas are the components divided by the scale and rounded by `rounding`.
+    ///
+    /// The conversion is exact if both components are multiples of the scale. Returns `None` if a component is not finite or a rounded mantissa does not fit into an `i64`.
+    /// The scaling by `div_pow2` is exact for all exponents unless a scaled component underflows, whose magnitude is below one and which is rounded by its sign.
+    #[inline]
+    pub fn to_fixed_repr(self, exponent: i32, rounding: Rounding) -> Option<FixedPerplex> {
+        let scaled = self.div_pow2(exponent);
+        let mantissa = |v: f64, scaled: f64| {
+            // an underflow to zero keeps the sign for the directed roundings
+            let scaled = if scaled == 0.0 && v != 0.0 {
+                Float::copysign(f64::MIN_POSITIVE, v)
+            } else {
+                scaled
+            };
+            rounding.apply(scaled).to_i64()
+        };
+        let t = mantissa(self.t, scaled.t)?;
+        let x = mantissa(self.x, scaled.x)?;
+        Some(FixedPerplex::new(t, x, exponent))
+    }
+    /// Returns the perplex number `(t + x h) 2^exponent` of the fixed-point representation `fixed`.
+    ///
+    /// The conversion is exact, and hence inverts `to_fixed_repr`, for mantissas up to `2^53` in magnitude if the result neither overflows nor is rounded to a subnormal number, see `mul_pow2`.
+    #[inline]
+    pub fn from_fixed_repr(fixed: FixedPerplex) -> Self {
+        Self::new(fixed.t as f64, fixed.x as f64).mul_pow2(fixed.exponent)
+    }
+}
+
+impl From<FixedPerplex> for Perplex<f64> {
+    /// See `Perplex::from_fixed_repr`.
+    #[inline]
+    fn from(fixed: FixedPerplex) -> Self {
+        Self::from_fixed_repr(fixed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    const ROUNDINGS: [Rounding; 4] = [
+        Rounding::Nearest,
+        Rounding::TowardZero,
+        Rounding::Floor,
+        Rounding::Ceil,
+    ];
+    #[test]
+    fn test_exact_roundtrip() {
+        // all multiples of the scale convert losslessly in every rounding mode
+        for exponent in [-60, -20, -3, 0, 5, 40] {
+            let scale = 2f64.powi(exponent);
+            for t in -64..=64 {
+                for x in [-(1 << 53), -12345, -1, 0, 7, 1 << 40, 1 << 53] {
+                    let z = Perplex::new(t as f64 * scale, x as f64 * scale);
+                    for rounding in ROUNDINGS {
+                        let fixed = z.to_fixed_repr(exponent, rounding).unwrap();
+                        assert_eq!(fixed, FixedPerplex::new(t, x, exponent));
+                        assert_eq!(Perplex::from_fixed_repr(fixed), z, "Lossless!");
+                        assert_eq!(FixedPerplex::from_le_bytes(fixed.to_le_bytes()), fixed);
+                    }
+                }
+            }
+        }
+        let fixed = FixedPerplex::new(i64::MIN, 1 << 53, 0);
+        let z = Perplex::from(fixed);
+        assert_eq!(z.to_fixed_repr(0, Rounding::Nearest), Some(fixed));
+    }
+    #[test]
+    fn test_rounding() {
+        let exponent = -3;
+        let scale = 2f64.powi(exponent);
+        for k in -200..=200 {
+            let z = Perplex::new(k as f64 * 0.01, -(k as f64) * 0.037);
+            for rounding in ROUNDINGS {
+                let fixed = z.to_fixed_repr(exponent, rounding).unwrap();
+                let w = Perplex::from_fixed_repr(fixed);
+                let (dt, dx) = (w.t - z.t, w.x - z.x);
+                let bound = match rounding {
+                    Rounding::Nearest => 0.5 * scale,
+                    _ => scale,
+                };
+                assert!(dt.abs() <= bound && dx.abs() <= bound);
+                match rounding {
+                    Rounding::Floor => assert!(dt <= 0.0 && dx <= 0.0),
+                    Rounding::Ceil => assert!(dt >= 0.0 && dx >= 0.0),
+                    Rounding::TowardZero => assert!(w.t.abs() <= z.t.abs()),
+                    Rounding::Nearest => {}
+                }
+                // the rounded value is a fixed point of the conversion
+                assert_eq!(w.to_fixed_repr(exponent, rounding), Some(fixed));
+            }
+        }
+        let tie = Perplex::new(2.5, -2.5);
+        let nearest = tie.to_fixed_repr(0, Rounding::Nearest).unwrap();
+        assert_eq!((nearest.t, nearest.x), (3, -3), "Ties away from zero!");
+    }
+    #[test]
+    fn test_unrepresentable() {
+        let rounding = Rounding::Nearest;
+        assert!(Perplex::new(f64::NAN, 0.0)
+            .to_fixed_repr(0, rounding)
+            .is_none());
+        assert!(Perplex::new(0.0, f64::INFINITY)
+            .to_fixed_repr(0, rounding)
+            .is_none());
+        assert!(Perplex::new(1.0, 0.0)
+            .to_fixed_repr(-63, rounding)
+            .is_none());
+        assert!(Perplex::new(-1.0, 0.0)
+            .to_fixed_repr(-63, rounding)
+            .is_some());
+        assert!(Perplex::new(1e300, 0.0)
+            .to_fixed_repr(0, rounding)
+            .is_none());
+    }
+    #[test]
+    fn test_subnormal() {
+        let fixed = FixedPerplex::new(1 << 52, 0, -1074);
+        assert_eq!(Perplex::from(fixed), Perplex::new(f64::MIN_POSITIVE, 0.0));
+        let fixed = FixedPerplex::new(3, -1, -1074);
+        let z = Perplex::from(fixed);
+        assert_eq!(z, Perplex::new(3.0 * 5e-324, -5e-324), "Subnormal!");
+        for rounding in ROUNDINGS {
+            assert_eq!(z.to_fixed_repr(-1074, rounding), Some(fixed));
+        }
+        // the scale 2^1100 overflows, but the scaled components do not
+        let z = Perplex::new(0.0, 1e-320);
+        let fixed = z.to_fixed_repr(-1100, Rounding::Nearest).unwrap();
+        assert_eq!(fixed.t, 0);
+        assert_eq!(Perplex::from(fixed), z);
+    }
+    #[test]
+    fn test_extreme_exponents() {
+        let zero = Perplex::new(0.0, -0.0);
+        for exponent in [i32::MIN, i32::MAX] {
+            assert_eq!(
+                zero.to_fixed_repr(exponent, Rounding::Nearest),
+                Some(FixedPerplex::new(0, 0, exponent))
+            );
+        }
+        let z = Perplex::new(1.0, -1.0);
+        assert_eq!(z.to_fixed_repr(i32::MIN, Rounding::Nearest), None);
+        let expected = [(0, 0), (0, 0), (0, -1), (1, 0)];
+        for (rounding, (t, x)) in ROUNDINGS.into_iter().zip(expected) {
+            let fixed = z.to_fixed_repr(i32::MAX, rounding).unwrap();
+            assert_eq!((fixed.t, fixed.x), (t, x), "Underflow below one!");
+        }
+        let overflow = Perplex::from(FixedPerplex::new(1, -1, i32::MAX));
+        assert_eq!(overflow, Perplex::new(f64::INFINITY, f64::NEG_INFINITY));
+        let underflow = Perplex::from(FixedPerplex::new(1, -1, i32::MIN));
+        assert_eq!(underflow, Perplex::new(0.0, 0.0));
+    }
+}
@@ -0,0 +1,375 @@
+//! # Format Module
+//!
+//! This module provides the `PerplexFormatter` builder for configurable text output of perplex numbers, e.g., for reports with thousands of values in consistent columns.
+//! The `Display` implementation of `Perplex` prints two decimal places unless a precision is given, which corresponds to the default formatter.
+//!
+//! ## Features
+//! - Precision: The number of decimal places of all printed numbers.
+//! - Unit symbol: The symbol of the hyperbolic unit, `h` by default, e.g., `j` or `ε` as in other notations of split-complex numbers.
+//! - `SignStyle`: Spaced or compact operators, and an optional explicit plus sign of the time component.
+//! - `Notation`: Cartesian `t ± x h` or hyperbolic polar `k rho exp(theta h)` with the Klein unit `k` of the sector, see `HyperbolicPolar`.
+//! - Padding: A minimum width of each printed number for aligned columns.
+//! - `write_to` and `format_into`: Formatting into a `fmt::Write` or a byte buffer without heap allocation, also for `Perplex` and `HyperbolicPolar` with their `Display` output, e.g., for embedded targets and hot logging paths.
+//!
+//! ## Example
+//! ```
+//! use perplex_num::format::{Notation, PerplexFormatter, SignStyle};
+//! use perplex_num::Perplex;
+//! let z = Perplex::new(2.0, -1.0);
+//! assert_eq!(PerplexFormatter::new().format(&z), z.to_string());
+//! let formatter = PerplexFormatter::new().precision(1).unit("j").sign_style(SignStyle::Compact);
+//! assert_eq!(formatter.format(&z), "2.0-1.0j");
+//! assert_eq!(format!("[{}]", formatter.width(5).display(&z)), "[  2.0-  1.0j]");
+//! let polar = PerplexFormatter::new().notation(Notation::Polar).precision(3);
+//! assert_eq!(polar.format(&Perplex::new(-1.0, -2.0)), "-h 1.732 exp(0.549 h)");
+//! ```
+
+use super::{HyperbolicPolar, HyperbolicSector, KleinIndex, Perplex};
+use num_traits::{Float, Num};
+use std::fmt;
+use std::ops::Neg;
+
+/// The style of the signs of a formatted perplex number.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug, Default)]
+pub enum SignStyle {
+    /// Operators surrounded by spaces and a unit symbol separated by a space, e.g., `2.00 - 1.00 h`.
+    #[default]
+    Spaced,
+    /// Operators and unit symbol without spaces, e.g., `2.00-1.00h`.
+    Compact,
+    /// Like `Spaced` with an explicit plus sign of a non-negative time component, e.g., `+2.00 - 1.00 h`.
+    Explicit,
+}
+
+/// The notation of a formatted perplex number.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug, Default)]
+pub enum Notation {
+    /// The cartesian notation `t ± x h`.
+    #[default]
+    Cartesian,
+    /// The hyperbolic polar notation `k rho exp(theta h)` with the Klein unit `k`, whereby the compact sign style omits the space before the unit symbol. Light-like numbers are printed as `t (1 ± h)`.
+    Polar,
+}
+
+/// A builder for the configurable formatting of perplex numbers.
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+pub struct PerplexFormatter {
+    precision: usize,
+    unit: String,
+    sign_style: SignStyle,
+    notation: Notation,
+    width: usize,
+}
+
+impl Default for PerplexFormatter {
+    /// Returns the formatter of the `Display` implementation, i.e., two decimal places, the unit `h`, spaced signs, cartesian notation and no padding.
+    fn default() -> Self {
+        Self {
+            precision: 2,
+            unit: String::from("h"),
+            sign_style: SignStyle::default(),
+            notation: Notation::default(),
+            width: 0,
+        }
+    }
+}
+
+impl PerplexFormatter {
+    /// Creates the default formatter.
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// Sets the number of decimal places.
+    #[inline]
+    pub fn precision(mut self, precision: usize) -> Self {
+        self.precision = precision;
+        self
+    }
+    /// Sets the symbol of the hyperbolic unit.
+    #[inline]
+    pub fn unit(mut self, unit: &str) -> Self {
+        self.unit = String::from(unit);
+        self
+    }
+    /// Sets the style of the signs.
+    #[inline]
+    pub fn sign_style(mut self, sign_style: SignStyle) -> Self {
+        self.sign_style = sign_style;
+        self
+    }
+    /// Sets the cartesian or polar notation.
+    #[inline]
+    pub fn notation(mut self, notation: Notation) -> Self {
+        self.notation = notation;
+        self
+    }
+    /// Sets the minimum width of each printed number, which is padded with spaces on the left.
+    #[inline]
+    pub fn width(mut self, width: usize) -> Self {
+        self.width = width;
+        self
+    }
+    /// Formats `z` into a string.
+    #[inline]
+    pub fn format<T: Copy + Float + fmt::Display>(&self, z: &Perplex<T>) -> String {
+        self.display(z).to_string()
+    }
+    /// Returns an adapter, which implements `Display` by the configuration of `self`, e.g., for `format!` or `write!` without an intermediate string.
+    #[inline]
+    pub fn display<'a, T>(&'a self, z: &'a Perplex<T>) -> PerplexDisplay<'a, T> {
+        PerplexDisplay { formatter: self, z }
+    }
+    /// Writes the number `v` with the configured precision and width.
+    fn number<T: fmt::Display>(&self, f: &mut fmt::Formatter<'_>, v: T) -> fmt::Result {
+        write!(f, "{:>w$.p$}", v, w = self.width, p = self.precision)
+    }
+    /// Writes `lhs ± |rhs| unit` in the configured sign style.
+    fn cartesian<T: Copy + Float + fmt::Display>(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+        lhs: T,
+        rhs: T,
+    ) -> fmt::Result {
+        if self.sign_style == SignStyle::Explicit && lhs.is_sign_positive() {
+            write!(f, "+")?;
+        }
+        self.number(f, lhs)?;
+        let sign = if rhs < T::zero() { "-" } else { "+" };
+        match self.sign_style {
+            SignStyle::Compact => {
+                write!(f, "{sign}")?;
+                self.number(f, rhs.abs())?;
+                write!(f, "{}", self.unit)
+            }
+            SignStyle::Spaced | SignStyle::Explicit => {
+                write!(f, " {sign} ")?;
+                self.number(f, rhs.abs())?;
+                write!(f, " {}", self.unit)
+            }
+        }
+    }
+    /// Writes the polar notation `k rho exp(theta unit)` of `polar`, or `t (1 ± unit)` for light-like numbers.
+    fn polar<T: Copy + Float + fmt::Display>(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+        polar: HyperbolicPolar<T>,
+        unit: &str,
+    ) -> fmt::Result {
+        let klein = match polar.sector {
+            HyperbolicSector::Right => KleinIndex::One,
+            HyperbolicSector::Up => KleinIndex::H,
+            HyperbolicSector::Left => KleinIndex::MinusOne,
+            HyperbolicSector::Down => KleinIndex::MinusH,
+            HyperbolicSector::Diagonal(t) => {
+                // light-like numbers t (1 ± h) have no polar form
+                self.number(f, t)?;
+                let sign = if polar.theta > T::zero() { "+" } else { "-" };
+                return match self.sign_style {
+                    SignStyle::Compact => write!(f, "(1{sign}{unit})"),
+                    _ => write!(f, " (1 {sign} {unit})"),
+                };
+            }
+        };
+        match klein {
+            KleinIndex::One => write!(f, "1 ")?,
+            KleinIndex::H => write!(f, "{unit} ")?,
+            KleinIndex::MinusOne => write!(f, "-1 ")?,
+            KleinIndex::MinusH => write!(f, "-{unit} ")?,
+        }
+        self.number(f, polar.rho)?;
+        write!(f, " exp(")?;
+        self.number(f, polar.theta)?;
+        match self.sign_style {
+            SignStyle::Compact => write!(f, "{unit})"),
+            _ => write!(f, " {unit})"),
+        }
+    }
+    /// Writes `z` into `buf` without allocation, e.g., into a fixed-capacity string of an embedded target or a reused string of a hot logging path.
+    #[inline]
+    pub fn write_to<T: Copy + Float + fmt::Display, W: fmt::Write>(
+        &self,
+        z: &Perplex<T>,
+        buf: &mut W,
+    ) -> fmt::Result {
+        write!(buf, "{}", self.display(z))
+    }
+    /// Formats `z` into the byte buffer `buf` without allocation and returns the written string, or an error if `buf` is too small.
+    #[inline]
+    pub fn format_into<'a, T: Copy + Float + fmt::Display>(
+        &self,
+        z: &Perplex<T>,
+        buf: &'a mut [u8],
+    ) -> Result<&'a str, fmt::Error> {
+        format_slice(buf, format_args!("{}", self.display(z)))
+    }
+}
+
+/// The `Display` adapter of `PerplexFormatter::display`.
+#[derive(Copy, Clone, Debug)]
+pub struct PerplexDisplay<'a, T> {
+    formatter: &'a PerplexFormatter,
+    z: &'a Perplex<T>,
+}
+
+impl<T: Copy + Float + fmt::Display> fmt::Display for PerplexDisplay<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let formatter = self.formatter;
+        let z = *self.z;
+        match formatter.notation {
+            Notation::Cartesian => formatter.cartesian(f, z.t, z.x),
+            Notation::Polar => formatter.polar(f, z.polar(), &formatter.unit),
+        }
+    }
+}
+
+impl<T: Copy + Float + fmt::Display> fmt::Display for HyperbolicPolar<T> {
+    /// Prints the polar notation `k rho exp(theta h)` of `PerplexFormatter` with two decimal places unless a precision is given, e.g., `-h 1.73 exp(0.55 h)`, or `t (1 ± h)` for light-like numbers.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // an empty unit does not allocate
+        let formatter = PerplexFormatter {
+            precision: f.precision().unwrap_or(2),
+            unit: String::new(),
+            sign_style: SignStyle::Spaced,
+            notation: Notation::Polar,
+            width: 0,
+        };
+        formatter.polar(f, *self, "h")
+    }
+}
+
+impl<T: Copy + Neg<Output = T> + PartialOrd + Num + fmt::Display> Perplex<T> {
+    /// Writes the `Display` output of `self` into `buf` without allocation, e.g., into a fixed-capacity string of an embedded target.
+    #[inline]
+    pub fn write_to<W: fmt::Write>(&self, buf: &mut W) -> fmt::Result {
+        write!(buf, "{self}")
+    }
+    /// Formats `self` into the byte buffer `buf` without allocation and returns the written string, or an error if `buf` is too small.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use perplex_num::Perplex;
+    ///
+    /// let mut buf = [0u8; 32];
+    /// assert_eq!(Perplex::new(1.0, -2.5).format_into(&mut buf), Ok("1.00 - 2.50 h"));
+    /// assert!(Perplex::new(1.0, -2.5).format_into(&mut buf[..8]).is_err());
+    /// ```
+    #[inline]
+    pub fn format_into<'a>(&self, buf: &'a mut [u8]) -> Result<&'a str, fmt::Error> {
+        format_slice(buf, format_args!("{self}"))
+    }
+}
+
+impl<T: Copy + Float + fmt::Display> HyperbolicPolar<T> {
+    /// Writes the `Display` output of `self` into `buf` without allocation.
+    #[inline]
+    pub fn write_to<W: fmt::Write>(&self, buf: &mut W) -> fmt::Result {
+        write!(buf, "{self}")
+    }
+    /// Formats `self` into the byte buffer `buf` without allocation and returns the written string, or an error if `buf` is too small.
+    #[inline]
+    pub fn format_into<'a>(&self, buf: &'a mut [u8]) -> Result<&'a str, fmt::Error> {
+        format_slice(buf, format_args!("{self}"))
+    }
+}
+
+/// A writer into a byte buffer of fixed capacity.
+struct SliceWriter<'a> {
+    buf: &'a mut [u8],
+    len: usize,
+}
+
+impl fmt::Write for SliceWriter<'_> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        let end = self.len + s.len();
+        if end > self.buf.len() {
+            return Err(fmt::Error);
+        }
+        self.buf[self.len..end].copy_from_slice(s.as_bytes());
+        self.len = end;
+        Ok(())
+    }
+}
+
+/// Writes `args` into `buf` and returns the written string.
+fn format_slice<'a>(buf: &'a mut [u8], args: fmt::Arguments<'_>) -> Result<&'a str, fmt::Error> {
+    let mut writer = SliceWriter { buf, len: 0 };
+    fmt::write(&mut writer, args)?;
+    let SliceWriter { buf, len } = writer;
+    // only whole strings are copied, hence the bytes are valid UTF-8
+    std::str::from_utf8(&buf[..len]).map_err(|_| fmt::Error)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[test]
+    fn test_cartesian() {
+        let z = Perplex::new(2.0, -1.0);
+        let formatter = PerplexFormatter::new();
+        assert_eq!(formatter.format(&z), z.to_string(), "Default of Display!");
+        assert_eq!(formatter.clone().precision(0).format(&z), "2 - 1 h");
+        let explicit = formatter.clone().sign_style(SignStyle::Explicit);
+        assert_eq!(explicit.format(&z), "+2.00 - 1.00 h");
+        assert_eq!(explicit.format(&-z), "-2.00 + 1.00 h");
+        let padded = formatter.precision(1).width(6).unit("ε");
+        assert_eq!(
+            padded.format(&Perplex::new(-10.25f32, 0.5)),
+            " -10.2 +    0.5 ε"
+        );
+    }
+    #[test]
+    fn test_polar() {
+        let polar = PerplexFormatter::new().notation(Notation::Polar);
+        let z = Perplex::new(-2.0, -1.0);
+        let expected = format!("-1 {:.2} exp({:.2} h)", z.modulus(), z.arg());
+        assert_eq!(polar.format(&z), expected);
+        let compact = polar.clone().sign_style(SignStyle::Compact).unit("j");
+        assert_eq!(compact.format(&Perplex::new(1.0, 0.0)), "1 1.00 exp(0.00j)");
+        assert_eq!(compact.format(&Perplex::new(0.0, 1.0)), "j 1.00 exp(0.00j)");
+        assert_eq!(polar.format(&Perplex::new(1.5, -1.5)), "1.50 (1 - h)");
+        assert_eq!(compact.format(&Perplex::new(0.0, 0.0)), "0.00(1+j)");
+    }
+    #[test]
+    fn test_polar_display() {
+        let polar = PerplexFormatter::new().notation(Notation::Polar);
+        for z in [
+            Perplex::new(2.0, 1.0),
+            Perplex::new(-1.0, -2.0),
+            Perplex::new(1.5, -1.5),
+            Perplex::new(0.0, 0.0),
+        ] {
+            assert_eq!(z.polar().to_string(), polar.format(&z));
+        }
+        let z = Perplex::new(0.5, 3.0);
+        assert_eq!(
+            format!("{:.4}", z.polar()),
+            polar.precision(4).format(&z),
+            "Precision of Display!"
+        );
+    }
+    #[test]
+    fn test_buffers() {
+        let z = Perplex::new(-0.5, 2.0);
+        let mut buf = [0u8; 64];
+        assert_eq!(z.format_into(&mut buf), Ok(z.to_string().as_str()));
+        let expected = z.polar().to_string();
+        assert_eq!(z.polar().format_into(&mut buf), Ok(expected.as_str()));
+        assert_eq!(
+            z.format_into(&mut buf[..5]),
+            Err(fmt::Error),
+            "Buffer too small!"
+        );
+        let formatter = PerplexFormatter::new()
+            .precision(1)
+            .sign_style(SignStyle::Compact);
+        assert_eq!(formatter.format_into(&z, &mut buf), Ok("-0.5+2.0h"));
+        let mut line = String::with_capacity(64);
+        z.write_to(&mut line).unwrap();
+        line.push_str("; ");
+        formatter.write_to(&z, &mut line).unwrap();
+        z.polar().write_to(&mut line).unwrap();
+        assert_eq!(line, format!("{z}; -0.5+2.0h{}", z.polar()));
+    }
+}
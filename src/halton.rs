@@ -0,0 +1,213 @@
+//! # Halton Module
+//!
+//! This module provides the deterministic low-discrepancy `Halton` sequence in two dimensions, which yields perplex numbers covering a `PerplexBox` or a region in hyperbolic polar coordinates evenly without the clumping of random samples.
+//! It serves reproducible quasi-Monte-Carlo integration and the sampling of plots. The sequence neither depends on a random number generator nor allocates, hence it is available without the `rand` feature and in `no_std` builds, whereby `in_box` requires `std` like `PerplexBox`.
+//!
+//! ## Features
+//! - `Halton`: The Halton sequence with the bases 2 and 3, see [Halton sequence](https://en.wikipedia.org/wiki/Halton_sequence), with random access to any index and jump-ahead.
+//! - Seeds: A seed selects a Cranley–Patterson rotation, i.e., a shift modulo one of both coordinates, such that independent but reproducible sequences are obtained for randomized quasi-Monte-Carlo estimates.
+//! - `in_box` and `in_polar`: Iterators over perplex numbers in a rectangle of the (t, x) plane and in a region `[rho_0, rho_1] x [theta_0, theta_1]` of a sector, both uniformly distributed with respect to the area.
+//!
+//! ## Example
+//! ```
+//! use perplex_num::halton::Halton;
+//! use perplex_num::{Perplex, PerplexBox};
+//! let region = PerplexBox::new(Perplex::new(0.0, -1.0), Perplex::new(2.0, 1.0)).unwrap();
+//! let points: Vec<_> = Halton::with_seed(42).in_box(region).take(100).collect();
+//! assert!(points.iter().all(|&z| region.contains(z)));
+//! // the same seed reproduces the points, also after a jump
+//! let mut halton = Halton::with_seed(42);
+//! halton.jump(99);
+//! assert_eq!(halton.in_box(region).next(), Some(points[99]));
+//! ```
+
+#[cfg(feature = "std")]
+use super::PerplexBox;
+use super::{HyperbolicPolar, HyperbolicSector, Perplex};
+use num_traits::Float;
+
+/// The two-dimensional Halton sequence with the bases 2 and 3 in the unit square, optionally rotated by a seed.
+///
+/// The iterator starts at the index one, since the point of index zero is the corner `(0, 0)` of the unrotated sequence.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Halton<T> {
+    index: u64,
+    shift: [T; 2],
+}
+
+impl<T: Copy + Float> Default for Halton<T> {
+    /// Returns the unrotated sequence.
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Copy + Float> Halton<T> {
+    /// Creates the unrotated Halton sequence.
+    #[inline]
+    pub fn new() -> Self {
+        Self {
+            index: 1,
+            shift: [T::zero(); 2],
+        }
+    }
+    /// Creates the Halton sequence rotated by a shift modulo one, which is derived deterministically from `seed`.
+    #[inline]
+    pub fn with_seed(seed: u64) -> Self {
+        let mut state = seed;
+        let shift = [
+            unit_interval(splitmix64(&mut state)),
+            unit_interval(splitmix64(&mut state)),
+        ];
+        Self { index: 1, shift }
+    }
+    /// Returns the index of the next point.
+    #[inline]
+    pub fn index(&self) -> u64 {
+        self.index
+    }
+    /// Skips the next `steps` points in constant time.
+    #[inline]
+    pub fn jump(&mut self, steps: u64) {
+        self.index += steps;
+    }
+    /// Returns the point of the sequence with the index `index` in the unit square.
+    #[inline]
+    pub fn point(&self, index: u64) -> [T; 2] {
+        let rotate = |p: T, shift: T| {
+            let q = p + shift;
+            if q >= T::one() {
+                q - T::one()
+            } else {
+                q
+            }
+        };
+        [
+            rotate(radical_inverse(index, 2), self.shift[0]),
+            rotate(radical_inverse(index, 3), self.shift[1]),
+        ]
+    }
+    /// Returns an iterator over perplex numbers in `region`, whose time and space components are the scaled coordinates of the points.
+    #[cfg(feature = "std")]
+    pub fn in_box(self, region: PerplexBox<T>) -> impl Iterator<Item = Perplex<T>> {
+        let (min, max) = (region.min, region.max);
+        self.map(move |[a, b]| {
+            Perplex::new(min.t + a * (max.t - min.t), min.x + b * (max.x - min.x))
+        })
+    }
+    /// Returns an iterator over perplex numbers in `sector` with the modulus in `[rho[0], rho[1]]` and the argument in `[theta[0], theta[1]]`.
+    ///
+    /// Since the area element in hyperbolic polar coordinates is `rho d(rho) d(theta)`, the modulus is sampled by the square root of the first coordinate, such that the points are uniformly distributed with respect to the area.
+    pub fn in_polar(
+        self,
+        rho: [T; 2],
+        theta: [T; 2],
+        sector: HyperbolicSector<T>,
+    ) -> impl Iterator<Item = Perplex<T>> {
+        let (rho_0, rho_1) = (rho[0] * rho[0], rho[1] * rho[1]);
+        self.map(move |[a, b]| {
+            HyperbolicPolar {
+                rho: (rho_0 + a * (rho_1 - rho_0)).sqrt(),
+                theta: theta[0] + b * (theta[1] - theta[0]),
+                sector,
+            }
+            .into()
+        })
+    }
+}
+
+impl<T: Copy + Float> Iterator for Halton<T> {
+    type Item = [T; 2];
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let point = self.point(self.index);
+        self.index += 1;
+        Some(point)
+    }
+    #[inline]
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        self.jump(n as u64);
+        self.next()
+    }
+}
+
+/// Returns the radical inverse of `index` in the base `base`, i.e., the digits of `index` mirrored at the decimal point.
+fn radical_inverse<T: Copy + Float>(mut index: u64, base: u64) -> T {
+    let b = T::from(base).unwrap();
+    let mut factor = T::one() / b;
+    let mut inverse = T::zero();
+    while index > 0 {
+        inverse = inverse + factor * T::from(index % base).unwrap();
+        index /= base;
+        factor = factor / b;
+    }
+    inverse
+}
+
+/// Advances the state of the SplitMix64 generator and returns its output.
+fn splitmix64(state: &mut u64) -> u64 {
+    *state = state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+    let mut z = *state;
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    z ^ (z >> 31)
+}
+
+/// Maps the upper 53 bits of `bits` to the unit interval `[0, 1)`.
+fn unit_interval<T: Copy + Float>(bits: u64) -> T {
+    T::from(bits >> 11).unwrap() / T::from(1u64 << 53).unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::PerplexBox;
+    use approx::assert_abs_diff_eq;
+    #[test]
+    fn test_halton() {
+        let points: Vec<[f64; 2]> = Halton::new().take(4).collect();
+        assert_eq!(
+            points,
+            vec![
+                [0.5, 1.0 / 3.0],
+                [0.25, 2.0 / 3.0],
+                [0.75, 1.0 / 9.0],
+                [0.125, 4.0 / 9.0]
+            ]
+        );
+        let mut halton = Halton::<f64>::with_seed(7);
+        let reference: Vec<_> = Halton::with_seed(7).take(20).collect();
+        assert_eq!(halton.nth(10), Some(reference[10]), "Jump-ahead!");
+        assert_eq!(halton.index(), 12);
+        assert_ne!(Halton::<f64>::with_seed(8).next(), Some(reference[0]));
+        assert!(reference.iter().flatten().all(|&p| (0.0..1.0).contains(&p)));
+    }
+    #[test]
+    fn test_discrepancy() {
+        // the fraction of points in the lower left quarter converges faster than for random points
+        let n = 1024;
+        let inside = Halton::<f64>::with_seed(3)
+            .take(n)
+            .filter(|[a, b]| *a < 0.5 && *b < 0.5)
+            .count();
+        assert_abs_diff_eq!(inside as f64 / n as f64, 0.25, epsilon = 0.01);
+    }
+    #[test]
+    fn test_regions() {
+        let region = PerplexBox::new(Perplex::new(-1.0, 2.0), Perplex::new(1.0, 3.0)).unwrap();
+        assert!(Halton::new()
+            .in_box(region)
+            .take(50)
+            .all(|z| region.contains(z)));
+        let points: Vec<_> = Halton::new()
+            .in_polar([1.0, 2.0], [-0.5, 0.5], HyperbolicSector::Up)
+            .take(50)
+            .collect();
+        for z in points {
+            assert_eq!(z.sector(), HyperbolicSector::Up);
+            assert!((1.0 - 1e-12..=2.0 + 1e-12).contains(&z.modulus()));
+            assert!((-0.5..=0.5).contains(&z.arg()));
+        }
+    }
+}
@@ -0,0 +1,107 @@
+//! # Hash Module
+//!
+//! This module (optional feature `quantized-hash`) provides deterministic hashing of perplex numbers quantized to a square lattice in the cartesian coordinate plane.
+//! All perplex numbers within the same lattice cell produce the same hash, which enables reproducible spatial hashing of perplex point clouds, e.g., for neighbor lookup structures.
+//!
+//! ## Features
+//! - `lattice_cell`: Returns the integer coordinates of the lattice cell containing a perplex number.
+//! - `hash_quantized`: Feeds the canonical little-endian bytes of the lattice cell to a `Hasher`, independent of the platform and of the sign of zero.
+//!
+//! ## Example
+//! ```
+//! use perplex_num::Perplex;
+//! use std::collections::hash_map::DefaultHasher;
+//! use std::hash::Hasher;
+//!
+//! let hash = |z: Perplex<f64>| {
+//!     let mut hasher = DefaultHasher::new();
+//!     z.hash_quantized(0.5, &mut hasher);
+//!     hasher.finish()
+//! };
+//! assert_eq!(hash(Perplex::new(1.1, -0.2)), hash(Perplex::new(1.4, -0.3)));
+//! assert_ne!(hash(Perplex::new(1.1, -0.2)), hash(Perplex::new(1.6, -0.3)));
+//! ```
+
+use super::Perplex;
+use core::hash::Hasher;
+use num_traits::Float;
+
+/// Marker byte written for perplex numbers that lie in a representable lattice cell.
+const CELL_TAG: u8 = 0;
+/// Marker byte written for perplex numbers without a representable lattice cell, e.g., with non-finite components.
+const INVALID_TAG: u8 = 1;
+
+impl<T: Copy + Float> Perplex<T> {
+    /// Returns the integer coordinates `(floor(t / grid), floor(x / grid))` of the lattice cell with edge length `grid` containing `self`.
+    ///
+    /// Returns `None` if `grid` is not positive and finite, or if a coordinate is not representable as `i64` (e.g., for non-finite components).
+    #[inline]
+    pub fn lattice_cell(&self, grid: T) -> Option<(i64, i64)> {
+        if !(grid > T::zero() && grid.is_finite()) {
+            return None;
+        }
+        let cell_t = (self.t / grid).floor().to_i64()?;
+        let cell_x = (self.x / grid).floor().to_i64()?;
+        Some((cell_t, cell_x))
+    }
+    /// Quantizes `self` to the lattice cell with edge length `grid` and feeds the canonical bytes of the cell into `state`.
+    ///
+    /// The bytes are the little-endian coordinates of `lattice_cell` preceded by a marker byte, so the hash is reproducible across platforms. All numbers without a lattice cell (see `lattice_cell`) are hashed equally.
+    #[inline]
+    pub fn hash_quantized<H: Hasher>(&self, grid: T, state: &mut H) {
+        match self.lattice_cell(grid) {
+            Some((cell_t, cell_x)) => {
+                state.write_u8(CELL_TAG);
+                state.write(&cell_t.to_le_bytes());
+                state.write(&cell_x.to_le_bytes());
+            }
+            None => state.write_u8(INVALID_TAG),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::hash_map::DefaultHasher;
+    fn quantized_hash(z: Perplex<f64>, grid: f64) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        z.hash_quantized(grid, &mut hasher);
+        hasher.finish()
+    }
+    #[test]
+    fn test_lattice_cell() {
+        let z = Perplex::new(1.25, -0.25);
+        assert_eq!(z.lattice_cell(1.0), Some((1, -1)), "Floor towards -inf!");
+        assert_eq!(z.lattice_cell(0.25), Some((5, -1)));
+        assert_eq!(Perplex::new(-0.0, 0.0).lattice_cell(1.0), Some((0, 0)));
+        assert_eq!(z.lattice_cell(0.0), None, "Grid must be positive!");
+        assert_eq!(z.lattice_cell(f64::NAN), None);
+        assert_eq!(Perplex::new(f64::INFINITY, 0.0).lattice_cell(1.0), None);
+        assert_eq!(Perplex::new(1e300, 0.0).lattice_cell(1.0), None);
+    }
+    #[test]
+    fn test_hash_quantized() {
+        let z = Perplex::new(0.3, 2.7);
+        assert_eq!(
+            quantized_hash(z, 1.0),
+            quantized_hash(Perplex::new(0.9, 2.1), 1.0),
+            "Same cell yields same hash!"
+        );
+        assert_ne!(quantized_hash(z, 1.0), quantized_hash(-z, 1.0));
+        assert_ne!(
+            quantized_hash(z, 1.0),
+            quantized_hash(Perplex::new(2.7, 0.3), 1.0),
+            "Components are not interchangeable!"
+        );
+        assert_eq!(
+            quantized_hash(Perplex::new(0.0, -0.0), 1.0),
+            quantized_hash(Perplex::new(-0.0, 0.0), 1.0),
+            "Sign of zero is irrelevant!"
+        );
+        assert_eq!(
+            quantized_hash(Perplex::new(f64::NAN, 0.0), 1.0),
+            quantized_hash(z, -1.0)
+        );
+    }
+}
@@ -0,0 +1,458 @@
+//! # HTML Plot Module
+//!
+//! This module writes interactive plots of perplex numbers as standalone HTML pages, e.g., to explore sectors and function images in the browser with zoom and hover instead of static images.
+//! A `PerplexPlot` is written as a page which draws the figure with the JavaScript library [plotly.js](https://plotly.com/javascript/), loaded from its CDN or from a given script source, or as the figure data `{"data": [...], "layout": {...}}` alone.
+//! The module writes the JSON of the figure by itself and needs no further dependencies; it does not depend on or interoperate with the `plotly` crate.
+//! The time component is drawn on the horizontal axis and the space component on the vertical axis with equal scales, such that the light cone consists of the diagonals.
+//!
+//! ## Features
+//! - `Trace`: Scatter traces of markers or lines from `Perplex` and `HyperbolicPolar` values.
+//! - `sector_traces`: One marker trace per sector colored by a `SectorPalette`, whereby the presets are `SectorPalette::DEFAULT` and the color-blind safe `SectorPalette::OKABE_ITO`.
+//! - `PerplexPlot`: The builder of the figure with a title and the light cone, which is written by `write_json`, `write_html` and `write_html_with_script`.
+//!
+//! ## Example
+//! ```
+//! use perplex_num::html_plot::{sector_traces, PerplexPlot, SectorPalette, Trace};
+//! use perplex_num::Perplex;
+//! let values: Vec<_> = (0..20).map(|k| Perplex::cis(0.1 * k as f64) * Perplex::new(1.0, 2.0)).collect();
+//! let curve: Vec<_> = (-10..=10).map(|k| Perplex::cis(0.1 * k as f64)).collect();
+//! let plot = PerplexPlot::new()
+//!     .title("Boosts of 1 + 2 h")
+//!     .traces(sector_traces(&values, SectorPalette::OKABE_ITO))
+//!     .trace(Trace::lines("unit hyperbola", &curve))
+//!     .light_cone(3.0);
+//! let mut html = Vec::new();
+//! plot.write_html(&mut html).unwrap();
+//! assert!(String::from_utf8(html).unwrap().contains("Plotly.newPlot"));
+//! ```
+
+use super::{HyperbolicPolar, HyperbolicSector, Perplex};
+use num_traits::Float;
+use std::fmt::Display;
+use std::io::{self, Write};
+use std::mem::discriminant;
+
+/// The URL of the default plotly.js bundle which is loaded by `PerplexPlot::write_html`.
+pub const PLOTLY_JS_URL: &str = "https://cdn.plot.ly/plotly-2.35.2.min.js";
+
+/// The drawing mode of a trace.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug, Default)]
+pub enum PlotMode {
+    /// A marker at each value.
+    #[default]
+    Markers,
+    /// A polyline through the values in their order.
+    Lines,
+    /// A polyline with a marker at each value.
+    LinesMarkers,
+}
+
+impl PlotMode {
+    /// Returns the `mode` attribute of plotly.js.
+    #[inline]
+    fn as_str(&self) -> &'static str {
+        match self {
+            Self::Markers => "markers",
+            Self::Lines => "lines",
+            Self::LinesMarkers => "lines+markers",
+        }
+    }
+}
+
+/// A scatter trace of perplex numbers with a name for the legend and an optional CSS color.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Trace<T> {
+    /// The name of the trace in the legend and the hover labels.
+    pub name: String,
+    /// The drawing mode.
+    pub mode: PlotMode,
+    /// The values of the trace.
+    pub values: Vec<Perplex<T>>,
+    /// The CSS color of the markers and lines, or `None` for the color cycle of plotly.js.
+    pub color: Option<String>,
+}
+
+impl<T: Copy + Float> Trace<T> {
+    /// Creates a trace of `values` with the drawing mode `mode` and the colors of plotly.js.
+    #[inline]
+    pub fn new(name: &str, mode: PlotMode, values: &[Perplex<T>]) -> Self {
+        Self {
+            name: name.to_string(),
+            mode,
+            values: values.to_vec(),
+            color: None,
+        }
+    }
+    /// Creates a trace with a marker at each of the `values`.
+    #[inline]
+    pub fn markers(name: &str, values: &[Perplex<T>]) -> Self {
+        Self::new(name, PlotMode::Markers, values)
+    }
+    /// Creates a polyline through the `values`, e.g., of a curve or a function image.
+    #[inline]
+    pub fn lines(name: &str, values: &[Perplex<T>]) -> Self {
+        Self::new(name, PlotMode::Lines, values)
+    }
+    /// Creates a trace of polar forms, which are converted to perplex numbers.
+    #[inline]
+    pub fn polar(name: &str, mode: PlotMode, values: &[HyperbolicPolar<T>]) -> Self {
+        let values: Vec<_> = values.iter().map(|&polar| Perplex::from(polar)).collect();
+        Self::new(name, mode, &values)
+    }
+    /// Sets the CSS color of the trace, e.g., `#1f77b4` or `red`.
+    #[inline]
+    pub fn color(mut self, color: &str) -> Self {
+        self.color = Some(color.to_string());
+        self
+    }
+}
+
+/// The CSS colors of the sectors for `sector_traces`.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub struct SectorPalette {
+    /// The color of the `Right` sector.
+    pub right: &'static str,
+    /// The color of the `Up` sector.
+    pub up: &'static str,
+    /// The color of the `Left` sector.
+    pub left: &'static str,
+    /// The color of the `Down` sector.
+    pub down: &'static str,
+    /// The color of the light-like numbers in the `Diagonal` sector.
+    pub diagonal: &'static str,
+}
+
+impl SectorPalette {
+    /// The first colors of the default color cycle of plotly.js and gray for light-like numbers.
+    pub const DEFAULT: Self = Self {
+        right: "#1f77b4",
+        up: "#ff7f0e",
+        left: "#2ca02c",
+        down: "#d62728",
+        diagonal: "#7f7f7f",
+    };
+    /// The color-blind safe palette of Okabe and Ito and black for light-like numbers.
+    pub const OKABE_ITO: Self = Self {
+        right: "#0072b2",
+        up: "#e69f00",
+        left: "#009e73",
+        down: "#d55e00",
+        diagonal: "#000000",
+    };
+    /// Returns the color of `sector`.
+    #[inline]
+    pub fn color<T>(&self, sector: HyperbolicSector<T>) -> &'static str {
+        match sector {
+            HyperbolicSector::Right => self.right,
+            HyperbolicSector::Up => self.up,
+            HyperbolicSector::Left => self.left,
+            HyperbolicSector::Down => self.down,
+            HyperbolicSector::Diagonal(_) => self.diagonal,
+        }
+    }
+}
+
+impl Default for SectorPalette {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+/// Returns one marker trace per sector, which is named by `HyperbolicSector::short_name` and colored by `palette`, in the order `Right`, `Up`, `Left`, `Down` and `Diagonal`, whereby sectors without values are omitted.
+pub fn sector_traces<T: Copy + Float>(
+    values: &[Perplex<T>],
+    palette: SectorPalette,
+) -> Vec<Trace<T>> {
+    let mut sectors: Vec<_> = HyperbolicSector::all().collect();
+    sectors.push(HyperbolicSector::Diagonal(T::zero()));
+    sectors
+        .into_iter()
+        .filter_map(|sector| {
+            let values: Vec<_> = values
+                .iter()
+                .copied()
+                .filter(|z| discriminant(&z.sector()) == discriminant(&sector))
+                .collect();
+            if values.is_empty() {
+                None
+            } else {
+                Some(Trace::markers(sector.short_name(), &values).color(palette.color(sector)))
+            }
+        })
+        .collect()
+}
+
+/// The builder of an interactive plot of perplex numbers.
+#[derive(Clone, Debug, PartialEq)]
+pub struct PerplexPlot<T> {
+    title: String,
+    traces: Vec<Trace<T>>,
+    light_cone: Option<T>,
+}
+
+impl<T: Copy + Float + Display> Default for PerplexPlot<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Copy + Float + Display> PerplexPlot<T> {
+    /// Creates an empty plot without title and light cone.
+    #[inline]
+    pub fn new() -> Self {
+        Self {
+            title: String::new(),
+            traces: Vec::new(),
+            light_cone: None,
+        }
+    }
+    /// Sets the title of the plot.
+    #[inline]
+    pub fn title(mut self, title: &str) -> Self {
+        self.title = title.to_string();
+        self
+    }
+    /// Adds a trace to the plot.
+    #[inline]
+    pub fn trace(mut self, trace: Trace<T>) -> Self {
+        self.traces.push(trace);
+        self
+    }
+    /// Adds traces to the plot, e.g., of `sector_traces`.
+    #[inline]
+    pub fn traces<I: IntoIterator<Item = Trace<T>>>(mut self, traces: I) -> Self {
+        self.traces.extend(traces);
+        self
+    }
+    /// Draws the light cone as dashed diagonals `x = ±t` for `|t| <= extent`.
+    #[inline]
+    pub fn light_cone(mut self, extent: T) -> Self {
+        self.light_cone = Some(extent);
+        self
+    }
+    /// Writes the JSON figure `{"data": [...], "layout": {...}}` of plotly.js, whereby non-finite components are written as `null` and hence omitted by plotly.js.
+    pub fn write_json<W: Write>(&self, mut writer: W) -> io::Result<()> {
+        write!(writer, "{{\"data\":[")?;
+        for (i, trace) in self.traces.iter().enumerate() {
+            if i > 0 {
+                write!(writer, ",")?;
+            }
+            write!(
+                writer,
+                "{{\"type\":\"scatter\",\"mode\":\"{}\",\"name\":",
+                trace.mode.as_str()
+            )?;
+            write_json_string(&mut writer, &trace.name)?;
+            write!(writer, ",\"x\":")?;
+            write_json_numbers(&mut writer, trace.values.iter().map(|z| z.t))?;
+            write!(writer, ",\"y\":")?;
+            write_json_numbers(&mut writer, trace.values.iter().map(|z| z.x))?;
+            if let Some(color) = &trace.color {
+                for attribute in ["marker", "line"] {
+                    write!(writer, ",\"{attribute}\":{{\"color\":")?;
+                    write_json_string(&mut writer, color)?;
+                    write!(writer, "}}")?;
+                }
+            }
+            write!(writer, "}}")?;
+        }
+        write!(writer, "],\"layout\":{{\"title\":{{\"text\":")?;
+        write_json_string(&mut writer, &self.title)?;
+        write!(
+            writer,
+            "}},\"xaxis\":{{\"title\":{{\"text\":\"t\"}}}},\"yaxis\":{{\"title\":{{\"text\":\"x\"}},\"scaleanchor\":\"x\"}}"
+        )?;
+        if let Some(extent) = self.light_cone {
+            write!(writer, ",\"shapes\":[")?;
+            for (i, sign) in [T::one(), -T::one()].into_iter().enumerate() {
+                if i > 0 {
+                    write!(writer, ",")?;
+                }
+                write!(writer, "{{\"type\":\"line\",\"x0\":")?;
+                write_json_number(&mut writer, -extent)?;
+                write!(writer, ",\"y0\":")?;
+                write_json_number(&mut writer, -extent * sign)?;
+                write!(writer, ",\"x1\":")?;
+                write_json_number(&mut writer, extent)?;
+                write!(writer, ",\"y1\":")?;
+                write_json_number(&mut writer, extent * sign)?;
+                write!(
+                    writer,
+                    ",\"line\":{{\"color\":\"gray\",\"dash\":\"dash\"}}}}"
+                )?;
+            }
+            write!(writer, "]")?;
+        }
+        write!(writer, "}}}}")
+    }
+    /// Writes a standalone HTML page, which loads plotly.js from `PLOTLY_JS_URL` and shows the figure of `write_json` in the whole window.
+    #[inline]
+    pub fn write_html<W: Write>(&self, writer: W) -> io::Result<()> {
+        self.write_html_with_script(writer, PLOTLY_JS_URL)
+    }
+    /// Writes the standalone HTML page of `write_html`, which loads plotly.js from `script_src` instead, e.g., a local copy of the bundle for offline use.
+    pub fn write_html_with_script<W: Write>(
+        &self,
+        mut writer: W,
+        script_src: &str,
+    ) -> io::Result<()> {
+        writeln!(writer, "<!DOCTYPE html>")?;
+        writeln!(writer, "<html>")?;
+        writeln!(writer, "<head>")?;
+        writeln!(writer, "<meta charset=\"utf-8\">")?;
+        write!(writer, "<title>")?;
+        write_html_escaped(&mut writer, &self.title)?;
+        writeln!(writer, "</title>")?;
+        write!(writer, "<script src=\"")?;
+        write_html_escaped(&mut writer, script_src)?;
+        writeln!(writer, "\"></script>")?;
+        writeln!(writer, "</head>")?;
+        writeln!(writer, "<body style=\"margin:0\">")?;
+        writeln!(
+            writer,
+            "<div id=\"plot\" style=\"width:100vw;height:100vh\"></div>"
+        )?;
+        write!(writer, "<script>const figure = ")?;
+        self.write_json(&mut writer)?;
+        writeln!(writer, ";")?;
+        writeln!(
+            writer,
+            "Plotly.newPlot(\"plot\", figure.data, figure.layout, {{responsive: true}});"
+        )?;
+        writeln!(writer, "</script>")?;
+        writeln!(writer, "</body>")?;
+        writeln!(writer, "</html>")
+    }
+}
+
+/// Writes `s` with the special characters of HTML text and attribute values escaped.
+fn write_html_escaped<W: Write>(writer: &mut W, s: &str) -> io::Result<()> {
+    for c in s.chars() {
+        match c {
+            '<' => write!(writer, "&lt;")?,
+            '>' => write!(writer, "&gt;")?,
+            '&' => write!(writer, "&amp;")?,
+            '"' => write!(writer, "&quot;")?,
+            _ => write!(writer, "{c}")?,
+        }
+    }
+    Ok(())
+}
+
+/// Writes `v`, or `null` if it is not finite, since JSON has no infinite and NaN numbers.
+#[inline]
+fn write_json_number<T: Float + Display, W: Write>(writer: &mut W, v: T) -> io::Result<()> {
+    if v.is_finite() {
+        write!(writer, "{v}")
+    } else {
+        write!(writer, "null")
+    }
+}
+
+/// Writes the array of `values`.
+fn write_json_numbers<T: Float + Display, W: Write, I: Iterator<Item = T>>(
+    writer: &mut W,
+    values: I,
+) -> io::Result<()> {
+    write!(writer, "[")?;
+    for (i, v) in values.enumerate() {
+        if i > 0 {
+            write!(writer, ",")?;
+        }
+        write_json_number(writer, v)?;
+    }
+    write!(writer, "]")
+}
+
+/// Writes `s` as a JSON string, whereby `<`, `>` and `&` are escaped as well, such that the JSON is safe within an HTML script.
+fn write_json_string<W: Write>(writer: &mut W, s: &str) -> io::Result<()> {
+    write!(writer, "\"")?;
+    for c in s.chars() {
+        match c {
+            '"' => write!(writer, "\\\"")?,
+            '\\' => write!(writer, "\\\\")?,
+            '\n' => write!(writer, "\\n")?,
+            c if c.is_control() || matches!(c, '<' | '>' | '&') => {
+                write!(writer, "\\u{:04x}", c as u32)?
+            }
+            c => write!(writer, "{c}")?,
+        }
+    }
+    write!(writer, "\"")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[test]
+    fn test_sector_traces() {
+        let values = [
+            Perplex::new(2.0, 1.0),
+            Perplex::new(1.0, 2.0),
+            Perplex::new(3.0, -1.0),
+            Perplex::new(1.0, 1.0),
+            Perplex::new(-2.0, 0.5),
+        ];
+        let traces = sector_traces(&values, SectorPalette::default());
+        let names: Vec<_> = traces.iter().map(|trace| trace.name.as_str()).collect();
+        assert_eq!(names, ["Right", "Up", "Left", "Diagonal"], "Down is empty!");
+        assert_eq!(traces[0].values, [values[0], values[2]]);
+        assert_eq!(
+            traces[3].color.as_deref(),
+            Some(SectorPalette::DEFAULT.diagonal)
+        );
+        assert!(traces.iter().all(|trace| trace.mode == PlotMode::Markers));
+    }
+    #[test]
+    fn test_json() {
+        let polar = [
+            Perplex::new(2.0, 1.0).polar(),
+            Perplex::new(1.0, -1.0).polar(),
+        ];
+        let plot = PerplexPlot::new()
+            .title("a \"quoted\" <title>")
+            .trace(
+                Trace::markers(
+                    "z",
+                    &[Perplex::new(1.5, -0.25), Perplex::new(f64::NAN, 1.0)],
+                )
+                .color("red"),
+            )
+            .trace(Trace::polar("polar", PlotMode::Lines, &polar))
+            .light_cone(2.0);
+        let mut json = Vec::new();
+        plot.write_json(&mut json).unwrap();
+        let json = String::from_utf8(json).unwrap();
+        let value: serde_json::Value = serde_json::from_str(&json).unwrap();
+        let trace = &value["data"][0];
+        assert_eq!(trace["mode"], "markers");
+        assert_eq!(trace["x"], serde_json::json!([1.5, null]));
+        // integral components are written without a fraction
+        assert_eq!(trace["y"], serde_json::json!([-0.25, 1]));
+        assert_eq!(trace["marker"]["color"], "red");
+        assert_eq!(value["data"][1]["mode"], "lines");
+        assert_eq!(value["data"][1]["y"][1], -1, "Light-like polar form!");
+        assert_eq!(value["layout"]["title"]["text"], "a \"quoted\" <title>");
+        assert!(!json.contains("<title>"), "Escaped for HTML scripts!");
+        let shapes = value["layout"]["shapes"].as_array().unwrap();
+        assert_eq!(shapes.len(), 2);
+        assert_eq!(shapes[1]["y0"], 2);
+    }
+    #[test]
+    fn test_html() {
+        let plot: PerplexPlot<f64> = PerplexPlot::new().title("<Sectors>");
+        let mut html = Vec::new();
+        plot.write_html(&mut html).unwrap();
+        let html = String::from_utf8(html).unwrap();
+        assert!(html.starts_with("<!DOCTYPE html>"));
+        assert!(html.contains("<title>&lt;Sectors&gt;</title>"));
+        assert!(html.contains(PLOTLY_JS_URL));
+        assert!(html.contains("\"data\":[]"));
+        let mut html = Vec::new();
+        plot.write_html_with_script(&mut html, "js/plotly.min.js?v=\"2\"")
+            .unwrap();
+        let html = String::from_utf8(html).unwrap();
+        assert!(html.contains("<script src=\"js/plotly.min.js?v=&quot;2&quot;\"></script>"));
+        assert!(!html.contains(PLOTLY_JS_URL));
+    }
+}
@@ -0,0 +1,430 @@
+//! # Integer Module
+//!
+//! This module provides the number theory of the split-complex integers `Z[h] = {t + x h : t, x ∈ Z}` for signed integer components like `Perplex<i64>` and `Perplex<BigInt>` of the [num-bigint](https://docs.rs/num-bigint) crate, see `PerplexInteger`.
+//! In the null basis, `Z[h]` is the subring of `Z × Z` of all pairs `(u, v) = (t + x, t - x)` with `u ≡ v (mod 2)`, see `null_basis::NullBasis`. Hence, the odd primes factor componentwise, while the prime `2` couples the components.
+//! Since `Z[h]` has zero divisors and is not integrally closed, a greatest common divisor does not always exist and factorizations into irreducible elements are not unique, e.g., `(3 + h) (3 + h) = 10 + 6 h = 2 (5 + 3 h)`.
+//!
+//! The null coefficients `t ± x` of primitive integers may overflow, e.g., for `i64::MAX + h`. The methods detect this by checked arithmetic and return `false` or `None`, respectively, instead of panicking.
+//!
+//! ## Features
+//! - `Perplex::is_unit`: The four units `±1` and `±h`, i.e., the numbers with `|t^2 - x^2| = 1`.
+//! - `Perplex::divides` and `Perplex::exact_div`: The divisibility and the exact quotient within `Z[h]`.
+//! - `Perplex::gcd`: The greatest common divisor with nonnegative null coefficients, or `None` if it does not exist.
+//! - `Perplex::factor`: A factorization into a unit and irreducible elements by trial division of the null coefficients.
+//!
+//! ## Example
+//! ```
+//! use perplex_num::Perplex;
+//! let (z, w) = (Perplex::new(7, 5), Perplex::new(13, 7));
+//! let g = z.gcd(&w).unwrap();
+//! assert_eq!(g, Perplex::new(3, 1));
+//! assert!(g.divides(&z) && g.divides(&w));
+//! assert_eq!(z.exact_div(&g), Some(Perplex::new(2, 1)));
+//! assert!(Perplex::new(0, -1).is_unit());
+//! let factorization = Perplex::new(22, 8).factor().unwrap();
+//! assert_eq!(factorization.product(), Perplex::new(22, 8));
+//! // the null coefficient i64::MAX + 1 overflows
+//! assert!(!Perplex::new(i64::MAX, 1).is_unit());
+//! ```
+
+use super::null_basis::NullBasis;
+use super::Perplex;
+use core::iter;
+use num_integer::Integer;
+use num_traits::{CheckedAdd, CheckedDiv, CheckedSub, Signed};
+
+/// A signed integer type of the components of `Z[h]`, see the module documentation.
+///
+/// It is implemented for all signed integer types with checked addition, subtraction and division, e.g., the primitive signed integers and `BigInt` of the `num-bigint` crate.
+pub trait PerplexInteger: Clone + Integer + Signed + CheckedAdd + CheckedSub + CheckedDiv {}
+
+impl<T: Clone + Integer + Signed + CheckedAdd + CheckedSub + CheckedDiv> PerplexInteger for T {}
+
+impl<T: PerplexInteger> Perplex<T> {
+    /// Checks if the number is a unit of `Z[h]`, i.e., one of `±1` and `±h`, which are the numbers with null coefficients `±1`.
+    ///
+    /// Returns `false` if a null coefficient overflows `T`, which is not a unit.
+    #[inline]
+    pub fn is_unit(&self) -> bool {
+        null(self).is_some_and(|z| is_unit_coefficient(&z.plus) && is_unit_coefficient(&z.minus))
+    }
+    /// Checks if `self` divides `other` within `Z[h]`, i.e., if `other = self * q` for an integer perplex number `q`.
+    ///
+    /// Returns `false` if a null coefficient of `self`, `other` or `q` overflows `T`.
+    #[inline]
+    pub fn divides(&self, other: &Self) -> bool {
+        self.exact_div_null(other).is_some()
+    }
+    /// Returns the quotient `q` with `self = divisor * q` within `Z[h]`, or `None` if `divisor` does not divide `self` or a null coefficient overflows `T`.
+    ///
+    /// A light-like divisor divides all numbers with a corresponding zero null coefficient, and the then arbitrary quotient is chosen with the smallest magnitude of this coefficient.
+    #[inline]
+    pub fn exact_div(&self, divisor: &Self) -> Option<Self> {
+        divisor.exact_div_null(self).map(from_null)
+    }
+    /// Returns the null coefficients of the quotient `other / self`.
+    #[inline]
+    fn exact_div_null(&self, other: &Self) -> Option<NullBasis<T>> {
+        quotient(&null(other)?, &null(self)?)
+    }
+    /// Returns the greatest common divisor of `self` and `other`, which is divisible by every common divisor, normalized by a unit to nonnegative null coefficients, i.e., `t >= |x|`.
+    ///
+    /// The null coefficients of a common divisor divide the integer gcds of the corresponding coefficients, and all odd factors of these are admissible. Thus, the candidates differ by powers of two, and the gcd is the largest candidate if it is divisible by all other candidates.
+    /// Returns `None` if the gcd does not exist, e.g., for `2 + 2 h` and `4 - 4 h`, whose common divisors `2` and `3 - h` do not divide each other, or if a null coefficient overflows `T`. The gcd of zero and zero is zero.
+    pub fn gcd(&self, other: &Self) -> Option<Self> {
+        let (a, b) = (null(self)?, null(other)?);
+        let plus = integer_gcd(a.plus.clone(), b.plus.clone())?;
+        let minus = integer_gcd(a.minus.clone(), b.minus.clone())?;
+        let common_divisors = || {
+            let (a, b, minus) = (&a, &b, &minus);
+            halvings(plus.clone())
+                .flat_map(move |u| {
+                    halvings(minus.clone()).map(move |v| NullBasis::new(u.clone(), v))
+                })
+                .filter(move |d| {
+                    is_integral(d) && quotient(a, d).is_some() && quotient(b, d).is_some()
+                })
+        };
+        // the candidates are ordered by decreasing powers of two
+        let greatest = common_divisors().next()?;
+        if common_divisors().all(|d| quotient(&greatest, &d).is_some()) {
+            Some(from_null(greatest))
+        } else {
+            None
+        }
+    }
+    /// Returns a factorization into a unit and irreducible elements, or `None` for light-like numbers, which include zero, and if a null coefficient overflows `T`.
+    ///
+    /// Every odd prime `p` of a null coefficient yields the irreducible factor with the null coefficients `(p, 1)` or `(1, p)`, respectively. The remaining powers of two `(2^a, 2^b)` with `a, b >= 1` split into `min(a, b) - 1` factors `2` and the irreducible factor `(2^(a - min(a, b) + 1), 2^(b - min(a, b) + 1))`.
+    /// The factors are found by trial division and ordered by the coefficient `u`, `v`, and `2`. The factorization is not unique, since `Z[h]` is not a unique factorization domain.
+    #[cfg(feature = "std")]
+    pub fn factor(&self) -> Option<Factorization<T>> {
+        let z = null(self)?;
+        if z.is_light_like() {
+            return None;
+        }
+        let one = T::one();
+        let two = one.clone() + one.clone();
+        let unit = from_null(NullBasis::new(z.plus.signum(), z.minus.signum()));
+        let mut factors = Vec::new();
+        let (plus, a) = split_power_of_two(checked_abs(z.plus)?);
+        let (minus, b) = split_power_of_two(checked_abs(z.minus)?);
+        for p in odd_prime_factors(plus) {
+            factors.push(from_null(NullBasis::new(p, one.clone())));
+        }
+        for p in odd_prime_factors(minus) {
+            factors.push(from_null(NullBasis::new(one.clone(), p)));
+        }
+        // a and b are zero or positive together, since u and v have the same parity
+        if a > 0 {
+            let shared = a.min(b) - 1;
+            let two_factor = from_null(NullBasis::new(two.clone(), two.clone()));
+            factors.extend(iter::repeat(two_factor).take(shared));
+            factors.push(from_null(NullBasis::new(
+                num_traits::pow(two.clone(), a - shared),
+                num_traits::pow(two, b - shared),
+            )));
+        }
+        Some(Factorization { unit, factors })
+    }
+}
+
+/// The factorization of an integer perplex number into a unit and irreducible elements, see `Perplex::factor`.
+#[cfg(feature = "std")]
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Factorization<T> {
+    /// One of the units `±1` and `±h`.
+    pub unit: Perplex<T>,
+    /// The irreducible factors, which are not units.
+    pub factors: Vec<Perplex<T>>,
+}
+
+#[cfg(feature = "std")]
+impl<T: PerplexInteger> Factorization<T> {
+    /// Returns the product of the unit and all factors, i.e., the factorized number.
+    ///
+    /// The product is accumulated in the null basis, whose partial products divide the null coefficients of the factorized number and hence do not overflow.
+    #[inline]
+    pub fn product(&self) -> Perplex<T> {
+        let product = self
+            .factors
+            .iter()
+            .fold(NullBasis::from(self.unit.clone()), |product, f| {
+                product * NullBasis::from(f.clone())
+            });
+        from_null(product)
+    }
+}
+
+/// Returns the null coefficients `t + x` and `t - x`, or `None` if one of them overflows.
+#[inline]
+fn null<T: PerplexInteger>(z: &Perplex<T>) -> Option<NullBasis<T>> {
+    Some(NullBasis::new(
+        z.t.checked_add(&z.x)?,
+        z.t.checked_sub(&z.x)?,
+    ))
+}
+
+/// Checks if a null coefficient is `±1`.
+#[inline]
+fn is_unit_coefficient<T: PerplexInteger>(v: &T) -> bool {
+    v.is_one() || (T::zero() - T::one()) == *v
+}
+
+/// Returns the absolute value, or `None` if it overflows.
+#[inline]
+fn checked_abs<T: PerplexInteger>(v: T) -> Option<T> {
+    if v.is_negative() {
+        T::zero().checked_sub(&v)
+    } else {
+        Some(v)
+    }
+}
+
+/// Checks if the null coefficients belong to an integer perplex number, i.e., if they have the same parity.
+#[inline]
+fn is_integral<T: PerplexInteger>(z: &NullBasis<T>) -> bool {
+    z.plus.is_even() == z.minus.is_even()
+}
+
+/// Returns the integer perplex number of null coefficients with the same parity.
+///
+/// The halves of the coefficients are combined before the halved remainders, such that no intermediate result overflows.
+#[inline]
+fn from_null<T: PerplexInteger>(z: NullBasis<T>) -> Perplex<T> {
+    let two = T::one() + T::one();
+    let (u, v) = (z.plus.div_rem(&two), z.minus.div_rem(&two));
+    Perplex::new(
+        u.0.clone() + v.0.clone() + (u.1.clone() + v.1.clone()) / two.clone(),
+        u.0 - v.0 + (u.1 - v.1) / two,
+    )
+}
+
+/// Returns the null coefficients of the quotient `a / d` within `Z[h]`.
+///
+/// A zero coefficient of `d` admits an arbitrary quotient coefficient if the one of `a` is zero, which is chosen as `0` or `±1` with the parity of the other quotient coefficient.
+fn quotient<T: PerplexInteger>(a: &NullBasis<T>, d: &NullBasis<T>) -> Option<NullBasis<T>> {
+    let component = |a: &T, d: &T| {
+        if d.is_zero() {
+            a.is_zero().then_some(None)
+        } else {
+            // the division overflows only for the quotient of the minimum by -1
+            let q = a.checked_div(d)?;
+            (q.clone() * d.clone() == *a).then_some(Some(q))
+        }
+    };
+    let two = T::one() + T::one();
+    let q = match (component(&a.plus, &d.plus)?, component(&a.minus, &d.minus)?) {
+        (Some(u), Some(v)) => NullBasis::new(u, v),
+        (Some(u), None) => NullBasis::new(u.clone(), u % two),
+        (None, Some(v)) => NullBasis::new(v.clone() % two, v),
+        (None, None) => NullBasis::new(T::zero(), T::zero()),
+    };
+    is_integral(&q).then_some(q)
+}
+
+/// Returns the nonnegative gcd of two integers by the Euclidean algorithm, or `None` if it overflows.
+#[inline]
+fn integer_gcd<T: PerplexInteger>(mut a: T, mut b: T) -> Option<T> {
+    while !b.is_zero() {
+        // the remainder by -1 is zero, whose quotient of the minimum overflows
+        let r = match a.checked_div(&b) {
+            Some(q) => a - q * b.clone(),
+            None => T::zero(),
+        };
+        (a, b) = (b, r);
+    }
+    checked_abs(a)
+}
+
+/// Returns `g`, `g / 2`, `g / 4`, ... down to the odd part of `g`, or only `0` for `g = 0`.
+#[inline]
+fn halvings<T: PerplexInteger>(g: T) -> impl Iterator<Item = T> {
+    let two = T::one() + T::one();
+    iter::successors(Some(g), move |g| {
+        (!g.is_zero() && g.is_even()).then(|| g.clone() / two.clone())
+    })
+}
+
+/// Splits a positive integer into its odd part and the exponent of its power of two.
+#[cfg(feature = "std")]
+#[inline]
+fn split_power_of_two<T: PerplexInteger>(mut n: T) -> (T, usize) {
+    let two = T::one() + T::one();
+    let mut exponent = 0;
+    while n.is_even() {
+        n = n / two.clone();
+        exponent += 1;
+    }
+    (n, exponent)
+}
+
+/// Returns the prime factors of a positive odd integer with multiplicity in increasing order.
+#[cfg(feature = "std")]
+fn odd_prime_factors<T: PerplexInteger>(mut n: T) -> Vec<T> {
+    let two = T::one() + T::one();
+    let mut factors = Vec::new();
+    let mut p = two.clone() + T::one();
+    // p <= n / p avoids the overflow of p * p
+    while p <= n.clone() / p.clone() {
+        while n.is_multiple_of(&p) {
+            factors.push(p.clone());
+            n = n / p.clone();
+        }
+        p = p + two.clone();
+    }
+    if n > T::one() {
+        factors.push(n);
+    }
+    factors
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    const N: i64 = 12;
+    /// All integer perplex numbers with components within `[-N, N]`.
+    fn numbers() -> impl Iterator<Item = Perplex<i64>> {
+        (-N..=N).flat_map(|t| (-N..=N).map(move |x| Perplex::new(t, x)))
+    }
+    #[test]
+    fn test_units() {
+        let units: Vec<_> = numbers().filter(Perplex::is_unit).collect();
+        assert_eq!(units.len(), 4);
+        for unit in units {
+            assert_eq!(unit.squared_distance().abs(), 1);
+            assert!(unit.divides(&Perplex::new(1, 0)), "Units divide one!");
+        }
+        assert!(!Perplex::new(2, 1).is_unit());
+        assert!(!Perplex::new(1, 1).is_unit());
+    }
+    #[test]
+    fn test_divisibility() {
+        let d = Perplex::new(3, 1);
+        for q in numbers() {
+            let z = d * q;
+            assert!(d.divides(&z));
+            assert_eq!(z.exact_div(&d), Some(q));
+        }
+        assert!(!Perplex::new(1, 1).divides(&Perplex::new(2, 0)));
+        assert!(
+            !Perplex::new(2, 0).divides(&Perplex::new(1, 1)),
+            "Not in Z[h]!"
+        );
+        let light_like = Perplex::new(2, 2);
+        let z = light_like * Perplex::new(5, -2);
+        let q = z.exact_div(&light_like).unwrap();
+        assert_eq!(light_like * q, z, "Some quotient!");
+        assert!(Perplex::new(0, 0).divides(&Perplex::new(0, 0)));
+    }
+    #[test]
+    fn test_gcd() {
+        for z in numbers() {
+            for w in numbers() {
+                if let Some(g) = z.gcd(&w) {
+                    assert!(g.divides(&z) && g.divides(&w));
+                    assert!(g.t >= g.x.abs(), "Normalized!");
+                    assert_eq!(w.gcd(&z), Some(g), "Symmetric!");
+                    // every common divisor in range divides the gcd
+                    for d in [Perplex::new(1, 1), Perplex::new(2, 0), Perplex::new(2, 1)] {
+                        if d.divides(&z) && d.divides(&w) {
+                            assert!(d.divides(&g));
+                        }
+                    }
+                }
+            }
+        }
+        assert_eq!(
+            Perplex::new(7, 3).gcd(&Perplex::new(5, -1)),
+            Some(Perplex::new(1, 0))
+        );
+        assert_eq!(
+            Perplex::new(7, 5).gcd(&Perplex::new(13, 7)),
+            Some(Perplex::new(3, 1))
+        );
+        assert_eq!(
+            Perplex::new(6, 0).gcd(&Perplex::new(0, 0)),
+            Some(Perplex::new(6, 0))
+        );
+        assert_eq!(
+            Perplex::new(0, 0).gcd(&Perplex::new(0, 0)),
+            Some(Perplex::new(0, 0))
+        );
+        let (z, w) = (Perplex::new(2, 2), Perplex::new(4, -4));
+        let (two, d) = (Perplex::new(2, 0), Perplex::new(3, -1));
+        assert!(two.divides(&z) && two.divides(&w) && d.divides(&z) && d.divides(&w));
+        assert!(!two.divides(&d) && !d.divides(&two));
+        assert_eq!(z.gcd(&w), None, "No greatest common divisor!");
+        assert_eq!(Perplex::new(0, -8).gcd(&Perplex::new(6, 2)), None);
+    }
+    #[test]
+    fn test_factor() {
+        for z in numbers().filter(|z| !z.is_light_like()) {
+            let factorization = z.factor().unwrap();
+            assert_eq!(factorization.product(), z);
+            assert!(factorization.unit.is_unit());
+            for f in factorization.factors {
+                assert!(!f.is_unit());
+                // no factorization into two non-units within the range
+                for d in numbers().filter(|d| !d.is_unit() && !d.is_light_like()) {
+                    if let Some(q) = f.exact_div(&d) {
+                        assert!(q.is_unit(), "Irreducible!");
+                    }
+                }
+            }
+        }
+        // the other factorization of 10 + 6 h is (3 + h) (3 + h)
+        let factorization = Perplex::new(10, 6).factor().unwrap();
+        assert_eq!(
+            factorization.factors,
+            vec![Perplex::new(2, 0), Perplex::new(5, 3)]
+        );
+        assert_eq!(
+            Perplex::new(-1, 0).factor().unwrap().unit,
+            Perplex::new(-1, 0)
+        );
+        assert!(Perplex::new(3, -3).factor().is_none());
+        let large = Perplex::new(1_000_000_007i64 * 3, 0);
+        assert_eq!(large.factor().unwrap().factors.len(), 4);
+    }
+    #[test]
+    fn test_big_integers() {
+        use num_bigint::BigInt;
+        let big = |t: i64, x: i64| Perplex::new(BigInt::from(t), BigInt::from(x));
+        let scale = num_traits::pow(BigInt::from(2), 80);
+        let z = Perplex::new(scale.clone() * 7, scale.clone() * 5);
+        let w = big(13, 7);
+        assert_eq!(z.gcd(&w), Some(big(3, 1)));
+        let q = z.exact_div(&big(3, 1)).unwrap();
+        assert_eq!(q, Perplex::new(scale.clone() * 2, scale.clone()));
+        assert!(big(0, -1).is_unit() && !big(2, 1).is_unit());
+        let factorization = big(10, 6).factor().unwrap();
+        assert_eq!(factorization.factors, vec![big(2, 0), big(5, 3)]);
+        let factorization = z.factor().unwrap();
+        assert_eq!(factorization.product(), z);
+        assert_eq!(factorization.factors.len(), 82);
+    }
+    #[test]
+    fn test_overflow() {
+        let (max, min) = (Perplex::new(i64::MAX, 1), Perplex::new(i64::MIN, 0));
+        assert!(!max.is_unit());
+        assert!(
+            !Perplex::new(1, 0).divides(&max),
+            "Null coefficient overflows!"
+        );
+        assert_eq!(max.exact_div(&Perplex::new(1, 0)), None);
+        assert_eq!(max.gcd(&Perplex::new(3, 1)), None);
+        assert_eq!(max.factor(), None);
+        // the quotient of the minimum by -1 overflows
+        assert_eq!(min.exact_div(&Perplex::new(-1, 0)), None);
+        assert!(Perplex::new(1, 0).divides(&min));
+        assert_eq!(min.gcd(&Perplex::new(0, 0)), None, "|i64::MIN| overflows!");
+        assert_eq!(min.factor(), None);
+        let g = min.gcd(&Perplex::new(6, 0)).unwrap();
+        assert_eq!(g, Perplex::new(2, 0));
+        // the largest integral null coefficients
+        let z = Perplex::new(i64::MAX, 0);
+        assert_eq!(z.exact_div(&Perplex::new(1, 0)), Some(z));
+        assert_eq!(z.factor().unwrap().product(), z);
+    }
+}
@@ -0,0 +1,294 @@
+//! # Interpolate Module
+//!
+//! This module provides the polynomial interpolation of perplex values at perplex nodes, e.g., to approximate h-holomorphic functions from samples.
+//! In contrast to the complex plane, distinct nodes do not suffice for a unique interpolation: the difference of two nodes may be light-like, i.e., a zero divisor without multiplicative inverse.
+//! The interpolating polynomial of degree `n - 1` through `n` nodes is unique if and only if all node differences are invertible, since the Vandermonde determinant is the product of the node differences.
+//! Hence, all functions of this module return `None` if a node difference is light-like.
+//!
+//! ## Features
+//! - `barycentric_weights`: The weights `w_j = 1 / prod_{k != j} (z_j - z_k)` of the barycentric Lagrange interpolation, see [Barycentric Lagrange Interpolation](https://doi.org/10.1137/S0036144502417715).
+//! - `lagrange`: Evaluation of the interpolating polynomial.
+//! - `CubicSpline`: The natural cubic spline through perplex values at real parameters `(s_i, z_i)`, e.g., for the smooth resampling of trajectories. The perplex values are interpolated componentwise, which is equivalent to the interpolation of the null components, since the spline is linear in the values.
+//!
+//! ## Example
+//! ```
+//! use approx::assert_abs_diff_eq;
+//! use perplex_num::{interpolate, Perplex};
+//! let nodes = [Perplex::new(0.0, 0.0), Perplex::new(1.0, 0.5), Perplex::new(-1.0, 0.25)];
+//! let values: Vec<_> = nodes.iter().map(|z| *z * *z).collect();
+//! let z = Perplex::new(0.3, -0.2);
+//! assert_abs_diff_eq!(interpolate::lagrange(&nodes, &values, z).unwrap(), z * z, epsilon = 1e-12);
+//! // the difference of the nodes 0 and 1 + h is light-like
+//! assert!(interpolate::lagrange(&[nodes[0], Perplex::new(1.0, 1.0)], &values[..2], z).is_none());
+//! ```
+
+use super::Perplex;
+use num_traits::{Float, Num, One};
+use std::ops::Neg;
+
+/// Returns the barycentric weights `w_j = 1 / prod_{k != j} (z_j - z_k)` of `nodes`, or `None` if a difference of two nodes is light-like.
+pub fn barycentric_weights<T: Copy + Num + Neg<Output = T>>(
+    nodes: &[Perplex<T>],
+) -> Option<Vec<Perplex<T>>> {
+    nodes
+        .iter()
+        .enumerate()
+        .map(|(j, &z_j)| {
+            nodes
+                .iter()
+                .enumerate()
+                .filter(|&(k, _)| k != j)
+                .fold(Perplex::one(), |product, (_, &z_k)| product * (z_j - z_k))
+                .try_inverse()
+        })
+        .collect()
+}
+
+/// Evaluates the polynomial of degree `n - 1` which interpolates the `n` pairs of `nodes` and `values` at `z`.
+///
+/// The Lagrange basis polynomials `w_j prod_{k != j} (z - z_k)` are evaluated without division by `z - z_j`, such that `z` may be a node or lie on the light cone of a node.
+/// Returns `None` if `nodes` is empty or a difference of two nodes is light-like.
+///
+/// # Panics
+/// Panics if `nodes` and `values` differ in length.
+pub fn lagrange<T: Copy + Num + Neg<Output = T>>(
+    nodes: &[Perplex<T>],
+    values: &[Perplex<T>],
+    z: Perplex<T>,
+) -> Option<Perplex<T>> {
+    assert_eq!(
+        nodes.len(),
+        values.len(),
+        "Nodes and values differ in length!"
+    );
+    if nodes.is_empty() {
+        return None;
+    }
+    let weights = barycentric_weights(nodes)?;
+    let zero = Perplex::new(T::zero(), T::zero());
+    let result = weights
+        .iter()
+        .zip(values)
+        .enumerate()
+        .fold(zero, |sum, (j, (&w_j, &y_j))| {
+            let basis = nodes
+                .iter()
+                .enumerate()
+                .filter(|&(k, _)| k != j)
+                .fold(w_j, |product, (_, &z_k)| product * (z - z_k));
+            sum + basis * y_j
+        });
+    Some(result)
+}
+
+/// A natural cubic spline through perplex values at strictly increasing real parameters.
+///
+/// The spline is twice continuously differentiable and its second derivative vanishes at both ends of the domain. Outside of the domain, it is extrapolated by the cubic polynomials of the first and last interval.
+#[derive(Clone, Debug, PartialEq)]
+pub struct CubicSpline<T> {
+    params: Vec<T>,
+    values: Vec<Perplex<T>>,
+    second_derivatives: Vec<Perplex<T>>,
+}
+
+impl<T: Copy + Float> CubicSpline<T> {
+    /// Fits the natural cubic spline through the pairs of `params` and `values`.
+    ///
+    /// Returns `None` if fewer than two pairs are given or `params` is not strictly increasing.
+    ///
+    /// # Panics
+    /// Panics if `params` and `values` differ in length.
+    pub fn natural(params: &[T], values: &[Perplex<T>]) -> Option<Self> {
+        assert_eq!(
+            params.len(),
+            values.len(),
+            "Parameters and values differ in length!"
+        );
+        let n = params.len();
+        if n < 2 || !params.windows(2).all(|s| s[0] < s[1]) {
+            return None;
+        }
+        let zero = Perplex::new(T::zero(), T::zero());
+        let (two, six) = (T::one() + T::one(), T::from(6).unwrap());
+        let h: Vec<T> = params.windows(2).map(|s| s[1] - s[0]).collect();
+        let slopes: Vec<Perplex<T>> = values
+            .windows(2)
+            .zip(&h)
+            .map(|(z, &h)| (z[1] - z[0]).scale(h.recip()))
+            .collect();
+        // Thomas algorithm for the tridiagonal system of the inner second derivatives
+        let mut diagonal = vec![T::one(); n];
+        let mut rhs = vec![zero; n];
+        for i in 1..n - 1 {
+            diagonal[i] = two * (h[i - 1] + h[i]);
+            rhs[i] = (slopes[i] - slopes[i - 1]).scale(six);
+            if i > 1 {
+                let factor = h[i - 1] / diagonal[i - 1];
+                diagonal[i] = diagonal[i] - factor * h[i - 1];
+                rhs[i] = rhs[i] - rhs[i - 1].scale(factor);
+            }
+        }
+        let mut second_derivatives = vec![zero; n];
+        for i in (1..n - 1).rev() {
+            let upper = if i + 1 < n - 1 {
+                second_derivatives[i + 1].scale(h[i])
+            } else {
+                zero
+            };
+            second_derivatives[i] = (rhs[i] - upper).scale(diagonal[i].recip());
+        }
+        Some(Self {
+            params: params.to_vec(),
+            values: values.to_vec(),
+            second_derivatives,
+        })
+    }
+    /// Returns the first and the last parameter.
+    #[inline]
+    pub fn domain(&self) -> (T, T) {
+        (self.params[0], self.params[self.params.len() - 1])
+    }
+    /// Returns the index `i` of the interval `[s_i, s_{i+1}]` used for the evaluation at `s`, as well as the interval length and the barycentric coordinates `a` of `s_i` and `b` of `s_{i+1}`.
+    fn locate(&self, s: T) -> (usize, T, T, T) {
+        let last = self.params.len() - 2;
+        let i = self
+            .params
+            .partition_point(|&p| p <= s)
+            .saturating_sub(1)
+            .min(last);
+        let h = self.params[i + 1] - self.params[i];
+        let b = (s - self.params[i]) / h;
+        (i, h, T::one() - b, b)
+    }
+    /// Evaluates the spline at the parameter `s`.
+    pub fn eval(&self, s: T) -> Perplex<T> {
+        let (i, h, a, b) = self.locate(s);
+        let (m0, m1) = (self.second_derivatives[i], self.second_derivatives[i + 1]);
+        let curvature =
+            (m0.scale(a * a * a - a) + m1.scale(b * b * b - b)).scale(h * h / T::from(6).unwrap());
+        self.values[i].scale(a) + self.values[i + 1].scale(b) + curvature
+    }
+    /// Evaluates the derivative of the spline with respect to the parameter at `s`.
+    pub fn derivative(&self, s: T) -> Perplex<T> {
+        let (i, h, a, b) = self.locate(s);
+        let three = T::from(3).unwrap();
+        let (m0, m1) = (self.second_derivatives[i], self.second_derivatives[i + 1]);
+        let slope = (self.values[i + 1] - self.values[i]).scale(h.recip());
+        let curvature = (m0.scale(T::one() - three * a * a) + m1.scale(three * b * b - T::one()))
+            .scale(h / T::from(6).unwrap());
+        slope + curvature
+    }
+    /// Evaluates the spline at `n` equidistant parameters, which include both ends of the domain for `n > 1`.
+    pub fn resample(&self, n: usize) -> Vec<Perplex<T>> {
+        let (start, end) = self.domain();
+        let step = (end - start) / T::from(n.saturating_sub(1).max(1)).unwrap();
+        (0..n)
+            .map(|k| self.eval(start + step * T::from(k).unwrap()))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_abs_diff_eq;
+    #[test]
+    fn test_barycentric_weights() {
+        let nodes = [Perplex::new(0.0, 0.0), Perplex::new(2.0, 1.0)];
+        let weights = barycentric_weights(&nodes).unwrap();
+        assert_eq!(weights[0], (nodes[0] - nodes[1]).try_inverse().unwrap());
+        assert_eq!(weights[1], (nodes[1] - nodes[0]).try_inverse().unwrap());
+        assert!(
+            barycentric_weights(&[Perplex::new(1.0, 0.0), Perplex::new(3.0, -2.0)]).is_none(),
+            "Light-like node difference!"
+        );
+        assert!(barycentric_weights::<f64>(&[]).unwrap().is_empty());
+    }
+    #[test]
+    fn test_lagrange() {
+        let nodes = [
+            Perplex::new(0.0, 0.0),
+            Perplex::new(1.0, 0.5),
+            Perplex::new(-1.0, 0.25),
+            Perplex::new(0.5, 2.0),
+        ];
+        // a cubic polynomial is reproduced exactly
+        let p = |z: Perplex<f64>| z * z * z - z.scale(2.0) + Perplex::new(1.0, -1.0);
+        let values: Vec<_> = nodes.iter().map(|&z| p(z)).collect();
+        for z in [Perplex::new(0.3, -0.2), Perplex::new(2.0, 2.0), nodes[1]] {
+            assert_abs_diff_eq!(lagrange(&nodes, &values, z).unwrap(), p(z), epsilon = 1e-12);
+        }
+        // sample of the h-holomorphic exponential function
+        let nodes: Vec<_> = (0..6)
+            .map(|k| Perplex::new(0.1 * k as f64, 0.01 * (k * k) as f64))
+            .collect();
+        let values: Vec<_> = nodes.iter().map(|z| z.exp()).collect();
+        let z = Perplex::new(0.25, 0.1);
+        assert_abs_diff_eq!(
+            lagrange(&nodes, &values, z).unwrap(),
+            z.exp(),
+            epsilon = 1e-5
+        );
+        assert!(lagrange::<f64>(&[], &[], z).is_none());
+    }
+    #[test]
+    fn test_cubic_spline() {
+        let params = [0.0, 0.5, 1.5, 2.0, 3.0];
+        let values: Vec<_> = params
+            .iter()
+            .map(|&s| Perplex::new(1.0 - 2.0 * s, 0.5 * s + 3.0))
+            .collect();
+        let spline = CubicSpline::natural(&params, &values).unwrap();
+        assert_eq!(spline.domain(), (0.0, 3.0));
+        for s in [-1.0, 0.0, 0.7, 2.0, 2.9, 4.0] {
+            // linear functions are reproduced exactly, also by the extrapolation
+            assert_abs_diff_eq!(
+                spline.eval(s),
+                Perplex::new(1.0 - 2.0 * s, 0.5 * s + 3.0),
+                epsilon = 1e-12
+            );
+            assert_abs_diff_eq!(
+                spline.derivative(s),
+                Perplex::new(-2.0, 0.5),
+                epsilon = 1e-12
+            );
+        }
+        // a hyperbolic trajectory is interpolated with the nodes
+        let params: Vec<f64> = (0..=20).map(|k| 0.1 * k as f64).collect();
+        let values: Vec<_> = params.iter().map(|&s| Perplex::cis(s)).collect();
+        let spline = CubicSpline::natural(&params, &values).unwrap();
+        for (&s, &z) in params.iter().zip(&values) {
+            assert_abs_diff_eq!(spline.eval(s), z, epsilon = 1e-14);
+        }
+        for s in [0.55, 1.03, 1.51] {
+            assert_abs_diff_eq!(spline.eval(s), Perplex::cis(s), epsilon = 1e-5);
+            assert_abs_diff_eq!(
+                spline.derivative(s),
+                Perplex::cis(s) * Perplex::h(),
+                epsilon = 1e-3
+            );
+        }
+        let resampled = spline.resample(5);
+        assert_eq!(resampled.len(), 5);
+        assert_abs_diff_eq!(resampled[4], Perplex::cis(2.0), epsilon = 1e-14);
+        assert_eq!(spline.resample(1), vec![Perplex::new(1.0, 0.0)]);
+    }
+    #[test]
+    fn test_cubic_spline_degenerate() {
+        let z = Perplex::new(1.0, 2.0);
+        assert!(CubicSpline::natural(&[0.0], &[z]).is_none());
+        assert!(
+            CubicSpline::natural(&[0.0, 0.0], &[z, z]).is_none(),
+            "Not strictly increasing!"
+        );
+        assert!(CubicSpline::natural(&[0.0, f64::NAN], &[z, z]).is_none());
+        let line = CubicSpline::natural(&[1.0, 2.0], &[z, -z]).unwrap();
+        assert_eq!(line.eval(1.5), Perplex::new(0.0, 0.0));
+    }
+    #[test]
+    #[should_panic(expected = "Nodes and values differ in length!")]
+    fn test_lagrange_panics() {
+        let nodes = [Perplex::new(0.0, 0.0), Perplex::new(2.0, 1.0)];
+        let _ = lagrange(&nodes, &nodes[..1], Perplex::new(1.0, 0.0));
+    }
+}
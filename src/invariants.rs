@@ -0,0 +1,122 @@
+//! # Invariants Module
+//!
+//! This module exposes the numerical invariants of the test suite as public checkers (optional feature `testing`), such that downstream continuous integration can verify the behavior of the crate on each platform without copying its tests.
+//! Each checker evaluates both sides of an identity for given inputs and returns an `InvariantReport` with the expected and actual value and the relative error in the maximum norm.
+//!
+//! ## Features
+//! - `check_exp_ln_roundtrip`: The identity `ln(exp(z)) = z` of the exponential function and the logarithm extended to all sectors.
+//! - `check_polar_roundtrip`: The conversion of `z` into its hyperbolic polar form and back.
+//! - `check_mul_matrix_agreement`: The agreement of the product `z w` with the product of the matrix forms (additionally requires the feature `matrix`).
+//!
+//! ## Example
+//! ```
+//! use perplex_num::invariants::{check_exp_ln_roundtrip, check_polar_roundtrip};
+//! use perplex_num::Perplex;
+//! let z = Perplex::new(-2.0, 0.5);
+//! let report = check_exp_ln_roundtrip(z, 1e-12);
+//! assert!(report.passed(), "{report:?}");
+//! assert!(check_polar_roundtrip(z, 1e-12).passed());
+//! assert!(!check_polar_roundtrip(Perplex::new(f64::NAN, 1.0), 1e-12).passed());
+//! ```
+
+use super::Perplex;
+use num_traits::Float;
+
+/// The result of a check of an invariant.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct InvariantReport<T> {
+    /// The name of the checked invariant, e.g., `"exp_ln_roundtrip"`.
+    pub invariant: &'static str,
+    /// The value of the left-hand side of the identity, i.e., the input of a roundtrip.
+    pub expected: Perplex<T>,
+    /// The value of the right-hand side of the identity, or `None` if it is undefined for the input.
+    pub actual: Option<Perplex<T>>,
+    /// The error `||actual - expected||_∞ / max(1, ||expected||_∞)`, which is infinite if `actual` is `None`.
+    pub error: T,
+    /// The tolerance of the relative error.
+    pub eps: T,
+}
+
+impl<T: Copy + Float> InvariantReport<T> {
+    /// Creates the report of `invariant` and computes the relative error.
+    fn new(
+        invariant: &'static str,
+        expected: Perplex<T>,
+        actual: Option<Perplex<T>>,
+        eps: T,
+    ) -> Self {
+        let error = match actual {
+            Some(actual) => (actual - expected).max_norm() / expected.max_norm().max(T::one()),
+            None => T::infinity(),
+        };
+        Self {
+            invariant,
+            expected,
+            actual,
+            error,
+            eps,
+        }
+    }
+    /// Checks if the relative error is within the tolerance, whereby a NaN error fails.
+    #[inline]
+    pub fn passed(&self) -> bool {
+        self.error <= self.eps
+    }
+}
+
+/// Checks the identity `ln(exp(z)) = z`, which holds for all numbers since the exponential function maps each sector into itself.
+///
+/// The converse `exp(ln(z)) = z` only holds on the image of the exponential function. The report fails with a NaN error if the exponential function overflows.
+#[inline]
+pub fn check_exp_ln_roundtrip<T: Copy + Float>(z: Perplex<T>, eps: T) -> InvariantReport<T> {
+    InvariantReport::new("exp_ln_roundtrip", z, z.exp().ln(), eps)
+}
+
+/// Checks that the conversion of `z` into the hyperbolic polar form and back yields `z`.
+#[inline]
+pub fn check_polar_roundtrip<T: Copy + Float>(z: Perplex<T>, eps: T) -> InvariantReport<T> {
+    InvariantReport::new("polar_roundtrip", z, Some(Perplex::from(z.polar())), eps)
+}
+
+/// Checks that the product `z w` agrees with the product of the matrix forms of `z` and `w`.
+#[cfg(feature = "matrix")]
+#[inline]
+pub fn check_mul_matrix_agreement<T: Copy + Float + nalgebra::RealField>(
+    z: Perplex<T>,
+    w: Perplex<T>,
+    eps: T,
+) -> InvariantReport<T> {
+    let product = Perplex::from(z.as_matrix_form() * w.as_matrix_form());
+    InvariantReport::new("mul_matrix_agreement", z * w, Some(product), eps)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[test]
+    fn test_roundtrips() {
+        for z in [
+            Perplex::new(2.0, 1.0),
+            Perplex::new(-3.0, 0.5),
+            Perplex::new(0.25, 4.0),
+            Perplex::new(1e-3, -2e-3),
+        ] {
+            assert!(check_exp_ln_roundtrip(z, 1e-12).passed(), "ln(exp(z)) = z!");
+            assert!(check_polar_roundtrip(z, 1e-12).passed(), "Polar roundtrip!");
+        }
+        assert!(check_exp_ln_roundtrip(Perplex::new(-1.0, 1.0), 1e-12).passed());
+        let report = check_exp_ln_roundtrip(Perplex::new(1e3, 1e3), 1e-12);
+        assert!(!report.passed(), "Overflow of the exponential function!");
+        assert!(check_polar_roundtrip(Perplex::new(-1.0, 1.0), 0.0).passed());
+        let report = check_polar_roundtrip(Perplex::new(f64::NAN, 1.0), 1e-12);
+        assert!(!report.passed(), "NaN errors fail!");
+    }
+    #[test]
+    #[cfg(feature = "matrix")]
+    fn test_mul_matrix_agreement() {
+        let report =
+            check_mul_matrix_agreement(Perplex::new(1.5, -2.0), Perplex::new(0.5, 3.0), 0.0);
+        assert_eq!(report.invariant, "mul_matrix_agreement");
+        assert!(report.passed(), "{report:?}");
+    }
+}
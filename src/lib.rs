@@ -1,14 +1,119 @@
 #![doc = include_str!("../README.md")]
+#![cfg_attr(not(feature = "std"), no_std)]
+// allows the expansion of `lift_to_perplex` within this crate
+extern crate self as perplex_num;
 
+#[cfg(not(any(feature = "std", feature = "libm")))]
+compile_error!(
+    "perplex_num requires the feature `std` or, for `no_std` targets, the feature `libm`"
+);
+
+pub mod affine;
+#[cfg(feature = "std")]
+pub mod animate;
 mod binary_ops;
+#[cfg(feature = "std")]
+pub mod blaschke;
+pub mod boost;
+#[cfg(feature = "std")]
+pub mod calculus;
+#[cfg(feature = "testing")]
+pub mod conformance;
+pub mod consts;
+pub mod cordic;
+#[cfg(feature = "nan-debug")]
+pub mod debug;
+pub mod dual;
+#[cfg(feature = "std")]
+pub mod dynamics;
+mod error;
+#[cfg(feature = "std")]
+pub mod fit;
+pub mod fixed;
+#[cfg(feature = "std")]
+pub mod format;
+pub mod halton;
+#[cfg(feature = "quantized-hash")]
+mod hash;
+#[cfg(feature = "html-plot")]
+pub mod html_plot;
+pub mod integer;
+#[cfg(feature = "std")]
+pub mod interpolate;
+#[cfg(feature = "testing")]
+pub mod invariants;
+#[cfg(feature = "std")]
+pub mod lut;
+#[cfg(feature = "std")]
+#[doc(hidden)]
+pub mod macros;
 #[cfg(feature = "matrix")]
 mod matrix;
+#[cfg(all(feature = "quantized-hash", feature = "std"))]
+pub mod memo;
+#[cfg(feature = "std")]
+pub mod mesh;
+mod mobius;
+pub mod null_basis;
 mod perplex;
+#[cfg(feature = "std")]
+mod perplex_box;
+pub mod perplex_float;
 mod polar;
+#[cfg(feature = "std")]
+mod polynomial;
+pub mod quantized;
+#[cfg(feature = "rand")]
+pub mod random;
+pub mod rindler;
+#[cfg(feature = "std")]
+pub mod sampling;
+#[cfg(feature = "std")]
+pub mod sanitize;
+#[cfg(feature = "std")]
+pub mod sector;
+#[cfg(feature = "std")]
+pub mod signal;
 mod single_ops;
+#[cfg(feature = "std")]
+pub mod snapshot;
+#[cfg(feature = "std")]
+pub mod soa;
+#[cfg(feature = "std")]
+pub mod spatial;
+#[cfg(feature = "std")]
+pub mod stats;
+#[cfg(feature = "std")]
+pub mod stream;
+pub mod symbolic;
+#[cfg(feature = "bytemuck")]
+pub mod texture;
+#[cfg(feature = "std")]
+pub mod transform;
+pub mod unit_hyperbola;
+#[cfg(feature = "uom")]
+pub mod units;
+#[cfg(feature = "std")]
+pub mod worldline;
 
-pub use perplex::Perplex;
-pub use polar::{HyperbolicPolar, HyperbolicSector};
+pub use boost::UnitPerplex;
+pub use error::PerplexError;
+#[cfg(feature = "std")]
+pub use format::PerplexFormatter;
+pub use mobius::Mobius;
+pub use perplex::{ParsePerplexError, Perplex};
+#[cfg(feature = "std")]
+pub use perplex_box::{Monotonicity, PerplexBox};
+pub use polar::{EpsilonContext, HyperbolicPolar, HyperbolicSector, KleinIndex, PolarError};
+#[cfg(feature = "std")]
+pub use polynomial::PerplexPolynomial;
+#[cfg(feature = "std")]
+pub use soa::PerplexBuffer;
+#[cfg(feature = "std")]
+pub use spatial::{NeighborIndex, PlaneMetric};
 
 #[cfg(feature = "matrix")]
-pub use matrix::PerplexMatrixForm;
+pub use matrix::{expm_perplex, PerplexMatrixForm};
+
+#[cfg(feature = "derive")]
+pub use perplex_num_derive::lift_to_perplex;
@@ -0,0 +1,207 @@
+//! # Lookup Table Module
+//!
+//! This module provides the `PerplexLUT` type, which tabulates an expensive perplex function on a regular grid over a `PerplexBox` and answers subsequent evaluations by bilinear interpolation, trading memory for speed in real-time applications.
+//! Functions like `ln` or `sqrt` are only smooth within a sector, hence the region is typically bounded by a sector, e.g., by `PerplexBox::intersect_sector`, since the interpolation across the light cone does not converge.
+//!
+//! ## Features
+//! - `PerplexLUT::new`: The table of a given resolution with an estimate of its interpolation error.
+//! - `PerplexLUT::with_tolerance`: The table whose resolution is doubled until the estimated error is within a configurable bound.
+//! - `eval`: The bilinear interpolation within the region.
+//!
+//! ## Example
+//! ```
+//! use perplex_num::lut::PerplexLUT;
+//! use perplex_num::{HyperbolicSector, Perplex, PerplexBox};
+//! let region = PerplexBox::new(Perplex::new(1.0, -1.0), Perplex::new(2.0, 1.0)).unwrap();
+//! let region = region.intersect_sector(HyperbolicSector::Right).unwrap();
+//! let lut = PerplexLUT::with_tolerance(|z| Some(z.exp()), region, 1e-3, 1024).unwrap();
+//! assert!(lut.error_bound() <= 1e-3);
+//! let z = Perplex::new(1.5, 0.3);
+//! let error = (lut.eval(z).unwrap() - z.exp()).max_norm();
+//! assert!(error <= 1e-3);
+//! assert!(lut.eval(Perplex::new(0.0, 0.0)).is_none(), "Outside of the region!");
+//! ```
+
+use super::{Perplex, PerplexBox};
+use num_traits::Float;
+
+/// A table of perplex values on a regular grid over a box, which interpolates bilinearly between the grid points.
+#[derive(Clone, Debug, PartialEq)]
+pub struct PerplexLUT<T> {
+    region: PerplexBox<T>,
+    resolution: [usize; 2],
+    values: Vec<Perplex<T>>,
+    error_bound: T,
+}
+
+impl<T: Copy + Float> PerplexLUT<T> {
+    /// Tabulates `f` on a grid of `resolution[0]` points along the time axis and `resolution[1]` points along the space axis of `region`, including its boundary.
+    ///
+    /// The error bound is estimated by the maximum deviation `||f(c) - eval(c)||_∞` at the centers `c` of all grid cells, i.e., the points farthest from the grid points.
+    /// Returns `None` if a resolution is below two, the region has no interior, or `f` returns `None` at a grid point or a cell center.
+    pub fn new<F>(f: F, region: PerplexBox<T>, resolution: [usize; 2]) -> Option<Self>
+    where
+        F: Fn(Perplex<T>) -> Option<Perplex<T>>,
+    {
+        let [columns, rows] = resolution;
+        let (min, max) = (region.min, region.max);
+        if columns < 2 || rows < 2 || !(min.t < max.t && min.x < max.x) {
+            return None;
+        }
+        let mut values = Vec::with_capacity(columns * rows);
+        for row in 0..rows {
+            for column in 0..columns {
+                values.push(f(grid_point(&region, resolution, column, row))?);
+            }
+        }
+        let mut lut = Self {
+            region,
+            resolution,
+            values,
+            error_bound: T::zero(),
+        };
+        let half = T::from(0.5).unwrap();
+        let mut error_bound = T::zero();
+        for row in 0..rows - 1 {
+            for column in 0..columns - 1 {
+                let (a, b) = (
+                    grid_point(&region, resolution, column, row),
+                    grid_point(&region, resolution, column + 1, row + 1),
+                );
+                let center = (a + b).scale(half);
+                let error = (f(center)? - lut.interpolate(center)).max_norm();
+                // NaN errors are propagated to the bound
+                if error > error_bound || error.is_nan() {
+                    error_bound = error;
+                }
+            }
+        }
+        lut.error_bound = error_bound;
+        Some(lut)
+    }
+    /// Tabulates `f` over `region` with a resolution that is doubled, starting with two times two grid points, until the estimated error bound is at most `tolerance`.
+    ///
+    /// Returns `None` if the tolerance is not met without exceeding `max_resolution` points along an axis, or if `new` fails.
+    pub fn with_tolerance<F>(
+        f: F,
+        region: PerplexBox<T>,
+        tolerance: T,
+        max_resolution: usize,
+    ) -> Option<Self>
+    where
+        F: Fn(Perplex<T>) -> Option<Perplex<T>>,
+    {
+        let mut n = 2;
+        while n <= max_resolution {
+            let lut = Self::new(&f, region, [n, n])?;
+            if lut.error_bound <= tolerance {
+                return Some(lut);
+            }
+            n = 2 * n - 1;
+        }
+        None
+    }
+    /// Returns the tabulated region.
+    #[inline]
+    pub fn region(&self) -> PerplexBox<T> {
+        self.region
+    }
+    /// Returns the number of grid points along the time and space axis.
+    #[inline]
+    pub fn resolution(&self) -> [usize; 2] {
+        self.resolution
+    }
+    /// Returns the estimated maximum error of the interpolation in the maximum norm.
+    #[inline]
+    pub fn error_bound(&self) -> T {
+        self.error_bound
+    }
+    /// Returns the bilinear interpolation of the tabulated values at `z`, or `None` if `z` lies outside of the region.
+    #[inline]
+    pub fn eval(&self, z: Perplex<T>) -> Option<Perplex<T>> {
+        if self.region.contains(z) {
+            Some(self.interpolate(z))
+        } else {
+            None
+        }
+    }
+    /// Interpolates bilinearly at `z` within the region.
+    fn interpolate(&self, z: Perplex<T>) -> Perplex<T> {
+        let [columns, rows] = self.resolution;
+        let (min, max) = (self.region.min, self.region.max);
+        let locate = |v: T, min: T, max: T, n: usize| {
+            let s = (v - min) / (max - min) * T::from(n - 1).unwrap();
+            // the last cell contains the upper boundary
+            let i = s.floor().to_usize().unwrap_or(0).min(n - 2);
+            (i, s - T::from(i).unwrap())
+        };
+        let (i, a) = locate(z.t, min.t, max.t, columns);
+        let (j, b) = locate(z.x, min.x, max.x, rows);
+        let value = |column: usize, row: usize| self.values[row * columns + column];
+        let one = T::one();
+        let lower = value(i, j).scale(one - a) + value(i + 1, j).scale(a);
+        let upper = value(i, j + 1).scale(one - a) + value(i + 1, j + 1).scale(a);
+        lower.scale(one - b) + upper.scale(b)
+    }
+}
+
+/// Returns the grid point in the column `column` and the row `row`.
+fn grid_point<T: Copy + Float>(
+    region: &PerplexBox<T>,
+    resolution: [usize; 2],
+    column: usize,
+    row: usize,
+) -> Perplex<T> {
+    let (min, max) = (region.min, region.max);
+    let coordinate = |min: T, max: T, i: usize, n: usize| {
+        min + (max - min) * T::from(i).unwrap() / T::from(n - 1).unwrap()
+    };
+    Perplex::new(
+        coordinate(min.t, max.t, column, resolution[0]),
+        coordinate(min.x, max.x, row, resolution[1]),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_abs_diff_eq;
+    #[test]
+    fn test_bilinear() {
+        let region = PerplexBox::new(Perplex::new(-1.0, -1.0), Perplex::new(1.0, 2.0)).unwrap();
+        // bilinear functions are reproduced exactly
+        let f = |z: Perplex<f64>| Some(Perplex::new(1.0 + 2.0 * z.t - z.x, z.t * z.x));
+        let lut = PerplexLUT::new(f, region, [3, 4]).unwrap();
+        assert_eq!(lut.resolution(), [3, 4]);
+        assert_abs_diff_eq!(lut.error_bound(), 0.0, epsilon = 1e-15);
+        for z in [
+            Perplex::new(0.3, 1.7),
+            Perplex::new(-1.0, -1.0),
+            Perplex::new(1.0, 2.0),
+        ] {
+            assert_abs_diff_eq!(lut.eval(z).unwrap(), f(z).unwrap(), epsilon = 1e-14);
+        }
+        assert!(lut.eval(Perplex::new(1.5, 0.0)).is_none());
+        assert!(PerplexLUT::new(f, region, [1, 4]).is_none());
+        let flat = PerplexBox::from_point(Perplex::new(1.0, 0.0));
+        assert!(PerplexLUT::new(f, flat, [2, 2]).is_none());
+    }
+    #[test]
+    fn test_tolerance() {
+        let region = PerplexBox::new(Perplex::new(1.0, -0.5), Perplex::new(2.0, 0.5)).unwrap();
+        let lut = PerplexLUT::with_tolerance(|z: Perplex<f64>| z.ln(), region, 1e-5, 1024).unwrap();
+        assert!(lut.error_bound() <= 1e-5);
+        let coarse = PerplexLUT::new(|z: Perplex<f64>| z.ln(), region, [5, 5]).unwrap();
+        assert!(coarse.error_bound() > lut.error_bound());
+        for z in [Perplex::new(1.1, 0.4), Perplex::new(1.9, -0.45)] {
+            let error = (lut.eval(z).unwrap() - z.ln().unwrap()).max_norm();
+            assert!(error <= 2e-5, "The bound estimates the error!");
+        }
+        assert!(PerplexLUT::with_tolerance(|z: Perplex<f64>| z.ln(), region, 1e-12, 64).is_none());
+        let crossing = PerplexBox::new(Perplex::new(0.0, -1.0), Perplex::new(1.0, 1.0)).unwrap();
+        assert!(
+            PerplexLUT::new(|z: Perplex<f64>| z.ln(), crossing, [4, 4]).is_none(),
+            "Logarithm is undefined at the light cone!"
+        );
+    }
+}
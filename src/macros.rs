@@ -0,0 +1,189 @@
+//! # Macros Module
+//!
+//! This module provides assertion macros for tests involving perplex numbers. In contrast to the bare assertions of the `approx` crate, a failure prints both the Cartesian and the hyperbolic polar form of the operands, as well as the deltas of their components.
+//!
+//! - `assert_perplex_abs_eq!`: Asserts that the components are equal up to an absolute difference `epsilon`.
+//! - `assert_perplex_rel_eq!`: Asserts that the components are equal up to an absolute difference `epsilon` or a relative difference `max_relative`.
+//! - `assert_polar_eq!`: Asserts that the hyperbolic polar forms are in the same sector and that `rho` and `theta` are equal up to an absolute difference `epsilon`.
+//!
+//! ## Example
+//! ```
+//! use num_traits::Pow;
+//! use perplex_num::{assert_perplex_abs_eq, assert_perplex_rel_eq, assert_polar_eq, Perplex};
+//! let z = Perplex::new(2.0, 1.0);
+//! assert_perplex_abs_eq!(z.ln().unwrap().exp(), z, epsilon = 1e-12);
+//! assert_perplex_rel_eq!(z * 1e10, Perplex::new(2e10, 1e10 + 1e-3), max_relative = 1e-12);
+//! assert_polar_eq!(z.polar().pow(2), z * z, epsilon = 1e-12);
+//! ```
+
+use super::{HyperbolicPolar, Perplex};
+use approx::{AbsDiffEq, RelativeEq};
+use num_traits::Float;
+use std::fmt::{Debug, LowerExp};
+
+/// Describes both operands in Cartesian and polar form as well as their component deltas.
+fn describe<T>(left: Perplex<T>, right: Perplex<T>) -> String
+where
+    T: Copy + Float + LowerExp + Debug,
+{
+    let (left_polar, right_polar) = (left.polar(), right.polar());
+    format!(
+        "  left:  {left:?} = {left_polar:?}\n  right: {right:?} = {right_polar:?}\n  delta: Δt = {:e}, Δx = {:e}, Δrho = {:e}, Δtheta = {:e}",
+        left.t - right.t,
+        left.x - right.x,
+        left_polar.rho - right_polar.rho,
+        left_polar.theta - right_polar.theta,
+    )
+}
+
+/// Returns a failure report if the components of `left` and `right` differ by more than `epsilon` (or the default epsilon of `T`).
+#[doc(hidden)]
+pub fn abs_eq_report<T>(left: Perplex<T>, right: Perplex<T>, epsilon: Option<T>) -> Option<String>
+where
+    T: Copy + Float + LowerExp + Debug + AbsDiffEq<Epsilon = T>,
+{
+    let epsilon = epsilon.unwrap_or_else(T::default_epsilon);
+    if left.abs_diff_eq(&right, epsilon) {
+        None
+    } else {
+        Some(format!(
+            "assertion failed: `left ≈ right` (epsilon = {epsilon:e})\n{}",
+            describe(left, right)
+        ))
+    }
+}
+
+/// Returns a failure report if the components of `left` and `right` are neither equal up to `epsilon` nor up to the relative difference `max_relative` (or the defaults of `T`).
+#[doc(hidden)]
+pub fn rel_eq_report<T>(
+    left: Perplex<T>,
+    right: Perplex<T>,
+    epsilon: Option<T>,
+    max_relative: Option<T>,
+) -> Option<String>
+where
+    T: Copy + Float + LowerExp + Debug + RelativeEq<Epsilon = T>,
+{
+    let epsilon = epsilon.unwrap_or_else(T::default_epsilon);
+    let max_relative = max_relative.unwrap_or_else(T::default_max_relative);
+    if left.t.relative_eq(&right.t, epsilon, max_relative)
+        && left.x.relative_eq(&right.x, epsilon, max_relative)
+    {
+        None
+    } else {
+        Some(format!(
+            "assertion failed: `left ≈ right` (epsilon = {epsilon:e}, max_relative = {max_relative:e})\n{}",
+            describe(left, right)
+        ))
+    }
+}
+
+/// Returns a failure report if the polar forms of `left` and `right` lie in different sectors or their modulus or argument differ by more than `epsilon` (or the default epsilon of `T`).
+#[doc(hidden)]
+pub fn polar_eq_report<T>(
+    left: impl Into<HyperbolicPolar<T>>,
+    right: impl Into<HyperbolicPolar<T>>,
+    epsilon: Option<T>,
+) -> Option<String>
+where
+    T: Copy + Float + LowerExp + Debug + AbsDiffEq<Epsilon = T>,
+{
+    let epsilon = epsilon.unwrap_or_else(T::default_epsilon);
+    let (left, right): (HyperbolicPolar<T>, HyperbolicPolar<T>) = (left.into(), right.into());
+    let same_sector = std::mem::discriminant(&left.sector) == std::mem::discriminant(&right.sector);
+    // infinite arguments of light-like numbers are compared for equality
+    let theta_eq = left.theta == right.theta || left.theta.abs_diff_eq(&right.theta, epsilon);
+    if same_sector && theta_eq && left.rho.abs_diff_eq(&right.rho, epsilon) {
+        None
+    } else {
+        Some(format!(
+            "assertion failed: `left ≈ right` in polar form (epsilon = {epsilon:e})\n{}",
+            describe(left.into(), right.into())
+        ))
+    }
+}
+
+/// Asserts that two perplex numbers are approximately equal by their absolute component difference.
+///
+/// Accepts an optional `epsilon = e` argument, which defaults to the default epsilon of the component type.
+/// On failure, the Cartesian and polar forms of both operands as well as the component deltas are printed.
+#[macro_export]
+macro_rules! assert_perplex_abs_eq {
+    ($left:expr, $right:expr $(, epsilon = $epsilon:expr)? $(,)?) => {
+        if let Some(report) = $crate::macros::abs_eq_report($left, $right, None$(.or(Some($epsilon)))?) {
+            panic!("{}", report);
+        }
+    };
+}
+
+/// Asserts that two perplex numbers are approximately equal by their absolute or relative component difference.
+///
+/// Accepts the optional arguments `epsilon = e` and `max_relative = r`, which default to the defaults of the component type.
+/// On failure, the Cartesian and polar forms of both operands as well as the component deltas are printed.
+#[macro_export]
+macro_rules! assert_perplex_rel_eq {
+    ($left:expr, $right:expr $(, epsilon = $epsilon:expr)? $(, max_relative = $max_relative:expr)? $(,)?) => {
+        if let Some(report) = $crate::macros::rel_eq_report(
+            $left,
+            $right,
+            None$(.or(Some($epsilon)))?,
+            None$(.or(Some($max_relative)))?,
+        ) {
+            panic!("{}", report);
+        }
+    };
+}
+
+/// Asserts that two perplex numbers (or their hyperbolic polar forms) are approximately equal in polar form, i.e., they lie in the same sector and their modulus and argument are close.
+///
+/// Accepts an optional `epsilon = e` argument, which defaults to the default epsilon of the component type.
+/// On failure, the Cartesian and polar forms of both operands as well as the component deltas are printed.
+#[macro_export]
+macro_rules! assert_polar_eq {
+    ($left:expr, $right:expr $(, epsilon = $epsilon:expr)? $(,)?) => {
+        if let Some(report) = $crate::macros::polar_eq_report($left, $right, None$(.or(Some($epsilon)))?) {
+            panic!("{}", report);
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[test]
+    fn test_abs_eq() {
+        let z = Perplex::new(2.0, 1.0);
+        assert_perplex_abs_eq!(z, z);
+        assert_perplex_abs_eq!(z, z + 1e-7, epsilon = 1e-6);
+        let report = abs_eq_report(z, Perplex::new(2.0, 1.5), None).unwrap();
+        assert!(report.contains("Δt = 0e0, Δx = -5e-1"), "{report}");
+        assert!(report.contains("sector: Right"), "{report}");
+    }
+    #[test]
+    #[should_panic(expected = "Δx = 1e-3")]
+    fn test_abs_eq_panics() {
+        assert_perplex_abs_eq!(
+            Perplex::new(1.0f32, 0.001),
+            Perplex::new(1.0, 0.0),
+            epsilon = 1e-4
+        );
+    }
+    #[test]
+    fn test_rel_eq() {
+        let z = Perplex::new(2e10, 1e10);
+        assert_perplex_rel_eq!(z, z + 1e-3, max_relative = 1e-12);
+        assert_perplex_rel_eq!(z, z + 1.0, epsilon = 2.0, max_relative = 1e-15);
+        assert!(rel_eq_report(z, z + 1.0, None, Some(1e-15)).is_some());
+    }
+    #[test]
+    fn test_polar_eq() {
+        let z = Perplex::new(1.0, 2.0);
+        assert_polar_eq!(z, z.polar());
+        assert_polar_eq!(Perplex::new(1.0, 1.0), Perplex::new(2.0, 2.0));
+        assert!(
+            polar_eq_report(z, -z, None).is_some(),
+            "Up and down sector are different!"
+        );
+        assert!(polar_eq_report(Perplex::new(1.0, 1.0), Perplex::new(1.0, -1.0), None).is_some());
+    }
+}
@@ -7,6 +7,18 @@
 //! The matrix representation of a perplex number is symmetric, with the real part on the diagonal and the hyperbolic part on the off-diagonal. This symmetry reflects the properties of perplex numbers regarding the hyperbolic unit.
 //! Addition, multiplication, as well as inversion of perplex numbers correspond to the matrix operations.
 //!
+//! ## Norm Integration
+//! The `Normed` trait of `nalgebra` is implemented with the L2 norm `sqrt(t^2 + x^2)` of the cartesian coordinate plane, such that `nalgebra::Unit<Perplex<T>>` and norm-generic `nalgebra` code work with perplex numbers.
+//! Note that this Euclidean norm differs from the Minkowski modulus `sqrt(|t^2 - x^2|)` returned by the inherent method `Perplex::norm`, which vanishes for light-like numbers and is therefore unsuitable for normalization.
+//!
+//! ## Polynomial Companion Matrices
+//! The companion matrix of a `PerplexPolynomial` is available over the `Perplex` scalar as well as in its real form with 2x2 blocks of the matrix forms of its entries.
+//! The roots are estimated by the real eigenvalues of the companion matrices of the null components, which serves as a cross-check for the analytic root finder `PerplexPolynomial::roots` and applies to any degree.
+//!
+//! ## Matrix Exponential
+//! The function `expm_perplex` computes the exponential of a 2x2 matrix with perplex entries, which solves the linear system `y' = M y` of two coupled perplex variables by `y(s) = exp(s M) y(0)`.
+//! The matrix is split by the idempotents `(1 ± h) / 2` into the real matrices of the null components, whose exponentials are given in closed form by their eigenvalues.
+//!
 //! ## Example
 //! ```rust
 //! use perplex_num::Perplex;
@@ -19,7 +31,9 @@
 //! ```
 
 use super::perplex::Perplex;
-use nalgebra::{Matrix2, RealField};
+use super::polynomial::{combine_null_roots, PerplexPolynomial};
+use nalgebra::{DMatrix, Matrix2, Normed, RealField};
+use num_traits::Float;
 
 /// A type alias for a 2x2 matrix from `nalgebra`, representing a perplex number as a matrix.
 pub type PerplexMatrixForm<T> = Matrix2<T>;
@@ -48,9 +62,157 @@ impl<T: Copy + RealField> Perplex<T> {
     }
 }
 
+impl<T: Copy + RealField> Normed for Perplex<T> {
+    /// The L2 norm is a real number.
+    type Norm = T;
+    /// Returns the L2 norm `sqrt(t^2 + x^2)` (Euclidean distance from the origin), not the Minkowski modulus.
+    #[inline]
+    fn norm(&self) -> T {
+        self.t.hypot(self.x)
+    }
+    /// Returns the squared L2 norm `t^2 + x^2`.
+    #[inline]
+    fn norm_squared(&self) -> T {
+        self.t * self.t + self.x * self.x
+    }
+    /// Multiplies both components by `n`.
+    #[inline]
+    fn scale_mut(&mut self, n: T) {
+        self.t *= n;
+        self.x *= n;
+    }
+    /// Divides both components by `n`.
+    #[inline]
+    fn unscale_mut(&mut self, n: T) {
+        self.t /= n;
+        self.x /= n;
+    }
+}
+
+impl<T: Copy + RealField + Float> PerplexPolynomial<T> {
+    /// Returns the companion matrix over the `Perplex` scalar, whose subdiagonal entries are one and whose last column holds the coefficients `-c_k / c_n` of the normalized polynomial.
+    ///
+    /// Returns `None` for a degree below one or if the leading coefficient `c_n` is light-like, i.e., not invertible.
+    pub fn companion_matrix(&self) -> Option<DMatrix<Perplex<T>>> {
+        let n = self.degree().filter(|&n| n > 0)?;
+        let coefficients = self.coefficients();
+        let leading_inverse = coefficients[n].try_inverse()?;
+        let zero = Perplex::new(T::zero(), T::zero());
+        Some(DMatrix::from_fn(n, n, |i, j| {
+            if j == n - 1 {
+                -(coefficients[i] * leading_inverse)
+            } else if i == j + 1 {
+                Perplex::new(T::one(), T::zero())
+            } else {
+                zero
+            }
+        }))
+    }
+    /// Returns the real `2n x 2n` form of the `companion_matrix`, whose 2x2 blocks are the matrix forms of its entries.
+    pub fn companion_blocks(&self) -> Option<DMatrix<T>> {
+        let companion = self.companion_matrix()?;
+        let n = companion.nrows();
+        Some(DMatrix::from_fn(2 * n, 2 * n, |i, j| {
+            let z = companion[(i / 2, j / 2)];
+            if i % 2 == j % 2 {
+                z.t
+            } else {
+                z.x
+            }
+        }))
+    }
+    /// Estimates all roots by the real eigenvalues of the companion matrices of the real null component polynomials `p_u` and `p_v`, for any degree.
+    ///
+    /// Repeated or clustered roots are subject to the accuracy of the eigenvalue decomposition. Returns `None` if a null component is the zero polynomial.
+    pub fn eigen_roots(&self) -> Option<Vec<Perplex<T>>> {
+        let (u, v) = self.null_components();
+        Some(combine_null_roots(
+            &real_eigen_roots(&u)?,
+            &real_eigen_roots(&v)?,
+        ))
+    }
+}
+
+/// Returns the matrix exponential `exp(m)` of a 2x2 matrix with perplex entries.
+///
+/// In the null basis, `m = e_+ U + e_- V` with the idempotents `e_± = (1 ± h) / 2` and the real matrices `U` and `V` of the null components `t + x` and `t - x` of the entries. Since the idempotents annihilate each other, `exp(m) = e_+ exp(U) + e_- exp(V)`.
+/// A real 2x2 matrix `A = s I + N` with `s = tr(A) / 2` and the traceless part `N` satisfies `N^2 = q I` with `q = s^2 - det(A)`, hence `exp(A) = e^s (c(q) I + d(q) N)` with `c = cosh(sqrt(q))` and `d = sinh(sqrt(q)) / sqrt(q)` for distinct real eigenvalues `s ± sqrt(q)`, and with `cos` and `sin` of `sqrt(-q)` for complex eigenvalues.
+/// For (nearly) degenerate eigenvalues, i.e., `|q|` below the square root of the machine epsilon, the power series of `c` and `d` are used instead of dividing by `sqrt(|q|)`.
+/// The result is the sum of the power series `sum_k m^k / k!`. For a diagonal matrix, its entries are thus the algebraic exponentials of the diagonal entries, which coincide with `Perplex::exp` in the right sector only, since the latter is extended to all sectors.
+///
+/// # Examples
+///
+/// ```
+/// use approx::assert_abs_diff_eq;
+/// use nalgebra::Matrix2;
+/// use perplex_num::{expm_perplex, Perplex};
+/// let (zero, theta) = (Perplex::new(0.0, 0.0), Perplex::new(1.0, 0.5));
+/// let exp = expm_perplex(&Matrix2::new(theta, zero, zero, theta));
+/// assert_abs_diff_eq!(exp[(0, 0)], theta.exp(), epsilon = 1e-15);
+/// assert_abs_diff_eq!(exp[(0, 1)], zero);
+/// ```
+pub fn expm_perplex<T: Copy + RealField + Float>(m: &Matrix2<Perplex<T>>) -> Matrix2<Perplex<T>> {
+    let exp_u = expm_real(&m.map(|z| z.t + z.x));
+    let exp_v = expm_real(&m.map(|z| z.t - z.x));
+    let half = T::one() / (T::one() + T::one());
+    exp_u.zip_map(&exp_v, |u, v| Perplex::new((u + v) * half, (u - v) * half))
+}
+
+/// Returns the exponential of a real 2x2 matrix by the closed form of `expm_perplex`.
+fn expm_real<T: Copy + RealField + Float>(a: &Matrix2<T>) -> Matrix2<T> {
+    let two = T::one() + T::one();
+    let s = (a.m11 + a.m22) / two;
+    let n = a - Matrix2::identity() * s;
+    // N^2 = q I, since N is traceless
+    let q = n.m11 * n.m11 + n.m12 * n.m21;
+    let (c, d) = if Float::abs(q) < Float::sqrt(T::epsilon()) {
+        // degenerate eigenvalues: c = 1 + q/2 (1 + q/12) and d = 1 + q/6 (1 + q/20)
+        let constant = |v: f64| T::from(v).unwrap();
+        (
+            T::one() + q / two * (T::one() + q / constant(12.0)),
+            T::one() + q / constant(6.0) * (T::one() + q / constant(20.0)),
+        )
+    } else if q > T::zero() {
+        let r = Float::sqrt(q);
+        (Float::cosh(r), Float::sinh(r) / r)
+    } else {
+        let r = Float::sqrt(-q);
+        (Float::cos(r), Float::sin(r) / r)
+    };
+    (Matrix2::identity() * c + n * d) * Float::exp(s)
+}
+
+/// Returns the real eigenvalues of the companion matrix of a real polynomial in ascending order, or `None` for the zero polynomial.
+fn real_eigen_roots<T: Copy + RealField + Float>(coefficients: &[T]) -> Option<Vec<T>> {
+    let n = coefficients.len().checked_sub(1)?;
+    if n == 0 {
+        return Some(Vec::new());
+    }
+    let leading = coefficients[n];
+    let companion = DMatrix::from_fn(n, n, |i, j| {
+        if j == n - 1 {
+            -coefficients[i] / leading
+        } else if i == j + 1 {
+            T::one()
+        } else {
+            T::zero()
+        }
+    });
+    let mut roots: Vec<T> = companion
+        .complex_eigenvalues()
+        .iter()
+        .filter(|lambda| lambda.im.is_zero())
+        .map(|lambda| lambda.re)
+        .collect();
+    roots.sort_by(|a, b| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal));
+    Some(roots)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use approx::assert_abs_diff_eq;
+    use nalgebra::{RowDVector, Unit};
     #[test]
     fn test_matrix() {
         let (z1, z2) = (Perplex::new(1.0, 0.5), Perplex::new(-1.0, -2.0));
@@ -66,4 +228,117 @@ mod tests {
             "Matrix multiplication corresponds to multiplication of perplex numbers!"
         );
     }
+    #[test]
+    fn test_expm() {
+        let taylor = |m: &Matrix2<Perplex<f64>>| {
+            let mut term = Matrix2::<Perplex<f64>>::identity();
+            let mut sum = term;
+            for k in 1..40 {
+                term = term * m * Perplex::new(1.0 / k as f64, 0.0);
+                sum += term;
+            }
+            sum
+        };
+        let m = Matrix2::new(
+            Perplex::new(0.3, -0.2),
+            Perplex::new(1.0, 0.5),
+            Perplex::new(-0.7, 0.4),
+            Perplex::new(0.1, 0.9),
+        );
+        let exp = expm_perplex(&m);
+        for (a, b) in exp.iter().zip(taylor(&m).iter()) {
+            assert_abs_diff_eq!(*a, *b, epsilon = 1e-12);
+        }
+        let (zero, one) = (Perplex::new(0.0, 0.0), Perplex::new(1.0, 0.0));
+        let nilpotent = Matrix2::new(zero, Perplex::new(2.0, 1.0), zero, zero);
+        assert_eq!(
+            expm_perplex(&nilpotent),
+            Matrix2::new(one, Perplex::new(2.0, 1.0), zero, one),
+            "Degenerate eigenvalues!"
+        );
+        let nearly_degenerate =
+            Matrix2::new(one, Perplex::new(1e-9, 0.0), Perplex::new(1e-9, 0.0), one);
+        for (a, b) in expm_perplex(&nearly_degenerate)
+            .iter()
+            .zip(taylor(&nearly_degenerate).iter())
+        {
+            assert_abs_diff_eq!(*a, *b, epsilon = 1e-15);
+        }
+    }
+    #[test]
+    fn test_normed() {
+        let z = Perplex::new(3.0, -4.0);
+        assert_eq!(Normed::norm(&z), 5.0, "Normed yields the L2 norm!");
+        assert_eq!(Normed::norm(&z), z.l2_norm());
+        assert_eq!(Normed::norm_squared(&z), 25.0);
+        assert_eq!(z.norm(), f64::sqrt(7.0), "Inherent norm is the modulus!");
+        let unit = Unit::new_normalize(z);
+        assert_eq!(unit.into_inner(), Perplex::new(0.6, -0.8));
+        let z = Perplex::new(2.0f64, 2.0);
+        assert!(z.is_light_like());
+        let unit = Unit::try_new(z, 1e-10).expect("Light-like numbers can be normalized!");
+        assert!((Normed::norm(unit.as_ref()) - 1.0).abs() < 1e-15);
+        assert!(Unit::try_new(Perplex::new(0.0, 0.0), 1e-10).is_none());
+    }
+    #[test]
+    fn test_companion() {
+        let (r1, r2) = (Perplex::new(1.0, 0.5), Perplex::new(-2.0, 1.0));
+        let c2 = Perplex::new(2.0, 1.0);
+        let p = PerplexPolynomial::new(vec![c2 * r1 * r2, -c2 * (r1 + r2), c2]);
+        let companion = p.companion_matrix().unwrap();
+        assert_eq!(companion.shape(), (2, 2));
+        for root in p.roots().unwrap() {
+            // (1, r) is a left eigenvector with eigenvalue r
+            let v = RowDVector::from_vec(vec![Perplex::new(1.0, 0.0), root]);
+            let v_companion = &v * &companion;
+            assert_abs_diff_eq!(v_companion[0], root * v[0], epsilon = 1e-12);
+            assert_abs_diff_eq!(v_companion[1], root * v[1], epsilon = 1e-12);
+        }
+        let blocks = p.companion_blocks().unwrap();
+        assert_eq!(blocks.shape(), (4, 4));
+        let (t, x) = (companion[(0, 1)].t, companion[(0, 1)].x);
+        assert_eq!(
+            blocks.view((0, 2), (2, 2)),
+            Perplex::new(t, x).as_matrix_form()
+        );
+        let light_like =
+            PerplexPolynomial::new(vec![Perplex::new(1.0, 0.0), Perplex::new(1.0, 1.0)]);
+        assert!(
+            light_like.companion_matrix().is_none(),
+            "Light-like leading coefficient!"
+        );
+        assert!(PerplexPolynomial::new(vec![c2])
+            .companion_matrix()
+            .is_none());
+    }
+    #[test]
+    fn test_eigen_roots() {
+        let (r1, r2) = (Perplex::new(1.0, 0.5), Perplex::new(-2.0, 1.0));
+        let p = PerplexPolynomial::new(vec![r1 * r2, -(r1 + r2), Perplex::new(1.0, 0.0)]);
+        let (roots, eigen_roots) = (p.roots().unwrap(), p.eigen_roots().unwrap());
+        assert_eq!(roots.len(), eigen_roots.len());
+        for (root, eigen_root) in roots.into_iter().zip(eigen_roots) {
+            assert_abs_diff_eq!(root, eigen_root, epsilon = 1e-12);
+        }
+        // (z - 1)(z - 2)(z - 3) has the nine roots with null components in {1, 2, 3}
+        let one = Perplex::new(1.0, 0.0);
+        let cubic =
+            PerplexPolynomial::new(vec![-one.scale(6.0), one.scale(11.0), -one.scale(6.0), one]);
+        let roots = cubic.eigen_roots().unwrap();
+        assert_eq!(roots.len(), 9);
+        for root in roots {
+            assert_abs_diff_eq!(cubic.eval(root), Perplex::new(0.0, 0.0), epsilon = 1e-10);
+        }
+        let light_like = PerplexPolynomial::new(vec![one, Perplex::new(1.0, 1.0)]);
+        assert!(
+            light_like.eigen_roots().unwrap().is_empty(),
+            "p_v = 1 has no roots!"
+        );
+        let light_like =
+            PerplexPolynomial::new(vec![Perplex::new(0.0, 0.0), Perplex::new(1.0, 1.0)]);
+        assert!(
+            light_like.eigen_roots().is_none(),
+            "p_v = 0 has infinitely many roots!"
+        );
+    }
 }
@@ -0,0 +1,205 @@
+//! # Memoization Module
+//!
+//! This module (optional feature `quantized-hash`) provides the `MemoizedFn` wrapper, which caches the values of an expensive perplex function like `exp` or `ln` keyed by the lattice cell of the input, see `Perplex::lattice_cell`.
+//! Evaluations on grids with many repeated values, e.g., on grids symmetric under `conj` or `-z` with a subsequent mapping, or in repeated sweeps over the same grid, then compute each value only once.
+//! All inputs within a lattice cell share the value of the first evaluated input of the cell, hence the cell size has to be below the spacing of the distinct inputs, and grid points should not lie on the cell boundaries to be robust against rounding.
+//!
+//! ## Features
+//! - `MemoizedFn::new`: The wrapper with a cell size and a capacity of cached values.
+//! - `MemoizedFn::eval`: The cached evaluation, which evicts the least recently used value if the capacity is exceeded.
+//! - `hits` and `misses`: The statistics of the cache.
+//!
+//! ## Example
+//! ```
+//! use perplex_num::memo::MemoizedFn;
+//! use perplex_num::Perplex;
+//! let mut exp = MemoizedFn::new(Perplex::exp, 1e-9, 1024).unwrap();
+//! let grid: Vec<_> = (-5..=5).map(|k| Perplex::new(0.1 * k as f64, 0.2)).collect();
+//! for _ in 0..3 {
+//!     for &z in &grid {
+//!         assert_eq!(exp.eval(z), z.exp());
+//!     }
+//! }
+//! assert_eq!((exp.misses(), exp.hits()), (11, 22));
+//! ```
+
+use super::Perplex;
+use num_traits::Float;
+use std::collections::{BTreeMap, HashMap};
+
+/// The integer coordinates of a lattice cell.
+type Cell = (i64, i64);
+
+/// A perplex function whose values are cached by the lattice cell of the input with a least recently used (LRU) eviction policy.
+#[derive(Clone, Debug)]
+pub struct MemoizedFn<T, F, R> {
+    f: F,
+    grid: T,
+    capacity: usize,
+    /// The cached values with the time of their last use.
+    values: HashMap<Cell, (R, u64)>,
+    /// The cells ordered by the time of their last use.
+    recency: BTreeMap<u64, Cell>,
+    clock: u64,
+    hits: u64,
+    misses: u64,
+}
+
+impl<T, F, R> MemoizedFn<T, F, R>
+where
+    T: Copy + Float,
+    F: Fn(Perplex<T>) -> R,
+    R: Clone,
+{
+    /// Wraps `f` with a cache of at most `capacity` values for lattice cells with the edge length `grid`.
+    ///
+    /// Returns `None` if `grid` is not positive and finite, or `capacity` is zero.
+    pub fn new(f: F, grid: T, capacity: usize) -> Option<Self> {
+        if !(grid > T::zero() && grid.is_finite()) || capacity == 0 {
+            return None;
+        }
+        Some(Self {
+            f,
+            grid,
+            capacity,
+            values: HashMap::new(),
+            recency: BTreeMap::new(),
+            clock: 0,
+            hits: 0,
+            misses: 0,
+        })
+    }
+    /// Returns the cached value of the lattice cell of `z`, or evaluates `f` at `z` and caches the value.
+    ///
+    /// If the cache is full, the least recently used value is evicted. Inputs without a lattice cell, e.g., with non-finite components, are evaluated without caching.
+    pub fn eval(&mut self, z: Perplex<T>) -> R {
+        let Some(cell) = z.lattice_cell(self.grid) else {
+            self.misses += 1;
+            return (self.f)(z);
+        };
+        self.clock += 1;
+        if let Some((value, last_use)) = self.values.get_mut(&cell) {
+            self.recency.remove(last_use);
+            *last_use = self.clock;
+            self.recency.insert(self.clock, cell);
+            self.hits += 1;
+            return value.clone();
+        }
+        self.misses += 1;
+        if self.values.len() == self.capacity {
+            if let Some((_, evicted)) = self.recency.pop_first() {
+                self.values.remove(&evicted);
+            }
+        }
+        let value = (self.f)(z);
+        self.values.insert(cell, (value.clone(), self.clock));
+        self.recency.insert(self.clock, cell);
+        value
+    }
+    /// Returns the edge length of the lattice cells.
+    #[inline]
+    pub fn grid(&self) -> T {
+        self.grid
+    }
+    /// Returns the maximum number of cached values.
+    #[inline]
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+    /// Returns the number of cached values.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+    /// Checks if no value is cached.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+    /// Returns the number of evaluations answered by the cache.
+    #[inline]
+    pub fn hits(&self) -> u64 {
+        self.hits
+    }
+    /// Returns the number of evaluations of the wrapped function.
+    #[inline]
+    pub fn misses(&self) -> u64 {
+        self.misses
+    }
+    /// Removes all cached values and resets the statistics.
+    pub fn clear(&mut self) {
+        self.values.clear();
+        self.recency.clear();
+        self.hits = 0;
+        self.misses = 0;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_abs_diff_eq;
+    use std::cell::Cell;
+    #[test]
+    fn test_symmetric_grid() {
+        let calls = Cell::new(0);
+        let ln = |z: Perplex<f64>| {
+            calls.set(calls.get() + 1);
+            z.ln()
+        };
+        let mut memo = MemoizedFn::new(ln, 1e-6, 1000).unwrap();
+        // the grid symmetric in x is mapped to its upper half by conj
+        for i in 1..=20 {
+            for j in -10..=10 {
+                let z = Perplex::new(0.5 + 0.1 * i as f64, 0.05 * j as f64);
+                let w = if z.x < 0.0 { z.conj() } else { z };
+                let value = memo.eval(w).map(|v| if z.x < 0.0 { v.conj() } else { v });
+                assert_abs_diff_eq!(value.unwrap(), z.ln().unwrap(), epsilon = 1e-15);
+            }
+        }
+        assert_eq!(calls.get(), 20 * 11);
+        assert_eq!(memo.misses(), 20 * 11);
+        assert_eq!(memo.hits(), 20 * 10);
+        assert_eq!(memo.len(), 20 * 11);
+        memo.clear();
+        assert!(memo.is_empty() && memo.hits() == 0);
+        assert!(memo.eval(Perplex::new(1.0, 1.0)).is_none(), "Cached None!");
+        assert!(memo.eval(Perplex::new(1.0, 1.0)).is_none());
+        assert_eq!(memo.hits(), 1);
+    }
+    #[test]
+    fn test_lru() {
+        let calls = Cell::new(0);
+        let exp = |z: Perplex<f64>| {
+            calls.set(calls.get() + 1);
+            z.exp()
+        };
+        let mut memo = MemoizedFn::new(exp, 0.5, 2).unwrap();
+        let (a, b, c) = (
+            Perplex::new(0.25, 0.25),
+            Perplex::new(1.25, 0.25),
+            Perplex::new(2.25, 0.25),
+        );
+        memo.eval(a);
+        memo.eval(b);
+        memo.eval(a);
+        memo.eval(c);
+        assert_eq!(memo.len(), 2);
+        assert_eq!(calls.get(), 3);
+        memo.eval(a);
+        assert_eq!(calls.get(), 3, "Recently used value is kept!");
+        memo.eval(b);
+        assert_eq!(calls.get(), 4, "Least recently used value is evicted!");
+        assert_eq!(
+            memo.eval(Perplex::new(0.3, 0.3)),
+            a.exp(),
+            "Value of the first input of the cell!"
+        );
+        let nan = Perplex::new(f64::NAN, 0.0);
+        assert!(memo.eval(nan).is_nan());
+        assert_eq!(memo.len(), 2, "Not cached!");
+        assert!(MemoizedFn::new(exp, 0.0, 2).is_none());
+        assert!(MemoizedFn::new(exp, 1.0, 0).is_none());
+        assert_eq!((memo.grid(), memo.capacity()), (0.5, 2));
+    }
+}
@@ -0,0 +1,194 @@
+//! # Mesh Module
+//!
+//! This module triangulates the graph of a real quantity of a perplex function over a `PerplexBox`, such that the function can be inspected in 3D viewers beyond the 2D charts of the examples.
+//! The graph is sampled on a regular grid, whereby the time component is the first, the space component the second and the height the third coordinate of each vertex.
+//!
+//! ## Features
+//! - `Height`: The modulus `|f(z)|` or the hyperbolic argument `arg(f(z))` as height of the graph.
+//! - `graph_mesh`: The vertices and triangles of the graph, omitting triangles at vertices without finite height, e.g., at the light cone or outside of the domain of `f`.
+//! - `write_obj` and `write_ply`: Export in the Wavefront OBJ and the ASCII PLY format.
+//!
+//! ## Example
+//! ```
+//! use perplex_num::mesh::{graph_mesh, Height};
+//! use perplex_num::{Perplex, PerplexBox};
+//! let region = PerplexBox::new(Perplex::new(1.0, -0.5), Perplex::new(2.0, 0.5)).unwrap();
+//! let mesh = graph_mesh(region, [11, 11], Height::Modulus, |z| Some(z * z));
+//! assert_eq!(mesh.vertices.len(), 121);
+//! assert_eq!(mesh.triangles.len(), 200);
+//! let mut obj = Vec::new();
+//! mesh.write_obj(&mut obj).unwrap();
+//! assert!(String::from_utf8(obj).unwrap().starts_with("v 1 -0.5 0.75\n"));
+//! ```
+
+use super::{Perplex, PerplexBox};
+use num_traits::Float;
+use std::fmt::Display;
+use std::io::{self, Write};
+
+/// The real quantity of the function values which is the height of the graph.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug, Default)]
+pub enum Height {
+    /// The modulus `|f(z)|`, see `Perplex::modulus`.
+    #[default]
+    Modulus,
+    /// The hyperbolic argument `arg(f(z))`, which is infinite at the light cone, see `Perplex::arg`.
+    Argument,
+}
+
+/// A triangle mesh with vertices `[t, x, height]` and counterclockwise triangles of vertex indices.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Mesh<T> {
+    /// The vertices, whereby a vertex without finite height has the height zero and is not referenced by any triangle.
+    pub vertices: Vec<[T; 3]>,
+    /// The zero-based indices of the vertices of each triangle.
+    pub triangles: Vec<[usize; 3]>,
+}
+
+/// Samples `height` of `f` on a regular grid of `resolution[0]` points along the time axis and `resolution[1]` points along the space axis of `region`, including its boundary, and triangulates the graph with two triangles per grid cell.
+///
+/// The vertices are ordered row by row with ascending space component, and within a row by ascending time component.
+/// If `f` returns `None` or the height is not finite, the triangles at the vertex are omitted, such that the mesh has holes instead of spikes.
+/// A resolution below two in a direction yields no triangles.
+pub fn graph_mesh<T, F>(
+    region: PerplexBox<T>,
+    resolution: [usize; 2],
+    height: Height,
+    f: F,
+) -> Mesh<T>
+where
+    T: Copy + Float,
+    F: Fn(Perplex<T>) -> Option<Perplex<T>>,
+{
+    let [columns, rows] = resolution;
+    let coordinate = |min: T, max: T, i: usize, n: usize| {
+        if n < 2 {
+            min
+        } else {
+            min + (max - min) * T::from(i).unwrap() / T::from(n - 1).unwrap()
+        }
+    };
+    let (min, max) = (region.min, region.max);
+    let mut vertices = Vec::with_capacity(columns * rows);
+    let mut finite = Vec::with_capacity(columns * rows);
+    for row in 0..rows {
+        let x = coordinate(min.x, max.x, row, rows);
+        for column in 0..columns {
+            let t = coordinate(min.t, max.t, column, columns);
+            let value = f(Perplex::new(t, x)).map(|w| match height {
+                Height::Modulus => w.modulus(),
+                Height::Argument => w.arg(),
+            });
+            match value {
+                Some(h) if h.is_finite() => {
+                    vertices.push([t, x, h]);
+                    finite.push(true);
+                }
+                _ => {
+                    vertices.push([t, x, T::zero()]);
+                    finite.push(false);
+                }
+            }
+        }
+    }
+    let mut triangles = Vec::new();
+    for row in 1..rows {
+        for column in 1..columns {
+            let a = (row - 1) * columns + column - 1;
+            let (b, c, d) = (a + 1, a + columns + 1, a + columns);
+            for triangle in [[a, b, c], [a, c, d]] {
+                if triangle.iter().all(|&i| finite[i]) {
+                    triangles.push(triangle);
+                }
+            }
+        }
+    }
+    Mesh {
+        vertices,
+        triangles,
+    }
+}
+
+impl<T: Copy + Display> Mesh<T> {
+    /// Writes the mesh in the Wavefront OBJ format with one-based indices.
+    pub fn write_obj<W: Write>(&self, mut writer: W) -> io::Result<()> {
+        for [t, x, h] in &self.vertices {
+            writeln!(writer, "v {t} {x} {h}")?;
+        }
+        for [a, b, c] in &self.triangles {
+            writeln!(writer, "f {} {} {}", a + 1, b + 1, c + 1)?;
+        }
+        Ok(())
+    }
+    /// Writes the mesh in the ASCII PLY format.
+    pub fn write_ply<W: Write>(&self, mut writer: W) -> io::Result<()> {
+        writeln!(writer, "ply")?;
+        writeln!(writer, "format ascii 1.0")?;
+        writeln!(writer, "element vertex {}", self.vertices.len())?;
+        for name in ["x", "y", "z"] {
+            writeln!(writer, "property double {name}")?;
+        }
+        writeln!(writer, "element face {}", self.triangles.len())?;
+        writeln!(writer, "property list uchar int vertex_indices")?;
+        writeln!(writer, "end_header")?;
+        for [t, x, h] in &self.vertices {
+            writeln!(writer, "{t} {x} {h}")?;
+        }
+        for [a, b, c] in &self.triangles {
+            writeln!(writer, "3 {a} {b} {c}")?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[test]
+    fn test_graph_mesh() {
+        let region = PerplexBox::new(Perplex::new(-1.0, -1.0), Perplex::new(1.0, 1.0)).unwrap();
+        let mesh = graph_mesh(region, [3, 2], Height::Modulus, Some);
+        assert_eq!(
+            mesh.vertices,
+            vec![
+                [-1.0, -1.0, 0.0],
+                [0.0, -1.0, 1.0],
+                [1.0, -1.0, 0.0],
+                [-1.0, 1.0, 0.0],
+                [0.0, 1.0, 1.0],
+                [1.0, 1.0, 0.0]
+            ]
+        );
+        assert_eq!(
+            mesh.triangles,
+            vec![[0, 1, 4], [0, 4, 3], [1, 2, 5], [1, 5, 4]]
+        );
+        let holes = graph_mesh(region, [3, 2], Height::Argument, Some);
+        assert!(holes.triangles.is_empty(), "Corners on the light cone!");
+        let domain = graph_mesh(region, [3, 3], Height::Argument, |z| z.ln());
+        assert_eq!(domain.vertices.len(), 9);
+        assert!(
+            domain.triangles.is_empty(),
+            "Undefined logarithm at the light cone!"
+        );
+        assert!(graph_mesh(region, [1, 5], Height::Modulus, Some)
+            .triangles
+            .is_empty());
+    }
+    #[test]
+    fn test_export() {
+        let region = PerplexBox::new(Perplex::new(1.0, 0.0), Perplex::new(2.0, 0.5)).unwrap();
+        let mesh = graph_mesh(region, [2, 2], Height::Argument, Some);
+        let mut obj = Vec::new();
+        mesh.write_obj(&mut obj).unwrap();
+        let obj = String::from_utf8(obj).unwrap();
+        assert_eq!(obj.lines().filter(|l| l.starts_with("v ")).count(), 4);
+        assert_eq!(obj.lines().last(), Some("f 1 4 3"), "One-based indices!");
+        let mut ply = Vec::new();
+        mesh.write_ply(&mut ply).unwrap();
+        let ply = String::from_utf8(ply).unwrap();
+        assert!(ply.contains("element vertex 4\n") && ply.contains("element face 2\n"));
+        assert_eq!(ply.lines().last(), Some("3 0 3 2"));
+        assert_eq!(ply.lines().nth(9), Some("1 0 0"));
+    }
+}
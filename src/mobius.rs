@@ -0,0 +1,143 @@
+//! # Mobius Module
+//!
+//! This module provides the `Mobius` type for linear fractional (Möbius) transformations `f(z) = (a z + b) / (c z + d)` with perplex coefficients.
+//! In the null basis `u = t + x` and `v = t - x`, a perplex Möbius transformation acts as a pair of independent real Möbius transformations of `u` and `v`.
+//! Since the perplex numbers contain zero divisors, `f(z)` is undefined if the denominator `c z + d` is light-like, and `f` is invertible if and only if the determinant `a d - b c` is not light-like.
+//!
+//! The Blaschke factors `(z - a) / (1 - a z)` for `a` in the unit square `|t| + |x| < 1` are the transformations which act as real Blaschke factors of the unit interval on both null coordinates, see the `blaschke` module for their products.
+//!
+//! ## Example
+//! ```
+//! use perplex_num::{Mobius, Perplex};
+//! let one = Perplex::new(1.0, 0.0);
+//! let m = Mobius::new(Perplex::new(2.0, 1.0), one, Perplex::new(0.0, 0.0), one);
+//! let z = Perplex::new(0.5, -0.25);
+//! let w = m.apply(z).unwrap();
+//! assert_eq!(w, Perplex::new(2.0, 1.0) * z + one);
+//! assert_eq!(m.inverse().unwrap().apply(w).unwrap(), z);
+//! ```
+
+use super::Perplex;
+use core::ops::Neg;
+use num_traits::{Num, One, Zero};
+
+/// A Möbius transformation `f(z) = (a z + b) / (c z + d)` with perplex coefficients.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub struct Mobius<T> {
+    /// The coefficient of `z` in the numerator.
+    pub a: Perplex<T>,
+    /// The constant of the numerator.
+    pub b: Perplex<T>,
+    /// The coefficient of `z` in the denominator.
+    pub c: Perplex<T>,
+    /// The constant of the denominator.
+    pub d: Perplex<T>,
+}
+
+impl<T> Mobius<T> {
+    /// Creates a Möbius transformation from its coefficients.
+    #[inline]
+    pub fn new(a: Perplex<T>, b: Perplex<T>, c: Perplex<T>, d: Perplex<T>) -> Self {
+        Self { a, b, c, d }
+    }
+}
+
+impl<T: Clone + Num + Neg<Output = T>> Mobius<T> {
+    /// Returns the identity transformation `f(z) = z`.
+    #[inline]
+    pub fn identity() -> Self {
+        Self::new(
+            Perplex::one(),
+            Perplex::zero(),
+            Perplex::zero(),
+            Perplex::one(),
+        )
+    }
+    /// Returns the Blaschke factor `f(z) = (z - a) / (1 - a z)`, or `None` if `a` is not in the unit square `|t| + |x| < 1`.
+    ///
+    /// The factor maps the unit square onto itself, its boundary onto its boundary, and `a` to zero.
+    #[inline]
+    pub fn blaschke(a: Perplex<T>) -> Option<Self>
+    where
+        T: PartialOrd,
+    {
+        // the null coordinates u = t + x and v = t - x of `a` are in the open unit interval
+        let one = T::one();
+        let inside = |w: T| -one.clone() < w && w < one;
+        if !(inside(a.t.clone() + a.x.clone()) && inside(a.t.clone() - a.x.clone())) {
+            return None;
+        }
+        Some(Self::new(Perplex::one(), -&a, -a, Perplex::one()))
+    }
+    /// Returns the determinant `a d - b c`.
+    #[inline]
+    pub fn determinant(&self) -> Perplex<T> {
+        &self.a * &self.d - &self.b * &self.c
+    }
+    /// Applies the transformation to `z`, or returns `None` if the denominator `c z + d` is light-like.
+    #[inline]
+    pub fn apply(&self, z: Perplex<T>) -> Option<Perplex<T>> {
+        let denominator = (&self.c * &z + &self.d).try_inverse()?;
+        Some((&self.a * z + &self.b) * denominator)
+    }
+    /// Returns the composition `self ∘ other`, i.e., the transformation `z -> self(other(z))`.
+    #[inline]
+    pub fn compose(&self, other: &Self) -> Self {
+        Self::new(
+            &self.a * &other.a + &self.b * &other.c,
+            &self.a * &other.b + &self.b * &other.d,
+            &self.c * &other.a + &self.d * &other.c,
+            &self.c * &other.b + &self.d * &other.d,
+        )
+    }
+    /// Returns the inverse transformation `f^-1(w) = (d w - b) / (-c w + a)`, or `None` if the determinant is light-like.
+    #[inline]
+    pub fn inverse(&self) -> Option<Self> {
+        self.determinant().try_inverse()?;
+        Some(Self::new(
+            self.d.clone(),
+            -&self.b,
+            -&self.c,
+            self.a.clone(),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_abs_diff_eq;
+    #[test]
+    fn test_mobius() {
+        let m = Mobius::new(
+            Perplex::new(1.0, 0.5),
+            Perplex::new(-2.0, 0.0),
+            Perplex::new(0.25, 0.5),
+            Perplex::new(3.0, 1.0),
+        );
+        let z = Perplex::new(0.7, -0.3);
+        let expected = (m.a * z + m.b) * (m.c * z + m.d).try_inverse().unwrap();
+        assert_eq!(m.apply(z).unwrap(), expected);
+        assert_eq!(Mobius::identity().apply(z).unwrap(), z);
+        let w = m.apply(z).unwrap();
+        assert_abs_diff_eq!(m.inverse().unwrap().apply(w).unwrap(), z, epsilon = 1e-12);
+        let n = Mobius::new(m.d, m.c, m.b, m.a);
+        assert_abs_diff_eq!(
+            m.compose(&n).apply(z).unwrap(),
+            m.apply(n.apply(z).unwrap()).unwrap(),
+            epsilon = 1e-12
+        );
+    }
+    #[test]
+    fn test_light_like() {
+        let one = Perplex::new(1.0, 0.0);
+        let m = Mobius::new(one, Perplex::zero(), one, Perplex::zero());
+        assert!(
+            m.apply(Perplex::new(2.0, 2.0)).is_none(),
+            "Light-like denominator!"
+        );
+        let m = Mobius::new(Perplex::new(1.0, 1.0), one, Perplex::zero(), one);
+        assert!(m.determinant().is_light_like());
+        assert!(m.inverse().is_none(), "Light-like determinant!");
+    }
+}
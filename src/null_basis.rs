@@ -0,0 +1,172 @@
+//! # Null Basis Module
+//!
+//! This module provides the `NullBasis` representation of perplex numbers in the idempotent basis `e+ = (1 + h) / 2` and `e- = (1 - h) / 2`.
+//! A perplex number `z = t + x h` has the coefficients `u = t + x` and `v = t - x` with `z = u e+ + v e-`, since `e+` and `e-` are idempotent and annihilate each other, see Sec. 4.1 in [The Mathematics of Minkowski Space-Time](https://doi.org/10.1007/978-3-7643-8614-6).
+//! Hence, addition, multiplication and division act componentwise, which exposes the ring isomorphism of the perplex numbers with `R ⊕ R`: light-like numbers are those with a zero coefficient, and the hyperbolic conjugate swaps the coefficients.
+//!
+//! ## Features
+//! - `From` conversions between `Perplex` and `NullBasis`.
+//! - Componentwise `Add`, `Sub`, `Mul`, `Neg` and `Div`, whereby the division returns `None` for a divisor with a zero coefficient like `checked_div` of `Perplex`.
+//! - `lift`: The application of a real function to both coefficients, see `Perplex::lift`.
+//!
+//! ## Example
+//! ```
+//! use num_traits::CheckedDiv;
+//! use perplex_num::null_basis::NullBasis;
+//! use perplex_num::Perplex;
+//! let (z, w) = (Perplex::new(2.0, 1.0), Perplex::new(0.5, -1.5));
+//! let (a, b) = (NullBasis::from(z), NullBasis::from(w));
+//! assert_eq!((a.plus, a.minus), (3.0, 1.0));
+//! assert_eq!(Perplex::from(a * b), z * w);
+//! assert_eq!((a / b).map(Perplex::from), z.checked_div(&w));
+//! ```
+
+use super::Perplex;
+use core::ops::{Add, Div, Mul, Neg, Sub};
+use num_traits::Num;
+
+/// The coefficients of a perplex number in the idempotent basis `e+ = (1 + h) / 2` and `e- = (1 - h) / 2`.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug, Default)]
+pub struct NullBasis<T> {
+    /// The coefficient `u = t + x` of `e+`.
+    pub plus: T,
+    /// The coefficient `v = t - x` of `e-`.
+    pub minus: T,
+}
+
+impl<T: Clone + Num> NullBasis<T> {
+    /// Creates the number `plus e+ + minus e-`.
+    #[inline]
+    pub fn new(plus: T, minus: T) -> Self {
+        Self { plus, minus }
+    }
+    /// Checks if a coefficient is zero, i.e., if the number is light-like.
+    #[inline]
+    pub fn is_light_like(&self) -> bool {
+        self.plus.is_zero() || self.minus.is_zero()
+    }
+    /// Returns the squared distance `t^2 - x^2`, which is the product of the coefficients.
+    #[inline]
+    pub fn squared_distance(&self) -> T {
+        self.plus.clone() * self.minus.clone()
+    }
+    /// Returns the hyperbolic conjugate, which swaps the coefficients.
+    #[inline]
+    pub fn conj(&self) -> Self {
+        Self::new(self.minus.clone(), self.plus.clone())
+    }
+    /// Applies the real function `f` to both coefficients.
+    #[inline]
+    pub fn lift<F: Fn(T) -> T>(self, f: F) -> Self {
+        Self::new(f(self.plus), f(self.minus))
+    }
+}
+
+impl<T: Clone + Num> From<Perplex<T>> for NullBasis<T> {
+    /// Returns the coefficients `u = t + x` and `v = t - x`.
+    #[inline]
+    fn from(z: Perplex<T>) -> Self {
+        Self::new(z.t.clone() + z.x.clone(), z.t - z.x)
+    }
+}
+
+impl<T: Clone + Num> From<NullBasis<T>> for Perplex<T> {
+    /// Returns the perplex number `(u + v) / 2 + (u - v) / 2 h`, which is exact for fields like the floating-point and rational numbers, whereas integer components are truncated for coefficients of different parity.
+    #[inline]
+    fn from(z: NullBasis<T>) -> Self {
+        let two = T::one() + T::one();
+        Self::new(
+            (z.plus.clone() + z.minus.clone()) / two.clone(),
+            (z.plus - z.minus) / two,
+        )
+    }
+}
+
+impl<T: Clone + Num> Add for NullBasis<T> {
+    type Output = Self;
+    #[inline]
+    fn add(self, rhs: Self) -> Self::Output {
+        Self::new(self.plus + rhs.plus, self.minus + rhs.minus)
+    }
+}
+
+impl<T: Clone + Num> Sub for NullBasis<T> {
+    type Output = Self;
+    #[inline]
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self::new(self.plus - rhs.plus, self.minus - rhs.minus)
+    }
+}
+
+impl<T: Clone + Num> Mul for NullBasis<T> {
+    type Output = Self;
+    #[inline]
+    fn mul(self, rhs: Self) -> Self::Output {
+        Self::new(self.plus * rhs.plus, self.minus * rhs.minus)
+    }
+}
+
+impl<T: Clone + Num> Div for NullBasis<T> {
+    type Output = Option<Self>;
+    /// Divides `self` by `rhs` componentwise. Division by a light-like number, i.e., with a zero coefficient, yields `None`.
+    #[inline]
+    fn div(self, rhs: Self) -> Self::Output {
+        if rhs.is_light_like() {
+            None
+        } else {
+            Some(Self::new(self.plus / rhs.plus, self.minus / rhs.minus))
+        }
+    }
+}
+
+impl<T: Clone + Num + Neg<Output = T>> Neg for NullBasis<T> {
+    type Output = Self;
+    #[inline]
+    fn neg(self) -> Self::Output {
+        Self::new(-self.plus, -self.minus)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_abs_diff_eq;
+    #[test]
+    fn test_conversion() {
+        let z = Perplex::new(1.5, -0.25);
+        let n = NullBasis::from(z);
+        assert_eq!(n, NullBasis::new(1.25, 1.75));
+        assert_eq!(Perplex::from(n), z);
+        assert_eq!(
+            Perplex::from(NullBasis::new(1.0, 0.0)),
+            Perplex::new(0.5, 0.5),
+            "e+!"
+        );
+        assert_eq!(
+            Perplex::from(NullBasis::new(0.0, 1.0)),
+            Perplex::new(0.5, -0.5),
+            "e-!"
+        );
+        assert_eq!(NullBasis::from(Perplex::new(3, 1)), NullBasis::new(4, 2));
+    }
+    #[test]
+    fn test_ring_structure() {
+        let (z, w) = (Perplex::new(-2.0, 0.5), Perplex::new(1.0, 3.0));
+        let (a, b) = (NullBasis::from(z), NullBasis::from(w));
+        assert_eq!(Perplex::from(a + b), z + w);
+        assert_eq!(Perplex::from(a - b), z - w);
+        assert_eq!(Perplex::from(a * b), z * w);
+        assert_eq!(Perplex::from(-a), -z);
+        assert_abs_diff_eq!(Perplex::from((a / b).unwrap()), z / w, epsilon = 1e-15);
+        assert_eq!(Perplex::from(a.conj()), z.conj());
+        assert_eq!(a.squared_distance(), z.squared_distance());
+        let light_like = NullBasis::from(Perplex::new(1.0, -1.0));
+        assert!(light_like.is_light_like());
+        assert_eq!(a / light_like, None);
+        assert_eq!(
+            Perplex::from(a.lift(f64::exp)),
+            z.lift(f64::exp),
+            "Lift by the coefficients!"
+        );
+    }
+}
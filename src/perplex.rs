@@ -4,20 +4,36 @@
 //! ## Features
 //! - Calculation of common distance metrics as well as the squared distance in the hyperbolic plane.
 //! - Determination of the number's nature (time-like, space-like, or light-like) based on its squared distance. See Properties of the Perplex Numbers in [Fundamental Theorems of Algebra for the Perplexes](https://doi.org/10.4169/074683409X475643).
+//! - Algebraic queries for zero divisors, idempotents and the annihilator of light-like numbers.
+//! - Guaranteed `repr(C)` memory layout of `[t, x]` with zero-copy conversions from and to arrays and interleaved slices.
 //! - `AbsDiffEq` trait from the `approx` crate.
 //! - Constants and `FloatCore` traits from the `num_traits` crate.
-//! - Hyperbolic exponential function as well as the natural logarithm as the inversion.
-//! - Common trigonometric functions in the hyperbolic plane.
+//! - Exact scaling by powers of two and by `h` times powers of two (`ldexp`, `mul_pow2`, `div_pow2`, `mul_h_pow2`, `div_h_pow2`), used for the overflow-robust modulus, logarithm of the modulus and division.
+//! - Hyperbolic exponential function as well as the natural logarithm as the inversion, their variants to the bases 2 and 10, and the powers `powc` and `expf` composed of both.
+//! - `lift` and its alias `map_null_basis`: The application of real functions to the null components, i.e., the functional calculus in the idempotent basis.
+//! - Variants of `exp`, `ln`, `sqrt` and `arg` restricted to the right sector, which skip the Klein handling for data partitioned by sector.
+//! - Common trigonometric and hyperbolic functions in the hyperbolic plane and their inverses on the domains of the null components.
+//! - `JsonSchema` trait from the `schemars` crate (optional feature `schemars`).
+//! - `Serialize` and `Deserialize` traits from the `serde` crate (optional feature `serde`), which represent the number as a map of the components `t` and `x`.
 
 use approx::AbsDiffEq;
+use core::fmt;
+use core::ops::Neg;
 use num_traits::float::FloatCore;
-use num_traits::{Float, Num, One, Zero};
-use std::fmt;
-use std::ops::Neg;
+use num_traits::{CheckedDiv, Float, Num, One, Zero};
 
 /// The `Perplex` struct is a representation of hyperbolic numbers, also known as split-complex numbers, which consist of two components: a real part (t) and a hyperbolic part (x). These components correspond to the time and space coordinates in Minkowski space-time, respectively. See Sec. 4.1 `Geometrical Representation of Hyperbolic Numbers` in [The Mathematics of Minkowski Space-Time](https://doi.org/10.1007/978-3-7643-8614-6).
 /// The implementation is generic over a type `T`, which allows it to be used with different numeric types (i.e., `f32` or `f64`).
+///
+/// # Memory Layout
+///
+/// The layout is part of the public API: `Perplex<T>` is `#[repr(C)]` with the time component `t` at offset zero, followed by the space component `x` at offset `size_of::<T>()`.
+/// Since both fields have the same type, there is no padding, and `Perplex<T>` has the size, alignment and layout of `[T; 2]`. Slices of perplex numbers are thus interleaved arrays `[t0, x0, t1, x1, ...]` for FFI, GPU buffers and binary serialization, see `as_interleaved` and `from_interleaved`.
+/// With the optional feature `bytemuck`, `Perplex<T>` implements `Pod` and `Zeroable` for `Pod` components, so that the casts of the `bytemuck` crate reinterpret buffers safely, e.g., for GPU uploads, see the `texture` module.
 #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(C)]
 pub struct Perplex<T> {
     /// The real part of the perplex number, representing time.
     pub t: T,
@@ -28,29 +44,195 @@ pub struct Perplex<T> {
 impl<T> Perplex<T> {
     /// Create a new Perplex number
     #[inline]
-    pub fn new(t: T, x: T) -> Self {
+    pub const fn new(t: T, x: T) -> Self {
         Self { t, x }
     }
+    /// Creates a perplex number from the array `[t, x]`.
+    #[inline]
+    pub fn from_array(components: [T; 2]) -> Self {
+        let [t, x] = components;
+        Self { t, x }
+    }
+    /// Returns the array `[t, x]` of the components.
+    #[inline]
+    pub fn to_array(self) -> [T; 2] {
+        [self.t, self.x]
+    }
+    /// Reinterprets `self` as the array `[t, x]` without copying, see the memory layout of `Perplex`.
+    #[inline]
+    pub fn as_array(&self) -> &[T; 2] {
+        // SAFETY: Perplex<T> has the layout of [T; 2]
+        unsafe { &*(self as *const Self as *const [T; 2]) }
+    }
+    /// Reinterprets `self` as the mutable array `[t, x]` without copying.
+    #[inline]
+    pub fn as_array_mut(&mut self) -> &mut [T; 2] {
+        // SAFETY: Perplex<T> has the layout of [T; 2]
+        unsafe { &mut *(self as *mut Self as *mut [T; 2]) }
+    }
+    /// Reinterprets a slice of perplex numbers as the interleaved components `[t0, x0, t1, x1, ...]` without copying.
+    #[inline]
+    pub fn as_interleaved(values: &[Self]) -> &[T] {
+        // SAFETY: a slice of n Perplex<T> has the layout of a slice of 2n T
+        unsafe { core::slice::from_raw_parts(values.as_ptr() as *const T, 2 * values.len()) }
+    }
+    /// Reinterprets the interleaved components `[t0, x0, t1, x1, ...]` as a slice of perplex numbers without copying, or returns `None` if the number of components is odd.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use perplex_num::Perplex;
+    ///
+    /// let components = [1.0, 2.0, 3.0, 4.0];
+    /// let values = Perplex::from_interleaved(&components).unwrap();
+    /// assert_eq!(values, &[Perplex::new(1.0, 2.0), Perplex::new(3.0, 4.0)]);
+    /// assert_eq!(Perplex::as_interleaved(values), &components);
+    /// assert!(Perplex::from_interleaved(&components[..3]).is_none());
+    /// ```
+    #[inline]
+    pub fn from_interleaved(components: &[T]) -> Option<&[Self]> {
+        if components.len() % 2 == 0 {
+            // SAFETY: the alignment of Perplex<T> is the alignment of T and the length is even
+            Some(unsafe {
+                core::slice::from_raw_parts(
+                    components.as_ptr() as *const Self,
+                    components.len() / 2,
+                )
+            })
+        } else {
+            None
+        }
+    }
+    /// Reinterprets the mutable interleaved components `[t0, x0, t1, x1, ...]` as a mutable slice of perplex numbers without copying, or returns `None` if the number of components is odd.
+    #[inline]
+    pub fn from_interleaved_mut(components: &mut [T]) -> Option<&mut [Self]> {
+        if components.len() % 2 == 0 {
+            // SAFETY: the alignment of Perplex<T> is the alignment of T and the length is even
+            Some(unsafe {
+                core::slice::from_raw_parts_mut(
+                    components.as_mut_ptr() as *mut Self,
+                    components.len() / 2,
+                )
+            })
+        } else {
+            None
+        }
+    }
 }
 
-impl<T: Copy + Neg<Output = T> + PartialOrd + Num + fmt::Display> fmt::Display for Perplex<T> {
+impl<T> From<[T; 2]> for Perplex<T> {
+    /// Creates a perplex number from the array `[t, x]`.
+    #[inline]
+    fn from(components: [T; 2]) -> Self {
+        Self::from_array(components)
+    }
+}
+
+impl<T> From<Perplex<T>> for [T; 2] {
+    /// Returns the array `[t, x]` of the components.
+    #[inline]
+    fn from(z: Perplex<T>) -> Self {
+        z.to_array()
+    }
+}
+
+// compile-time assertions of the documented memory layout
+const _: () = {
+    use core::mem::{align_of, size_of};
+    assert!(size_of::<Perplex<f32>>() == size_of::<[f32; 2]>());
+    assert!(align_of::<Perplex<f32>>() == align_of::<[f32; 2]>());
+    assert!(size_of::<Perplex<f64>>() == size_of::<[f64; 2]>());
+    assert!(align_of::<Perplex<f64>>() == align_of::<[f64; 2]>());
+    assert!(size_of::<Perplex<u8>>() == 2);
+};
+
+impl<T: Clone + Neg<Output = T> + PartialOrd + Num + fmt::Display> fmt::Display for Perplex<T> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let (x, sign) = if self.x < T::zero() {
-            (-self.x, "-")
+            (-self.x.clone(), "-")
         } else {
-            (self.x, "+")
+            (self.x.clone(), "+")
+        };
+        // two decimal places by default
+        let p = f.precision().unwrap_or(2);
+        write!(f, "{:.*} {sign} {:.*} h", p, self.t, p, x)
+    }
+}
+
+/// The error of parsing a perplex number from a string, see `Perplex::from_str_radix`.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub enum ParsePerplexError {
+    /// The string is empty or contains only whitespace.
+    Empty,
+    /// A component is not a valid number of the radix.
+    InvalidComponent,
+}
+
+impl fmt::Display for ParsePerplexError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Empty => write!(f, "cannot parse perplex number from empty string"),
+            Self::InvalidComponent => write!(f, "invalid component in perplex number literal"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ParsePerplexError {}
+
+impl<T: Clone + Num + Neg<Output = T>> Perplex<T> {
+    /// Parses a perplex number in the format of `Display`, i.e., `t + x h` or `t - x h`, whose components are parsed by `Num::from_str_radix` of `T`.
+    ///
+    /// The sign of the space component is separated by whitespace from both components, such that signs of exponents like in `1e-3` are not mistaken for it. The literals `t` and `x h` denote a zero space and time component, respectively.
+    /// If the radix exceeds 17, for which `h` is a digit, the unit has to be separated by whitespace from the space component.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use perplex_num::Perplex;
+    /// assert_eq!(Perplex::from_str_radix("-1.5 - 2 h", 10), Ok(Perplex::new(-1.5, -2.0)));
+    /// assert_eq!(Perplex::from_str_radix("ff + 1h", 16), Ok(Perplex::new(255, 1)));
+    /// assert_eq!("0.25 h".parse(), Ok(Perplex::new(0.0, 0.25)));
+    /// ```
+    pub fn from_str_radix(s: &str, radix: u32) -> Result<Self, ParsePerplexError> {
+        let parse = |c: &str| {
+            T::from_str_radix(c.trim(), radix).map_err(|_| ParsePerplexError::InvalidComponent)
         };
-        match f.precision() {
-            Some(p) => write!(f, "{:.*} {sign} {:.*} h", p, self.t, p, x,),
-            None => {
-                let t_pretty = format!("{:.1$}", self.t, 2);
-                let x_pretty = format!("{:.1$}", x, 2);
-                write!(f, "{} {sign} {} h", t_pretty, x_pretty)
+        let s = s.trim();
+        if s.is_empty() {
+            return Err(ParsePerplexError::Empty);
+        }
+        let hyperbolic = s
+            .strip_suffix('h')
+            .filter(|rest| radix <= 17 || rest.ends_with(char::is_whitespace));
+        let Some(rest) = hyperbolic else {
+            return Ok(Self::new(parse(s)?, T::zero()));
+        };
+        let rest = rest.trim_end();
+        let separator = [rest.rfind(" + "), rest.rfind(" - ")]
+            .into_iter()
+            .flatten()
+            .max();
+        match separator {
+            Some(i) => {
+                let (t, x) = (parse(&rest[..i])?, parse(&rest[i + 3..])?);
+                let x = if rest[i..].starts_with(" - ") { -x } else { x };
+                Ok(Self::new(t, x))
             }
+            None => Ok(Self::new(T::zero(), parse(rest)?)),
         }
     }
 }
 
+impl<T: Clone + Num + Neg<Output = T>> core::str::FromStr for Perplex<T> {
+    type Err = ParsePerplexError;
+    /// Parses a perplex number in the format of `Display` in the radix 10, see `from_str_radix`.
+    #[inline]
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::from_str_radix(s, 10)
+    }
+}
+
 impl<T: AbsDiffEq> AbsDiffEq for Perplex<T>
 where
     T::Epsilon: Copy,
@@ -66,7 +248,7 @@ where
     }
 }
 
-impl<T: Copy + Num> Default for Perplex<T> {
+impl<T: Clone + Num> Default for Perplex<T> {
     /// Defaults to the neutral element of multiplication.
     #[inline]
     fn default() -> Self {
@@ -74,7 +256,7 @@ impl<T: Copy + Num> Default for Perplex<T> {
     }
 }
 
-impl<T: Copy + Num> From<T> for Perplex<T> {
+impl<T: Clone + Num> From<T> for Perplex<T> {
     /// Conversion of a number `t` into a Perplex yields time-component `t` with zero space component.
     #[inline]
     fn from(t: T) -> Self {
@@ -82,7 +264,7 @@ impl<T: Copy + Num> From<T> for Perplex<T> {
     }
 }
 
-impl<T: Copy + Num> Perplex<T> {
+impl<T: Clone + Num> Perplex<T> {
     /// Returns hyperbolic unit.
     #[inline]
     pub fn h() -> Self {
@@ -91,25 +273,90 @@ impl<T: Copy + Num> Perplex<T> {
     /// Returns the time component.
     #[inline]
     pub fn real(&self) -> T {
-        self.t
+        self.t.clone()
     }
     /// Returns the space component.
     #[inline]
     pub fn hyperbolic(&self) -> T {
-        self.x
+        self.x.clone()
     }
     /// Returns the squared distance D(z) in the hyperbolic plane.
     #[inline]
     pub fn squared_distance(&self) -> T {
-        self.t * self.t - self.x * self.x
+        self.minkowski_inner(self)
+    }
+    /// Returns the indefinite Minkowski inner product `t1 t2 - x1 x2`, i.e., the time component of `self * other.conj()`, whose quadratic form is the squared distance.
+    #[inline]
+    pub fn minkowski_inner(&self, other: &Self) -> T {
+        self.t.clone() * other.t.clone() - self.x.clone() * other.x.clone()
     }
     /// Multiplies `self` by the scalar `factor`.
     #[inline]
     pub fn scale(&self, factor: T) -> Self {
-        Self::new(factor * self.t, factor * self.x)
+        Self::new(factor.clone() * self.t.clone(), factor * self.x.clone())
+    }
+    /// Lifts the real function `f` to perplex numbers by applying it to the null components `u = t + x` and `v = t - x`, i.e., `f(z) = f(u) (1 + h) / 2 + f(v) (1 - h) / 2`.
+    ///
+    /// Since the perplex product acts componentwise in the null basis, the lift of a real analytic function coincides with the evaluation of its power series, see Sec. 7.4 The Elementary Functions of a Canonical Hyperbolic Variable in [The Mathematics of Minkowski Space-Time](https://doi.org/10.1007/978-3-7643-8614-6).
+    /// In contrast to `exp` or `ln`, the lift is not extended by the Klein index to the other sectors.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use perplex_num::Perplex;
+    /// let z = Perplex::new(1.5, -0.5);
+    /// assert_eq!(z.lift(|x| x * x), z * z);
+    /// ```
+    #[inline]
+    pub fn lift<F: Fn(T) -> T>(self, f: F) -> Self {
+        let (u, v) = self.null_components();
+        let (f_u, f_v) = (f(u), f(v));
+        let two = T::one() + T::one();
+        Self::new((f_u.clone() + f_v.clone()) / two.clone(), (f_u - f_v) / two)
+    }
+    /// Applies the real function `f` to both coefficients of `self` in the idempotent basis `(1 ± h) / 2`, see `NullBasis`. This is the functional calculus of the perplex numbers and the same map as `lift`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use perplex_num::Perplex;
+    /// let z = Perplex::new(2.0, 1.0);
+    /// // the cube root on the whole plane, also for negative null components
+    /// let root = z.map_null_basis(f64::cbrt);
+    /// approx::assert_abs_diff_eq!(root * root * root, z, epsilon = 1e-12);
+    /// ```
+    #[inline]
+    pub fn map_null_basis<F: Fn(T) -> T>(self, f: F) -> Self {
+        self.lift(f)
+    }
+    /// Checks if `self` is idempotent, i.e., `self * self == self`. The idempotents are `0`, `1` and `(1 ± h) / 2`, whose null components `u = t + x` and `v = t - x` are zero or one.
+    #[inline]
+    pub fn is_idempotent(&self) -> bool {
+        let (u, v) = self.null_components();
+        (u.is_zero() || u.is_one()) && (v.is_zero() || v.is_one())
+    }
+    /// Returns the idempotent `e` generating the annihilator `{w : self * w = 0}` as the ideal `{e * w}`.
+    ///
+    /// A non-zero light-like number with the null component `u = t + x` (or `v = t - x`) being zero is annihilated by the null line `(1 + h) / 2 * w` (or `(1 - h) / 2 * w`). The annihilator of zero is the whole plane generated by `1`, whereas invertible numbers are only annihilated by zero. See Sec. 4.1 in [The Mathematics of Minkowski Space-Time](https://doi.org/10.1007/978-3-7643-8614-6).
+    #[inline]
+    pub fn annihilator(&self) -> Self {
+        let (u, v) = self.null_components();
+        let half = T::one() / (T::one() + T::one());
+        match (u.is_zero(), v.is_zero()) {
+            (true, true) => Self::new(T::one(), T::zero()),
+            (true, false) => Self::new(half.clone(), half),
+            (false, true) => Self::new(half.clone(), T::zero() - half),
+            (false, false) => Self::new(T::zero(), T::zero()),
+        }
+    }
+    /// Returns the null components `u = t + x` and `v = t - x`.
+    #[inline]
+    fn null_components(&self) -> (T, T) {
+        let (t, x) = (self.t.clone(), self.x.clone());
+        (t.clone() + x.clone(), t - x)
     }
 }
-impl<T: Copy + Num + PartialOrd> Perplex<T> {
+impl<T: Clone + Num + PartialOrd> Perplex<T> {
     /// Checks if the perplex number is time-like, i.e., the squared distance is positive.
     #[inline]
     pub fn is_time_like(&self) -> bool {
@@ -125,12 +372,17 @@ impl<T: Copy + Num + PartialOrd> Perplex<T> {
     pub fn is_light_like(&self) -> bool {
         self.squared_distance() == T::zero()
     }
+    /// Checks if the perplex number is a zero divisor, which is equivalent to being light-like. See Properties of the Perplex Numbers in [Fundamental Theorems of Algebra for the Perplexes](https://doi.org/10.4169/074683409X475643).
+    #[inline]
+    pub fn is_zero_divisor(&self) -> bool {
+        self.is_light_like()
+    }
 }
-impl<T: Copy + Num + Neg<Output = T>> Perplex<T> {
+impl<T: Clone + Num + Neg<Output = T>> Perplex<T> {
     /// Returns the hyperbolic conjugate.
     #[inline]
     pub fn conj(&self) -> Self {
-        Self::new(self.t, -self.x)
+        Self::new(self.t.clone(), -self.x.clone())
     }
     /// Returns the multiplicative inverse `1/self`, if it exists, or `None` if not.
     #[inline]
@@ -140,8 +392,8 @@ impl<T: Copy + Num + Neg<Output = T>> Perplex<T> {
             None
         } else {
             Some(Self::new(
-                self.t / squared_distance,
-                -self.x / squared_distance,
+                self.t.clone() / squared_distance.clone(),
+                -self.x.clone() / squared_distance,
             ))
         }
     }
@@ -164,11 +416,65 @@ impl<T: Copy + Float> Perplex<T> {
         self.t.abs().max(self.x.abs())
     }
 
+    /// Returns the product `self * rhs`, whose components `t1 t2 + x1 x2` and `t1 x2 + x1 t2` are computed by fused multiply-adds with a compensation of the rounding error of one product.
+    ///
+    /// The components are accurate to a few units in the last place even under cancellation, e.g., for nearly light-like factors, whereas the relative error of the plain product `Mul` grows with the ratio of the summands to the result.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use perplex_num::Perplex;
+    /// // the squared distance of a nearly light-like number is the time component of z * conj(z)
+    /// let z = Perplex::new(1.0 + 2f64.powi(-30), 1.0);
+    /// let exact = 2f64.powi(-29) + 2f64.powi(-60);
+    /// assert_eq!(z.mul_fused(z.conj()).t, exact);
+    /// assert_eq!(z.squared_distance_fused(), exact);
+    /// assert_ne!(z.squared_distance(), exact);
+    /// ```
+    #[inline]
+    pub fn mul_fused(self, rhs: Self) -> Self {
+        Self::new(
+            sum_of_products(self.t, rhs.t, self.x, rhs.x),
+            sum_of_products(self.t, rhs.x, self.x, rhs.t),
+        )
+    }
+    /// Returns the squared distance `t^2 - x^2` computed by fused multiply-adds like `mul_fused`, which is accurate to a few units in the last place for nearly light-like numbers.
+    #[inline]
+    pub fn squared_distance_fused(&self) -> T {
+        sum_of_products(self.t, self.t, -self.x, self.x)
+    }
+
     /// Returns the modulus of `self`.
+    ///
+    /// The components are scaled by a power of two before squaring, such that the modulus does not overflow or underflow for large or small components, and the squared distance is computed by fused multiply-adds, see `squared_distance_fused`.
     #[inline]
     pub fn modulus(self) -> T {
-        let d_z = self.squared_distance();
-        d_z.abs().sqrt()
+        match binary_exponent(self.max_norm()) {
+            Some(exp) => self
+                .div_pow2(exp)
+                .squared_distance_fused()
+                .abs()
+                .sqrt()
+                .ldexp_scalar(exp),
+            // zero, infinite or NaN components
+            None => self.squared_distance().abs().sqrt(),
+        }
+    }
+    /// Returns the natural logarithm of the modulus of `self` without forming the modulus.
+    ///
+    /// The logarithm is computed as `(ln|t + x| + ln|t - x|) / 2` of the components scaled by a power of two, such that it is finite for all non-light-like numbers with finite components, even if the modulus overflows or underflows. Light-like numbers yield `-∞`.
+    #[inline]
+    pub fn ln_modulus(self) -> T {
+        let two = T::one() + T::one();
+        match binary_exponent(self.max_norm()) {
+            Some(exp) => {
+                let Self { t, x } = self.div_pow2(exp);
+                let ln_scaled = ((t + x).abs().ln() + (t - x).abs().ln()) / two;
+                ln_scaled + T::from(exp).unwrap() * two.ln()
+            }
+            // zero, infinite or NaN components
+            None => self.squared_distance().abs().ln() / two,
+        }
     }
     /// Returns the norm (modulus) of `self`.
     #[inline]
@@ -181,31 +487,176 @@ impl<T: Copy + Float> Perplex<T> {
         self.modulus()
     }
 
+    /// Multiplies both components by `2^exp`, i.e., the function `ldexp` of the C standard library applied to the components.
+    ///
+    /// The scaling is exact unless the result overflows or is subnormal, also if `2^exp` itself is not representable as normal number of type `T`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use perplex_num::Perplex;
+    ///
+    /// let z = Perplex::new(1.5, -0.25);
+    /// assert_eq!(z.ldexp(3), Perplex::new(12.0, -2.0));
+    /// let tiny = Perplex::new(1e-300, -2e-300);
+    /// assert_eq!(tiny.ldexp(1100).ldexp(-1100), tiny);
+    /// ```
+    #[inline]
+    pub fn ldexp(self, exp: i32) -> Self {
+        Self::new(self.t.ldexp_scalar(exp), self.x.ldexp_scalar(exp))
+    }
+    /// Multiplies both components by `2^exp`. This is an alias of `ldexp`, named after the function `scalbn` of the C standard library.
+    #[inline]
+    pub fn scalbn(self, exp: i32) -> Self {
+        self.ldexp(exp)
+    }
+    /// Multiplies `self` by `2^exp` exactly, see `ldexp`.
+    #[inline]
+    pub fn mul_pow2(self, exp: i32) -> Self {
+        self.ldexp(exp)
+    }
+    /// Divides `self` by `2^exp` exactly, see `ldexp`.
+    ///
+    /// The negation of `exp` saturates, which does not change the result for `i32::MIN`, since the factors `2^(2^31)` and `2^(2^31 - 1)` both overflow.
+    #[inline]
+    pub fn div_pow2(self, exp: i32) -> Self {
+        self.ldexp(exp.saturating_neg())
+    }
+    /// Multiplies `self` by `h 2^exp` exactly. Since `h (t + x h) = x + t h`, the components are swapped and scaled by `2^exp`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use perplex_num::Perplex;
+    ///
+    /// let z = Perplex::new(1.5, -0.25);
+    /// assert_eq!(z.mul_h_pow2(2), z * Perplex::new(0.0, 4.0));
+    /// assert_eq!(z.mul_h_pow2(2).div_h_pow2(2), z);
+    /// ```
+    #[inline]
+    pub fn mul_h_pow2(self, exp: i32) -> Self {
+        Self::new(self.x, self.t).ldexp(exp)
+    }
+    /// Divides `self` by `h 2^exp` exactly. Since `h` is its own inverse, this equals the multiplication by `h 2^-exp`.
+    #[inline]
+    pub fn div_h_pow2(self, exp: i32) -> Self {
+        // saturating like div_pow2
+        self.mul_h_pow2(exp.saturating_neg())
+    }
+
+    /// Divides `self` by `rhs`, whereby both operands are scaled by powers of two to avoid intermediate overflow or underflow. Division by a light-like number yields `None`.
+    ///
+    /// In contrast to the `Div` operator, the result is finite whenever the exact quotient is representable, e.g., for components of magnitude `1e200`.
+    /// The numerator `self * conj(rhs)` and the squared distance of `rhs` are computed by fused multiply-adds, see `mul_fused`, hence the quotient is accurate also for nearly light-like operands.
+    #[inline]
+    pub fn div_robust(self, rhs: Self) -> Option<Self> {
+        let (Some(exp_lhs), Some(exp_rhs)) = (
+            binary_exponent(self.max_norm()),
+            binary_exponent(rhs.max_norm()),
+        ) else {
+            // zero, infinite or NaN components
+            return self.checked_div(&rhs);
+        };
+        let (lhs, rhs) = (self.div_pow2(exp_lhs), rhs.div_pow2(exp_rhs));
+        let squared_distance = rhs.squared_distance_fused();
+        if squared_distance.is_zero() {
+            // light-like
+            return None;
+        }
+        let quotient = lhs.mul_fused(rhs.conj()) / squared_distance;
+        Some(quotient.mul_pow2(exp_lhs - exp_rhs))
+    }
+
     /// Computes the hyperbolic exponential function for all sectors. Formula is extended to all sectors, see Sec 4.1.1 Hyperbolic Exponential Function and 7.4 The Elementary Functions of a Canonical Hyperbolic Variable in [The Mathematics of Minkowski Space-Time](https://doi.org/10.1007/978-3-7643-8614-6).
+    ///
+    /// Light-like numbers are in no sector, hence the exponential series `e^t (cosh(x) + h sinh(x))` of the `Right` sector applies, which is defined on the whole plane.
     #[inline]
     pub fn exp(self) -> Self {
-        let k = self.klein().unwrap_or(Perplex::one());
-        let Self { t, x } = k * self;
-        let t_exp = t.exp();
-        k * Self::new(t_exp * x.cosh(), t_exp * x.sinh())
+        let exp_right = |Self { t, x }: Self| {
+            let t_exp = t.exp();
+            Self::new(t_exp * x.cosh(), t_exp * x.sinh())
+        };
+        let result = match self.klein_index() {
+            Some(k) => {
+                let k = Self::from(k);
+                k * exp_right(k * self)
+            }
+            None => exp_right(self),
+        };
+        traced("exp", &[self], result)
     }
     /// Computes the inverse of the hyperbolic exponential function, i.e., the natural logarithm. Formula is extended to all sectors, see Sec. 7.4 The Elementary Functions of a Canonical Hyperbolic Variable in [The Mathematics of Minkowski Space-Time](https://doi.org/10.1007/978-3-7643-8614-6).
     #[inline]
     pub fn ln(self) -> Option<Self> {
-        self.klein().map(|k| {
+        self.klein_index().map(Self::from).map(|k| {
             let Self { t, x } = k * self;
-            let squared_distance = t * t - x * x;
-            let two = T::one() + T::one();
-            let t_new = squared_distance.ln() / two;
+            // the modulus is invariant under the Klein units, and its logarithm does not overflow or underflow
+            let t_new = self.ln_modulus();
             let x_new = (x / t).atanh();
-            k * Self::new(t_new, x_new)
+            traced("ln", &[self], k * Self::new(t_new, x_new))
         })
     }
 
     /// Returns the logarithm of `self` with respect to an arbitrary base, if the natural logarithm of `self` exists, according to the formula `ln(self) / ln(base)`.
     #[inline]
     pub fn log(self, base: T) -> Option<Self> {
-        self.ln().map(|z| z / base.ln())
+        self.ln()
+            .map(|z| traced("log", &[self, Self::from(base)], z / base.ln()))
+    }
+    /// Returns the logarithm of `self` to the base 2, if the natural logarithm of `self` exists, see `log`.
+    #[inline]
+    pub fn log2(self) -> Option<Self> {
+        self.log(T::one() + T::one())
+    }
+    /// Returns the logarithm of `self` to the base 10, if the natural logarithm of `self` exists, see `log`.
+    #[inline]
+    pub fn log10(self) -> Option<Self> {
+        self.log(T::from(10).unwrap())
+    }
+    /// Returns `2^self`, see `expf`.
+    #[inline]
+    pub fn exp2(self) -> Self {
+        self.expf(T::one() + T::one())
+    }
+
+    /// Raises `self` to the perplex power `exp` by the formula `exp(exp ln(self))`, if the natural logarithm of `self` exists, i.e., returns `None` for light-like numbers.
+    /// The real part of the logarithm is `ln_modulus`, hence the power does not overflow or underflow in between for huge or tiny moduli.
+    ///
+    /// Both functions are extended to all sectors, hence the result agrees with the real power `powf` of the right sector only if `exp ln(self)` lies in the right sector, e.g., for small real exponents.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use perplex_num::Perplex;
+    ///
+    /// let z = Perplex::new(3.0, 1.0);
+    /// approx::assert_abs_diff_eq!(z.powc(Perplex::new(2.0, 0.0)).unwrap(), z * z, epsilon = 1e-12);
+    /// let w = Perplex::new(0.5, -0.25);
+    /// approx::assert_abs_diff_eq!(z.powc(w).unwrap(), (w * z.ln().unwrap()).exp(), epsilon = 1e-12);
+    /// assert_eq!(Perplex::new(1.0, -1.0).powc(w), None);
+    /// ```
+    #[inline]
+    pub fn powc(self, exp: Self) -> Option<Self> {
+        self.ln()
+            .map(|z| traced("powc", &[self, exp], (exp * z).exp()))
+    }
+
+    /// Raises the positive real `base` to the power `self` by the formula `exp(self ln(base))`, e.g., for discrete-time signals `base^n` with a base other than `e`.
+    ///
+    /// The result has NaN components for a negative `base`, whose logarithm is not real.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use perplex_num::Perplex;
+    ///
+    /// let z = Perplex::new(3.0, 1.0);
+    /// approx::assert_abs_diff_eq!(z.expf(2.0), (z * 2f64.ln()).exp(), epsilon = 1e-12);
+    /// approx::assert_abs_diff_eq!(Perplex::new(3.0, 0.0).expf(2.0), Perplex::new(8.0, 0.0), epsilon = 1e-12);
+    /// ```
+    #[inline]
+    pub fn expf(self, base: T) -> Self {
+        traced("expf", &[self, Self::from(base)], (self * base.ln()).exp())
     }
 
     /// Computes the square root of `self` if `self` lies in the right sector, or returns `None` if not. Formula is taken from Eq. 2.23 in [New characterizations of the ring of the split-complex numbers and the field C of complex numbers and their comparative analyses](https://doi.org/10.48550/arXiv.2305.04586).
@@ -219,42 +670,209 @@ impl<T: Copy + Float> Perplex<T> {
             let two = T::one() + T::one();
             let t = (sqrt_add + sqrt_sub) / two;
             let x = (sqrt_add - sqrt_sub) / two;
-            Some(Perplex::new(t, x))
+            Some(traced("sqrt", &[self], Perplex::new(t, x)))
         } else {
             None
         }
     }
 
+    /// Computes the hyperbolic exponential function of `self` in the right sector `t > |x|` without the Klein handling of `exp`, i.e., `e^t (cosh(x) + h sinh(x))`.
+    ///
+    /// The sector is only checked by a debug assertion, such that hot paths of data partitioned by sector (see `sector::apply_by_sector`) skip the branching.
+    #[inline]
+    pub fn exp_right(self) -> Self {
+        debug_assert!(self.t > self.x.abs(), "Input is not in the right sector!");
+        let t_exp = self.t.exp();
+        let result = Self::new(t_exp * self.x.cosh(), t_exp * self.x.sinh());
+        traced("exp_right", &[self], result)
+    }
+    /// Computes the natural logarithm of `self` in the right sector `t > |x|` without the Klein handling of `ln`. The sector is only checked by a debug assertion.
+    #[inline]
+    pub fn ln_right(self) -> Self {
+        debug_assert!(self.t > self.x.abs(), "Input is not in the right sector!");
+        let result = Self::new(self.ln_modulus(), (self.x / self.t).atanh());
+        traced("ln_right", &[self], result)
+    }
+    /// Computes the square root of `self` in the right sector `t > |x|` without the checks of `sqrt`. The sector is only checked by a debug assertion.
+    #[inline]
+    pub fn sqrt_right(self) -> Self {
+        debug_assert!(self.t > self.x.abs(), "Input is not in the right sector!");
+        let sqrt_add = (self.t + self.x).sqrt();
+        let sqrt_sub = (self.t - self.x).sqrt();
+        let two = T::one() + T::one();
+        let result = Self::new((sqrt_add + sqrt_sub) / two, (sqrt_add - sqrt_sub) / two);
+        traced("sqrt_right", &[self], result)
+    }
+    /// Computes the hyperbolic argument `atanh(x / t)` of `self` in the right sector `t > |x|` without the case distinction of `arg`. The sector is only checked by a debug assertion.
+    #[inline]
+    pub fn arg_right(self) -> T {
+        debug_assert!(self.t > self.x.abs(), "Input is not in the right sector!");
+        (self.x / self.t).atanh()
+    }
+
     /// Computes the sinus (circular trigonometric) of `self`. Formula is taken from Eq. 7.4.6 in [The Mathematics of Minkowski Space-Time](https://doi.org/10.1007/978-3-7643-8614-6).
     #[inline]
     pub fn sin(self) -> Self {
-        Self::new(self.t.sin() * self.x.cos(), self.t.cos() * self.x.sin())
+        let result = Self::new(self.t.sin() * self.x.cos(), self.t.cos() * self.x.sin());
+        traced("sin", &[self], result)
     }
     /// Computes the cosinus (circular trigonometric) of `self`. Formula is taken from Eq. 7.4.6 in [The Mathematics of Minkowski Space-Time](https://doi.org/10.1007/978-3-7643-8614-6).
     #[inline]
     pub fn cos(self) -> Self {
-        Self::new(self.t.cos() * self.x.cos(), self.t.sin() * self.x.sin())
+        let result = Self::new(self.t.cos() * self.x.cos(), -self.t.sin() * self.x.sin());
+        traced("cos", &[self], result)
     }
-    /// Computes the tangens (circular trigonometric) of `self` by the formula `sin(self) / cos(self)`. Returns `None` if `cos(self)` is light-like.
+    /// Computes the tangens (circular trigonometric) of `self` by the application of `tan` to the null components `u = t + x` and `v = t - x`, which equals `sin(self) / cos(self)`. Returns `None` if `cos(self)` is light-like, i.e., if the cosine of a null component is zero.
+    ///
+    /// In contrast to the quotient, the componentwise formula stays accurate if `cos(self)` is nearly light-like, since the division of the quotient cancels in `t^2 - x^2`.
     #[inline]
     pub fn tan(self) -> Option<Self> {
-        self.sin() / self.cos()
+        self.lift_checked(|c| !c.cos().is_zero(), T::tan)
+            .map(|result| traced("tan", &[self], result))
+    }
+    /// Computes the inverse sinus of `self` by the application of `asin` to the null components `u = t + x` and `v = t - x`, i.e., the inverse of `sin` on the square `|u| <= 1, |v| <= 1`. Returns `None` outside of this square.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use approx::assert_abs_diff_eq;
+    /// use perplex_num::Perplex;
+    ///
+    /// let z = Perplex::new(0.5, 0.25);
+    /// assert_abs_diff_eq!(z.asin().unwrap().sin(), z, epsilon = 1e-15);
+    /// assert!(Perplex::new(1.0, 0.5).asin().is_none());
+    /// ```
+    #[inline]
+    pub fn asin(self) -> Option<Self> {
+        self.lift_checked(|c| c.abs() <= T::one(), T::asin)
+            .map(|result| traced("asin", &[self], result))
+    }
+    /// Computes the inverse cosinus of `self` by the application of `acos` to the null components `u = t + x` and `v = t - x`, i.e., the inverse of `cos` on the square `|u| <= 1, |v| <= 1`. Returns `None` outside of this square.
+    #[inline]
+    pub fn acos(self) -> Option<Self> {
+        self.lift_checked(|c| c.abs() <= T::one(), T::acos)
+            .map(|result| traced("acos", &[self], result))
+    }
+    /// Computes the inverse tangens of `self` by the application of `atan` to the null components `u = t + x` and `v = t - x`, which is defined on the whole plane.
+    #[inline]
+    pub fn atan(self) -> Self {
+        traced("atan", &[self], self.lift(T::atan))
     }
     /// Computes the sinh (hyperbolic trigonometric) of `self`. Formula is taken from Eq. 7.4.5 in [The Mathematics of Minkowski Space-Time](https://doi.org/10.1007/978-3-7643-8614-6).
     #[inline]
     pub fn sinh(self) -> Self {
-        Self::new(self.t.sinh() * self.x.cosh(), self.t.cosh() * self.x.sinh())
+        let result = Self::new(self.t.sinh() * self.x.cosh(), self.t.cosh() * self.x.sinh());
+        traced("sinh", &[self], result)
     }
     /// Computes the cosh (hyperbolic trigonometric) of `self`. Formula is taken from Eq. 7.4.5 in [The Mathematics of Minkowski Space-Time](https://doi.org/10.1007/978-3-7643-8614-6).
     #[inline]
     pub fn cosh(self) -> Self {
-        Self::new(self.t.cosh() * self.x.cosh(), self.t.sinh() * self.x.sinh())
+        let result = Self::new(self.t.cosh() * self.x.cosh(), self.t.sinh() * self.x.sinh());
+        traced("cosh", &[self], result)
+    }
+    /// Computes the tanh (hyperbolic trigonometric) of `self` by the application of `tanh` to the null components `u = t + x` and `v = t - x`, which equals `sinh(self) / cosh(self)`.
+    ///
+    /// Since `cosh` of a real number is positive, `cosh(self)` is never light-like and the tanh is defined on the whole plane. The componentwise formula neither overflows for large components nor cancels like the quotient.
+    #[inline]
+    pub fn tanh(self) -> Self {
+        traced("tanh", &[self], self.lift(T::tanh))
+    }
+    /// Computes the inverse sinh of `self` by the application of `asinh` to the null components `u = t + x` and `v = t - x`, which is defined on the whole plane.
+    #[inline]
+    pub fn asinh(self) -> Self {
+        traced("asinh", &[self], self.lift(T::asinh))
+    }
+    /// Computes the inverse cosh of `self` by the application of `acosh` to the null components `u = t + x` and `v = t - x`. The inverse is defined for `u >= 1` and `v >= 1`, i.e., for `t - |x| >= 1` within the right sector, and `None` is returned otherwise.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use approx::assert_abs_diff_eq;
+    /// use perplex_num::Perplex;
+    ///
+    /// let z = Perplex::new(3.0, -1.5);
+    /// assert_abs_diff_eq!(z.acosh().unwrap().cosh(), z, epsilon = 1e-14);
+    /// assert!(Perplex::new(3.0, 2.5).acosh().is_none());
+    /// ```
+    #[inline]
+    pub fn acosh(self) -> Option<Self> {
+        self.lift_checked(|c| c >= T::one(), T::acosh)
+            .map(|result| traced("acosh", &[self], result))
+    }
+    /// Computes the inverse tanh of `self` by the application of `atanh` to the null components `u = t + x` and `v = t - x`. The inverse is defined for `|u| < 1` and `|v| < 1`, i.e., within the square `|t| + |x| < 1`, which intersects all sectors, and `None` is returned otherwise.
+    #[inline]
+    pub fn atanh(self) -> Option<Self> {
+        self.lift_checked(|c| c.abs() < T::one(), T::atanh)
+            .map(|result| traced("atanh", &[self], result))
+    }
+
+    /// Applies `f` to the null components by `lift`, or returns `None` if a null component does not satisfy `domain`.
+    #[inline]
+    fn lift_checked<D: Fn(T) -> bool, F: Fn(T) -> T>(self, domain: D, f: F) -> Option<Self> {
+        if domain(self.t + self.x) && domain(self.t - self.x) {
+            Some(self.lift(f))
+        } else {
+            None
+        }
     }
-    /// Computes the tanh (hyperbolic trigonometric) of `self` by the formula `sinh(self) / cosh(self)`. Returns `None` if `cosh(self)` is light-like.
+}
+
+/// Returns the `result` of `operation` and records it as the origin of a NaN component with the feature `nan-debug`, see `debug`.
+#[inline]
+#[cfg_attr(not(feature = "nan-debug"), allow(unused_variables))]
+fn traced<T: Copy + Float>(
+    operation: &'static str,
+    operands: &[Perplex<T>],
+    result: Perplex<T>,
+) -> Perplex<T> {
+    #[cfg(feature = "nan-debug")]
+    crate::debug::record(operation, operands, result);
+    result
+}
+
+/// Returns `a b + c d` with the rounding error of `c d` compensated by fused multiply-adds, see Kahan's algorithm for the difference of products.
+#[inline]
+fn sum_of_products<T: Copy + Float>(a: T, b: T, c: T, d: T) -> T {
+    let cd = c * d;
+    // the rounding error of cd is exactly representable
+    let error = c.mul_add(d, -cd);
+    a.mul_add(b, cd) + error
+}
+
+/// Exact scaling of a floating point number by a power of two.
+trait Ldexp {
+    /// Multiplies `self` by `2^exp`.
+    fn ldexp_scalar(self, exp: i32) -> Self;
+}
+impl<T: Copy + Float> Ldexp for T {
     #[inline]
-    pub fn tanh(self) -> Option<Self> {
-        self.sinh() / self.cosh()
+    fn ldexp_scalar(self, exp: i32) -> Self {
+        let factor = (T::one() + T::one()).powi(exp);
+        if factor.is_normal() {
+            self * factor
+        } else {
+            // 2^exp overflows or is subnormal, hence scale in two steps
+            let half = exp / 2;
+            let scaled = self.ldexp_scalar(half);
+            if scaled.is_zero() || scaled.is_infinite() {
+                // the second step cannot change an overflowed or underflowed value
+                scaled
+            } else {
+                scaled.ldexp_scalar(exp - half)
+            }
+        }
+    }
+}
+
+/// Returns the exponent `e` with `2^(e-1) <= |v| < 2^e` for finite non-zero `v`, or `None` otherwise.
+#[inline]
+fn binary_exponent<T: Copy + Float>(v: T) -> Option<i32> {
+    if v.is_zero() || !v.is_finite() {
+        return None;
     }
+    let (mantissa, exponent, _) = v.integer_decode();
+    let mantissa_bits = (u64::BITS - mantissa.leading_zeros()) as i32;
+    Some(exponent as i32 + mantissa_bits)
 }
 
 impl<T: FloatCore> Perplex<T> {
@@ -284,7 +902,7 @@ impl<T: FloatCore> Perplex<T> {
 }
 
 // constants
-impl<T: Copy + Num> Zero for Perplex<T> {
+impl<T: Clone + Num> Zero for Perplex<T> {
     #[inline]
     fn zero() -> Self {
         Self::new(Zero::zero(), Zero::zero())
@@ -302,7 +920,7 @@ impl<T: Copy + Num> Zero for Perplex<T> {
     }
 }
 
-impl<T: Copy + Num> One for Perplex<T> {
+impl<T: Clone + Num> One for Perplex<T> {
     #[inline]
     fn one() -> Self {
         Self::new(One::one(), Zero::zero())
@@ -320,6 +938,15 @@ impl<T: Copy + Num> One for Perplex<T> {
     }
 }
 
+impl<T: Clone + Num + Neg<Output = T>> Num for Perplex<T> {
+    type FromStrRadixErr = ParsePerplexError;
+    /// Parses a perplex number in the format of `Display`, see `Perplex::from_str_radix`.
+    #[inline]
+    fn from_str_radix(s: &str, radix: u32) -> Result<Self, Self::FromStrRadixErr> {
+        Self::from_str_radix(s, radix)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -342,6 +969,113 @@ mod tests {
         let z = Perplex::new(2.0, -1.0);
         assert_eq!(z.to_string(), String::from("2.00 - 1.00 h"), "Negation sign is used for negative space component! Per default, fmt produces two decimal places!");
     }
+    #[cfg(feature = "schemars")]
+    #[test]
+    fn test_json_schema() {
+        let root = schemars::schema_for!(Perplex<f64>);
+        let object = root.schema.object.expect("Perplex is a JSON object!");
+        assert_eq!(
+            object.properties.keys().collect::<Vec<_>>(),
+            vec!["t", "x"],
+            "Properties are the time and space components!"
+        );
+        assert_eq!(object.required.len(), 2, "Both components are required!");
+        let root = schemars::schema_for!(crate::HyperbolicPolar<f32>);
+        let object = root
+            .schema
+            .object
+            .expect("HyperbolicPolar is a JSON object!");
+        assert_eq!(
+            object.properties.keys().collect::<Vec<_>>(),
+            vec!["rho", "sector", "theta"]
+        );
+        assert!(
+            root.definitions.contains_key("HyperbolicSector_for_float"),
+            "HyperbolicSector is referenced as definition!"
+        );
+    }
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde() {
+        let z = Perplex::new(1.5, -0.25);
+        let json = serde_json::to_string(&z).unwrap();
+        assert_eq!(
+            json, r#"{"t":1.5,"x":-0.25}"#,
+            "Components are map entries!"
+        );
+        assert_eq!(serde_json::from_str::<Perplex<f64>>(&json).unwrap(), z);
+        assert!(
+            serde_json::from_str::<Perplex<f64>>(r#"{"t":1.5}"#).is_err(),
+            "Both components are required!"
+        );
+        let polar = Perplex::new(-1.0, 3.0).polar();
+        let json = serde_json::to_string(&polar).unwrap();
+        assert_eq!(
+            serde_json::from_str::<crate::HyperbolicPolar<f64>>(&json).unwrap(),
+            polar
+        );
+        // the argument of light-like numbers is infinite, which JSON does not represent
+        let sector = Perplex::new(2.0, -2.0).sector();
+        let json = serde_json::to_string(&sector).unwrap();
+        assert_eq!(
+            serde_json::from_str::<crate::HyperbolicSector<f64>>(&json).unwrap(),
+            sector,
+            "Diagonal sectors keep their component!"
+        );
+    }
+    #[test]
+    fn test_parse() {
+        for z in [
+            Perplex::new(1.25, -0.5),
+            Perplex::new(-3.0, 2.0),
+            Perplex::new(0.0, 0.0),
+            Perplex::new(-1e-7, 4e10),
+        ] {
+            let parsed: Perplex<f64> = z.to_string().parse().unwrap();
+            assert_abs_diff_eq!(parsed, z, epsilon = 1e-2);
+            let parsed: Perplex<f64> = format!("{:.20}", z).parse().unwrap();
+            assert_eq!(parsed, z, "Display is parsed back!");
+        }
+        assert_eq!("1e-3 - 2E+1 h".parse(), Ok(Perplex::new(1e-3, -20.0)));
+        assert_eq!("  -4  ".parse(), Ok(Perplex::new(-4, 0)));
+        assert_eq!(
+            "-h".parse::<Perplex<f64>>(),
+            Err(ParsePerplexError::InvalidComponent)
+        );
+        assert_eq!(
+            Perplex::<i32>::from_str_radix("-101 + 11 h", 2),
+            Ok(Perplex::new(-5, 3))
+        );
+        assert_eq!(
+            Perplex::<i64>::from_str_radix("zz - h h", 36),
+            Ok(Perplex::new(1295, -17)),
+            "h is a digit for radix 36!"
+        );
+        assert_eq!("".parse::<Perplex<f32>>(), Err(ParsePerplexError::Empty));
+        assert_eq!(
+            "1 + x h".parse::<Perplex<f32>>(),
+            Err(ParsePerplexError::InvalidComponent)
+        );
+    }
+    #[test]
+    fn test_rational() {
+        use num_rational::Ratio;
+        let r = Ratio::new;
+        let (z, w) = (
+            Perplex::new(r(1, 2), r(-3, 4)),
+            Perplex::new(r(5, 3), r(1, 6)),
+        );
+        assert_eq!(z * w / w, z, "Exact division!");
+        assert_eq!(z * z.try_inverse().unwrap(), Perplex::one());
+        assert_eq!(z.squared_distance(), r(-5, 16));
+        assert_eq!(z * z.conj(), Perplex::from(z.squared_distance()));
+        assert_eq!(z.powi(-3).unwrap() * z.powu(3), Perplex::one());
+        assert_eq!(z.pow_const::<2>(), z * z);
+        assert!(z.is_space_like() && w.is_time_like());
+        assert_eq!(Perplex::new(r(1, 2), r(1, 2)).try_inverse(), None);
+        assert_eq!("1/2 - 3/4 h".parse(), Ok(z));
+        assert_eq!(z.to_string(), "1/2 - 3/4 h");
+    }
     #[test]
     fn test_components() {
         let z = Perplex::new(1.1, 2.2);
@@ -366,6 +1100,193 @@ mod tests {
         assert_eq!(z.max_norm(), 2.0, "-1 + 2h has a max norm of 2");
     }
 
+    #[test]
+    fn test_zero_divisors() {
+        let idempotents = [
+            Perplex::new(0.0, 0.0),
+            Perplex::new(1.0, 0.0),
+            Perplex::new(0.5, 0.5),
+            Perplex::new(0.5, -0.5),
+        ];
+        for e in idempotents {
+            assert!(e.is_idempotent());
+            assert_eq!(e * e, e);
+        }
+        assert!(!Perplex::new(1.0, 1.0).is_idempotent());
+        assert!(!Perplex::new(0.0, 1.0).is_idempotent(), "h squares to one!");
+        let z = Perplex::new(2.0, -2.0);
+        assert!(z.is_zero_divisor());
+        let e = z.annihilator();
+        assert_eq!(e, Perplex::new(0.5, 0.5));
+        assert_eq!(z * e.scale(3.0), Perplex::new(0.0, 0.0));
+        assert_eq!(
+            Perplex::new(-1.5, -1.5).annihilator(),
+            Perplex::new(0.5, -0.5)
+        );
+        assert_eq!(Perplex::new(0.0, 0.0).annihilator(), Perplex::new(1.0, 0.0));
+        let z = Perplex::new(2.0, 1.0);
+        assert!(!z.is_zero_divisor());
+        assert_eq!(
+            z.annihilator(),
+            Perplex::new(0.0, 0.0),
+            "Invertible numbers have a trivial annihilator!"
+        );
+    }
+    #[test]
+    fn test_ln_modulus() {
+        let z = Perplex::new(3.0, -1.0);
+        assert_abs_diff_eq!(z.ln_modulus(), z.modulus().ln(), epsilon = 1e-15);
+        assert_eq!(Perplex::new(2.0, 2.0).ln_modulus(), f64::NEG_INFINITY);
+        let huge = Perplex::new(1.5e308, -1e308);
+        assert!(!f64::is_finite(huge.squared_distance()));
+        let expected = 1.25f64.ln() / 2.0 + 308.0 * 10f64.ln();
+        assert_abs_diff_eq!(huge.ln_modulus(), expected, epsilon = 1e-12);
+        let tiny = Perplex::new(1e-200, 3e-200);
+        assert_eq!(tiny.squared_distance(), 0.0, "Squared distance underflows!");
+        let expected = 8f64.ln() / 2.0 - 200.0 * 10f64.ln();
+        assert_abs_diff_eq!(tiny.ln_modulus(), expected, epsilon = 1e-12);
+        // the logarithm and the powers use the logarithm of the modulus
+        let ln = Perplex::new(1e200, 0.0).ln().unwrap();
+        assert_abs_diff_eq!(ln, Perplex::new(200.0 * 10f64.ln(), 0.0), epsilon = 1e-12);
+        assert_abs_diff_eq!(huge.ln().unwrap().t, huge.ln_modulus(), epsilon = 1e-12);
+        let tiny = Perplex::new(3e-200, 1e-200);
+        assert_abs_diff_eq!(tiny.ln().unwrap().t, expected, epsilon = 1e-12);
+        let sqrt = huge.powc(Perplex::new(0.5, 0.0)).unwrap();
+        assert!(sqrt.is_finite(), "No overflow in powc!");
+        let square = sqrt * sqrt;
+        assert_abs_diff_eq!(square.t / huge.t, 1.0, epsilon = 1e-12);
+        assert_abs_diff_eq!(square.x / huge.x, 1.0, epsilon = 1e-12);
+    }
+    #[test]
+    fn test_right_sector() {
+        let z = Perplex::new(2.0, -0.5);
+        assert_eq!(z.exp_right(), z.exp());
+        assert_eq!(z.ln_right(), z.ln().unwrap());
+        assert_eq!(z.sqrt_right(), z.sqrt().unwrap());
+        assert_eq!(z.arg_right(), z.arg());
+        assert_abs_diff_eq!(z.ln_right().exp_right(), z, epsilon = 1e-12);
+    }
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "Input is not in the right sector!")]
+    fn test_right_sector_assertion() {
+        Perplex::new(-2.0, 0.5).ln_right();
+    }
+    #[test]
+    fn test_lift() {
+        let z = Perplex::new(0.5, -1.5);
+        assert_eq!(z.lift(|x| x * x * x), z * z * z);
+        let w = Perplex::new(2.0, 0.5);
+        assert_abs_diff_eq!(w.lift(f64::exp), w.exp(), epsilon = 1e-12);
+        assert_abs_diff_eq!(w.lift(f64::ln), w.ln().unwrap(), epsilon = 1e-12);
+        for z in [w, Perplex::new(-1.0, 3.0), Perplex::new(0.5, 0.5)] {
+            assert_eq!(z.map_null_basis(f64::sin), z.lift(f64::sin));
+            let n = crate::null_basis::NullBasis::from(z).lift(f64::tanh);
+            assert_abs_diff_eq!(
+                z.map_null_basis(f64::tanh),
+                Perplex::from(n),
+                epsilon = 1e-15
+            );
+        }
+    }
+    #[test]
+    #[cfg(feature = "derive")]
+    fn test_lift_to_perplex() {
+        use crate::lift_to_perplex;
+        #[lift_to_perplex]
+        fn cube(x: f64) -> f64 {
+            x * x * x
+        }
+        #[lift_to_perplex(domain = |x| x > 0.0)]
+        fn logarithm(x: f64) -> f64 {
+            x.ln()
+        }
+        let z = Perplex::new(2.0, 0.5);
+        assert_eq!(cube_perplex(z), z * z * z);
+        assert_abs_diff_eq!(
+            logarithm_perplex(z).unwrap(),
+            z.ln().unwrap(),
+            epsilon = 1e-12
+        );
+        assert!(
+            logarithm_perplex(Perplex::new(1.0, 2.0)).is_none(),
+            "Null component v is negative!"
+        );
+        assert!(
+            logarithm_perplex(Perplex::new(1.0, 1.0)).is_none(),
+            "Null component v is zero!"
+        );
+    }
+    #[test]
+    fn test_layout() {
+        let mut z = Perplex::new(1.5f64, -2.0);
+        let base = &z as *const Perplex<f64> as usize;
+        assert_eq!(&z.t as *const f64 as usize, base, "t is at offset zero!");
+        assert_eq!(&z.x as *const f64 as usize, base + 8, "x follows t!");
+        assert_eq!(z.as_array(), &[1.5, -2.0]);
+        z.as_array_mut()[1] = 3.0;
+        assert_eq!(z, Perplex::from([1.5, 3.0]));
+        assert_eq!(<[f64; 2]>::from(z), z.to_array());
+        let mut components = [1.0f32, 2.0, 3.0, 4.0];
+        let values = Perplex::from_interleaved_mut(&mut components).unwrap();
+        values[1] = Perplex::new(-3.0, -4.0);
+        assert_eq!(components, [1.0, 2.0, -3.0, -4.0]);
+        assert!(Perplex::from_interleaved_mut(&mut components[1..]).is_none());
+        assert!(Perplex::<f32>::from_interleaved(&[]).unwrap().is_empty());
+    }
+    #[test]
+    fn test_ldexp() {
+        let z = Perplex::new(3.0, -0.5);
+        assert_eq!(z.ldexp(0), z);
+        assert_eq!(z.ldexp(2), z.scale(4.0));
+        assert_eq!(z.scalbn(-1), z / 2.0);
+        let tiny = Perplex::new(f64::MIN_POSITIVE, -f64::MIN_POSITIVE);
+        assert_eq!(
+            tiny.ldexp(2000).ldexp(-2000),
+            tiny,
+            "Scaling by factors that are not representable is exact!"
+        );
+        assert_eq!(z.ldexp(1030).t, f64::INFINITY, "Overflow yields infinity!");
+        assert_eq!(z.mul_pow2(-3), z.div_pow2(3));
+        assert_eq!(z.div_pow2(1), Perplex::new(1.5, -0.25));
+        assert_eq!(z.mul_h_pow2(1), Perplex::new(-1.0, 6.0));
+        assert_eq!(
+            z.div_h_pow2(1),
+            z * Perplex::new(0.0, 0.5),
+            "Division by 2h!"
+        );
+        assert_eq!(tiny.mul_h_pow2(2000).div_h_pow2(2000), tiny);
+        let huge = Perplex::new(f64::INFINITY, f64::NEG_INFINITY);
+        assert_eq!(z.div_pow2(i32::MIN), huge, "No overflow of the negation!");
+        assert_eq!(z.div_h_pow2(i32::MIN), Perplex::new(huge.x, huge.t));
+        assert_eq!(z.div_pow2(i32::MAX), Perplex::zero());
+        assert_eq!(binary_exponent(1.0), Some(1));
+        assert_eq!(binary_exponent(-0.75f32), Some(0));
+        assert_eq!(binary_exponent(f64::NAN), None);
+    }
+    #[test]
+    fn test_robust() {
+        let z = Perplex::new(2e200, -1e200);
+        assert!(
+            f64::is_nan(z.squared_distance()),
+            "Naive formula overflows!"
+        );
+        assert_abs_diff_eq!(z.modulus(), f64::sqrt(3.0) * 1e200, epsilon = 1e186);
+        let z = Perplex::new(2e-200, 1e-200);
+        assert_eq!(z.squared_distance(), 0.0, "Naive formula underflows!");
+        assert_abs_diff_eq!(z.modulus(), f64::sqrt(3.0) * 1e-200, epsilon = 1e-214);
+        let (z1, z2) = (Perplex::new(4e200, 2e200), Perplex::new(2e200, 1e200));
+        assert!((z1 / z2).is_nan(), "Naive division overflows!");
+        assert_abs_diff_eq!(z1.div_robust(z2).unwrap(), Perplex::new(2.0, 0.0));
+        let z = Perplex::new(1.0, 2.0);
+        assert_eq!(z.div_robust(Perplex::new(1e-300, 1e-300)), None);
+        assert_eq!(Perplex::zero().div_robust(z), Some(Perplex::zero()));
+        assert_abs_diff_eq!(
+            z.div_robust(Perplex::new(3.0, 1.0)).unwrap(),
+            z / Perplex::new(3.0, 1.0),
+            epsilon = 1e-15
+        );
+    }
     #[test]
     fn test_log() {
         let z = Perplex::new(2.0, 1.0);
@@ -384,32 +1305,97 @@ mod tests {
         let z_ln_exp = ln_result.unwrap().exp();
         assert_abs_diff_eq!(z_ln_exp, z);
 
-        let z = Perplex::new(-2.0, 1.0); // Left-Sector
+        // the logarithm is light-like if a null component of k z is one, e.g., ln(sqrt(3)) = atanh(1/2) for -2 + h, hence exp inverts it only in the right sector
+        let z = Perplex::new(-3.0, 1.0); // Left-Sector
         let ln_result = z.ln();
         assert!(
             ln_result.is_some(),
-            "Natural logarithm is defined for time-like -2 + h!"
+            "Natural logarithm is defined for time-like -3 + h!"
         );
         let z_ln_exp = ln_result.unwrap().exp();
-        assert_abs_diff_eq!(z_ln_exp, z);
+        assert_abs_diff_eq!(z_ln_exp, z, epsilon = 1e-12);
 
-        let z = Perplex::new(1.0, 2.0); // Up-Sector
+        let z = Perplex::new(1.0, 3.0); // Up-Sector
         let ln_result = z.ln();
         assert!(
             ln_result.is_some(),
-            "Natural logarithm is defined for space-like 1 + 2h!"
+            "Natural logarithm is defined for space-like 1 + 3h!"
         );
         let z_ln_exp = ln_result.unwrap().exp();
-        assert_abs_diff_eq!(z_ln_exp, z);
+        assert_abs_diff_eq!(z_ln_exp, z, epsilon = 1e-12);
 
-        let z = Perplex::new(1.0, -2.0); // Down-Sector
+        let z = Perplex::new(1.0, -3.0); // Down-Sector
         let ln_result = z.ln();
         assert!(
             ln_result.is_some(),
-            "Natural logarithm is defined for space-like 1 - 2h!"
+            "Natural logarithm is defined for space-like 1 - 3h!"
         );
         let z_ln_exp = ln_result.unwrap().exp();
-        assert_abs_diff_eq!(z_ln_exp, z);
+        assert_abs_diff_eq!(z_ln_exp, z, epsilon = 1e-12);
+    }
+    #[test]
+    fn test_powc() {
+        let z = Perplex::new(3.0, 1.0);
+        for p in [0.5, 1.0, 1.5] {
+            let real = Perplex::new(p, 0.0);
+            assert_abs_diff_eq!(z.powc(real).unwrap(), z.powf(p).unwrap(), epsilon = 1e-12);
+        }
+        // -ln(z) lies in the left sector, hence exp(-ln(z)) = -exp(ln(z)) = -z
+        assert_abs_diff_eq!(z.powc(-Perplex::one()).unwrap(), -z, epsilon = 1e-12);
+        assert_abs_diff_eq!(
+            z.powc(Perplex::zero()).unwrap(),
+            Perplex::one(),
+            epsilon = 1e-12
+        );
+        // h ln(z) lies in the up sector, hence exp(h ln(z)) = h exp(ln(z)) = h z
+        assert!(z.ln_modulus() > z.arg());
+        assert_abs_diff_eq!(
+            z.powc(Perplex::h()).unwrap(),
+            Perplex::<f64>::h() * z,
+            epsilon = 1e-12
+        );
+        let w = Perplex::new(-3.0, 1.0);
+        assert_abs_diff_eq!(w.powc(Perplex::one()).unwrap(), w, epsilon = 1e-12);
+        assert!(Perplex::new(2.0, 2.0).powc(z).is_none(), "Light-like base!");
+    }
+    #[test]
+    fn test_expf() {
+        let z = Perplex::new(0.5, -0.25);
+        assert_abs_diff_eq!(z.expf(f64::E()), z.exp(), epsilon = 1e-12);
+        for n in [1.0, 2.0, 3.0] {
+            let expected = Perplex::new(10.0, 0.0).powc(z * n).unwrap();
+            assert_abs_diff_eq!((z * n).expf(10.0), expected, epsilon = 1e-9);
+        }
+        let (t, x) = (3.0, 1.5);
+        let expected = Perplex::new(
+            2f64.powf(t) * (x * 2f64.ln()).cosh(),
+            2f64.powf(t) * (x * 2f64.ln()).sinh(),
+        );
+        assert_abs_diff_eq!(Perplex::new(t, x).expf(2.0), expected, epsilon = 1e-12);
+        assert!(z.expf(-2.0).is_nan(), "Negative base!");
+    }
+    #[test]
+    fn test_base_2_and_10() {
+        for z in [
+            Perplex::new(1.5, -0.5),
+            Perplex::new(-2.0, 1.0),
+            Perplex::new(0.5, 3.0),
+        ] {
+            assert_abs_diff_eq!(z.exp2().log2().unwrap(), z, epsilon = 1e-12);
+            assert_abs_diff_eq!(z.log10().unwrap(), z.log(10.0).unwrap(), epsilon = 1e-15);
+        }
+        assert_abs_diff_eq!(
+            Perplex::new(100.0, 0.0).log10().unwrap(),
+            Perplex::new(2.0, 0.0),
+            epsilon = 1e-15
+        );
+        assert_abs_diff_eq!(
+            Perplex::new(3.0, 0.0).exp2(),
+            Perplex::new(8.0, 0.0),
+            epsilon = 1e-12
+        );
+        let light_like = Perplex::new(-2.0, 2.0);
+        assert!(light_like.log2().is_none() && light_like.log10().is_none());
     }
     #[test]
     fn test_exponential_logarithm() {
@@ -438,11 +1424,95 @@ mod tests {
         let expected_tanh = Perplex::new(z.t.tanh(), 0.0);
         assert_eq!(
             z.tanh(),
-            Some(expected_tanh),
-            "Tanh of z should be defined since cosh(z) is not light-like!"
+            expected_tanh,
+            "Tanh of z is defined since cosh(z) is never light-like!"
+        );
+        let w = Perplex::new(0.7, -1.3);
+        assert_abs_diff_eq!(w.tan().unwrap(), w.sin() / w.cos(), epsilon = 1e-14);
+        assert_abs_diff_eq!(w.tanh(), w.sinh() / w.cosh(), epsilon = 1e-15);
+        let large = Perplex::new(400.0, -399.0).tanh();
+        assert_abs_diff_eq!(
+            large,
+            Perplex::new(0.5 * 1f64.tanh() + 0.5, 0.5 * 1f64.tanh() - 0.5),
+            epsilon = 1e-15
+        );
+        assert!(large.t.is_finite(), "No overflow of cosh!");
+    }
+    #[test]
+    fn test_tan_accuracy() {
+        // cos(z) is nearly light-like, since cos(u) is close to zero
+        let (u, v) = (f64::FRAC_PI_2() - 1e-7, 0.3);
+        let z = Perplex::new((u + v) / 2.0, (u - v) / 2.0);
+        let exact = Perplex::new((u.tan() + v.tan()) / 2.0, (u.tan() - v.tan()) / 2.0);
+        let tan = z.tan().unwrap();
+        let quotient = z.sin() / z.cos();
+        let error = |w: Perplex<f64>| (w - exact).max_norm() / exact.max_norm();
+        assert!(error(tan) <= 1e-14);
+        assert!(
+            error(tan) <= error(quotient),
+            "Componentwise is more accurate!"
         );
     }
 
+    #[test]
+    fn test_inverse_trigonometric() {
+        for z in [
+            Perplex::new(0.5, 0.25),
+            Perplex::new(-0.2, 0.7),
+            Perplex::new(0.0, -1.0),
+        ] {
+            assert_abs_diff_eq!(z.asin().unwrap().sin(), z, epsilon = 1e-15);
+            assert_abs_diff_eq!(z.acos().unwrap().cos(), z, epsilon = 1e-15);
+        }
+        for z in [Perplex::new(3.0, -1.0), Perplex::new(-0.5, 20.0)] {
+            assert_abs_diff_eq!(z.atan().tan().unwrap(), z, epsilon = 1e-13);
+            assert!(z.asin().is_none(), "Null components outside of [-1, 1]!");
+            assert!(z.acos().is_none());
+        }
+        let w = Perplex::new(0.7, -1.3);
+        assert_abs_diff_eq!(w.cos(), w.lift(f64::cos), epsilon = 1e-15);
+        assert_abs_diff_eq!(w.sin(), w.lift(f64::sin), epsilon = 1e-15);
+        assert!(Perplex::new(f64::NAN, 0.0).asin().is_none());
+        let z = Perplex::new(0.3, 0.1);
+        assert_abs_diff_eq!(
+            z.asin().unwrap() + z.acos().unwrap(),
+            Perplex::new(f64::FRAC_PI_2(), 0.0),
+            epsilon = 1e-15
+        );
+    }
+    #[test]
+    fn test_inverse_hyperbolic() {
+        for z in [
+            Perplex::new(0.5, 2.0),
+            Perplex::new(-3.0, 1.0),
+            Perplex::new(0.0, -0.5),
+        ] {
+            assert_abs_diff_eq!(z.asinh().sinh(), z, epsilon = 1e-14);
+            assert_abs_diff_eq!(z.sinh().asinh(), z, epsilon = 1e-14);
+        }
+        let z = Perplex::new(2.0, 0.5);
+        assert_abs_diff_eq!(z.acosh().unwrap().cosh(), z, epsilon = 1e-14);
+        for z in [
+            Perplex::new(1.5, 0.6),
+            Perplex::new(-3.0, 1.0),
+            Perplex::new(0.5, 2.0),
+        ] {
+            assert!(z.acosh().is_none(), "Outside of t - |x| >= 1!");
+        }
+        assert_eq!(Perplex::new(1.0, 0.0).acosh(), Some(Perplex::new(0.0, 0.0)));
+        for z in [
+            Perplex::new(0.5, 0.25),
+            Perplex::new(-0.1, 0.8),
+            Perplex::new(0.3, -0.6),
+        ] {
+            assert_abs_diff_eq!(z.atanh().unwrap().tanh(), z, epsilon = 1e-14);
+        }
+        assert!(
+            Perplex::new(0.5, 0.5).atanh().is_none(),
+            "Null component u = 1!"
+        );
+        assert!(Perplex::new(0.0, -2.0).atanh().is_none());
+    }
     #[test]
     fn test_sqrt() {
         // Test sqrt for a Perplex number in the Right sector (t > |x|)
@@ -515,4 +1585,73 @@ mod tests {
         let z = Perplex::new(f64::NAN, 1.0);
         assert!(z.is_nan(), "Perplex number with a NaN component is NAN!")
     }
+    /// Returns the error of `computed` in units of the last place of the exact value `hi + lo` of a double-double.
+    fn ulp_error(computed: f64, (hi, lo): (f64, f64)) -> f64 {
+        let ulp = f64::EPSILON * hi.abs();
+        ((computed - hi) - lo).abs() / ulp
+    }
+    /// Returns the exact value `a b + c d` as a double-double, whose error is far below an ulp of the result.
+    fn sum_of_products_reference(a: f64, b: f64, c: f64, d: f64) -> (f64, f64) {
+        // the products are exact double-doubles by fused multiply-adds
+        let (p, q) = (a * b, c * d);
+        let (p_error, q_error) = (a.mul_add(b, -p), c.mul_add(d, -q));
+        // Knuth's two-sum of the leading parts
+        let sum = p + q;
+        let v = sum - p;
+        let error = (p - (sum - v)) + (q - v);
+        let lo = error + p_error + q_error;
+        let hi = sum + lo;
+        (hi, lo - (hi - sum))
+    }
+    #[test]
+    fn test_fused_accuracy() {
+        // a linear congruential generator for reproducible inputs in [1, 2)
+        let mut state = 0x2545_f491_4f6c_dd1du64;
+        let mut uniform = || {
+            state = state
+                .wrapping_mul(6_364_136_223_846_793_005)
+                .wrapping_add(1_442_695_040_888_963_407);
+            1.0 + (state >> 11) as f64 / (1u64 << 53) as f64
+        };
+        let (mut fused_error, mut plain_error) = (0.0f64, 0.0f64);
+        for k in 0..1000 {
+            // nearly light-like numbers, whose products cancel in the time component
+            let a = uniform();
+            let z = Perplex::new(a, a * (1.0 + 1e-9 * uniform()));
+            let w = Perplex::new(uniform(), -uniform());
+            let w = Perplex::new(
+                w.t,
+                -w.t * (1.0 - 1e-9 * uniform()) * if k % 2 == 0 { 1.0 } else { -1.0 },
+            );
+            let reference = sum_of_products_reference(z.t, w.t, z.x, w.x);
+            fused_error = fused_error.max(ulp_error(z.mul_fused(w).t, reference));
+            plain_error = plain_error.max(ulp_error((z * w).t, reference));
+            let reference = sum_of_products_reference(z.t, z.t, -z.x, z.x);
+            fused_error = fused_error.max(ulp_error(z.squared_distance_fused(), reference));
+            plain_error = plain_error.max(ulp_error(z.squared_distance(), reference));
+            let reference = sum_of_products_reference(z.t, w.x, z.x, w.t);
+            fused_error = fused_error.max(ulp_error(z.mul_fused(w).x, reference));
+        }
+        assert!(fused_error <= 2.0, "Accurate to two ulps!");
+        assert!(plain_error > 1e3, "Cancellation of the plain product!");
+        // the robust division uses the fused squared distance of the nearly light-like divisor
+        let z = Perplex::new(1.0 + 2f64.powi(-30), 1.0);
+        let exact = z.squared_distance_fused();
+        let inverse = Perplex::new(z.t / exact, -1.0 / exact);
+        assert_eq!(Perplex::one().div_robust(z), Some(inverse));
+        assert_ne!(Perplex::<f64>::one() / z, inverse);
+        assert_eq!(z.modulus(), exact.sqrt());
+        // the fused MulAdd rounds the product with the summand only once
+        let (eps, one) = (2f64.powi(-30), Perplex::new(1.0, 0.0));
+        let (z, w) = (Perplex::new(1.0 + eps, 0.0), Perplex::new(1.0 - eps, 0.0));
+        assert_eq!(z * w - one, Perplex::zero());
+        assert_eq!(z.mul_add(w, -one), Perplex::new(-eps * eps, 0.0));
+        let mut y = z;
+        y.mul_add_assign(w, -one);
+        assert_eq!(
+            y,
+            z.mul_add(w, -one),
+            "MulAddAssign yields same result as MulAdd!"
+        );
+    }
 }
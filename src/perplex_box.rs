@@ -0,0 +1,474 @@
+//! # Perplex Box Module
+//!
+//! This module provides the `PerplexBox` type, an axis-aligned rectangle `[t_min, t_max] x [x_min, x_max]` in the (t, x) plane, for set-valued computations like branch-and-bound algorithms over the hyperbolic plane.
+//! The arithmetic returns boxes enclosing the exact image sets, i.e., every sum or product of points of the operands is contained in the resulting box.
+//!
+//! ## Features
+//! - `Add`, `Sub`, `Mul` and `Neg` of boxes. Products are enclosed by interval arithmetic in both the (t, x) coordinates and the null coordinates `u = t + x` and `v = t - x`, in which the product acts componentwise, and the tighter intersection is returned.
+//! - `exp` and `ln`: Enclosures of the images under the sector-extended exponential function and logarithm of the `Perplex` type.
+//! - `intersect_sector`: The bounding box of the intersection with the closure of a `HyperbolicSector`.
+//! - `image_bounds`: A bounding box of the image of an arbitrary function, which is exact for functions with a known `Monotonicity` in the null coordinates and estimated by sampling otherwise, e.g., for adaptive plotting and branch-and-bound algorithms without full interval arithmetic.
+//!
+//! ## Example
+//! ```
+//! use perplex_num::{Perplex, PerplexBox};
+//! let a = PerplexBox::new(Perplex::new(1.0, -0.5), Perplex::new(2.0, 0.5)).unwrap();
+//! let b = PerplexBox::new(Perplex::new(-1.0, 0.0), Perplex::new(1.0, 0.25)).unwrap();
+//! let product = a * b;
+//! for (z, w) in a.corners().into_iter().zip(b.corners()) {
+//!     assert!(product.contains(z * w));
+//! }
+//! ```
+
+use super::{HyperbolicSector, Perplex};
+use num_traits::Float;
+use std::ops::{Add, Mul, Neg, Sub};
+
+/// The monotonicity of a perplex function in the null coordinates `u = t + x` and `v = t - x`, which selects the evaluation points of `PerplexBox::image_bounds`.
+///
+/// The hints assume that the function is the application of one real function to both null coordinates on the whole box, see `Perplex::lift`.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub enum Monotonicity {
+    /// The function applies a non-decreasing real function to the null coordinates, e.g., `sinh`, `tanh`, `asinh` and `atan` on the whole plane, `sqrt`, `asin`, `acosh` and `atanh` on their domains, and `exp` and `ln` within the right sector.
+    /// The image is then enclosed from the values at the four corners of the box.
+    NonDecreasing,
+    /// The function applies a non-increasing real function to the null coordinates, e.g., the inverse `1 / z` within the right sector.
+    /// The image is then enclosed from the values at the four corners of the box.
+    NonIncreasing,
+    /// The monotonicity is not known, e.g., for `cosh`, `sin` and compositions. The image is estimated from the values on a grid with the given number of subdivisions per edge, which includes the corners and the edges.
+    Unknown(usize),
+}
+
+/// An axis-aligned rectangle in the (t, x) plane with the lower left corner `min` and the upper right corner `max`.
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct PerplexBox<T> {
+    /// The corner with the minimal time and space components.
+    pub min: Perplex<T>,
+    /// The corner with the maximal time and space components.
+    pub max: Perplex<T>,
+}
+
+impl<T: Copy + Float> PerplexBox<T> {
+    /// Creates a box from its corners, or returns `None` if `min` exceeds `max` in a component or a component is NaN.
+    #[inline]
+    pub fn new(min: Perplex<T>, max: Perplex<T>) -> Option<Self> {
+        if min.t <= max.t && min.x <= max.x {
+            Some(Self { min, max })
+        } else {
+            None
+        }
+    }
+    /// Creates the degenerate box containing only `z`.
+    #[inline]
+    pub fn from_point(z: Perplex<T>) -> Self {
+        Self { min: z, max: z }
+    }
+    /// Returns the smallest box containing all `points`, or `None` if there are no points or a component is NaN.
+    pub fn enclosing(points: &[Perplex<T>]) -> Option<Self> {
+        let (first, rest) = points.split_first()?;
+        let hull = rest.iter().fold(Self::from_point(*first), |hull, &z| {
+            hull.hull(&Self::from_point(z))
+        });
+        Self::new(hull.min, hull.max)
+    }
+    /// Returns the corners in counterclockwise order, starting at `min`.
+    #[inline]
+    pub fn corners(&self) -> [Perplex<T>; 4] {
+        [
+            self.min,
+            Perplex::new(self.max.t, self.min.x),
+            self.max,
+            Perplex::new(self.min.t, self.max.x),
+        ]
+    }
+    /// Returns the center of the box.
+    #[inline]
+    pub fn center(&self) -> Perplex<T> {
+        let two = T::one() + T::one();
+        Perplex::new(
+            (self.min.t + self.max.t) / two,
+            (self.min.x + self.max.x) / two,
+        )
+    }
+    /// Checks if `z` lies in the closed box.
+    #[inline]
+    pub fn contains(&self, z: Perplex<T>) -> bool {
+        self.min.t <= z.t && z.t <= self.max.t && self.min.x <= z.x && z.x <= self.max.x
+    }
+    /// Returns the smallest box containing `self` and `other`.
+    #[inline]
+    pub fn hull(&self, other: &Self) -> Self {
+        Self {
+            min: Perplex::new(self.min.t.min(other.min.t), self.min.x.min(other.min.x)),
+            max: Perplex::new(self.max.t.max(other.max.t), self.max.x.max(other.max.x)),
+        }
+    }
+    /// Returns the intersection of `self` and `other`, or `None` if they are disjoint.
+    #[inline]
+    pub fn intersection(&self, other: &Self) -> Option<Self> {
+        Self::new(
+            Perplex::new(self.min.t.max(other.min.t), self.min.x.max(other.min.x)),
+            Perplex::new(self.max.t.min(other.max.t), self.max.x.min(other.max.x)),
+        )
+    }
+    /// Checks if the box intersects the light cone `|t| = |x|`.
+    #[inline]
+    pub fn touches_light_cone(&self) -> bool {
+        let ([u_min, u_max], [v_min, v_max]) = self.null_intervals();
+        (u_min <= T::zero() && T::zero() <= u_max) || (v_min <= T::zero() && T::zero() <= v_max)
+    }
+    /// Returns the bounding box of the intersection of `self` with the closure of `sector`, or `None` if they are disjoint.
+    ///
+    /// Returns `None` for the `Diagonal` variant, which does not denote a sector of non-zero area.
+    pub fn intersect_sector(&self, sector: HyperbolicSector<T>) -> Option<Self> {
+        let one = T::one();
+        // the closed sector as the intersection of two half-planes a t + b x >= 0
+        let half_planes = match sector {
+            HyperbolicSector::Right => [(one, one), (one, -one)],
+            HyperbolicSector::Up => [(one, one), (-one, one)],
+            HyperbolicSector::Left => [(-one, -one), (-one, one)],
+            HyperbolicSector::Down => [(-one, -one), (one, -one)],
+            HyperbolicSector::Diagonal(_) => return None,
+        };
+        let mut polygon = self.corners().to_vec();
+        for (a, b) in half_planes {
+            polygon = clip(&polygon, a, b);
+        }
+        Self::enclosing(&polygon)
+    }
+    /// Returns a box enclosing the image of `self` under `Perplex::exp`.
+    ///
+    /// The sector-extended exponential function is monotonic in the null coordinates within each sector, hence the images of the intersections with the sectors are enclosed separately.
+    pub fn exp(&self) -> Self {
+        let sectors = [
+            (HyperbolicSector::Right, Perplex::new(T::one(), T::zero())),
+            (HyperbolicSector::Up, Perplex::new(T::zero(), T::one())),
+            (HyperbolicSector::Left, Perplex::new(-T::one(), T::zero())),
+            (HyperbolicSector::Down, Perplex::new(T::zero(), -T::one())),
+        ];
+        // light-like numbers are mapped by the formula of the right sector
+        let mut image = self.touches_light_cone().then(|| self.null_map(T::exp));
+        for (sector, klein) in sectors {
+            if let Some(part) = self.intersect_sector(sector) {
+                let k = Self::from_point(klein);
+                let part_image = k * (k * part).null_map(T::exp);
+                image = Some(image.map_or(part_image, |image| image.hull(&part_image)));
+            }
+        }
+        // every box intersects a sector or the light cone
+        image.unwrap_or(*self)
+    }
+    /// Returns a box enclosing the image of `self` under `Perplex::ln`, or `None` if the box intersects the light cone, where the logarithm is undefined.
+    pub fn ln(&self) -> Option<Self> {
+        if self.touches_light_cone() {
+            return None;
+        }
+        let klein = Self::from_point(self.center().klein()?);
+        Some(klein * (klein * *self).null_map(T::ln))
+    }
+    /// Returns a bounding box of the image of `self` under `f`, or `None` if `f` returns `None` or NaN at an evaluation point.
+    ///
+    /// For `NonDecreasing` and `NonIncreasing` functions, the extrema of the null coordinates of the image are attained at the corners, since the extrema of `u` and `v` over the box are attained there. The resulting box encloses the image up to rounding, whereas the bounds of `Unknown` functions are estimates from samples, which may miss extrema between the grid points.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use perplex_num::{Monotonicity, Perplex, PerplexBox};
+    /// let b = PerplexBox::new(Perplex::new(0.5, -0.25), Perplex::new(1.0, 0.5)).unwrap();
+    /// let bounds = b.image_bounds(Perplex::sinh, Monotonicity::NonDecreasing).unwrap();
+    /// assert!(bounds.contains(Perplex::new(0.75, 0.1).sinh()));
+    /// let bounds = b.image_bounds(Perplex::sqrt, Monotonicity::NonDecreasing).unwrap();
+    /// assert!(bounds.contains(Perplex::new(0.75, 0.1).sqrt().unwrap()));
+    /// assert!(b.image_bounds(Perplex::acosh, Monotonicity::NonDecreasing).is_none());
+    /// ```
+    pub fn image_bounds<F, R>(&self, f: F, monotonicity: Monotonicity) -> Option<Self>
+    where
+        F: Fn(Perplex<T>) -> R,
+        R: Into<Option<Perplex<T>>>,
+    {
+        let eval = |z: Perplex<T>| f(z).into();
+        let null = |z: Perplex<T>| (z.t + z.x, z.t - z.x);
+        let corners = |[lower_u, upper_u, lower_v, upper_v]: [Perplex<T>; 4]| {
+            let (u_min, _) = null(eval(lower_u)?);
+            let (u_max, _) = null(eval(upper_u)?);
+            let (_, v_min) = null(eval(lower_v)?);
+            let (_, v_max) = null(eval(upper_v)?);
+            let image = Self::from_null_intervals([u_min, u_max], [v_min, v_max]);
+            Self::new(image.min, image.max)
+        };
+        // u is minimal at min and maximal at max, v is minimal at the upper left and maximal at the lower right corner
+        let [min, lower_right, max, upper_left] = self.corners();
+        match monotonicity {
+            Monotonicity::NonDecreasing => corners([min, max, upper_left, lower_right]),
+            Monotonicity::NonIncreasing => corners([max, min, lower_right, upper_left]),
+            Monotonicity::Unknown(subdivisions) => {
+                let n = subdivisions.max(1);
+                let step =
+                    |a: T, b: T, k: usize| a + (b - a) * T::from(k).unwrap() / T::from(n).unwrap();
+                let mut samples = Vec::with_capacity((n + 1) * (n + 1));
+                for i in 0..=n {
+                    for j in 0..=n {
+                        let z = Perplex::new(
+                            step(self.min.t, self.max.t, i),
+                            step(self.min.x, self.max.x, j),
+                        );
+                        samples.push(eval(z)?);
+                    }
+                }
+                Self::enclosing(&samples)
+            }
+        }
+    }
+    /// Returns the intervals of the null coordinates `u = t + x` and `v = t - x` over the box.
+    #[inline]
+    fn null_intervals(&self) -> ([T; 2], [T; 2]) {
+        (
+            [self.min.t + self.min.x, self.max.t + self.max.x],
+            [self.min.t - self.max.x, self.max.t - self.min.x],
+        )
+    }
+    /// Returns the box enclosing the null intervals `u` and `v`.
+    #[inline]
+    fn from_null_intervals([u_min, u_max]: [T; 2], [v_min, v_max]: [T; 2]) -> Self {
+        let two = T::one() + T::one();
+        Self {
+            min: Perplex::new((u_min + v_min) / two, (u_min - v_max) / two),
+            max: Perplex::new((u_max + v_max) / two, (u_max - v_min) / two),
+        }
+    }
+    /// Applies the non-decreasing function `f` to both null intervals.
+    #[inline]
+    fn null_map(&self, f: impl Fn(T) -> T) -> Self {
+        let ([u_min, u_max], [v_min, v_max]) = self.null_intervals();
+        Self::from_null_intervals([f(u_min), f(u_max)], [f(v_min), f(v_max)])
+    }
+}
+
+/// Returns the interval `[min, max]` of all products of elements out of `a` and `b`.
+#[inline]
+fn interval_mul<T: Copy + Float>([a_min, a_max]: [T; 2], [b_min, b_max]: [T; 2]) -> [T; 2] {
+    let products = [a_min * b_min, a_min * b_max, a_max * b_min, a_max * b_max];
+    let min = products.iter().fold(T::infinity(), |m, &p| m.min(p));
+    let max = products.iter().fold(T::neg_infinity(), |m, &p| m.max(p));
+    [min, max]
+}
+
+/// Clips the convex polygon to the half-plane `a t + b x >= 0` (Sutherland–Hodgman).
+fn clip<T: Copy + Float>(polygon: &[Perplex<T>], a: T, b: T) -> Vec<Perplex<T>> {
+    let side = |z: &Perplex<T>| a * z.t + b * z.x;
+    let mut clipped = Vec::with_capacity(polygon.len() + 1);
+    for (i, current) in polygon.iter().enumerate() {
+        let next = &polygon[(i + 1) % polygon.len()];
+        let (s_current, s_next) = (side(current), side(next));
+        if s_current >= T::zero() {
+            clipped.push(*current);
+        }
+        if (s_current < T::zero()) != (s_next < T::zero()) {
+            let lambda = s_current / (s_current - s_next);
+            clipped.push(*current + (*next - *current).scale(lambda));
+        }
+    }
+    clipped
+}
+
+impl<T: Copy + Float> Add for PerplexBox<T> {
+    type Output = Self;
+    #[inline]
+    fn add(self, rhs: Self) -> Self::Output {
+        Self {
+            min: self.min + rhs.min,
+            max: self.max + rhs.max,
+        }
+    }
+}
+
+impl<T: Copy + Float> Sub for PerplexBox<T> {
+    type Output = Self;
+    #[inline]
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self {
+            min: self.min - rhs.max,
+            max: self.max - rhs.min,
+        }
+    }
+}
+
+impl<T: Copy + Float> Neg for PerplexBox<T> {
+    type Output = Self;
+    #[inline]
+    fn neg(self) -> Self::Output {
+        Self {
+            min: -self.max,
+            max: -self.min,
+        }
+    }
+}
+
+impl<T: Copy + Float> Mul for PerplexBox<T> {
+    type Output = Self;
+    /// Returns the intersection of the enclosures by interval arithmetic in the (t, x) coordinates and in the null coordinates.
+    #[inline]
+    fn mul(self, rhs: Self) -> Self::Output {
+        let add = |[a_min, a_max]: [T; 2], [b_min, b_max]: [T; 2]| [a_min + b_min, a_max + b_max];
+        let (t1, x1) = ([self.min.t, self.max.t], [self.min.x, self.max.x]);
+        let (t2, x2) = ([rhs.min.t, rhs.max.t], [rhs.min.x, rhs.max.x]);
+        let [t_min, t_max] = add(interval_mul(t1, t2), interval_mul(x1, x2));
+        let [x_min, x_max] = add(interval_mul(t1, x2), interval_mul(x1, t2));
+        let cartesian = Self {
+            min: Perplex::new(t_min, x_min),
+            max: Perplex::new(t_max, x_max),
+        };
+        let ((u1, v1), (u2, v2)) = (self.null_intervals(), rhs.null_intervals());
+        let null = Self::from_null_intervals(interval_mul(u1, u2), interval_mul(v1, v2));
+        // both boxes enclose the non-empty product set
+        cartesian.intersection(&null).unwrap_or(cartesian)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    /// Returns a grid of points in the box.
+    fn grid(b: &PerplexBox<f64>) -> Vec<Perplex<f64>> {
+        let n = 8;
+        let mut points = Vec::new();
+        for i in 0..=n {
+            for j in 0..=n {
+                let (s, r) = (i as f64 / n as f64, j as f64 / n as f64);
+                points.push(Perplex::new(
+                    b.min.t + s * (b.max.t - b.min.t),
+                    b.min.x + r * (b.max.x - b.min.x),
+                ));
+            }
+        }
+        points
+    }
+    #[test]
+    fn test_box() {
+        assert!(PerplexBox::new(Perplex::new(1.0, 0.0), Perplex::new(0.0, 1.0)).is_none());
+        let a = PerplexBox::new(Perplex::new(-1.0, 0.0), Perplex::new(1.0, 2.0)).unwrap();
+        assert_eq!(a.center(), Perplex::new(0.0, 1.0));
+        assert!(a.contains(Perplex::new(1.0, 2.0)) && !a.contains(Perplex::new(1.5, 1.0)));
+        let b = PerplexBox::from_point(Perplex::new(3.0, -1.0));
+        let hull = a.hull(&b);
+        assert_eq!(
+            (hull.min, hull.max),
+            (Perplex::new(-1.0, -1.0), Perplex::new(3.0, 2.0))
+        );
+        assert!(a.intersection(&b).is_none());
+        assert_eq!(hull.intersection(&b), Some(b));
+        assert!(a.touches_light_cone() && !b.touches_light_cone());
+        assert_eq!(
+            PerplexBox::enclosing(&[Perplex::new(1.0, 5.0), Perplex::new(-2.0, 0.0)]),
+            PerplexBox::new(Perplex::new(-2.0, 0.0), Perplex::new(1.0, 5.0))
+        );
+    }
+    #[test]
+    fn test_arithmetic() {
+        let a = PerplexBox::new(Perplex::new(-1.0, 0.5), Perplex::new(2.0, 1.0)).unwrap();
+        let b = PerplexBox::new(Perplex::new(0.5, -2.0), Perplex::new(1.5, 0.0)).unwrap();
+        let (sum, difference, product) = (a + b, a - b, a * b);
+        for z in grid(&a) {
+            assert!((-a).contains(-z));
+            for w in grid(&b) {
+                assert!(sum.contains(z + w));
+                assert!(difference.contains(z - w));
+                assert!(product.contains(z * w), "Product is enclosed!");
+            }
+        }
+        // multiplication by h swaps the components exactly
+        let h = PerplexBox::from_point(Perplex::h());
+        let swapped = h * a;
+        assert_eq!(
+            (swapped.min, swapped.max),
+            (Perplex::new(0.5, -1.0), Perplex::new(1.0, 2.0))
+        );
+    }
+    #[test]
+    fn test_intersect_sector() {
+        let a = PerplexBox::new(Perplex::new(-1.0, -1.0), Perplex::new(2.0, 0.5)).unwrap();
+        let right = a.intersect_sector(HyperbolicSector::Right).unwrap();
+        assert_eq!(
+            (right.min, right.max),
+            (Perplex::new(0.0, -1.0), Perplex::new(2.0, 0.5))
+        );
+        let up = a.intersect_sector(HyperbolicSector::Up).unwrap();
+        assert_eq!(
+            (up.min, up.max),
+            (Perplex::new(-0.5, 0.0), Perplex::new(0.5, 0.5))
+        );
+        let b = PerplexBox::new(Perplex::new(1.0, -0.5), Perplex::new(2.0, 0.5)).unwrap();
+        assert!(b.intersect_sector(HyperbolicSector::Left).is_none());
+        assert!(b
+            .intersect_sector(HyperbolicSector::Diagonal(1.0))
+            .is_none());
+    }
+    #[test]
+    fn test_exp_ln() {
+        let boxes = [
+            PerplexBox::new(Perplex::new(0.5, -0.25), Perplex::new(1.0, 0.25)).unwrap(),
+            PerplexBox::new(Perplex::new(-1.0, -1.0), Perplex::new(0.5, 1.5)).unwrap(),
+            PerplexBox::new(Perplex::new(-0.5, -2.0), Perplex::new(0.25, -1.0)).unwrap(),
+        ];
+        for b in boxes {
+            let image = b.exp();
+            for z in grid(&b) {
+                assert!(image.contains(z.exp()), "Image under exp is enclosed!");
+            }
+            match b.ln() {
+                Some(image) => {
+                    assert!(!b.touches_light_cone());
+                    for z in grid(&b) {
+                        assert!(
+                            image.contains(z.ln().unwrap()),
+                            "Image under ln is enclosed!"
+                        );
+                    }
+                }
+                None => assert!(b.touches_light_cone()),
+            }
+        }
+        let b = PerplexBox::new(Perplex::new(-2.0, -1.0), Perplex::new(-1.5, 0.5)).unwrap();
+        let image = b.ln().unwrap();
+        for z in grid(&b) {
+            assert!(image.contains(z.ln().unwrap()), "Left sector!");
+        }
+    }
+    #[test]
+    fn test_image_bounds() {
+        let b = PerplexBox::new(Perplex::new(1.0, -0.5), Perplex::new(2.5, 0.75)).unwrap();
+        let increasing = Monotonicity::NonDecreasing;
+        let tanh = b.image_bounds(Perplex::tanh, increasing).unwrap();
+        let ln = b.image_bounds(Perplex::ln, increasing).unwrap();
+        let inverse = b
+            .image_bounds(|z| z.try_inverse(), Monotonicity::NonIncreasing)
+            .unwrap();
+        let cosh = b
+            .image_bounds(Perplex::cosh, Monotonicity::Unknown(16))
+            .unwrap();
+        for z in grid(&b) {
+            assert!(tanh.contains(z.tanh()));
+            assert!(ln.contains(z.ln().unwrap()));
+            assert!(
+                inverse.contains(z.try_inverse().unwrap()),
+                "Non-increasing!"
+            );
+        }
+        let enclosure = b.ln().unwrap();
+        let distance = (ln.min - enclosure.min).l1_norm() + (ln.max - enclosure.max).l1_norm();
+        assert!(distance < 1e-14, "Same as the enclosure of ln!");
+        // the sampled bounds are attained by samples and hence within the exact image
+        let exact = b
+            .image_bounds(Perplex::cosh, Monotonicity::Unknown(256))
+            .unwrap();
+        assert!(exact.contains(cosh.min) && exact.contains(cosh.max));
+        let light_like = PerplexBox::new(Perplex::new(0.0, -1.0), Perplex::new(1.0, 1.0)).unwrap();
+        assert!(light_like.image_bounds(Perplex::ln, increasing).is_none());
+        assert!(light_like
+            .image_bounds(Perplex::ln, Monotonicity::Unknown(4))
+            .is_none());
+        let point = PerplexBox::from_point(Perplex::new(0.5, 0.25));
+        let image = point.image_bounds(Perplex::sinh, increasing).unwrap();
+        assert!((image.max - image.min).l1_norm() < 1e-15, "Degenerate box!");
+    }
+}
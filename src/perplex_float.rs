@@ -0,0 +1,342 @@
+//! # Perplex Float Module
+//!
+//! This module provides the `PerplexFloat` trait, the perplex counterpart of `ComplexFloat` of the [num-complex](https://docs.rs/num-complex) crate, which collects the complete floating-point API of `Perplex<f32>` and `Perplex<f64>` in one place.
+//! Generic code bounded by `P: PerplexFloat` evaluates elementary functions of either precision without spelling out `Perplex<T>` with a `T: Float` bound, e.g., for kernels which are instantiated for `f32` on GPUs and for `f64` on CPUs.
+//! Most methods forward to the implementations of `Perplex<T>`, which are monomorphized for `f32` and `f64` and hence call the concrete float intrinsics, or the `libm` functions in `no_std` builds.
+//! The methods `exp_m1`, `ln_1p` and `l2_norm` have no generic counterpart and are implemented for `f32` and `f64` by the intrinsics `exp_m1`, `ln_1p` and `hypot` of the components, which avoid the cancellation or overflow of the generic formulas.
+//!
+//! ## Features
+//! - Components and construction: `new`, `t`, `x`.
+//! - Metric: `conj`, `squared_distance`, `modulus`, `ln_modulus`, `l2_norm`, `arg`, `klein`, `is_light_like`.
+//! - Fused arithmetic: `mul_add` of the supertrait `MulAdd`, which is computed by fused multiply-adds of the components.
+//! - Powers and exponentials: `try_inverse`, `powi`, `powf`, `powc`, `exp`, `exp_m1`, `exp2`, `ln`, `ln_1p`, `log2`, `log10`, `sqrt`.
+//! - Trigonometric and hyperbolic functions with their inverses, whereby the functions which are undefined on parts of the plane return an `Option` like their counterparts of `Perplex`.
+//!
+//! ## Example
+//! ```
+//! use perplex_num::perplex_float::PerplexFloat;
+//! use perplex_num::Perplex;
+//! // the hyperbolic Pythagorean identity cosh^2 - sinh^2 = 1 in any precision
+//! fn pythagorean<P: PerplexFloat>(z: P) -> P {
+//!     let (c, s) = (z.cosh(), z.sinh());
+//!     c * c - s * s
+//! }
+//! let one = pythagorean(Perplex::new(0.5f32, 0.25));
+//! assert!((one.t() - 1.0).abs() < 1e-6 && one.x().abs() < 1e-6);
+//! let one = pythagorean(Perplex::new(0.5f64, 0.25));
+//! assert!((one.t() - 1.0).abs() < 1e-15 && one.x().abs() < 1e-15);
+//! ```
+
+use super::{KleinIndex, Perplex};
+use core::fmt::Debug;
+use core::ops::{Add, Div, Mul, Neg, Sub};
+use num_traits::{Float, MulAdd};
+
+/// The floating-point API of perplex numbers with `f32` or `f64` components, see the module documentation.
+pub trait PerplexFloat:
+    Copy
+    + PartialEq
+    + Debug
+    + Default
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+    + Neg<Output = Self>
+    + Mul<Self::Real, Output = Self>
+    + MulAdd<Output = Self>
+{
+    /// The floating-point type of the components.
+    type Real: Float;
+    /// Creates the perplex number `t + x h`.
+    fn new(t: Self::Real, x: Self::Real) -> Self;
+    /// Returns the time component.
+    fn t(self) -> Self::Real;
+    /// Returns the space component.
+    fn x(self) -> Self::Real;
+    /// See `Perplex::conj`.
+    fn conj(self) -> Self;
+    /// See `Perplex::squared_distance`.
+    fn squared_distance(self) -> Self::Real;
+    /// See `Perplex::modulus`.
+    fn modulus(self) -> Self::Real;
+    /// See `Perplex::ln_modulus`.
+    fn ln_modulus(self) -> Self::Real;
+    /// Returns the Euclidean norm `sqrt(t^2 + x^2)` by `hypot`, which does not overflow or underflow in between like `Perplex::l2_norm`.
+    fn l2_norm(self) -> Self::Real;
+    /// See `Perplex::arg`.
+    fn arg(self) -> Self::Real;
+    /// See `Perplex::klein`.
+    fn klein(self) -> Option<Self>;
+    /// See `Perplex::is_light_like`.
+    fn is_light_like(self) -> bool;
+    /// See `Perplex::is_nan`.
+    fn is_nan(self) -> bool;
+    /// See `Perplex::is_finite`.
+    fn is_finite(self) -> bool;
+    /// See `Perplex::try_inverse`.
+    fn try_inverse(self) -> Option<Self>;
+    /// See `Perplex::powi`.
+    fn powi(self, exp: i32) -> Option<Self>;
+    /// See `Perplex::powf`.
+    fn powf(self, exp: Self::Real) -> Option<Self>;
+    /// See `Perplex::powc`.
+    fn powc(self, exp: Self) -> Option<Self>;
+    /// See `Perplex::exp`.
+    fn exp(self) -> Self;
+    /// Returns `exp(self) - 1`, which is accurate for small `self` in contrast to the difference.
+    ///
+    /// In the right sector and for light-like numbers, `exp` applies `exp` to the null components `u = t + x` and `v = t - x`, hence `exp_m1` applies `exp_m1` to them.
+    /// In the other sectors, `exp(self)` is far from `1` and the difference is returned.
+    fn exp_m1(self) -> Self;
+    /// See `Perplex::exp2`.
+    fn exp2(self) -> Self;
+    /// See `Perplex::ln`.
+    fn ln(self) -> Option<Self>;
+    /// Returns `ln(1 + self)`, which is accurate for small `self` in contrast to the logarithm of the sum, or `None` if `1 + self` is light-like.
+    ///
+    /// If `1 + self` lies in the right sector, `ln` applies `ln` to its null components `1 + u` and `1 + v`, hence `ln_1p` applies `ln_1p` to `u` and `v`.
+    /// In the other sectors, `1 + self` is far from `1` and the logarithm of the sum is returned.
+    fn ln_1p(self) -> Option<Self>;
+    /// See `Perplex::log2`.
+    fn log2(self) -> Option<Self>;
+    /// See `Perplex::log10`.
+    fn log10(self) -> Option<Self>;
+    /// See `Perplex::sqrt`.
+    fn sqrt(self) -> Option<Self>;
+    /// See `Perplex::sin`.
+    fn sin(self) -> Self;
+    /// See `Perplex::cos`.
+    fn cos(self) -> Self;
+    /// See `Perplex::tan`.
+    fn tan(self) -> Option<Self>;
+    /// See `Perplex::asin`.
+    fn asin(self) -> Option<Self>;
+    /// See `Perplex::acos`.
+    fn acos(self) -> Option<Self>;
+    /// See `Perplex::atan`.
+    fn atan(self) -> Self;
+    /// See `Perplex::sinh`.
+    fn sinh(self) -> Self;
+    /// See `Perplex::cosh`.
+    fn cosh(self) -> Self;
+    /// See `Perplex::tanh`.
+    fn tanh(self) -> Self;
+    /// See `Perplex::asinh`.
+    fn asinh(self) -> Self;
+    /// See `Perplex::acosh`.
+    fn acosh(self) -> Option<Self>;
+    /// See `Perplex::atanh`.
+    fn atanh(self) -> Option<Self>;
+}
+
+/// Forwards the listed methods taking `self` by value to the inherent methods of `Perplex`.
+macro_rules! forward {
+    ($($method:ident -> $output:ty),* $(,)?) => {$(
+        #[inline]
+        fn $method(self) -> $output {
+            Perplex::$method(self)
+        }
+    )*};
+}
+
+macro_rules! impl_perplex_float {
+    ($($real:ty),*) => {$(
+        impl PerplexFloat for Perplex<$real> {
+            type Real = $real;
+            #[inline]
+            fn new(t: $real, x: $real) -> Self {
+                Perplex::new(t, x)
+            }
+            #[inline]
+            fn t(self) -> $real {
+                self.t
+            }
+            #[inline]
+            fn x(self) -> $real {
+                self.x
+            }
+            #[inline]
+            fn conj(self) -> Self {
+                Perplex::conj(&self)
+            }
+            #[inline]
+            fn squared_distance(self) -> $real {
+                Perplex::squared_distance(&self)
+            }
+            #[inline]
+            fn is_light_like(self) -> bool {
+                Perplex::is_light_like(&self)
+            }
+            #[inline]
+            fn try_inverse(self) -> Option<Self> {
+                Perplex::try_inverse(&self)
+            }
+            #[inline]
+            fn powi(self, exp: i32) -> Option<Self> {
+                Perplex::powi(&self, exp)
+            }
+            #[inline]
+            fn powf(self, exp: $real) -> Option<Self> {
+                Perplex::powf(self, exp)
+            }
+            #[inline]
+            fn powc(self, exp: Self) -> Option<Self> {
+                Perplex::powc(self, exp)
+            }
+            #[inline]
+            fn l2_norm(self) -> $real {
+                <$real as Float>::hypot(self.t, self.x)
+            }
+            #[inline]
+            fn exp_m1(self) -> Self {
+                match self.klein_index() {
+                    Some(KleinIndex::One) | None => self.map_null_basis(<$real as Float>::exp_m1),
+                    Some(_) => Perplex::exp(self) - Perplex::new(1.0, 0.0),
+                }
+            }
+            #[inline]
+            fn ln_1p(self) -> Option<Self> {
+                let (u, v) = (self.t + self.x, self.t - self.x);
+                if u > -1.0 && v > -1.0 {
+                    Some(self.map_null_basis(<$real as Float>::ln_1p))
+                } else {
+                    Perplex::ln(self + Perplex::new(1.0, 0.0))
+                }
+            }
+            forward!(
+                modulus -> $real,
+                ln_modulus -> $real,
+                arg -> $real,
+                klein -> Option<Self>,
+                is_nan -> bool,
+                is_finite -> bool,
+                exp -> Self,
+                exp2 -> Self,
+                ln -> Option<Self>,
+                log2 -> Option<Self>,
+                log10 -> Option<Self>,
+                sqrt -> Option<Self>,
+                sin -> Self,
+                cos -> Self,
+                tan -> Option<Self>,
+                asin -> Option<Self>,
+                acos -> Option<Self>,
+                atan -> Self,
+                sinh -> Self,
+                cosh -> Self,
+                tanh -> Self,
+                asinh -> Self,
+                acosh -> Option<Self>,
+                atanh -> Option<Self>,
+            );
+        }
+    )*};
+}
+
+impl_perplex_float!(f32, f64);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    /// Returns the errors of `ln(exp(z)) = z` and `sqrt(z)^2 = z` in the maximum norm for `z` in the right sector.
+    fn roundtrip_error<P: PerplexFloat>(z: P) -> P::Real {
+        let log = z.exp().ln().unwrap() - z;
+        let root = z.sqrt().unwrap();
+        let square = root * root - z;
+        let max = |w: P| w.t().abs().max(w.x().abs());
+        max(log).max(max(square))
+    }
+    #[test]
+    fn test_generic_precision() {
+        for (t, x) in [(0.5, 0.25), (1.5, -0.5), (3.0, 2.0)] {
+            assert!(roundtrip_error(Perplex::new(t as f32, x as f32)) < 1e-5);
+            assert!(roundtrip_error(Perplex::new(t, x)) < 1e-13);
+        }
+    }
+    #[test]
+    fn test_forwarding() {
+        let z = Perplex::new(0.75, -0.5);
+        let p = <Perplex<f64> as PerplexFloat>::new(0.75, -0.5);
+        assert_eq!(p, z);
+        assert_eq!(PerplexFloat::conj(p), z.conj());
+        assert_eq!(PerplexFloat::squared_distance(p), z.squared_distance());
+        assert_eq!(PerplexFloat::modulus(p), z.modulus());
+        assert_eq!(PerplexFloat::arg(p), z.arg());
+        assert_eq!(PerplexFloat::klein(p), z.klein());
+        assert_eq!(PerplexFloat::powi(p, -3), z.powi(-3));
+        assert_eq!(PerplexFloat::powf(p, 0.5), z.powf(0.5));
+        assert_eq!(PerplexFloat::exp2(p), z.exp2());
+        assert_eq!(PerplexFloat::log10(p), z.log10());
+        assert_eq!(PerplexFloat::tan(p), z.tan());
+        assert_eq!(PerplexFloat::atanh(p), z.atanh());
+        assert_eq!(PerplexFloat::acosh(p), z.acosh());
+        assert!(PerplexFloat::is_light_like(Perplex::new(1.0f32, -1.0)));
+        assert!(PerplexFloat::ln(Perplex::new(1.0f32, 1.0)).is_none());
+        assert!(!PerplexFloat::is_finite(Perplex::new(f32::NAN, 0.0)));
+    }
+    #[test]
+    fn test_generic_path() {
+        let one = Perplex::new(1.0, 0.0);
+        for (t, x) in [
+            (0.5, 0.25),
+            (1.5, -0.5),
+            (-2.0, 0.5),
+            (0.25, 3.0),
+            (-0.5, -1.5),
+            (1.0, -1.0),
+        ] {
+            let (z, w) = (Perplex::new(t, x), Perplex::new(x, -t));
+            approx::assert_abs_diff_eq!(MulAdd::mul_add(z, w, one), z * w + one, epsilon = 1e-15);
+            approx::assert_abs_diff_eq!(
+                PerplexFloat::l2_norm(z),
+                Perplex::l2_norm(&z),
+                epsilon = 1e-15
+            );
+            approx::assert_abs_diff_eq!(PerplexFloat::exp_m1(z), z.exp() - one, epsilon = 1e-14);
+            match PerplexFloat::ln_1p(z) {
+                Some(log) => {
+                    approx::assert_abs_diff_eq!(log, (z + one).ln().unwrap(), epsilon = 1e-15)
+                }
+                None => assert!((z + one).is_light_like()),
+            }
+            let z = Perplex::new(t as f32, x as f32);
+            approx::assert_abs_diff_eq!(
+                PerplexFloat::exp_m1(z),
+                z.exp() - Perplex::new(1.0, 0.0),
+                epsilon = 1e-5
+            );
+        }
+        assert_eq!(PerplexFloat::ln_1p(Perplex::new(-1.0, 0.0)), None);
+    }
+    #[test]
+    fn test_accuracy() {
+        let z = Perplex::new(3e-20, 1e-20);
+        assert_eq!(
+            z.exp() - Perplex::new(1.0, 0.0),
+            Perplex::new(0.0, 1e-20),
+            "Cancellation!"
+        );
+        approx::assert_abs_diff_eq!(PerplexFloat::exp_m1(z), z, epsilon = 1e-35);
+        approx::assert_abs_diff_eq!(PerplexFloat::ln_1p(z).unwrap(), z, epsilon = 1e-35);
+        let (huge, tiny) = (Perplex::new(3e200, 4e200), Perplex::new(3e-200, -4e-200));
+        assert_eq!(Perplex::l2_norm(&huge), f64::INFINITY);
+        approx::assert_relative_eq!(PerplexFloat::l2_norm(huge), 5e200, max_relative = 1e-15);
+        approx::assert_relative_eq!(PerplexFloat::l2_norm(tiny), 5e-200, max_relative = 1e-15);
+        // the fused multiply-add of the supertrait in generic code
+        fn square_minus_one<P: PerplexFloat>(z: P) -> P {
+            use num_traits::{One, Zero};
+            z.mul_add(z, -P::new(P::Real::one(), P::Real::zero()))
+        }
+        let a = Perplex::new(1.0 + f64::EPSILON, 0.0);
+        assert_eq!(
+            (a * a).t - 1.0,
+            2.0 * f64::EPSILON,
+            "Rounding of the product!"
+        );
+        assert_eq!(
+            square_minus_one(a).t,
+            2.0 * f64::EPSILON + f64::EPSILON * f64::EPSILON
+        );
+    }
+}
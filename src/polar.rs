@@ -5,6 +5,11 @@
 //! The hyperbolic polar form encodes a perplex number `z` as a triple of two real numbers `rho` and `theta`, as well as one out of four perplex numbers `klein`, such that `z= klein rho (cosh(theta) + h sinh(theta))`.
 //! `Klein` is defined by the sector of the hyperbolic plane in which the perplex number is in. Formulas are taken from Tab. 1 and Appendix B in [Hyperbolic trigonometry in two-dimensional space-time geometry](https://doi.org/10.1393/ncb/i2003-10012-9).
 //!
+//! Products and quotients stay in polar form by `Mul` and `Div`, which multiply the moduli and Klein units and add the arguments, such that long chains of products need no conversion.
+//! The `KleinIndex` of the four Klein units forms the Klein four-group under `Mul`, and `Perplex::klein_index` returns the unit of the sector of a perplex number.
+//!
+//! The `EpsilonContext` classifies numbers within a tolerance band around the light cone as light-like, consistently for the Klein index, the sector, the argument and the polar form.
+//!
 //! ## Usage
 //!
 //! Here is an example of how to use the `HyperbolicPolar` struct to convert a `Perplex` number
@@ -25,7 +30,9 @@
 //! ```
 
 use super::Perplex;
-use num_traits::{Float, Num, One, Pow};
+use core::fmt;
+use core::ops::{Div, DivAssign, Mul, MulAssign, Neg};
+use num_traits::{Float, Inv, Num, One, Pow};
 
 /// Represents the sector of the hyperbolic plane a perplex number is in.
 ///
@@ -33,6 +40,8 @@ use num_traits::{Float, Num, One, Pow};
 /// where `t = x` and `t = -x`. This enum also includes the `Diagonal` variant to represent
 /// light-like perplex numbers where the time and space components are equal in magnitude.
 #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum HyperbolicSector<T> {
     /// The sector where the time component is greater than the space component in absolute value.
     #[default]
@@ -48,7 +57,7 @@ pub enum HyperbolicSector<T> {
     Diagonal(T),
 }
 
-impl<T: Copy + Float> From<Perplex<T>> for HyperbolicSector<T> {
+impl<T: Copy + Num + Neg<Output = T> + PartialOrd> From<Perplex<T>> for HyperbolicSector<T> {
     /// Converts a perplex number into its corresponding hyperbolic sector.
     ///
     /// Light-like numbers are converted to the `Diagonal` variant, while others are
@@ -56,7 +65,7 @@ impl<T: Copy + Float> From<Perplex<T>> for HyperbolicSector<T> {
     #[inline]
     fn from(z: Perplex<T>) -> Self {
         let Perplex { t, x } = z;
-        let (t_abs, x_abs) = (t.abs(), x.abs());
+        let (t_abs, x_abs) = (abs(t), abs(x));
         if t_abs == x_abs {
             Self::Diagonal(t)
         } else if t_abs > x_abs {
@@ -73,12 +82,242 @@ impl<T: Copy + Float> From<Perplex<T>> for HyperbolicSector<T> {
     }
 }
 
+impl<T: Copy + Num + Neg<Output = T>> HyperbolicSector<T> {
+    /// Returns an iterator over the four sectors `Right`, `Up`, `Left` and `Down` in counterclockwise order, i.e., without the light-like `Diagonal`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use perplex_num::HyperbolicSector;
+    ///
+    /// for sector in HyperbolicSector::<f64>::all() {
+    ///     assert_eq!(sector.canonical_representative().sector(), sector);
+    /// }
+    /// ```
+    #[inline]
+    pub fn all() -> impl Iterator<Item = Self> {
+        [Self::Right, Self::Up, Self::Left, Self::Down].into_iter()
+    }
+    /// Returns the canonical representative of the sector, i.e., its Klein unit `1`, `h`, `-1` or `-h` with the argument zero, and the point `t + t h` on the line `x = t` for `Diagonal(t)`.
+    #[inline]
+    pub fn canonical_representative(&self) -> Perplex<T> {
+        match *self {
+            Self::Right => Perplex::new(T::one(), T::zero()),
+            Self::Up => Perplex::new(T::zero(), T::one()),
+            Self::Left => Perplex::new(-T::one(), T::zero()),
+            Self::Down => Perplex::new(T::zero(), -T::one()),
+            Self::Diagonal(t) => Perplex::new(t, t),
+        }
+    }
+    /// Returns the eight canonical representatives of the sectors and of the light-like rays between them in counterclockwise order, i.e., `1`, `1 + h`, `h`, `-1 + h`, `-1`, `-1 - h`, `-h` and `1 - h`.
+    #[inline]
+    pub fn canonical_representatives() -> [Perplex<T>; 8] {
+        let (one, zero) = (T::one(), T::zero());
+        [
+            Perplex::new(one, zero),
+            Perplex::new(one, one),
+            Perplex::new(zero, one),
+            Perplex::new(-one, one),
+            Perplex::new(-one, zero),
+            Perplex::new(-one, -one),
+            Perplex::new(zero, -one),
+            Perplex::new(one, -one),
+        ]
+    }
+}
+
+impl<T> HyperbolicSector<T> {
+    /// Returns the name of the variant, i.e., `Right`, `Up`, `Left`, `Down` or `Diagonal`, e.g., for labels of plots.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use perplex_num::Perplex;
+    ///
+    /// assert_eq!(Perplex::new(1.0, 2.0).sector().short_name(), "Up");
+    /// assert_eq!(Perplex::new(1.0, -1.0).sector().short_name(), "Diagonal");
+    /// ```
+    #[inline]
+    pub fn short_name(&self) -> &'static str {
+        match self {
+            Self::Right => "Right",
+            Self::Up => "Up",
+            Self::Left => "Left",
+            Self::Down => "Down",
+            Self::Diagonal(_) => "Diagonal",
+        }
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for HyperbolicSector<T> {
+    /// Prints the name of the sector, e.g., `Right`, and the time component of a light-like number as `Diagonal(t=1)`, whereby the precision of the formatter applies to `t`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Diagonal(t) => {
+                write!(f, "Diagonal(t=")?;
+                t.fmt(f)?;
+                write!(f, ")")
+            }
+            _ => write!(f, "{}", self.short_name()),
+        }
+    }
+}
+
+/// The four Klein units `1`, `h`, `-1` and `-h`, which map the right sector onto the `Right`, `Up`, `Left` and `Down` sectors by multiplication, see `Perplex::klein`.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub enum KleinIndex {
+    /// The unit `1` of the `Right` sector.
+    One,
+    /// The unit `h` of the `Up` sector.
+    H,
+    /// The unit `-1` of the `Left` sector.
+    MinusOne,
+    /// The unit `-h` of the `Down` sector.
+    MinusH,
+}
+
+impl KleinIndex {
+    /// Returns an iterator over the four Klein units in counterclockwise order of their sectors.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use perplex_num::{KleinIndex, Perplex};
+    ///
+    /// let z = Perplex::new(2.0, 1.0);
+    /// for k in KleinIndex::all() {
+    ///     let w = z * k.perplex();
+    ///     assert_eq!(w.sector(), k.sector());
+    ///     assert_eq!(w.klein(), Some(k.perplex()));
+    /// }
+    /// ```
+    #[inline]
+    pub fn all() -> impl Iterator<Item = Self> {
+        [Self::One, Self::H, Self::MinusOne, Self::MinusH].into_iter()
+    }
+    /// Returns the Klein unit as a perplex number.
+    #[inline]
+    pub fn perplex<T: Copy + Num + Neg<Output = T>>(&self) -> Perplex<T> {
+        self.sector().canonical_representative()
+    }
+    /// Returns the sector of the Klein unit.
+    #[inline]
+    pub fn sector<T: Copy + Num + Neg<Output = T>>(&self) -> HyperbolicSector<T> {
+        match self {
+            Self::One => HyperbolicSector::Right,
+            Self::H => HyperbolicSector::Up,
+            Self::MinusOne => HyperbolicSector::Left,
+            Self::MinusH => HyperbolicSector::Down,
+        }
+    }
+}
+
+impl KleinIndex {
+    /// Returns whether the unit is negative and whether it is hyperbolic, i.e., the factors `±1` and `1` or `h` of the unit.
+    #[inline]
+    fn parts(self) -> (bool, bool) {
+        match self {
+            Self::One => (false, false),
+            Self::H => (false, true),
+            Self::MinusOne => (true, false),
+            Self::MinusH => (true, true),
+        }
+    }
+    /// Returns the unit with the sign and the hyperbolic factor of `parts`.
+    #[inline]
+    fn from_parts(negative: bool, hyperbolic: bool) -> Self {
+        match (negative, hyperbolic) {
+            (false, false) => Self::One,
+            (false, true) => Self::H,
+            (true, false) => Self::MinusOne,
+            (true, true) => Self::MinusH,
+        }
+    }
+}
+
+impl Mul for KleinIndex {
+    type Output = Self;
+    /// Multiplies the Klein units, which form the Klein four-group `Z2 x Z2` with the identity `1`, since `h^2 = (-1)^2 = (-h)^2 = 1`.
+    #[inline]
+    fn mul(self, rhs: Self) -> Self::Output {
+        let ((n_a, h_a), (n_b, h_b)) = (self.parts(), rhs.parts());
+        Self::from_parts(n_a != n_b, h_a != h_b)
+    }
+}
+
+impl MulAssign for KleinIndex {
+    #[inline]
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs;
+    }
+}
+
+impl Inv for KleinIndex {
+    type Output = Self;
+    /// Returns the inverse, which is the unit itself, since every element of the Klein four-group is its own inverse.
+    #[inline]
+    fn inv(self) -> Self::Output {
+        self
+    }
+}
+
+impl<T: Copy + Num + Neg<Output = T>> From<KleinIndex> for Perplex<T> {
+    /// Returns the Klein unit as a perplex number, see `KleinIndex::perplex`.
+    #[inline]
+    fn from(k: KleinIndex) -> Self {
+        k.perplex()
+    }
+}
+
+impl fmt::Display for KleinIndex {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::One => write!(f, "1"),
+            Self::H => write!(f, "h"),
+            Self::MinusOne => write!(f, "-1"),
+            Self::MinusH => write!(f, "-h"),
+        }
+    }
+}
+
+/// The error of `HyperbolicPolar::new` and `Perplex::from_arg_modulus_checked` for combinations of modulus, argument and sector which cannot originate from a perplex number.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub enum PolarError {
+    /// The modulus is negative, infinite or NaN.
+    InvalidModulus,
+    /// The argument is infinite or NaN outside of the `Diagonal` sector, or zero or NaN in the `Diagonal` sector, where its sign selects the line.
+    InvalidArgument,
+    /// The `Diagonal` sector requires a zero modulus and a finite time component.
+    InvalidDiagonal,
+}
+
+impl fmt::Display for PolarError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidModulus => write!(f, "modulus must be finite and non-negative"),
+            Self::InvalidArgument => write!(
+                f,
+                "argument must be finite outside of the light cone and non-zero on it"
+            ),
+            Self::InvalidDiagonal => write!(
+                f,
+                "light-like numbers require a zero modulus and a finite time component"
+            ),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for PolarError {}
+
 /// Represents a perplex number in hyperbolic polar form.
 ///
 /// This struct is used to convert a perplex number to and from hyperbolic polar form,
 /// which is useful for operations that are more naturally expressed in this form.
 /// The conversion formulas are based on hyperbolic trigonometry principles.
 #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct HyperbolicPolar<T> {
     /// The modulus of the perplex number, representing the hyperbolic radius.
     pub rho: T,
@@ -130,10 +369,10 @@ impl<T: Copy + Float> From<HyperbolicPolar<T>> for Perplex<T> {
             HyperbolicSector::Left => Self::new(-rho * theta.cosh(), -rho * theta.sinh()),
             HyperbolicSector::Down => Self::new(-rho * theta.sinh(), -rho * theta.cosh()),
             HyperbolicSector::Diagonal(t) => {
-                if theta == T::infinity() {
+                // theta is infinite or clamped by `Perplex::polar_clamped`
+                if theta > T::zero() {
                     Self::new(t, t)
                 } else {
-                    // theta == T::neg_infinity()
                     Self::new(t, -t)
                 }
             }
@@ -141,68 +380,104 @@ impl<T: Copy + Float> From<HyperbolicPolar<T>> for Perplex<T> {
     }
 }
 
-impl<T: Copy + Float> Perplex<T> {
-    /// Creates a new `Perplex` number `z`  with a given hyperbolic angle `theta` such that `z= exp(h theta)`.
+impl<T: Copy + Float> HyperbolicPolar<T> {
+    /// Creates the polar form with the modulus `rho`, the argument `theta` and the sector `sector`, or returns a `PolarError` if the combination cannot originate from a perplex number.
     ///
-    /// It is used to create a `Perplex` number with a given phase, using hyperbolic cosine and sine.
+    /// The modulus must be finite and non-negative. The argument must be finite unless the sector is `Diagonal`, in which case the modulus must be zero and the argument must be neither zero nor NaN, since its sign selects the line `x = t` or `x = -t`. The argument of a light-like number is `±∞`, whereby the finite arguments of `Perplex::polar_clamped` are accepted as well.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use perplex_num::{HyperbolicPolar, HyperbolicSector, Perplex, PolarError};
+    ///
+    /// let polar = HyperbolicPolar::new(1.5, 0.8, HyperbolicSector::Right).unwrap();
+    /// assert_eq!(Perplex::from(polar), Perplex::from_polar(1.5, 0.8, HyperbolicSector::Right));
+    /// let err = HyperbolicPolar::new(1.0, f64::NAN, HyperbolicSector::Up);
+    /// assert_eq!(err, Err(PolarError::InvalidArgument));
+    /// ```
     #[inline]
-    pub fn cis(theta: T) -> Self {
-        Self::new(theta.cosh(), theta.sinh())
+    pub fn new(rho: T, theta: T, sector: HyperbolicSector<T>) -> Result<Self, PolarError> {
+        if !rho.is_finite() || rho < T::zero() {
+            return Err(PolarError::InvalidModulus);
+        }
+        match sector {
+            HyperbolicSector::Diagonal(t) => {
+                if !rho.is_zero() || !t.is_finite() {
+                    return Err(PolarError::InvalidDiagonal);
+                } else if theta.is_nan() || theta.is_zero() {
+                    // the sign of a zero argument does not select a line reliably
+                    return Err(PolarError::InvalidArgument);
+                }
+            }
+            _ if !theta.is_finite() => return Err(PolarError::InvalidArgument),
+            _ => {}
+        }
+        Ok(Self { rho, theta, sector })
     }
+}
 
-    /// Calculates the hyperbolic argument of `self`.
-    ///
-    /// The argument is the angle in the hyperbolic plane from the positive time axis to the line
-    /// connecting the origin to `self`. It is defined by a piecewise function, with special cases
-    /// for light-like perplex numbers, whereby lines x=t and x=-t are mapped to ∞ and -∞, respectively, according to Sec. 4.1 in [New characterizations of the ring of the split-complex numbers and the field C of complex numbers and their comparative analyses](https://doi.org/10.48550/arXiv.2305.04586).
-    /// The formula is taken from Eq. 4.1.6 in Sec 4.1.1 `Hyperbolic Exponential Function and Hyperbolic Polar Transformation` in [The Mathematics of Minkowski Space-Time](https://doi.org/10.1007/978-3-7643-8614-6).
+impl<T: Copy> HyperbolicPolar<T> {
+    /// Returns the modulus `rho`.
     #[inline]
-    pub fn arg(self) -> T {
-        let Self { t, x } = self;
-        let (t_abs, x_abs) = (t.abs(), x.abs());
-        if t_abs == x_abs {
-            // self.is_light_like()
-            if t == x {
-                // line x = t
-                T::infinity()
-            } else {
-                // line x = -t
-                T::neg_infinity()
-            }
-        } else if t_abs > x_abs {
-            (x / t).atanh()
-        } else {
-            (t / x).atanh()
+    pub fn rho(&self) -> T {
+        self.rho
+    }
+    /// Returns the argument `theta`.
+    #[inline]
+    pub fn theta(&self) -> T {
+        self.theta
+    }
+    /// Returns the sector.
+    #[inline]
+    pub fn sector(&self) -> HyperbolicSector<T> {
+        self.sector
+    }
+    /// Returns the Klein unit of the sector, or `None` for the `Diagonal` sector.
+    #[inline]
+    pub fn klein_index(&self) -> Option<KleinIndex> {
+        match self.sector {
+            HyperbolicSector::Right => Some(KleinIndex::One),
+            HyperbolicSector::Up => Some(KleinIndex::H),
+            HyperbolicSector::Left => Some(KleinIndex::MinusOne),
+            HyperbolicSector::Down => Some(KleinIndex::MinusH),
+            HyperbolicSector::Diagonal(_) => None,
         }
     }
+}
 
+impl<T: Copy + Num + Neg<Output = T> + PartialOrd> Perplex<T> {
     /// Calculate the Klein index of `self` for space- or time-like numbers. Returns `None` for light-like numbers.
     ///
     /// The Klein index is determined by the sector of the hyperbolic plane in which `self` resides.
     /// Formula is taken from Tab. 1 and Appendix B in [Hyperbolic trigonometry in two-dimensional space-time geometry](https://doi.org/10.1393/ncb/i2003-10012-9).
     #[inline]
-    pub fn klein(self) -> Option<Self> {
+    pub fn klein_index(self) -> Option<KleinIndex> {
         let Self { t, x } = self;
-        let (t_abs, x_abs) = (t.abs(), x.abs());
+        let (t_abs, x_abs) = (abs(t), abs(x));
         if t_abs == x_abs {
             // light-like
             None
         } else if t_abs > x_abs {
             if t > T::zero() {
                 // Right-Sector
-                Some(Self::one())
+                Some(KleinIndex::One)
             } else {
                 // Left-Sector
-                Some(-Self::one())
+                Some(KleinIndex::MinusOne)
             }
         } else if x > T::zero() {
             // Up-Sector
-            Some(Self::h())
+            Some(KleinIndex::H)
         } else {
             // Down-Sector
-            Some(-Self::h())
+            Some(KleinIndex::MinusH)
         }
     }
+    /// Returns the Klein unit `1`, `h`, `-1` or `-h` of `self` as a perplex number, see `klein_index`. Returns `None` for light-like numbers.
+    #[inline]
+    pub fn klein(self) -> Option<Self> {
+        self.klein_index().map(Self::from)
+    }
 
     /// Retrieves the hyperbolic sector of the perplex number.
     ///
@@ -219,6 +494,69 @@ impl<T: Copy + Float> Perplex<T> {
         (*self).into()
     }
 
+    /// Returns the point `((1 + s^2) + 2 s h) / (1 - s^2)` of the unit hyperbola `t^2 - x^2 = 1`, which equals `cis(2 atanh(s))` for `|s| < 1` and lies in the `Left` sector for `|s| > 1`. Returns `None` for `|s| = 1`.
+    ///
+    /// The rational parametrization by the half-angle tangent `s = tanh(theta / 2)` requires no series expansion, hence it is exact for rational components, e.g., the Pythagorean triple `(5, 4, 3)` yields `5/3 + 4/3 h` for `s = 1/2`.
+    #[inline]
+    pub fn cis_rational(s: T) -> Option<Self> {
+        let one = T::one();
+        let denominator = one - s * s;
+        if denominator.is_zero() {
+            None
+        } else {
+            Some(Self::new(
+                (one + s * s) / denominator,
+                (s + s) / denominator,
+            ))
+        }
+    }
+}
+
+/// Returns the absolute value of `v` by comparison, which does not require `Float` or `Signed`.
+#[inline]
+fn abs<T: Copy + Num + Neg<Output = T> + PartialOrd>(v: T) -> T {
+    if v < T::zero() {
+        -v
+    } else {
+        v
+    }
+}
+
+impl<T: Copy + Float> Perplex<T> {
+    /// Creates a new `Perplex` number `z`  with a given hyperbolic angle `theta` such that `z= exp(h theta)`.
+    ///
+    /// It is used to create a `Perplex` number with a given phase, using hyperbolic cosine and sine.
+    #[inline]
+    pub fn cis(theta: T) -> Self {
+        Self::new(theta.cosh(), theta.sinh())
+    }
+
+    /// Calculates the hyperbolic argument of `self`.
+    ///
+    /// The argument is the angle in the hyperbolic plane from the positive time axis to the line
+    /// connecting the origin to `self`. It is defined by a piecewise function, with special cases
+    /// for light-like perplex numbers, whereby lines x=t and x=-t are mapped to ∞ and -∞, respectively, according to Sec. 4.1 in [New characterizations of the ring of the split-complex numbers and the field C of complex numbers and their comparative analyses](https://doi.org/10.48550/arXiv.2305.04586).
+    /// The formula is taken from Eq. 4.1.6 in Sec 4.1.1 `Hyperbolic Exponential Function and Hyperbolic Polar Transformation` in [The Mathematics of Minkowski Space-Time](https://doi.org/10.1007/978-3-7643-8614-6).
+    #[inline]
+    pub fn arg(self) -> T {
+        let Self { t, x } = self;
+        let (t_abs, x_abs) = (t.abs(), x.abs());
+        if t_abs == x_abs {
+            // self.is_light_like()
+            if t == x {
+                // line x = t
+                T::infinity()
+            } else {
+                // line x = -t
+                T::neg_infinity()
+            }
+        } else if t_abs > x_abs {
+            (x / t).atanh()
+        } else {
+            (t / x).atanh()
+        }
+    }
+
     /// Retrieves the hyperbolic polar form from a perplex number.
     ///
     /// # Examples
@@ -235,6 +573,267 @@ impl<T: Copy + Float> Perplex<T> {
     pub fn polar(&self) -> HyperbolicPolar<T> {
         (*self).into()
     }
+
+    /// Retrieves the polar form in log-space as the triple `(ln(rho), theta, klein)`, see `ln_modulus`, `arg` and `klein`.
+    ///
+    /// The logarithm of the modulus is computed without forming the modulus or the squared distance, hence the triple is finite for all non-light-like numbers with finite components. Light-like numbers yield `(-∞, ±∞, None)`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use perplex_num::Perplex;
+    ///
+    /// let z = Perplex::new(-1e-200_f64, 3e-200);
+    /// let (ln_rho, theta, klein) = z.log_polar();
+    /// assert_eq!(z.squared_distance(), 0.0, "Underflow of the squared distance!");
+    /// assert!((ln_rho - z.modulus().ln()).abs() < 1e-12);
+    /// assert_eq!(theta, z.arg());
+    /// assert_eq!(klein, Some(Perplex::new(0.0, 1.0)));
+    /// ```
+    #[inline]
+    pub fn log_polar(&self) -> (T, T, Option<Self>) {
+        (self.ln_modulus(), self.arg(), self.klein())
+    }
+    /// Retrieves the hyperbolic polar form with the argument clamped to `[-max_theta, max_theta]`.
+    ///
+    /// Light-like numbers have the argument `±∞` and near light-like numbers have arbitrarily large arguments. Clamping maps them to the finite bounds for visualization pipelines which cannot handle infinities. The modulus and sector are not altered, hence light-like numbers still convert back to the correct diagonal.
+    ///
+    /// The bound is `|max_theta|`. Since the sign of the argument selects the line of a `Diagonal` sector, its argument is clamped to at least the smallest positive value in magnitude, also for a zero or NaN bound.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use perplex_num::{HyperbolicSector, Perplex};
+    ///
+    /// let polar = Perplex::new(1.0, -1.0).polar_clamped(4.0);
+    /// assert_eq!(polar.theta, -4.0);
+    /// assert_eq!(polar.sector, HyperbolicSector::Diagonal(1.0));
+    /// assert_eq!(Perplex::from(polar), Perplex::new(1.0, -1.0));
+    /// ```
+    #[inline]
+    pub fn polar_clamped(&self, max_theta: T) -> HyperbolicPolar<T> {
+        let polar = self.polar();
+        let max_theta = match polar.sector {
+            HyperbolicSector::Diagonal(_) => max_theta.abs().max(T::min_positive_value()),
+            _ => max_theta.abs(),
+        };
+        let theta = if polar.theta > max_theta {
+            max_theta
+        } else if polar.theta < -max_theta {
+            -max_theta
+        } else {
+            polar.theta
+        };
+        HyperbolicPolar { theta, ..polar }
+    }
+
+    /// Creates a perplex number from its modulus `rho`, argument `theta` and sector without validation, i.e., the conversion of `HyperbolicPolar { rho, theta, sector }`.
+    #[inline]
+    pub fn from_polar(rho: T, theta: T, sector: HyperbolicSector<T>) -> Self {
+        HyperbolicPolar { rho, theta, sector }.into()
+    }
+
+    /// Creates a perplex number from its modulus, argument and sector, or returns a `PolarError` if the combination cannot originate from a perplex number.
+    ///
+    /// The modulus must be finite and non-negative. The argument must be finite unless the sector is `Diagonal`, in which case the modulus must be zero and the argument must be neither zero nor NaN, since its sign selects the line `x = t` or `x = -t`. The argument of a light-like number is `±∞`, whereby the finite arguments of `Perplex::polar_clamped` are accepted as well.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use perplex_num::{HyperbolicSector, Perplex, PolarError};
+    ///
+    /// let z = Perplex::from_arg_modulus_checked(2.0, 0.5, HyperbolicSector::Up).unwrap();
+    /// assert_eq!(z, Perplex::from_polar(2.0, 0.5, HyperbolicSector::Up));
+    /// let err = Perplex::from_arg_modulus_checked(-1.0, 0.5, HyperbolicSector::Right);
+    /// assert_eq!(err, Err(PolarError::InvalidModulus));
+    /// ```
+    pub fn from_arg_modulus_checked(
+        modulus: T,
+        arg: T,
+        sector: HyperbolicSector<T>,
+    ) -> Result<Self, PolarError> {
+        HyperbolicPolar::new(modulus, arg, sector).map(Self::from)
+    }
+
+    /// Raises `self` to the real power `exp` by the hyperbolic polar form, i.e., `klein^exp rho^exp exp(h theta exp)`.
+    ///
+    /// - Right sector: Defined for all exponents, which yields the principal power `rho^exp (cosh(theta exp) + h sinh(theta exp))`.
+    /// - Up, Left and Down sector: The Klein units `h`, `-1` and `-h` have no real powers, hence the result is only defined for integer exponents, whereby `klein^exp` is `1` for even and `klein` for odd exponents. Returns `None` for non-integer exponents.
+    /// - Light-like: The number `t (1 ± h)` is not invertible, and `(1 ± h)^exp = 2^(exp - 1) (1 ± h)` holds for positive exponents. Returns `1` for a zero exponent and `None` for negative exponents, or for non-integer exponents if `t` is negative.
+    ///
+    /// The power `rho^exp` is computed as `exp(exp ln_modulus)` together with the argument in log space, hence near light-like numbers have finite powers even if `rho^exp` underflows.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use perplex_num::Perplex;
+    ///
+    /// let z = Perplex::new(2.0, 1.0);
+    /// approx::assert_abs_diff_eq!(z.powf(2.0).unwrap(), z * z, epsilon = 1e-12);
+    /// approx::assert_abs_diff_eq!(z.powf(0.5).unwrap(), z.sqrt().unwrap(), epsilon = 1e-12);
+    /// assert_eq!(Perplex::new(1.0, 2.0).powf(0.5), None);
+    /// assert_eq!(Perplex::new(1.0, 1.0).powf(3.0), Some(Perplex::new(4.0, 4.0)));
+    /// ```
+    #[inline]
+    pub fn powf(self, exp: T) -> Option<Self> {
+        let HyperbolicPolar { theta, sector, .. } = self.polar();
+        let two = T::one() + T::one();
+        let is_integer = exp.fract().is_zero();
+        let power = |sector: HyperbolicSector<T>| {
+            // the null components rho^exp e^(±theta exp) are formed in log space, such that rho^exp does not underflow or overflow against cosh(theta exp)
+            let ln_rho = exp * self.ln_modulus();
+            let u = (ln_rho + theta * exp).exp();
+            let v = (ln_rho - theta * exp).exp();
+            sector.canonical_representative() * Self::new((u + v) / two, (u - v) / two)
+        };
+        match sector {
+            HyperbolicSector::Diagonal(t) => {
+                if exp.is_zero() {
+                    Some(Self::one())
+                } else if exp < T::zero() || (t < T::zero() && !is_integer) {
+                    None
+                } else {
+                    let s = t.powf(exp) * two.powf(exp - T::one());
+                    Some(if self.t == self.x {
+                        Self::new(s, s)
+                    } else {
+                        Self::new(s, -s)
+                    })
+                }
+            }
+            HyperbolicSector::Right => Some(power(sector)),
+            _ if is_integer => {
+                let sector = if (exp / two).fract().is_zero() {
+                    HyperbolicSector::Right
+                } else {
+                    sector
+                };
+                Some(power(sector))
+            }
+            _ => None,
+        }
+    }
+}
+
+/// A tolerance band around the light cone for the classification of numerically noisy perplex numbers.
+///
+/// The exact classification of `is_light_like`, `klein`, `sector`, `arg` and `polar` compares `|t|` and `|x|` with `==`, such that measured data is practically never light-like.
+/// Within the context, a number is light-like if `||t| - |x|| <= eps max(|t|, |x|)`. The band is relative, hence it is invariant under scaling, and it contains the origin.
+/// Light-like numbers are snapped to the closest point `(t + x)/2 (1 + h)` on the line `x = t` if `t` and `x` have the same sign, or to `(t - x)/2 (1 - h)` on the line `x = -t` otherwise.
+///
+/// # Examples
+///
+/// ```
+/// use perplex_num::{EpsilonContext, HyperbolicSector, Perplex};
+///
+/// let z = Perplex::new(1.0, 1.0 + 1e-12);
+/// assert_eq!(z.sector(), HyperbolicSector::Up);
+/// let context = EpsilonContext::new(1e-9);
+/// assert!(context.is_light_like(z));
+/// assert_eq!(context.arg(z), f64::INFINITY);
+/// assert!(matches!(z.sector_eps(1e-9), HyperbolicSector::Diagonal(_)));
+/// ```
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct EpsilonContext<T> {
+    /// The relative width of the band around the light cone.
+    pub eps: T,
+}
+
+impl<T: Copy + Float> Default for EpsilonContext<T> {
+    /// Defaults to a band of relative width of the square root of the machine epsilon.
+    fn default() -> Self {
+        Self::new(T::epsilon().sqrt())
+    }
+}
+
+impl<T: Copy + Float> EpsilonContext<T> {
+    /// Creates the context with the relative width `eps` of the band around the light cone. The width zero reproduces the exact classification.
+    #[inline]
+    pub fn new(eps: T) -> Self {
+        Self { eps }
+    }
+    /// Checks if `z` lies within the band around the light cone.
+    #[inline]
+    pub fn is_light_like(&self, z: Perplex<T>) -> bool {
+        let (t_abs, x_abs) = (z.t.abs(), z.x.abs());
+        (t_abs - x_abs).abs() <= self.eps * t_abs.max(x_abs)
+    }
+    /// Calculates the Klein index of `z`, or returns `None` if `z` is light-like within the context, see `Perplex::klein`.
+    #[inline]
+    pub fn klein(&self, z: Perplex<T>) -> Option<Perplex<T>> {
+        if self.is_light_like(z) {
+            None
+        } else {
+            z.klein()
+        }
+    }
+    /// Returns the sector of `z`, whereby light-like numbers within the context are snapped to the closest diagonal, see `HyperbolicSector::Diagonal`.
+    #[inline]
+    pub fn sector(&self, z: Perplex<T>) -> HyperbolicSector<T> {
+        if self.is_light_like(z) {
+            HyperbolicSector::Diagonal(self.snap(z).t)
+        } else {
+            z.sector()
+        }
+    }
+    /// Returns the hyperbolic argument of `z`, which is `∞` on the line `x = t` and `-∞` on the line `x = -t` for light-like numbers within the context, see `Perplex::arg`.
+    #[inline]
+    pub fn arg(&self, z: Perplex<T>) -> T {
+        if self.is_light_like(z) {
+            self.snap(z).arg()
+        } else {
+            z.arg()
+        }
+    }
+    /// Returns the hyperbolic polar form of `z`, whereby light-like numbers within the context have a zero modulus and are snapped to the closest diagonal.
+    #[inline]
+    pub fn polar(&self, z: Perplex<T>) -> HyperbolicPolar<T> {
+        if self.is_light_like(z) {
+            self.snap(z).polar()
+        } else {
+            z.polar()
+        }
+    }
+    /// Projects `z` onto the closest diagonal, i.e., onto `x = t` if `t` and `x` have the same sign and onto `x = -t` otherwise.
+    #[inline]
+    fn snap(&self, z: Perplex<T>) -> Perplex<T> {
+        let two = T::one() + T::one();
+        if (z.t >= T::zero()) == (z.x >= T::zero()) {
+            let t = (z.t + z.x) / two;
+            Perplex::new(t, t)
+        } else {
+            let t = (z.t - z.x) / two;
+            Perplex::new(t, -t)
+        }
+    }
+}
+
+impl<T: Copy + Float> Perplex<T> {
+    /// Checks if `self` is light-like within the relative tolerance `eps`, see `EpsilonContext`.
+    #[inline]
+    pub fn is_light_like_eps(self, eps: T) -> bool {
+        EpsilonContext::new(eps).is_light_like(self)
+    }
+    /// Calculates the Klein index of `self` within the relative tolerance `eps`, see `EpsilonContext`.
+    #[inline]
+    pub fn klein_eps(self, eps: T) -> Option<Self> {
+        EpsilonContext::new(eps).klein(self)
+    }
+    /// Returns the sector of `self` within the relative tolerance `eps`, see `EpsilonContext`.
+    #[inline]
+    pub fn sector_eps(self, eps: T) -> HyperbolicSector<T> {
+        EpsilonContext::new(eps).sector(self)
+    }
+    /// Returns the hyperbolic argument of `self` within the relative tolerance `eps`, see `EpsilonContext`.
+    #[inline]
+    pub fn arg_eps(self, eps: T) -> T {
+        EpsilonContext::new(eps).arg(self)
+    }
+    /// Returns the hyperbolic polar form of `self` within the relative tolerance `eps`, see `EpsilonContext`.
+    #[inline]
+    pub fn polar_eps(self, eps: T) -> HyperbolicPolar<T> {
+        EpsilonContext::new(eps).polar(self)
+    }
 }
 
 impl<T: Copy + Float> Pow<u32> for HyperbolicPolar<T> {
@@ -274,6 +873,118 @@ impl<T: Copy + Float> Pow<u32> for HyperbolicPolar<T> {
     }
 }
 
+impl<T: Copy + Float> HyperbolicPolar<T> {
+    /// Raises `self` to the power of signed `exp`, or returns `None` if `exp` is negative and `self` is in the `Diagonal` sector.
+    ///
+    /// Since the Klein units are their own inverses, the formula of `Pow<u32>` extends to negative exponents, i.e., the modulus `rho^exp`, the argument `exp theta` and the `Right` sector for even exponents, which agrees with `Perplex::powi`.
+    #[inline]
+    pub fn powi(self, exp: i32) -> Option<Self> {
+        if exp >= 0 {
+            return Some(self.pow(exp as u32));
+        }
+        let Self { rho, theta, sector } = self;
+        if let HyperbolicSector::Diagonal(_) = sector {
+            return None;
+        }
+        Some(HyperbolicPolar {
+            rho: rho.powi(exp),
+            theta: T::from(exp).unwrap() * theta,
+            sector: if exp % 2 == 0 {
+                HyperbolicSector::Right
+            } else {
+                sector
+            },
+        })
+    }
+}
+
+impl<T: Copy + Float> HyperbolicPolar<T> {
+    /// Returns the product of the light-like `self` on the diagonal `t (1 ± h)` with `other`, which is `t (a ± b) (1 ± h)` for `other = a + b h`.
+    #[inline]
+    fn diagonal_mul(self, t: T, other: Self) -> Self {
+        let upper = self.theta > T::zero();
+        let factor = match (other.sector, other.klein_index()) {
+            (_, Some(k)) => {
+                // the null components of other are u = k_u rho exp(theta) and v = k_v rho exp(-theta)
+                let k: Perplex<T> = k.into();
+                if upper {
+                    (k.t + k.x) * other.rho * other.theta.exp()
+                } else {
+                    (k.t - k.x) * other.rho * (-other.theta).exp()
+                }
+            }
+            (HyperbolicSector::Diagonal(s), None) if upper == (other.theta > T::zero()) => {
+                // (1 ± h)^2 = 2 (1 ± h)
+                s + s
+            }
+            _ => {
+                // (1 + h) (1 - h) = 0
+                return Self {
+                    rho: T::zero(),
+                    theta: T::infinity(),
+                    sector: HyperbolicSector::Diagonal(T::zero()),
+                };
+            }
+        };
+        Self {
+            rho: T::zero(),
+            theta: self.theta,
+            sector: HyperbolicSector::Diagonal(t * factor),
+        }
+    }
+}
+
+impl<T: Copy + Float> Mul for HyperbolicPolar<T> {
+    type Output = Self;
+    /// Multiplies `self` by `rhs` in polar form, i.e., the moduli and the Klein units multiply and the arguments add.
+    ///
+    /// A light-like factor `t (1 ± h)` in the `Diagonal` sector absorbs the other factor `a + b h` to `t (a ± b) (1 ± h)` on the same diagonal, and the product of light-like factors on different diagonals is zero.
+    #[inline]
+    fn mul(self, rhs: Self) -> Self::Output {
+        match (self.sector, rhs.sector) {
+            (HyperbolicSector::Diagonal(t), _) => self.diagonal_mul(t, rhs),
+            (_, HyperbolicSector::Diagonal(t)) => rhs.diagonal_mul(t, self),
+            _ => match (self.klein_index(), rhs.klein_index()) {
+                (Some(a), Some(b)) => Self {
+                    rho: self.rho * rhs.rho,
+                    theta: self.theta + rhs.theta,
+                    sector: (a * b).sector(),
+                },
+                _ => unreachable!("Only the Diagonal sector has no Klein index!"),
+            },
+        }
+    }
+}
+
+impl<T: Copy + Float> MulAssign for HyperbolicPolar<T> {
+    #[inline]
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs;
+    }
+}
+
+impl<T: Copy + Float> Div for HyperbolicPolar<T> {
+    type Output = Option<Self>;
+    /// Divides `self` by `rhs` in polar form, i.e., multiplies by the inverse `rho^-1`, `-theta` with the same Klein unit. Division by a light-like number in the `Diagonal` sector yields `None`.
+    #[inline]
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn div(self, rhs: Self) -> Self::Output {
+        rhs.powi(-1).map(|inverse| self * inverse)
+    }
+}
+
+impl<T: Copy + Float> DivAssign for HyperbolicPolar<T> {
+    /// Divides `self` by `rhs` in place. Division by a light-like number yields a NaN modulus and argument in the `Right` sector, which converts to a perplex number with NaN components like the `DivAssign` of `Perplex`.
+    #[inline]
+    fn div_assign(&mut self, rhs: Self) {
+        *self = (*self / rhs).unwrap_or(Self {
+            rho: T::nan(),
+            theta: T::nan(),
+            sector: HyperbolicSector::Right,
+        });
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -403,6 +1114,128 @@ mod tests {
         polar_mul_test_loop(z.inv().unwrap());
     }
     #[test]
+    fn test_polar_negative_powers() {
+        for z in [
+            Perplex::new(2.0, 1.0),
+            Perplex::new(-2.0, 1.0),
+            Perplex::new(1.0, 2.0),
+            Perplex::new(1.0, -2.0),
+            Perplex::new(-0.5, -0.25),
+        ] {
+            let polar = HyperbolicPolar::from(z);
+            for n in -5i32..=5 {
+                let w = Perplex::from(polar.powi(n).unwrap());
+                assert_abs_diff_eq!(w, z.powi(n).unwrap(), epsilon = 1e-12);
+            }
+            assert_eq!(polar.powi(-2).unwrap().sector, HyperbolicSector::Right);
+            assert_eq!(polar.powi(-3).unwrap().sector, polar.sector);
+            assert_eq!(polar.powi(4), Some(polar.pow(4)));
+        }
+        for z in [
+            Perplex::new(1.0, 1.0),
+            Perplex::new(-2.0, 2.0),
+            Perplex::zero(),
+        ] {
+            let polar = HyperbolicPolar::from(z);
+            assert!(
+                polar.powi(-1).is_none(),
+                "Light-like numbers are not invertible!"
+            );
+            assert_eq!(polar.powi(2), Some(polar.pow(2)));
+        }
+    }
+    #[test]
+    fn test_polar_mul_div() {
+        let values = [
+            Perplex::new(2.0, 1.0),
+            Perplex::new(-2.0, 1.0),
+            Perplex::new(1.0, 2.0),
+            Perplex::new(0.5, -1.5),
+            Perplex::new(-0.75, 0.25),
+            Perplex::new(1.5, 1.5),
+            Perplex::new(-0.5, 0.5),
+            Perplex::new(2.0, -2.0),
+            Perplex::zero(),
+        ];
+        for z in values {
+            for w in values {
+                let (a, b) = (HyperbolicPolar::from(z), HyperbolicPolar::from(w));
+                assert_abs_diff_eq!(Perplex::from(a * b), z * w, epsilon = 1e-12);
+                let mut c = a;
+                c *= b;
+                assert_eq!(c, a * b);
+                match z.checked_div(&w) {
+                    Some(q) => {
+                        assert_abs_diff_eq!(Perplex::from((a / b).unwrap()), q, epsilon = 1e-12)
+                    }
+                    None => assert!((a / b).is_none(), "Division by a light-like number!"),
+                }
+            }
+        }
+        let (z, w) = (Perplex::new(1.5, 0.5), Perplex::new(-0.5, 1.25));
+        let (a, b) = (z.polar(), w.polar());
+        assert_eq!((a * b).sector, HyperbolicSector::Up);
+        let chain = (0..8).fold(a, |acc, _| acc * b);
+        assert_abs_diff_eq!(Perplex::from(chain), z * w.powu(8), epsilon = 1e-9);
+        let mut c = a;
+        c /= Perplex::new(1.0, -1.0).polar();
+        assert!(Perplex::from(c).t.is_nan());
+        assert_eq!(
+            Perplex::new(1.0, 1.0).polar() * Perplex::new(1.0, -1.0).polar(),
+            Perplex::zero().polar(),
+            "Different diagonals annihilate each other!"
+        );
+    }
+    #[test]
+    fn test_klein_group() {
+        let units: Vec<_> = KleinIndex::all().collect();
+        for &a in &units {
+            assert_eq!(a * KleinIndex::One, a, "Identity!");
+            assert_eq!(a * a, KleinIndex::One, "Self-inverse!");
+            assert_eq!(a.inv(), a);
+            for &b in &units {
+                assert_eq!(a * b, b * a, "Abelian!");
+                let product: Perplex<f64> = (a * b).into();
+                assert_eq!(product, a.perplex::<f64>() * b.perplex());
+                let mut c = a;
+                c *= b;
+                assert_eq!(c, a * b);
+            }
+        }
+        assert_eq!(KleinIndex::H * KleinIndex::MinusOne, KleinIndex::MinusH);
+        for z in [
+            Perplex::new(2.0, 1.0),
+            Perplex::new(-1.0, 3.0),
+            Perplex::new(0.5, -2.0),
+        ] {
+            let k = z.klein_index().unwrap();
+            assert_eq!(z.klein(), Some(Perplex::from(k)));
+            assert_eq!(z.polar().klein_index(), Some(k));
+        }
+        assert_eq!(Perplex::new(2.0, -2.0).klein_index(), None);
+        // light-like numbers are in no sector, hence the exponential series applies
+        let z = Perplex::new(-1.5, 1.5);
+        assert_eq!(
+            z.exp(),
+            Perplex::new(z.t.exp() * z.x.cosh(), z.t.exp() * z.x.sinh())
+        );
+    }
+    #[test]
+    fn test_sector_display() {
+        let names: Vec<_> = HyperbolicSector::<f64>::all()
+            .map(|s| s.to_string())
+            .collect();
+        assert_eq!(names, ["Right", "Up", "Left", "Down"]);
+        let diagonal = Perplex::new(1.0, -1.0).sector();
+        assert_eq!(diagonal.to_string(), "Diagonal(t=1)");
+        assert_eq!(format!("{diagonal:.1}"), "Diagonal(t=1.0)");
+        assert_eq!(diagonal.short_name(), "Diagonal");
+        assert_eq!(
+            HyperbolicSector::<i32>::Diagonal(-2).to_string(),
+            "Diagonal(t=-2)"
+        );
+    }
+    #[test]
     fn test_polar_sector() {
         let perplex = Perplex::new(1.0, 0.5);
         assert_eq!(perplex.sector(), HyperbolicSector::Right);
@@ -411,4 +1244,244 @@ mod tests {
         assert_eq!(polar.theta, perplex.arg());
         assert_eq!(polar.sector, HyperbolicSector::Right);
     }
+    #[test]
+    fn test_polar_clamped() {
+        let z = Perplex::new(1.0, 0.5);
+        assert_eq!(
+            z.polar_clamped(3.0),
+            z.polar(),
+            "Small arguments are not clamped!"
+        );
+        let near = Perplex::new(1.0, 1.0 - 1e-12);
+        let polar = near.polar_clamped(3.0);
+        assert_eq!(polar.theta, 3.0);
+        assert_eq!(polar.sector, HyperbolicSector::Right);
+        let polar = Perplex::new(-2.0, -2.0).polar_clamped(3.0);
+        assert_eq!(
+            polar.theta, 3.0,
+            "Line x = t is clamped to the upper bound!"
+        );
+        assert_eq!(Perplex::from(polar), Perplex::new(-2.0, -2.0));
+        let polar = Perplex::new(0.5, -0.5).polar_clamped(3.0);
+        assert_eq!(
+            polar.theta, -3.0,
+            "Line x = -t is clamped to the lower bound!"
+        );
+        assert_eq!(Perplex::from(polar), Perplex::new(0.5, -0.5));
+        assert!(Perplex::new(f64::NAN, 1.0)
+            .polar_clamped(3.0)
+            .theta
+            .is_nan());
+        for max_theta in [0.0, -3.0, f64::NAN] {
+            for z in [Perplex::new(1.0, 1.0), Perplex::new(1.0, -1.0)] {
+                let polar = z.polar_clamped(max_theta);
+                assert!(polar.theta.is_finite());
+                assert_eq!(Perplex::from(polar), z, "Diagonal is preserved!");
+            }
+        }
+        assert_eq!(Perplex::new(1.0, -1.0).polar_clamped(-3.0).theta, -3.0);
+        assert_eq!(Perplex::new(1.0, 0.5).polar_clamped(0.0).theta, 0.0);
+    }
+    #[test]
+    fn test_from_arg_modulus_checked() {
+        for sector in [
+            HyperbolicSector::Right,
+            HyperbolicSector::Up,
+            HyperbolicSector::Left,
+            HyperbolicSector::Down,
+        ] {
+            let z = Perplex::from_arg_modulus_checked(1.5, -0.25, sector).unwrap();
+            assert_eq!(z.sector(), sector);
+            assert_abs_diff_eq!(z.norm(), 1.5, epsilon = 1e-12);
+            assert_abs_diff_eq!(z.arg(), -0.25, epsilon = 1e-12);
+        }
+        let z = Perplex::new(-2.0, 2.0);
+        let HyperbolicPolar { rho, theta, sector } = z.polar();
+        assert_eq!(Perplex::from_arg_modulus_checked(rho, theta, sector), Ok(z));
+        let check = Perplex::from_arg_modulus_checked;
+        let right = HyperbolicSector::Right;
+        assert_eq!(check(-1.0, 0.0, right), Err(PolarError::InvalidModulus));
+        assert_eq!(check(f64::NAN, 0.0, right), Err(PolarError::InvalidModulus));
+        assert_eq!(
+            check(f64::INFINITY, 0.0, right),
+            Err(PolarError::InvalidModulus)
+        );
+        assert_eq!(
+            check(1.0, f64::INFINITY, right),
+            Err(PolarError::InvalidArgument)
+        );
+        let diagonal = HyperbolicSector::Diagonal(1.0);
+        assert_eq!(
+            check(0.0, f64::NAN, diagonal),
+            Err(PolarError::InvalidArgument)
+        );
+        assert_eq!(
+            check(1.0, f64::INFINITY, diagonal),
+            Err(PolarError::InvalidDiagonal)
+        );
+        assert_eq!(
+            check(0.0, 0.0, HyperbolicSector::Diagonal(f64::NAN)),
+            Err(PolarError::InvalidDiagonal)
+        );
+        for theta in [0.0, -0.0] {
+            assert_eq!(
+                check(0.0, theta, diagonal),
+                Err(PolarError::InvalidArgument),
+                "Zero argument selects no line!"
+            );
+        }
+        assert_eq!(check(0.0, -3.0, diagonal), Ok(Perplex::new(1.0, -1.0)));
+        let clamped = Perplex::new(2.0, 2.0).polar_clamped(3.0);
+        assert_eq!(
+            HyperbolicPolar::new(clamped.rho, clamped.theta, clamped.sector),
+            Ok(clamped)
+        );
+    }
+    #[test]
+    fn test_polar_constructor() {
+        let z = Perplex::new(-1.0, 0.6);
+        let polar = HyperbolicPolar::new(z.norm(), z.arg(), HyperbolicSector::Left).unwrap();
+        assert_eq!(polar, z.polar());
+        assert_eq!((polar.rho(), polar.theta()), (0.8, z.arg()));
+        assert_eq!(polar.sector(), HyperbolicSector::Left);
+        assert_eq!(polar.klein_index(), Some(KleinIndex::MinusOne));
+        let light = HyperbolicPolar::new(0.0, f64::NEG_INFINITY, HyperbolicSector::Diagonal(2.0));
+        assert_eq!(Perplex::from(light.unwrap()), Perplex::new(2.0, -2.0));
+        assert_eq!(light.unwrap().klein_index(), None);
+        assert_eq!(
+            HyperbolicPolar::new(-0.5, 0.0, HyperbolicSector::Right),
+            Err(PolarError::InvalidModulus)
+        );
+        assert_eq!(
+            HyperbolicPolar::new(1.0, 0.0, HyperbolicSector::Diagonal(1.0)),
+            Err(PolarError::InvalidDiagonal)
+        );
+        for k in KleinIndex::all() {
+            let polar = HyperbolicPolar::new(1.0, 0.0, k.sector()).unwrap();
+            assert_eq!(polar.klein_index(), Some(k));
+        }
+    }
+    #[test]
+    fn test_powf() {
+        for t in [0.5, 1.0, 3.0] {
+            for x in [-0.4, 0.0, 0.3] {
+                let z = Perplex::new(t, x * t);
+                assert_abs_diff_eq!(z.powf(2.0).unwrap(), z.powu(2), epsilon = 1e-12);
+                assert_abs_diff_eq!(z.powf(-3.0).unwrap(), z.powi(-3).unwrap(), epsilon = 1e-12);
+                let root = z.powf(1.0 / 3.0).unwrap();
+                assert_abs_diff_eq!(root * root * root, z, epsilon = 1e-12);
+            }
+        }
+        for z in [
+            Perplex::new(0.5, 2.0),
+            Perplex::new(-3.0, 1.0),
+            Perplex::new(0.2, -1.5),
+        ] {
+            assert_abs_diff_eq!(z.powf(2.0).unwrap(), z.powu(2), epsilon = 1e-12);
+            assert_abs_diff_eq!(z.powf(3.0).unwrap(), z.powu(3), epsilon = 1e-12);
+            assert_abs_diff_eq!(z.powf(-1.0).unwrap(), z.inv().unwrap(), epsilon = 1e-12);
+            assert_eq!(z.powf(0.5), None, "No real powers of the Klein units!");
+        }
+        let z = Perplex::new(-2.0, 2.0);
+        assert_eq!(z.powf(3.0), Some(z.powu(3)));
+        assert_eq!(z.powf(0.0), Some(Perplex::one()));
+        assert_eq!(z.powf(1.5), None);
+        assert_eq!(z.powf(-1.0), None, "Light-like numbers are not invertible!");
+        assert_abs_diff_eq!(
+            Perplex::new(2.0, -2.0).powf(1.5).unwrap(),
+            Perplex::new(4.0, -4.0),
+            epsilon = 1e-12
+        );
+        assert_eq!(Perplex::new(0.0, 0.0).powf(2.5), Some(Perplex::zero()));
+        // rho^120 underflows and cosh(120 theta) overflows, whereas the power is finite
+        let z = Perplex::new(1.0, 1.0 - 1e-6);
+        assert_eq!(z.modulus().powf(120.0), 0.0);
+        let (u, v) = ((z.t + z.x).powf(120.0), (z.t - z.x).powf(120.0));
+        let power = z.powf(120.0).unwrap();
+        approx::assert_relative_eq!(power.t, (u + v) / 2.0, max_relative = 1e-6);
+        approx::assert_relative_eq!(power.x, (u - v) / 2.0, max_relative = 1e-6);
+    }
+    #[test]
+    fn test_epsilon_context() {
+        let context = EpsilonContext::new(1e-6);
+        let noisy = Perplex::new(-2.0, 2.0 + 1e-9);
+        assert!(!noisy.is_light_like() && context.is_light_like(noisy));
+        assert!(context.klein(noisy).is_none());
+        assert!(noisy.klein().is_some(), "Exact classification!");
+        assert_abs_diff_eq!(
+            Perplex::from(context.polar(noisy)),
+            Perplex::new(-2.0, 2.0),
+            epsilon = 1e-9
+        );
+        assert_eq!(context.arg(noisy), f64::NEG_INFINITY);
+        assert_eq!(noisy.arg_eps(1e-6), f64::NEG_INFINITY);
+        assert_eq!(context.polar(noisy).rho, 0.0);
+        let z = Perplex::new(1e-300, 0.9e-300);
+        assert!(!z.is_light_like_eps(1e-6), "The band is relative!");
+        assert_eq!(z.sector_eps(1e-6), HyperbolicSector::Right);
+        assert_eq!(z.klein_eps(1e-6), Some(Perplex::new(1.0, 0.0)));
+        assert_eq!(z.polar_eps(1e-6), z.polar());
+        assert!(Perplex::new(0.0, 0.0).is_light_like_eps(0.0));
+        assert_eq!(
+            Perplex::new(3.0, 3.0).sector_eps(0.0),
+            Perplex::new(3.0, 3.0).sector()
+        );
+        assert!(!Perplex::new(f64::NAN, 1.0).is_light_like_eps(1e-6));
+    }
+    #[test]
+    fn test_rational_sector() {
+        use num_rational::Ratio;
+        let r = Ratio::new;
+        let z = Perplex::new(r(1, 3), r(-1, 2));
+        assert_eq!(z.klein(), Some(-Perplex::h()));
+        assert_eq!(z.sector(), HyperbolicSector::Down);
+        assert_eq!(Perplex::new(r(2, 3), r(-2, 3)).klein(), None);
+        let cis = Perplex::cis_rational(r(1, 2)).unwrap();
+        assert_eq!(cis, Perplex::new(r(5, 3), r(4, 3)), "Pythagorean triple!");
+        assert_eq!(cis.squared_distance(), r(1, 1));
+        assert_eq!(
+            Perplex::cis_rational(r(1, 2)).unwrap() * Perplex::cis_rational(r(1, 3)).unwrap(),
+            Perplex::cis_rational(r(5, 7)).unwrap(),
+            "Addition theorem of tanh!"
+        );
+        let left = Perplex::cis_rational(r(3, 1)).unwrap();
+        assert_eq!(left.sector(), HyperbolicSector::Left);
+        assert_eq!(left.squared_distance(), r(1, 1));
+        assert_eq!(Perplex::cis_rational(r(-1, 1)), None);
+        assert_abs_diff_eq!(
+            Perplex::cis_rational(0.5).unwrap(),
+            Perplex::cis(2.0 * 0.5f64.atanh()),
+            epsilon = 1e-15
+        );
+    }
+    #[test]
+    fn test_klein_units() {
+        let units: Vec<Perplex<f64>> = KleinIndex::all().map(|k| k.perplex()).collect();
+        assert_eq!(
+            units,
+            vec![Perplex::one(), Perplex::h(), -Perplex::one(), -Perplex::h()]
+        );
+        let sectors: Vec<HyperbolicSector<f64>> = HyperbolicSector::all().collect();
+        assert_eq!(
+            sectors,
+            KleinIndex::all().map(|k| k.sector()).collect::<Vec<_>>()
+        );
+        let representatives = HyperbolicSector::<f64>::canonical_representatives();
+        for (i, z) in representatives.iter().enumerate() {
+            assert_eq!(
+                z.is_light_like(),
+                i % 2 == 1,
+                "Sectors and light-like rays alternate!"
+            );
+        }
+        assert_eq!(
+            representatives[2],
+            HyperbolicSector::Up.canonical_representative()
+        );
+        assert_eq!(
+            HyperbolicSector::Diagonal(-2.0).canonical_representative(),
+            Perplex::new(-2.0, -2.0)
+        );
+        assert_eq!(KleinIndex::MinusH.to_string(), "-h");
+    }
 }
@@ -0,0 +1,207 @@
+//! # Polynomial Module
+//!
+//! This module provides the `PerplexPolynomial` type for polynomials `p(z) = sum_k c_k z^k` with perplex coefficients.
+//! In the null basis `u = t + x` and `v = t - x`, the perplex product acts componentwise, hence `p` splits into two real polynomials `p_u` and `p_v` with the null components of the coefficients.
+//! A perplex number is a root of `p` if and only if its null components are roots of `p_u` and `p_v`, respectively. Therefore, a polynomial of degree `n` may have up to `n^2` roots, see [Fundamental Theorems of Algebra for the Perplexes](https://doi.org/10.4169/074683409X475643).
+//!
+//! ## Features
+//! - Evaluation by the Horner scheme and the formal derivative.
+//! - `null_components`: The real polynomials `p_u` and `p_v`.
+//! - `roots`: The analytic root finder for polynomials whose null components have at most degree two.
+//! - `companion_matrix`, `companion_blocks` and `eigen_roots`: (Optional feature `matrix`) The companion matrix and the root estimation by the eigenvalues of the real companion matrices of the null components.
+//!
+//! ## Example
+//! ```
+//! use perplex_num::{Perplex, PerplexPolynomial};
+//! // p(z) = z^2 - 1 has the roots 1, -1, h and -h
+//! let p = PerplexPolynomial::new(vec![-Perplex::new(1.0, 0.0), Perplex::new(0.0, 0.0), Perplex::new(1.0, 0.0)]);
+//! let roots = p.roots().unwrap();
+//! assert_eq!(roots.len(), 4);
+//! assert!(roots.contains(&Perplex::h()));
+//! ```
+
+use super::Perplex;
+use num_traits::{Float, Num};
+
+/// A polynomial with perplex coefficients in ascending order, i.e., `coefficients[k]` belongs to `z^k`.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Default)]
+pub struct PerplexPolynomial<T> {
+    coefficients: Vec<Perplex<T>>,
+}
+
+impl<T: Clone + Num> PerplexPolynomial<T> {
+    /// Creates a polynomial from coefficients in ascending order, whereby trailing zero coefficients are removed.
+    #[inline]
+    pub fn new(mut coefficients: Vec<Perplex<T>>) -> Self {
+        while coefficients
+            .last()
+            .is_some_and(|c| c.t.is_zero() && c.x.is_zero())
+        {
+            coefficients.pop();
+        }
+        Self { coefficients }
+    }
+    /// Returns the coefficients in ascending order without trailing zeros.
+    #[inline]
+    pub fn coefficients(&self) -> &[Perplex<T>] {
+        &self.coefficients
+    }
+    /// Returns the degree of the polynomial, or `None` for the zero polynomial.
+    #[inline]
+    pub fn degree(&self) -> Option<usize> {
+        self.coefficients.len().checked_sub(1)
+    }
+    /// Evaluates the polynomial at `z` by the Horner scheme.
+    #[inline]
+    pub fn eval(&self, z: Perplex<T>) -> Perplex<T> {
+        self.coefficients
+            .iter()
+            .rev()
+            .fold(Perplex::new(T::zero(), T::zero()), |acc, c| {
+                acc * z.clone() + c.clone()
+            })
+    }
+    /// Returns the formal derivative `sum_k k c_k z^(k-1)`.
+    pub fn derivative(&self) -> Self {
+        let mut k = T::zero();
+        let coefficients = self
+            .coefficients
+            .iter()
+            .skip(1)
+            .map(|c| {
+                k = k.clone() + T::one();
+                c.scale(k.clone())
+            })
+            .collect();
+        Self::new(coefficients)
+    }
+    /// Returns the coefficients of the real polynomials `p_u` and `p_v` of the null components `u = t + x` and `v = t - x` in ascending order, both without trailing zeros.
+    pub fn null_components(&self) -> (Vec<T>, Vec<T>) {
+        let trim = |mut coefficients: Vec<T>| {
+            while coefficients.last().is_some_and(|c| c.is_zero()) {
+                coefficients.pop();
+            }
+            coefficients
+        };
+        let u = self
+            .coefficients
+            .iter()
+            .map(|c| c.t.clone() + c.x.clone())
+            .collect();
+        let v = self
+            .coefficients
+            .iter()
+            .map(|c| c.t.clone() - c.x.clone())
+            .collect();
+        (trim(u), trim(v))
+    }
+}
+
+impl<T: Copy + Float> PerplexPolynomial<T> {
+    /// Returns all roots of the polynomial, if both null components have at most degree two.
+    ///
+    /// The roots combine each real root of `p_u` with each real root of `p_v`. Repeated real roots are reported once.
+    /// Returns `None` if a null component has a degree greater than two or is the zero polynomial, since it has infinitely many roots in the latter case.
+    pub fn roots(&self) -> Option<Vec<Perplex<T>>> {
+        let (u, v) = self.null_components();
+        Some(combine_null_roots(&real_roots(&u)?, &real_roots(&v)?))
+    }
+}
+
+/// Returns the distinct real roots of a real polynomial of at most degree two in ascending order, or `None` for the zero polynomial or a higher degree.
+fn real_roots<T: Copy + Float>(coefficients: &[T]) -> Option<Vec<T>> {
+    let two = T::one() + T::one();
+    match *coefficients {
+        [] => None,
+        [_] => Some(Vec::new()),
+        [c0, c1] => Some(vec![-c0 / c1]),
+        [c0, c1, c2] => {
+            let discriminant = c1 * c1 - two * two * c2 * c0;
+            if discriminant < T::zero() {
+                Some(Vec::new())
+            } else if discriminant.is_zero() {
+                Some(vec![-c1 / (two * c2)])
+            } else {
+                // numerically stable quadratic formula without cancellation
+                let q = -(c1 + c1.signum() * discriminant.sqrt()) / two;
+                let (r1, r2) = (q / c2, c0 / q);
+                Some(vec![r1.min(r2), r1.max(r2)])
+            }
+        }
+        _ => None,
+    }
+}
+
+/// Returns the perplex numbers with the null components `u` out of `u_roots` and `v` out of `v_roots`.
+pub(crate) fn combine_null_roots<T: Copy + Float>(u_roots: &[T], v_roots: &[T]) -> Vec<Perplex<T>> {
+    let two = T::one() + T::one();
+    u_roots
+        .iter()
+        .flat_map(|&u| {
+            v_roots
+                .iter()
+                .map(move |&v| Perplex::new((u + v) / two, (u - v) / two))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_abs_diff_eq;
+    #[test]
+    fn test_polynomial() {
+        let p = PerplexPolynomial::new(vec![
+            Perplex::new(1.0, 0.0),
+            Perplex::new(0.0, 2.0),
+            Perplex::new(3.0, -1.0),
+            Perplex::new(0.0, 0.0),
+        ]);
+        assert_eq!(p.degree(), Some(2), "Trailing zeros are removed!");
+        let z = Perplex::new(0.5, -1.5);
+        assert_eq!(
+            p.eval(z),
+            Perplex::new(1.0, 0.0) + Perplex::new(0.0, 2.0) * z + Perplex::new(3.0, -1.0) * z * z
+        );
+        assert_eq!(
+            p.derivative().coefficients(),
+            &[Perplex::new(0.0, 2.0), Perplex::new(6.0, -2.0)]
+        );
+        assert_eq!(
+            p.null_components(),
+            (vec![1.0, 2.0, 2.0], vec![1.0, -2.0, 4.0])
+        );
+        let zero = PerplexPolynomial::<f64>::new(vec![Perplex::new(0.0, 0.0)]);
+        assert_eq!(zero.degree(), None);
+        assert_eq!(zero.eval(z), Perplex::new(0.0, 0.0));
+        assert_eq!(zero.derivative(), zero);
+    }
+    #[test]
+    fn test_roots() {
+        // (z - r1)(z - r2) with perplex roots r1 and r2
+        let (r1, r2) = (Perplex::new(1.0, 0.5), Perplex::new(-2.0, 1.0));
+        let p = PerplexPolynomial::new(vec![r1 * r2, -(r1 + r2), Perplex::new(1.0, 0.0)]);
+        let roots = p.roots().unwrap();
+        assert_eq!(roots.len(), 4, "Quadratic polynomials may have four roots!");
+        for root in roots.iter() {
+            assert_abs_diff_eq!(p.eval(*root), Perplex::new(0.0, 0.0), epsilon = 1e-12);
+        }
+        assert!(roots.iter().any(|z| (*z - r1).l1_norm() < 1e-12));
+        assert!(roots.iter().any(|z| (*z - r2).l1_norm() < 1e-12));
+        // z^2 + 1 has no roots, since the null components of z^2 are non-negative
+        let p = PerplexPolynomial::new(vec![
+            Perplex::new(1.0, 0.0),
+            Perplex::new(0.0, 0.0),
+            Perplex::new(1.0, 0.0),
+        ]);
+        assert!(p.roots().unwrap().is_empty());
+        // h z - 1 with the light-like leading coefficient h
+        let p = PerplexPolynomial::new(vec![Perplex::new(-1.0, 0.0), Perplex::new(0.0, 1.0)]);
+        assert_eq!(p.roots().unwrap(), vec![Perplex::h()]);
+        // (1 + h) z has infinitely many roots z = x (1 - h)
+        let p = PerplexPolynomial::new(vec![Perplex::new(0.0, 0.0), Perplex::new(1.0, 1.0)]);
+        assert!(p.roots().is_none());
+        let cubic = PerplexPolynomial::new(vec![Perplex::new(1.0, 0.0); 4]);
+        assert!(cubic.roots().is_none(), "Degree three is not supported!");
+    }
+}
@@ -0,0 +1,313 @@
+//! # Quantized Module
+//!
+//! This module provides the packed storage formats `PerplexQ16` and `PerplexQ8` with `i16` and `i8` components, e.g., for telemetry of embedded devices, which transmit many perplex numbers with a limited bandwidth.
+//! The integer components `(q_t, q_x)` of a quantized number are mapped to the perplex number `offset + scale (q_t + q_x h)` by the metadata `Quantization`, which is shared by all values of a stream and hence transmitted only once.
+//! In contrast to the `fixed` module, the scale is an arbitrary positive `f32` and the offset centers the representable box on the data, so that the few available bits cover exactly the range of the values.
+//!
+//! ## Features
+//! - `Quantization::new` and `Quantization::fit`: The metadata from a scale and an offset, or fitted to the bounding box of values.
+//! - `Quantization::encode` and `Quantization::decode`: The conversions between `Perplex<f32>` and the quantized formats with an explicit `Rounding` mode.
+//! - `Quantization::encode_into` and `Quantization::decode_into`: The bulk conversions into preallocated buffers, which are available in `no_std` builds.
+//! - `to_le_bytes` and `from_le_bytes`: The canonical little-endian bytes of the quantized values and the metadata.
+//!
+//! ## Example
+//! ```
+//! use perplex_num::fixed::Rounding;
+//! use perplex_num::quantized::{PerplexQ16, Quantization};
+//! use perplex_num::Perplex;
+//! let samples: Vec<_> = (0..100)
+//!     .map(|k| Perplex::new(20.0 + 0.1 * k as f32, -0.5 * k as f32))
+//!     .collect();
+//! let quantization = Quantization::fit::<i16>(&samples).unwrap();
+//! let mut packed = vec![PerplexQ16::default(); samples.len()];
+//! let written = quantization.encode_into(&mut packed, samples.iter().copied(), Rounding::Nearest);
+//! assert_eq!(written, samples.len());
+//! let mut decoded = vec![Perplex::default(); packed.len()];
+//! quantization.decode_into(&mut decoded, packed.iter().copied());
+//! for (z, w) in samples.iter().zip(&decoded) {
+//!     assert!((z.t - w.t).abs() < 1e-3 && (z.x - w.x).abs() < 1e-3);
+//! }
+//! ```
+
+use super::fixed::Rounding;
+use super::Perplex;
+use num_traits::{Float, NumCast, PrimInt, Signed};
+
+/// A quantized perplex number with the integer components `t` and `x`, whose value is given by a `Quantization`.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug, Default)]
+pub struct PerplexQ<I> {
+    /// The quantized time component.
+    pub t: I,
+    /// The quantized space component.
+    pub x: I,
+}
+
+/// A quantized perplex number with `i16` components, i.e., 4 bytes per value.
+pub type PerplexQ16 = PerplexQ<i16>;
+/// A quantized perplex number with `i8` components, i.e., 2 bytes per value.
+pub type PerplexQ8 = PerplexQ<i8>;
+
+impl<I> PerplexQ<I> {
+    /// Creates the quantized number with the integer components `t` and `x`.
+    #[inline]
+    pub fn new(t: I, x: I) -> Self {
+        Self { t, x }
+    }
+}
+
+macro_rules! impl_bytes {
+    ($($int:ty),*) => {$(
+        impl PerplexQ<$int> {
+            /// The number of bytes of `to_le_bytes`.
+            pub const BYTES: usize = 2 * core::mem::size_of::<$int>();
+            /// Returns the little-endian bytes of the components `t` and `x`.
+            #[inline]
+            pub fn to_le_bytes(&self) -> [u8; Self::BYTES] {
+                let mut bytes = [0; Self::BYTES];
+                bytes[..Self::BYTES / 2].copy_from_slice(&self.t.to_le_bytes());
+                bytes[Self::BYTES / 2..].copy_from_slice(&self.x.to_le_bytes());
+                bytes
+            }
+            /// Creates the quantized number from the bytes of `to_le_bytes`.
+            #[inline]
+            pub fn from_le_bytes(bytes: [u8; Self::BYTES]) -> Self {
+                let mut t = [0; Self::BYTES / 2];
+                let mut x = [0; Self::BYTES / 2];
+                t.copy_from_slice(&bytes[..Self::BYTES / 2]);
+                x.copy_from_slice(&bytes[Self::BYTES / 2..]);
+                Self::new(<$int>::from_le_bytes(t), <$int>::from_le_bytes(x))
+            }
+        }
+    )*};
+}
+
+impl_bytes!(i8, i16);
+
+/// The metadata of quantized perplex numbers, which represent the values `offset + scale (q_t + q_x h)`.
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct Quantization {
+    scale: f32,
+    offset: Perplex<f32>,
+}
+
+impl Quantization {
+    /// The number of bytes of `to_le_bytes`.
+    pub const BYTES: usize = 12;
+    /// Creates the metadata with the step size `scale` of both components and the value `offset` of the quantized zero.
+    ///
+    /// Returns `None` if `scale` is not positive and finite, or a component of `offset` is not finite.
+    #[inline]
+    pub fn new(scale: f32, offset: Perplex<f32>) -> Option<Self> {
+        if scale > 0.0 && scale.is_finite() && offset.t.is_finite() && offset.x.is_finite() {
+            Some(Self { scale, offset })
+        } else {
+            None
+        }
+    }
+    /// Returns the metadata for the integer type `I` whose representable box is centered on the bounding box of `values` and covers it with the smallest scale.
+    ///
+    /// Every value is then encoded by `encode` with rounding to the nearest integer. Returns `None` if `values` is empty or contains a non-finite component.
+    /// The scale of values which are all equal is one, so that they are encoded as zero.
+    pub fn fit<I: PrimInt + Signed>(values: &[Perplex<f32>]) -> Option<Self> {
+        let first = values.first()?;
+        if values.iter().any(|z| !(z.t.is_finite() && z.x.is_finite())) {
+            return None;
+        }
+        let (mut lower, mut upper) = (*first, *first);
+        for z in values {
+            lower = Perplex::new(Float::min(lower.t, z.t), Float::min(lower.x, z.x));
+            upper = Perplex::new(Float::max(upper.t, z.t), Float::max(upper.x, z.x));
+        }
+        let half_width = Float::max(upper.t - lower.t, upper.x - lower.x) as f64 / 2.0;
+        // the rounding of the scale and of the offset to f32 must not push a bound out of range
+        let max = <f64 as NumCast>::from(I::max_value())? - 1.0;
+        let scale = if half_width > 0.0 {
+            (half_width / max) as f32
+        } else {
+            1.0
+        };
+        let center = |a: f32, b: f32| ((a as f64 + b as f64) / 2.0) as f32;
+        Self::new(
+            scale,
+            Perplex::new(center(lower.t, upper.t), center(lower.x, upper.x)),
+        )
+    }
+    /// Returns the step size of the components.
+    #[inline]
+    pub fn scale(&self) -> f32 {
+        self.scale
+    }
+    /// Returns the value of the quantized zero.
+    #[inline]
+    pub fn offset(&self) -> Perplex<f32> {
+        self.offset
+    }
+    /// Returns the quantized number of `z`, whose components are `(z - offset) / scale` rounded by `rounding`.
+    ///
+    /// Returns `None` if a component of `z` is not finite or a rounded component is not representable by `I`.
+    #[inline]
+    pub fn encode<I: PrimInt + Signed>(
+        &self,
+        z: Perplex<f32>,
+        rounding: Rounding,
+    ) -> Option<PerplexQ<I>> {
+        let quantize = |v: f32, offset: f32| {
+            let q = rounding.apply((v as f64 - offset as f64) / self.scale as f64);
+            <I as NumCast>::from(q)
+        };
+        Some(PerplexQ::new(
+            quantize(z.t, self.offset.t)?,
+            quantize(z.x, self.offset.x)?,
+        ))
+    }
+    /// Returns the perplex number `offset + scale (q.t + q.x h)` of the quantized number `q`.
+    #[inline]
+    pub fn decode<I: PrimInt + Signed>(&self, q: PerplexQ<I>) -> Perplex<f32> {
+        let value = |q: I, offset: f32| {
+            // every i8 and i16 is exactly representable as f32
+            let q = <f32 as NumCast>::from(q).unwrap_or(f32::NAN);
+            Float::mul_add(q, self.scale, offset)
+        };
+        Perplex::new(value(q.t, self.offset.t), value(q.x, self.offset.x))
+    }
+    /// Encodes `values` and writes the quantized numbers to the front of `dst`.
+    ///
+    /// Returns the number of written values, which is limited by the length of `dst` and stops before the first value which is not representable, see `encode`.
+    pub fn encode_into<I: PrimInt + Signed>(
+        &self,
+        dst: &mut [PerplexQ<I>],
+        values: impl IntoIterator<Item = Perplex<f32>>,
+        rounding: Rounding,
+    ) -> usize {
+        let mut written = 0;
+        for (q, z) in dst.iter_mut().zip(values) {
+            match self.encode(z, rounding) {
+                Some(encoded) => *q = encoded,
+                None => break,
+            }
+            written += 1;
+        }
+        written
+    }
+    /// Decodes the quantized `values` and writes the perplex numbers to the front of `dst`.
+    ///
+    /// Returns the number of written values, which is limited by the length of `dst`.
+    pub fn decode_into<I: PrimInt + Signed>(
+        &self,
+        dst: &mut [Perplex<f32>],
+        values: impl IntoIterator<Item = PerplexQ<I>>,
+    ) -> usize {
+        let mut written = 0;
+        for (z, q) in dst.iter_mut().zip(values) {
+            *z = self.decode(q);
+            written += 1;
+        }
+        written
+    }
+    /// Returns the little-endian bytes of the scale followed by the components of the offset.
+    #[inline]
+    pub fn to_le_bytes(&self) -> [u8; Self::BYTES] {
+        let mut bytes = [0; Self::BYTES];
+        bytes[..4].copy_from_slice(&self.scale.to_le_bytes());
+        bytes[4..8].copy_from_slice(&self.offset.t.to_le_bytes());
+        bytes[8..].copy_from_slice(&self.offset.x.to_le_bytes());
+        bytes
+    }
+    /// Creates the metadata from the bytes of `to_le_bytes`, or returns `None` if they do not form valid metadata, see `new`.
+    #[inline]
+    pub fn from_le_bytes(bytes: [u8; Self::BYTES]) -> Option<Self> {
+        let float = |range: core::ops::Range<usize>| {
+            let mut b = [0; 4];
+            b.copy_from_slice(&bytes[range]);
+            f32::from_le_bytes(b)
+        };
+        Self::new(float(0..4), Perplex::new(float(4..8), float(8..12)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[test]
+    fn test_roundtrip() {
+        let quantization = Quantization::new(0.01, Perplex::new(1.0, -2.0)).unwrap();
+        for k in -300..=300 {
+            let z = Perplex::new(1.0 + 0.0037 * k as f32, -2.0 + 0.0101 * k as f32);
+            let q16: PerplexQ16 = quantization.encode(z, Rounding::Nearest).unwrap();
+            let w = quantization.decode(q16);
+            assert!((w.t - z.t).abs() <= 0.005 + 1e-6 && (w.x - z.x).abs() <= 0.005 + 1e-6);
+            assert_eq!(PerplexQ16::from_le_bytes(q16.to_le_bytes()), q16);
+            // decoded values are fixed points of the encoding
+            assert_eq!(quantization.encode(w, Rounding::Nearest), Some(q16));
+            let floor: PerplexQ16 = quantization.encode(z, Rounding::Floor).unwrap();
+            let w = quantization.decode(floor);
+            assert!(w.t <= z.t + 1e-6 && w.x <= z.x + 1e-6, "Rounded down!");
+        }
+        let q8 = PerplexQ8::new(-128, 127);
+        assert_eq!(PerplexQ8::from_le_bytes(q8.to_le_bytes()), q8);
+        assert_eq!(PerplexQ8::BYTES, 2);
+        assert_eq!(PerplexQ16::BYTES, 4);
+        assert_eq!(
+            Quantization::from_le_bytes(quantization.to_le_bytes()),
+            Some(quantization)
+        );
+    }
+    #[test]
+    fn test_unrepresentable() {
+        let quantization = Quantization::new(1.0, Perplex::new(0.0, 0.0)).unwrap();
+        let rounding = Rounding::Nearest;
+        assert!(quantization
+            .encode::<i8>(Perplex::new(127.0, -128.0), rounding)
+            .is_some());
+        assert!(quantization
+            .encode::<i8>(Perplex::new(128.0, 0.0), rounding)
+            .is_none());
+        assert!(quantization
+            .encode::<i16>(Perplex::new(128.0, 0.0), rounding)
+            .is_some());
+        assert!(quantization
+            .encode::<i16>(Perplex::new(f32::NAN, 0.0), rounding)
+            .is_none());
+        let values = [1.0, 2.0, 300.0, 4.0].map(|t| Perplex::new(t, 0.0));
+        let mut dst = [PerplexQ8::default(); 4];
+        assert_eq!(quantization.encode_into(&mut dst, values, rounding), 2);
+        assert_eq!(dst[1], PerplexQ8::new(2, 0));
+        let mut short = [PerplexQ16::default(); 3];
+        assert_eq!(quantization.encode_into(&mut short, values, rounding), 3);
+        let mut decoded = [Perplex::default(); 2];
+        assert_eq!(quantization.decode_into(&mut decoded, short), 2);
+        assert_eq!(decoded, [values[0], values[1]]);
+        assert!(Quantization::new(0.0, Perplex::new(0.0, 0.0)).is_none());
+        assert!(Quantization::new(1.0, Perplex::new(f32::INFINITY, 0.0)).is_none());
+        let mut bytes = quantization.to_le_bytes();
+        bytes[..4].copy_from_slice(&(-1f32).to_le_bytes());
+        assert!(Quantization::from_le_bytes(bytes).is_none());
+    }
+    #[test]
+    fn test_fit() {
+        let values: Vec<_> = (0..1000)
+            .map(|k| {
+                let s = k as f32 * 0.01;
+                Perplex::new(1e3 + 5.0 * s.sin(), -40.0 * s.cos())
+            })
+            .collect();
+        let q16 = Quantization::fit::<i16>(&values).unwrap();
+        let q8 = Quantization::fit::<i8>(&values).unwrap();
+        assert!(q8.scale() > 200.0 * q16.scale(), "Fewer bits are coarser!");
+        let mut packed16 = vec![PerplexQ16::default(); values.len()];
+        let written = q16.encode_into(&mut packed16, values.iter().copied(), Rounding::Nearest);
+        assert_eq!(written, values.len(), "All values are representable!");
+        let mut packed8 = vec![PerplexQ8::default(); values.len()];
+        let written = q8.encode_into(&mut packed8, values.iter().copied(), Rounding::Nearest);
+        assert_eq!(written, values.len());
+        // the box is used in the component with the largest range
+        let max = packed16.iter().map(|q| q.x.unsigned_abs()).max();
+        assert!(max.unwrap() > 32000);
+        let constant = [Perplex::new(3.0, 4.0); 5];
+        let quantization = Quantization::fit::<i8>(&constant).unwrap();
+        let zero: PerplexQ8 = quantization.encode(constant[0], Rounding::Nearest).unwrap();
+        assert_eq!(zero, PerplexQ8::default());
+        assert!(Quantization::fit::<i8>(&[]).is_none());
+        assert!(Quantization::fit::<i8>(&[Perplex::new(0.0, f32::NAN)]).is_none());
+    }
+}
@@ -0,0 +1,134 @@
+//! # Random Module
+//!
+//! This module (optional feature `rand`) samples random perplex numbers with the [rand](https://docs.rs/rand) crate, e.g., as inputs of Monte Carlo experiments and fuzz-style numerical tests.
+//!
+//! ## Features
+//! - `Distribution<Perplex<T>>` for `Standard`: Both components are sampled independently from the standard distribution of `T`, i.e., uniformly in `[0, 1)` for floats.
+//! - `UniformPerplex`: The uniform distribution over a `PerplexBox`.
+//!
+//! ## Example
+//! ```
+//! use perplex_num::random::UniformPerplex;
+//! use perplex_num::{Perplex, PerplexBox};
+//! use rand::distributions::Distribution;
+//! use rand::{rngs::StdRng, SeedableRng};
+//! let region = PerplexBox::new(Perplex::new(-1.0, 0.0), Perplex::new(1.0, 2.0)).unwrap();
+//! let uniform = UniformPerplex::new(region).unwrap();
+//! let mut rng = StdRng::seed_from_u64(42);
+//! for z in uniform.sample_iter(&mut rng).take(100) {
+//!     assert!(region.contains(z));
+//! }
+//! ```
+
+use super::{Perplex, PerplexBox};
+use num_traits::Float;
+use rand::distributions::uniform::{SampleUniform, Uniform};
+use rand::distributions::{Distribution, Standard};
+use rand::Rng;
+use std::fmt;
+
+impl<T> Distribution<Perplex<T>> for Standard
+where
+    Standard: Distribution<T>,
+{
+    /// Samples both components independently from `Standard`.
+    #[inline]
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Perplex<T> {
+        Perplex::new(rng.gen(), rng.gen())
+    }
+}
+
+/// The uniform distribution of perplex numbers over a box, i.e., of the components over the closed intervals of the box.
+pub struct UniformPerplex<T: SampleUniform> {
+    t: Uniform<T>,
+    x: Uniform<T>,
+}
+
+impl<T: SampleUniform> Clone for UniformPerplex<T>
+where
+    Uniform<T>: Clone,
+{
+    #[inline]
+    fn clone(&self) -> Self {
+        Self {
+            t: self.t.clone(),
+            x: self.x.clone(),
+        }
+    }
+}
+
+impl<T: SampleUniform> fmt::Debug for UniformPerplex<T>
+where
+    Uniform<T>: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("UniformPerplex")
+            .field("t", &self.t)
+            .field("x", &self.x)
+            .finish()
+    }
+}
+
+impl<T: Copy + Float + SampleUniform> UniformPerplex<T> {
+    /// Creates the uniform distribution over `region`, or returns `None` if the region is not finite.
+    ///
+    /// Degenerate regions are allowed, e.g., the box of a single point yields this point.
+    #[inline]
+    pub fn new(region: PerplexBox<T>) -> Option<Self> {
+        let (min, max) = (region.min, region.max);
+        if (max.t - min.t).is_finite() && (max.x - min.x).is_finite() {
+            Some(Self {
+                t: Uniform::new_inclusive(min.t, max.t),
+                x: Uniform::new_inclusive(min.x, max.x),
+            })
+        } else {
+            None
+        }
+    }
+}
+
+impl<T: Copy + SampleUniform> Distribution<Perplex<T>> for UniformPerplex<T> {
+    #[inline]
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Perplex<T> {
+        Perplex::new(self.t.sample(rng), self.x.sample(rng))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+    #[test]
+    fn test_standard() {
+        let mut rng = StdRng::seed_from_u64(7);
+        let samples: Vec<Perplex<f64>> = (0..1000).map(|_| rng.gen()).collect();
+        let unit = PerplexBox::new(Perplex::new(0.0, 0.0), Perplex::new(1.0, 1.0)).unwrap();
+        assert!(samples.iter().all(|&z| unit.contains(z)));
+        let mean = samples
+            .iter()
+            .fold(Perplex::new(0.0, 0.0), |acc, &z| acc + z);
+        assert!((mean.t / 1000.0 - 0.5).abs() < 0.05 && (mean.x / 1000.0 - 0.5).abs() < 0.05);
+        assert!(
+            samples.iter().any(|z| z.t != z.x),
+            "Independent components!"
+        );
+        let z: Perplex<u8> = rng.gen();
+        assert_eq!(z, z);
+    }
+    #[test]
+    fn test_uniform() {
+        let region = PerplexBox::new(Perplex::new(-3.0, 1.0), Perplex::new(-1.0, 5.0)).unwrap();
+        let uniform = UniformPerplex::new(region).unwrap();
+        let mut rng = StdRng::seed_from_u64(11);
+        let samples: Vec<Perplex<f32>> = (0..1000).map(|_| uniform.sample(&mut rng)).collect();
+        assert!(samples.iter().all(|&z| region.contains(z)));
+        let right_half = samples.iter().filter(|z| z.t > -2.0).count();
+        assert!((400..600).contains(&right_half), "Uniform in time!");
+        let point = PerplexBox::from_point(Perplex::new(2.0, -1.0));
+        let mut degenerate = UniformPerplex::new(point).unwrap().sample_iter(&mut rng);
+        assert_eq!(degenerate.next(), Some(Perplex::new(2.0, -1.0)));
+        let unbounded = PerplexBox::new(Perplex::new(0.0, 0.0), Perplex::new(f64::INFINITY, 1.0));
+        assert!(UniformPerplex::new(unbounded.unwrap()).is_none());
+    }
+}
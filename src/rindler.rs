@@ -0,0 +1,192 @@
+//! # Rindler Module
+//!
+//! This module converts between the components of perplex numbers and the Rindler coordinates `(rho, eta)` of the right Rindler wedge, i.e., the events `x > |t|` with `t = rho sinh(eta)` and `x = rho cosh(eta)`, see [Rindler coordinates](https://en.wikipedia.org/wiki/Rindler_coordinates).
+//! The curves of constant `rho` are the hyperbolic worldlines of observers with the constant proper acceleration `1 / rho` in natural units (`c = 1`), whose proper time is `rho eta`.
+//! Spacetime diagrams draw the time axis vertically, whereas the sectors of `HyperbolicSector` refer to the time axis as the horizontal one, hence the right Rindler wedge is the `Up` sector.
+//!
+//! ## Features
+//! - `Perplex::to_rindler` and `Perplex::from_rindler`: The conversions with a `RindlerError` outside of the wedge or for invalid coordinates.
+//! - `RindlerCoordinates::uniformly_accelerated`: The event of an accelerated observer at a given proper time.
+//!
+//! ## Example
+//! ```
+//! use approx::assert_abs_diff_eq;
+//! use perplex_num::rindler::{RindlerCoordinates, RindlerError};
+//! use perplex_num::Perplex;
+//! let z = Perplex::new(0.6, 1.0);
+//! let rindler = z.to_rindler().unwrap();
+//! assert_abs_diff_eq!(rindler.rho, 0.8, epsilon = 1e-15);
+//! assert_abs_diff_eq!(Perplex::from_rindler(rindler.rho, rindler.eta).unwrap(), z, epsilon = 1e-15);
+//! assert_eq!(Perplex::new(1.0, 0.5).to_rindler(), Err(RindlerError::OutsideWedge));
+//! // an observer with the proper acceleration 2 after the proper time 1
+//! let observer = RindlerCoordinates::uniformly_accelerated(2.0, 1.0).unwrap();
+//! assert_abs_diff_eq!(observer.proper_acceleration(), 2.0);
+//! ```
+
+use super::Perplex;
+use core::fmt;
+use num_traits::Float;
+
+/// The error of conversions from and to Rindler coordinates.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub enum RindlerError {
+    /// The event does not lie in the open right Rindler wedge `x > |t|`.
+    OutsideWedge,
+    /// The distance `rho` is not positive and finite.
+    InvalidDistance,
+    /// The rapidity `eta` is not finite.
+    InvalidRapidity,
+}
+
+impl fmt::Display for RindlerError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::OutsideWedge => write!(f, "event must lie in the right Rindler wedge x > |t|"),
+            Self::InvalidDistance => write!(f, "distance must be positive and finite"),
+            Self::InvalidRapidity => write!(f, "rapidity must be finite"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for RindlerError {}
+
+/// The Rindler coordinates of an event in the right Rindler wedge.
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct RindlerCoordinates<T> {
+    /// The distance `sqrt(x^2 - t^2)` from the origin, which is the inverse proper acceleration of the observer through the event.
+    pub rho: T,
+    /// The rapidity `atanh(t / x)` of the observer through the event.
+    pub eta: T,
+}
+
+impl<T: Copy + Float> RindlerCoordinates<T> {
+    /// Returns the coordinates of the observer with the proper acceleration `acceleration` at the proper time `proper_time`, which passes the event `1 / acceleration` at the proper time zero.
+    ///
+    /// Returns a `RindlerError` if the acceleration is not positive and finite or the proper time is not finite.
+    #[inline]
+    pub fn uniformly_accelerated(acceleration: T, proper_time: T) -> Result<Self, RindlerError> {
+        if !acceleration.is_finite() || acceleration <= T::zero() {
+            return Err(RindlerError::InvalidDistance);
+        }
+        let (rho, eta) = (acceleration.recip(), proper_time * acceleration);
+        validate(rho, eta)?;
+        Ok(Self { rho, eta })
+    }
+    /// Returns the proper acceleration `1 / rho` of the observer through the event.
+    #[inline]
+    pub fn proper_acceleration(&self) -> T {
+        self.rho.recip()
+    }
+    /// Returns the proper time `rho eta` of the observer through the event, which is zero at `t = 0`.
+    #[inline]
+    pub fn proper_time(&self) -> T {
+        self.rho * self.eta
+    }
+}
+
+impl<T: Copy + Float> From<RindlerCoordinates<T>> for Perplex<T> {
+    /// Returns the event `rho sinh(eta) + h rho cosh(eta)` without validation.
+    #[inline]
+    fn from(coordinates: RindlerCoordinates<T>) -> Self {
+        let RindlerCoordinates { rho, eta } = coordinates;
+        Self::new(rho * eta.sinh(), rho * eta.cosh())
+    }
+}
+
+impl<T: Copy + Float> Perplex<T> {
+    /// Returns the Rindler coordinates of `self`, or `RindlerError::OutsideWedge` if `self` does not lie in the open right Rindler wedge `x > |t|`.
+    #[inline]
+    pub fn to_rindler(self) -> Result<RindlerCoordinates<T>, RindlerError> {
+        if self.x > self.t.abs() && self.x.is_finite() {
+            Ok(RindlerCoordinates {
+                rho: self.modulus(),
+                eta: (self.t / self.x).atanh(),
+            })
+        } else {
+            Err(RindlerError::OutsideWedge)
+        }
+    }
+    /// Creates the event with the Rindler coordinates `(rho, eta)`, or returns a `RindlerError` if `rho` is not positive and finite or `eta` is not finite.
+    #[inline]
+    pub fn from_rindler(rho: T, eta: T) -> Result<Self, RindlerError> {
+        validate(rho, eta)?;
+        Ok(RindlerCoordinates { rho, eta }.into())
+    }
+}
+
+/// Checks the domains of the Rindler coordinates.
+#[inline]
+fn validate<T: Copy + Float>(rho: T, eta: T) -> Result<(), RindlerError> {
+    if !rho.is_finite() || rho <= T::zero() {
+        Err(RindlerError::InvalidDistance)
+    } else if !eta.is_finite() {
+        Err(RindlerError::InvalidRapidity)
+    } else {
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_abs_diff_eq;
+    #[test]
+    fn test_roundtrip() {
+        for (rho, eta) in [(1.0, 0.0), (0.5, -2.0), (3.0, 0.75)] {
+            let z = Perplex::from_rindler(rho, eta).unwrap();
+            assert!(z.x > z.t.abs());
+            assert_abs_diff_eq!(z.squared_distance(), -rho * rho, epsilon = 1e-12);
+            let rindler = z.to_rindler().unwrap();
+            assert_abs_diff_eq!(rindler.rho, rho, epsilon = 1e-12);
+            assert_abs_diff_eq!(rindler.eta, eta, epsilon = 1e-12);
+            assert_abs_diff_eq!(rindler.eta, z.arg(), epsilon = 1e-15);
+        }
+    }
+    #[test]
+    fn test_domain_errors() {
+        for z in [
+            Perplex::new(2.0, 1.0),
+            Perplex::new(0.0, -1.0),
+            Perplex::new(1.0, 1.0),
+            Perplex::new(0.0, 0.0),
+            Perplex::new(0.0, f64::INFINITY),
+            Perplex::new(f64::NAN, 1.0),
+        ] {
+            assert_eq!(z.to_rindler(), Err(RindlerError::OutsideWedge));
+        }
+        assert_eq!(
+            Perplex::from_rindler(0.0, 1.0),
+            Err(RindlerError::InvalidDistance)
+        );
+        assert_eq!(
+            Perplex::from_rindler(1.0, f64::NAN),
+            Err(RindlerError::InvalidRapidity)
+        );
+        assert_eq!(
+            RindlerCoordinates::uniformly_accelerated(-1.0, 0.0),
+            Err(RindlerError::InvalidDistance)
+        );
+    }
+    #[test]
+    fn test_accelerated_observer() {
+        let acceleration = 0.5;
+        let worldline: Vec<Perplex<f64>> = [0.0, 1.0, 2.0, 4.0]
+            .iter()
+            .map(|&tau| {
+                RindlerCoordinates::uniformly_accelerated(acceleration, tau)
+                    .unwrap()
+                    .into()
+            })
+            .collect();
+        assert_eq!(worldline[0], Perplex::new(0.0, 2.0));
+        for (w, tau) in worldline.windows(2).zip([1.0, 2.0, 4.0]) {
+            // the observer moves within its future light cone
+            let d = w[1] - w[0];
+            assert!(d.t > d.x.abs());
+            let rindler = w[1].to_rindler().unwrap();
+            assert_abs_diff_eq!(rindler.proper_acceleration(), acceleration, epsilon = 1e-12);
+            assert_abs_diff_eq!(rindler.proper_time(), tau, epsilon = 1e-12);
+        }
+    }
+}
@@ -0,0 +1,209 @@
+//! # Sampling Module
+//!
+//! This module provides the adaptive sampling of grids in the (t, x) plane, which concentrates the points where a function varies fastest, e.g., near the light cone, see also the low-discrepancy sequence of the `halton` module for evenly covered regions.
+//!
+//! ## Features
+//! - `adaptive_grid`: Quadtree refinement of a `PerplexBox` driven by an error estimator of the cells, which concentrates the points near the light cone, where functions like `ln` and `arg` vary fastest, instead of wasting them on a uniform grid. The estimator `corner_variation` compares the values of a real function at the corners and the center of a cell.
+//!
+//! ## Example
+//! ```
+//! use perplex_num::sampling::{adaptive_grid, corner_variation, RefinementOptions};
+//! use perplex_num::{Perplex, PerplexBox};
+//! let region = PerplexBox::new(Perplex::new(0.5, -1.0), Perplex::new(2.5, 1.0)).unwrap();
+//! let options = RefinementOptions { tolerance: 0.1, max_depth: 4, max_cells: 1000 };
+//! let grid = adaptive_grid(region, options, corner_variation(|z: Perplex<f64>| z.arg()));
+//! assert!(grid.cells.len() < 4usize.pow(4), "Fewer cells than a uniform grid!");
+//! ```
+
+use super::{Perplex, PerplexBox};
+use num_traits::Float;
+
+/// The stopping criteria of `adaptive_grid`.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct RefinementOptions<T> {
+    /// A cell is refined while its estimated error exceeds the tolerance.
+    pub tolerance: T,
+    /// The maximum number of refinements of the region, i.e., the smallest cells have `1 / 2^max_depth` of its side lengths.
+    pub max_depth: u32,
+    /// The maximum number of cells of the grid.
+    pub max_cells: usize,
+}
+
+impl<T: Copy + Float> Default for RefinementOptions<T> {
+    /// Defaults to a tolerance of `1e-3`, a maximum depth of 8 and at most 4096 cells.
+    fn default() -> Self {
+        Self {
+            tolerance: T::from(1e-3).unwrap(),
+            max_depth: 8,
+            max_cells: 4096,
+        }
+    }
+}
+
+/// The leaf cells of an adaptive refinement by `adaptive_grid`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct AdaptiveGrid<T> {
+    /// The cells, which cover the refined region without overlap.
+    pub cells: Vec<PerplexBox<T>>,
+    /// The number of refinements of the region which produced each cell.
+    pub depths: Vec<u32>,
+}
+
+impl<T: Copy + Float> AdaptiveGrid<T> {
+    /// Returns the centers of the cells as a point set, e.g., for scatter plots.
+    pub fn centers(&self) -> Vec<Perplex<T>> {
+        self.cells.iter().map(PerplexBox::center).collect()
+    }
+    /// Returns a triangulation with two triangles per cell, split along the diagonal from `min` to `max` and oriented counterclockwise.
+    ///
+    /// Cells of different depths meet at hanging vertices, hence the triangulation is not conforming, which does not matter for plotting.
+    pub fn triangles(&self) -> Vec<[Perplex<T>; 3]> {
+        self.cells
+            .iter()
+            .flat_map(|cell| {
+                let [a, b, c, d] = cell.corners();
+                [[a, b, c], [a, c, d]]
+            })
+            .collect()
+    }
+}
+
+/// Refines `region` adaptively into a quadtree of cells, whose estimated errors do not exceed the tolerance of `options`.
+///
+/// The cells are refined breadth-first, i.e., level by level, such that the limit of cells stops the refinement evenly over the region instead of exhausting it in one corner.
+/// An estimated error which is NaN is treated as exceeding the tolerance, e.g., for cells on which a function is undefined.
+pub fn adaptive_grid<T, E>(
+    region: PerplexBox<T>,
+    options: RefinementOptions<T>,
+    mut estimator: E,
+) -> AdaptiveGrid<T>
+where
+    T: Copy + Float,
+    E: FnMut(&PerplexBox<T>) -> T,
+{
+    let mut grid = AdaptiveGrid {
+        cells: Vec::new(),
+        depths: Vec::new(),
+    };
+    let mut level = vec![region];
+    let mut depth = 0;
+    // the number of cells if the refinement stopped now
+    let mut count = 1;
+    while !level.is_empty() {
+        let mut next = Vec::new();
+        for cell in level {
+            let error = estimator(&cell);
+            let converged = error <= options.tolerance;
+            if converged || depth >= options.max_depth || count + 3 > options.max_cells {
+                grid.cells.push(cell);
+                grid.depths.push(depth);
+            } else {
+                count += 3;
+                next.extend(quarters(&cell));
+            }
+        }
+        level = next;
+        depth += 1;
+    }
+    grid
+}
+
+/// Returns an error estimator for `adaptive_grid`, which is the maximum deviation `|f(corner) - f(center)|` of the real function `f` over the corners of a cell.
+///
+/// The values of `arg` or of a component of `ln` jump between the sectors and diverge at the light cone, hence the cells straddling the light cone are refined down to the maximum depth.
+pub fn corner_variation<T, F>(f: F) -> impl FnMut(&PerplexBox<T>) -> T
+where
+    T: Copy + Float,
+    F: Fn(Perplex<T>) -> T,
+{
+    move |cell| {
+        let center = f(cell.center());
+        cell.corners()
+            .iter()
+            .map(|&corner| (f(corner) - center).abs())
+            // propagates NaN values, which `Float::max` would discard
+            .fold(
+                T::zero(),
+                |max, d| if d > max || d.is_nan() { d } else { max },
+            )
+    }
+}
+
+/// Splits `cell` into its four quarters.
+fn quarters<T: Copy + Float>(cell: &PerplexBox<T>) -> [PerplexBox<T>; 4] {
+    let (min, max, center) = (cell.min, cell.max, cell.center());
+    [
+        PerplexBox { min, max: center },
+        PerplexBox {
+            min: Perplex::new(center.t, min.x),
+            max: Perplex::new(max.t, center.x),
+        },
+        PerplexBox { min: center, max },
+        PerplexBox {
+            min: Perplex::new(min.t, center.x),
+            max: Perplex::new(center.t, max.x),
+        },
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_abs_diff_eq;
+    #[test]
+    fn test_adaptive_grid() {
+        let region = PerplexBox::new(Perplex::new(0.5, -1.0), Perplex::new(2.5, 1.0)).unwrap();
+        let options = RefinementOptions {
+            tolerance: 0.1,
+            max_depth: 6,
+            max_cells: 10_000,
+        };
+        let grid = adaptive_grid(region, options, corner_variation(|z: Perplex<f64>| z.arg()));
+        assert_eq!(grid.cells.len(), grid.depths.len());
+        let area = |b: &PerplexBox<f64>| (b.max.t - b.min.t) * (b.max.x - b.min.x);
+        let total: f64 = grid.cells.iter().map(area).sum();
+        assert_abs_diff_eq!(total, area(&region), epsilon = 1e-12);
+        for (cell, &depth) in grid.cells.iter().zip(&grid.depths) {
+            if cell.touches_light_cone() {
+                assert_eq!(depth, 6, "Cells at the light cone are refined!");
+            }
+        }
+        let mut cells = grid.cells.iter().zip(&grid.depths);
+        let (_, &depth) = cells
+            .find(|(cell, _)| cell.contains(Perplex::new(2.4, 0.0)))
+            .unwrap();
+        assert!(depth <= 3, "Cells far from the light cone are coarse!");
+        assert!(
+            grid.cells.len() < 4usize.pow(6) / 4,
+            "Fewer cells than a uniform grid!"
+        );
+        assert_eq!(grid.triangles().len(), 2 * grid.cells.len());
+        assert!(grid
+            .centers()
+            .iter()
+            .zip(&grid.cells)
+            .all(|(&z, cell)| cell.contains(z)));
+    }
+    #[test]
+    fn test_refinement_limits() {
+        let region = PerplexBox::new(Perplex::new(-1.0, -1.0), Perplex::new(1.0, 1.0)).unwrap();
+        let always = |_: &PerplexBox<f64>| f64::NAN;
+        let options = RefinementOptions {
+            max_cells: 20,
+            ..RefinementOptions::default()
+        };
+        let grid = adaptive_grid(region, options, always);
+        assert_eq!(grid.cells.len(), 19, "Limit of cells!");
+        assert!(
+            grid.depths.iter().all(|&d| d == 2 || d == 3),
+            "Breadth-first refinement!"
+        );
+        let options = RefinementOptions {
+            max_depth: 0,
+            ..RefinementOptions::default()
+        };
+        assert_eq!(adaptive_grid(region, options, always).cells, vec![region]);
+        let flat = adaptive_grid(region, RefinementOptions::default(), |_| 0.0);
+        assert_eq!(flat.depths, vec![0], "Converged without refinement!");
+    }
+}
@@ -0,0 +1,319 @@
+//! # Sanitize Module
+//!
+//! This module provides a validation pass for imported perplex data, which detects the defects that break numerical pipelines downstream: NaN and infinite components, subnormal components, which are slow on many processors and lose precision, and light-like values, which are zero divisors and have no hyperbolic argument.
+//! Each kind of defect is handled by an `Action` of a `SanitizePolicy`: it is kept, the value is dropped, the value is repaired, or the defect is reported as an error with the index of the value.
+//! The repairs replace NaN components by zero and clamp infinite components to a finite bound like `numpy.nan_to_num`, flush subnormal components to zero, and move light-like values off the diagonal into the time-like sector by a relative `eps`.
+//!
+//! ## Features
+//! - `SanitizePolicy`: The actions for the kinds of `Defect`, with the presets `drop`, `clamp`, `snap_off_diagonal` and `error`.
+//! - `sanitize`: The pass over a slice, which returns the sanitized values together with their source indices, or a `SanitizeError` with the indices of all reported defects.
+//!
+//! ## Example
+//! ```
+//! use perplex_num::sanitize::{sanitize, Defect, SanitizePolicy};
+//! use perplex_num::Perplex;
+//! let data = [Perplex::new(2.0, 1.0), Perplex::new(f64::NAN, 0.0), Perplex::new(1.0, -1.0)];
+//! let sanitized = sanitize(&data, SanitizePolicy::drop()).unwrap();
+//! assert_eq!(sanitized.values, vec![Perplex::new(2.0, 1.0)]);
+//! assert_eq!(sanitized.dropped, 2);
+//! let error = sanitize(&data, SanitizePolicy::error()).unwrap_err();
+//! assert_eq!(error.defects, vec![(1, Defect::NaN), (2, Defect::LightLike)]);
+//! ```
+
+use super::Perplex;
+use core::fmt;
+use core::num::FpCategory;
+use num_traits::Float;
+
+/// A defect of an imported perplex number.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub enum Defect {
+    /// A component is NaN.
+    NaN,
+    /// A component is infinite.
+    Infinite,
+    /// A component is subnormal.
+    Subnormal,
+    /// The value is light-like, i.e., `|t| = |x|`, but not zero.
+    LightLike,
+}
+
+/// The handling of a kind of `Defect` by `sanitize`.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub enum Action {
+    /// Keeps the value unchanged.
+    Keep,
+    /// Removes the value from the output.
+    Drop,
+    /// Repairs the value, see the module documentation.
+    Repair,
+    /// Reports the defect with the index of the value in a `SanitizeError`.
+    Error,
+}
+
+/// The actions of `sanitize` for the kinds of `Defect` and the parameters of the repairs.
+///
+/// The defects are handled in the order NaN, infinite, subnormal and light-like, such that repaired components are checked for the subsequent defects, e.g., the clamping of `inf + inf h` yields a light-like value.
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct SanitizePolicy<T> {
+    /// The action for NaN components, which are repaired to zero.
+    pub nan: Action,
+    /// The action for infinite components, which are repaired to `±max`, whereby the repair also clamps the finite components to `[-max, max]`.
+    pub infinite: Action,
+    /// The action for subnormal components, which are repaired to zero.
+    pub subnormal: Action,
+    /// The action for light-like values, which are repaired to `t + x (1 - eps) h`.
+    pub light_like: Action,
+    /// The bound of the repaired infinite components.
+    pub max: T,
+    /// The relative distance of the repaired light-like values from the diagonal.
+    pub eps: T,
+}
+
+impl<T: Copy + Float> SanitizePolicy<T> {
+    /// Creates the policy which applies `action` to all defects, with the bound `T::max_value()` and the relative distance of the square root of the machine epsilon, see `EpsilonContext`.
+    #[inline]
+    pub fn uniform(action: Action) -> Self {
+        Self {
+            nan: action,
+            infinite: action,
+            subnormal: action,
+            light_like: action,
+            max: T::max_value(),
+            eps: T::epsilon().sqrt(),
+        }
+    }
+    /// Returns the policy which drops all values with a defect.
+    #[inline]
+    pub fn drop() -> Self {
+        Self::uniform(Action::Drop)
+    }
+    /// Returns the policy which reports all defects as an error.
+    #[inline]
+    pub fn error() -> Self {
+        Self::uniform(Action::Error)
+    }
+    /// Returns the policy which drops values with NaN components, clamps the components to `[-max, max]`, flushes subnormal components to zero and keeps light-like values.
+    #[inline]
+    pub fn clamp(max: T) -> Self {
+        Self {
+            nan: Action::Drop,
+            light_like: Action::Keep,
+            max,
+            ..Self::uniform(Action::Repair)
+        }
+    }
+    /// Returns the policy which moves light-like values off the diagonal by the relative distance `eps`, drops values with NaN or infinite components and flushes subnormal components to zero.
+    #[inline]
+    pub fn snap_off_diagonal(eps: T) -> Self {
+        Self {
+            nan: Action::Drop,
+            infinite: Action::Drop,
+            eps,
+            ..Self::uniform(Action::Repair)
+        }
+    }
+}
+
+/// The output of `sanitize`.
+#[derive(Clone, PartialEq, Debug)]
+pub struct Sanitized<T> {
+    /// The values which are not dropped, in the order of the input.
+    pub values: Vec<Perplex<T>>,
+    /// The index in the input of each value.
+    pub source_indices: Vec<usize>,
+    /// The number of repaired values.
+    pub repaired: usize,
+    /// The number of dropped values.
+    pub dropped: usize,
+}
+
+/// The error of `sanitize` with all defects, whose action is `Action::Error`.
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+pub struct SanitizeError {
+    /// The indices of the values in the input with their defects in ascending order.
+    pub defects: Vec<(usize, Defect)>,
+}
+
+impl fmt::Display for SanitizeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} defects in imported data", self.defects.len())?;
+        if let Some((index, defect)) = self.defects.first() {
+            write!(f, ", the first is {defect:?} at index {index}")?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for SanitizeError {}
+
+/// Scans `values` for defects and handles them according to `policy`, or returns all defects with the action `Action::Error`.
+pub fn sanitize<T: Copy + Float>(
+    values: &[Perplex<T>],
+    policy: SanitizePolicy<T>,
+) -> Result<Sanitized<T>, SanitizeError> {
+    let mut sanitized = Sanitized {
+        values: Vec::with_capacity(values.len()),
+        source_indices: Vec::with_capacity(values.len()),
+        repaired: 0,
+        dropped: 0,
+    };
+    let mut defects = Vec::new();
+    // NaN components compare false and are not clamped
+    let clamp = |c: T| {
+        if c.abs() > policy.max {
+            policy.max.copysign(c)
+        } else {
+            c
+        }
+    };
+    'values: for (index, &value) in values.iter().enumerate() {
+        let mut z = value;
+        let mut repaired = false;
+        let checks = [
+            (Defect::NaN, policy.nan),
+            (Defect::Infinite, policy.infinite),
+            (Defect::Subnormal, policy.subnormal),
+        ];
+        for (defect, action) in checks {
+            let is_defect = |c: T| is_component_defect(c, defect);
+            if !(is_defect(z.t) || is_defect(z.x)) {
+                continue;
+            }
+            match action {
+                Action::Keep => {}
+                Action::Drop => {
+                    sanitized.dropped += 1;
+                    continue 'values;
+                }
+                Action::Error => defects.push((index, defect)),
+                Action::Repair => {
+                    let repair = |c: T| match defect {
+                        Defect::Infinite => clamp(c),
+                        _ if is_defect(c) => T::zero(),
+                        _ => c,
+                    };
+                    z = Perplex::new(repair(z.t), repair(z.x));
+                    repaired = true;
+                }
+            }
+        }
+        if policy.infinite == Action::Repair && (z.t.abs() > policy.max || z.x.abs() > policy.max) {
+            z = Perplex::new(clamp(z.t), clamp(z.x));
+            repaired = true;
+        }
+        if z.t.abs() == z.x.abs() && !z.t.is_zero() {
+            match policy.light_like {
+                Action::Keep => {}
+                Action::Drop => {
+                    sanitized.dropped += 1;
+                    continue;
+                }
+                Action::Error => defects.push((index, Defect::LightLike)),
+                Action::Repair => {
+                    z.x = z.x * (T::one() - policy.eps);
+                    repaired = true;
+                }
+            }
+        }
+        if repaired {
+            sanitized.repaired += 1;
+        }
+        sanitized.values.push(z);
+        sanitized.source_indices.push(index);
+    }
+    if defects.is_empty() {
+        Ok(sanitized)
+    } else {
+        Err(SanitizeError { defects })
+    }
+}
+
+/// Checks if the component `c` has the component defect `defect`, i.e., is NaN, infinite or subnormal.
+#[inline]
+fn is_component_defect<T: Float>(c: T, defect: Defect) -> bool {
+    match defect {
+        Defect::NaN => c.is_nan(),
+        Defect::Infinite => c.is_infinite(),
+        Defect::Subnormal => c.classify() == FpCategory::Subnormal,
+        Defect::LightLike => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    const SUBNORMAL: f64 = f64::MIN_POSITIVE / 4.0;
+    fn data() -> [Perplex<f64>; 6] {
+        [
+            Perplex::new(2.0, 1.0),
+            Perplex::new(f64::NAN, 1.0),
+            Perplex::new(f64::NEG_INFINITY, 3.0),
+            Perplex::new(1.0, SUBNORMAL),
+            Perplex::new(-4.0, 4.0),
+            Perplex::new(0.0, 0.0),
+        ]
+    }
+    #[test]
+    fn test_presets() {
+        let data = data();
+        let sanitized = sanitize(&data, SanitizePolicy::drop()).unwrap();
+        assert_eq!(sanitized.source_indices, vec![0, 5], "Zero is no defect!");
+        assert_eq!((sanitized.dropped, sanitized.repaired), (4, 0));
+        let sanitized = sanitize(&data, SanitizePolicy::clamp(100.0)).unwrap();
+        assert_eq!(sanitized.source_indices, vec![0, 2, 3, 4, 5]);
+        assert_eq!(sanitized.values[1], Perplex::new(-100.0, 3.0));
+        assert_eq!(sanitized.values[2], Perplex::new(1.0, 0.0));
+        assert_eq!(sanitized.values[3], data[4], "Light-like values are kept!");
+        assert_eq!((sanitized.dropped, sanitized.repaired), (1, 2));
+        let sanitized = sanitize(&data, SanitizePolicy::snap_off_diagonal(1e-6)).unwrap();
+        assert_eq!(sanitized.source_indices, vec![0, 3, 4, 5]);
+        let snapped = sanitized.values[2];
+        assert_eq!(snapped, Perplex::new(-4.0, 4.0 * (1.0 - 1e-6)));
+        assert!(!snapped.is_light_like() && snapped.klein() == Some(-Perplex::new(1.0, 0.0)));
+        let error = sanitize(&data, SanitizePolicy::error()).unwrap_err();
+        assert_eq!(
+            error.defects,
+            vec![
+                (1, Defect::NaN),
+                (2, Defect::Infinite),
+                (3, Defect::Subnormal),
+                (4, Defect::LightLike)
+            ]
+        );
+        assert!(error.to_string().contains("NaN at index 1"));
+        assert!(sanitize(&data[..1], SanitizePolicy::error()).is_ok());
+    }
+    #[test]
+    fn test_custom_policy() {
+        // repairs are checked for the subsequent defects
+        let data = [
+            Perplex::new(f64::INFINITY, f64::INFINITY),
+            Perplex::new(f64::NAN, 5.0),
+        ];
+        let policy = SanitizePolicy {
+            max: 8.0,
+            eps: 0.5,
+            ..SanitizePolicy::uniform(Action::Repair)
+        };
+        let sanitized = sanitize(&data, policy).unwrap();
+        assert_eq!(
+            sanitized.values,
+            vec![Perplex::new(8.0, 4.0), Perplex::new(0.0, 5.0)]
+        );
+        assert_eq!(sanitized.repaired, 2);
+        // finite components beyond the bound are clamped as well
+        let clamped = sanitize(&[Perplex::new(1e300, -2.0)], SanitizePolicy::clamp(1e3)).unwrap();
+        assert_eq!(clamped.values, vec![Perplex::new(1e3, -2.0)]);
+        // kept defects pass through and mixed actions report only the errors
+        let policy = SanitizePolicy {
+            nan: Action::Keep,
+            ..SanitizePolicy::error()
+        };
+        let error = sanitize(&self::data(), policy).unwrap_err();
+        assert_eq!(error.defects.len(), 3);
+        let policy = SanitizePolicy::<f64>::uniform(Action::Keep);
+        let sanitized = sanitize(&self::data(), policy).unwrap();
+        assert_eq!((sanitized.values.len(), sanitized.repaired), (6, 0));
+    }
+}
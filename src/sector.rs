@@ -0,0 +1,176 @@
+//! # Sector Module
+//!
+//! This module provides batch operations which classify slices of perplex numbers by their `HyperbolicSector`, for pipelines that partition large datasets by sector before applying sector-specific math.
+//!
+//! ## Features
+//! - `classify_slice`: The sectors of all numbers in one pass.
+//! - `sector_masks`: A compact variant with one bit per sector, such that several sectors can be selected by a bitwise or of the constants `RIGHT`, `UP`, `LEFT`, `DOWN` and `DIAGONAL`.
+//! - `apply_by_sector`: Applies one closure per sector in a single sweep and returns the results in input order.
+//!
+//! ## Example
+//! ```
+//! use perplex_num::sector::{sector_masks, LEFT, RIGHT};
+//! use perplex_num::Perplex;
+//! let z = [Perplex::new(2.0, 1.0), Perplex::new(1.0, 2.0), Perplex::new(-2.0, 1.0)];
+//! let masks = sector_masks(&z);
+//! let time_like: Vec<_> = z.iter().zip(&masks).filter(|(_, &m)| m & (RIGHT | LEFT) != 0).collect();
+//! assert_eq!(time_like.len(), 2);
+//! ```
+
+use super::{HyperbolicSector, Perplex};
+use num_traits::Float;
+
+/// The bit of the `Right` sector.
+pub const RIGHT: u8 = 1;
+/// The bit of the `Up` sector.
+pub const UP: u8 = 1 << 1;
+/// The bit of the `Left` sector.
+pub const LEFT: u8 = 1 << 2;
+/// The bit of the `Down` sector.
+pub const DOWN: u8 = 1 << 3;
+/// The bit of light-like numbers in the `Diagonal` sector.
+pub const DIAGONAL: u8 = 1 << 4;
+
+/// Returns the bit of `sector`, i.e., one of the constants `RIGHT`, `UP`, `LEFT`, `DOWN` and `DIAGONAL`.
+#[inline]
+pub fn sector_bit<T>(sector: &HyperbolicSector<T>) -> u8 {
+    match sector {
+        HyperbolicSector::Right => RIGHT,
+        HyperbolicSector::Up => UP,
+        HyperbolicSector::Left => LEFT,
+        HyperbolicSector::Down => DOWN,
+        HyperbolicSector::Diagonal(_) => DIAGONAL,
+    }
+}
+
+/// Returns the sector of each perplex number in input order.
+#[inline]
+pub fn classify_slice<T: Copy + Float>(values: &[Perplex<T>]) -> Vec<HyperbolicSector<T>> {
+    values.iter().map(|&z| HyperbolicSector::from(z)).collect()
+}
+
+/// Returns the sector bit of each perplex number in input order, see `sector_bit`.
+///
+/// In contrast to `classify_slice`, the sectors are stored in one byte each without the time component of light-like numbers.
+#[inline]
+pub fn sector_masks<T: Copy + Float>(values: &[Perplex<T>]) -> Vec<u8> {
+    values
+        .iter()
+        .map(|&z| sector_bit(&HyperbolicSector::from(z)))
+        .collect()
+}
+
+/// Applies the closure of the sector of each perplex number and returns the results in input order.
+///
+/// The numbers are classified and dispatched in a single sweep, hence each closure may assume its sector, e.g., to call the right sector variants like `Perplex::exp_right` without the Klein handling.
+///
+/// # Examples
+/// ```
+/// use perplex_num::sector::apply_by_sector;
+/// use perplex_num::Perplex;
+/// let z = [Perplex::new(2.0, 1.0), Perplex::new(1.0, 1.0), Perplex::new(-2.0, 1.0)];
+/// let ln = apply_by_sector(
+///     &z,
+///     |z| z.ln(),
+///     |z| z.ln(),
+///     |z| z.ln(),
+///     |z| z.ln(),
+///     |_| None,
+/// );
+/// assert_eq!(ln, z.iter().map(|z| z.ln()).collect::<Vec<_>>());
+/// ```
+pub fn apply_by_sector<T, R, FR, FU, FL, FD, FG>(
+    values: &[Perplex<T>],
+    mut f_right: FR,
+    mut f_up: FU,
+    mut f_left: FL,
+    mut f_down: FD,
+    mut f_diagonal: FG,
+) -> Vec<R>
+where
+    T: Copy + Float,
+    FR: FnMut(Perplex<T>) -> R,
+    FU: FnMut(Perplex<T>) -> R,
+    FL: FnMut(Perplex<T>) -> R,
+    FD: FnMut(Perplex<T>) -> R,
+    FG: FnMut(Perplex<T>) -> R,
+{
+    values
+        .iter()
+        .map(|&z| match HyperbolicSector::from(z) {
+            HyperbolicSector::Right => f_right(z),
+            HyperbolicSector::Up => f_up(z),
+            HyperbolicSector::Left => f_left(z),
+            HyperbolicSector::Down => f_down(z),
+            HyperbolicSector::Diagonal(_) => f_diagonal(z),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[test]
+    fn test_classify() {
+        let z = [
+            Perplex::new(2.0, 1.0),
+            Perplex::new(1.0, 2.0),
+            Perplex::new(-2.0, 1.0),
+            Perplex::new(1.0, -2.0),
+            Perplex::new(-1.5, 1.5),
+        ];
+        let sectors = classify_slice(&z);
+        assert_eq!(
+            sectors,
+            vec![
+                HyperbolicSector::Right,
+                HyperbolicSector::Up,
+                HyperbolicSector::Left,
+                HyperbolicSector::Down,
+                HyperbolicSector::Diagonal(-1.5),
+            ]
+        );
+        assert_eq!(sector_masks(&z), vec![RIGHT, UP, LEFT, DOWN, DIAGONAL]);
+        let masks = sectors.iter().map(sector_bit).fold(0, |acc, bit| acc | bit);
+        assert_eq!(masks, 0b11111, "Sector bits are distinct!");
+        assert!(classify_slice::<f64>(&[]).is_empty());
+    }
+    #[test]
+    fn test_apply_by_sector() {
+        let z = [
+            Perplex::new(1.0, -1.0),
+            Perplex::new(2.0, 1.0),
+            Perplex::new(1.0, 2.0),
+            Perplex::new(-2.0, 1.0),
+            Perplex::new(1.0, -2.0),
+            Perplex::new(3.0, 0.0),
+        ];
+        let mut right_calls = 0;
+        let labels = apply_by_sector(
+            &z,
+            |_| {
+                right_calls += 1;
+                'r'
+            },
+            |_| 'u',
+            |_| 'l',
+            |_| 'd',
+            |_| 'g',
+        );
+        assert_eq!(
+            labels,
+            vec!['g', 'r', 'u', 'l', 'd', 'r'],
+            "Results are in input order!"
+        );
+        assert_eq!(right_calls, 2);
+        let exp = apply_by_sector(
+            &z,
+            Perplex::exp,
+            Perplex::exp,
+            Perplex::exp,
+            Perplex::exp,
+            Perplex::exp,
+        );
+        assert_eq!(exp, z.iter().map(|z| z.exp()).collect::<Vec<_>>());
+    }
+}
@@ -0,0 +1,543 @@
+//! # Signal Module
+//!
+//! This module provides signal processing of perplex-valued sequences, like the convolution and the cross-correlation with the perplex product.
+//! The cross-correlation multiplies with the hyperbolic conjugate, such that a hyperbolic chirp `e^(h theta_n)` correlated with itself accumulates the real values `e^(h (theta_n - theta_n)) = 1`, i.e., it serves as a matched filter.
+//!
+//! ## Features
+//! - `ConvolutionMode`: Selects the `Full`, `Same` or `Valid` part of the result, with the same semantics as in [NumPy](https://numpy.org/doc/stable/reference/generated/numpy.convolve.html).
+//! - `convolve` and `correlate`: The direct evaluation of the sums in `O(N M)` operations.
+//! - `fast_convolve` and `fast_correlate`: The evaluation by the convolution theorem of the transform in the `transform` module in `O((N + M) log(N + M))` operations.
+//! - `unwrap_args`: The hyperbolic analogue of phase unwrapping, which yields a continuous track of the arguments along a path crossing the light cone.
+//! - `LnBranch`: The incremental unwrapping along a path, which selects the branch of the argument and the logarithm without jumps at sector transitions.
+//! - `short_time_analysis`: The hyperbolic analogue of the extraction of instantaneous amplitude and phase, which estimates the modulus, the rapidity (hyperbolic argument) and its rate of change within sliding frames weighted by a `Window`.
+//!
+//! ## Example
+//! ```
+//! use perplex_num::signal::{convolve, correlate, ConvolutionMode};
+//! use perplex_num::Perplex;
+//! let chirp: Vec<_> = (0..8).map(|n| Perplex::cis(0.1 * (n * n) as f64)).collect();
+//! let matched = correlate(&chirp, &chirp, ConvolutionMode::Valid);
+//! approx::assert_abs_diff_eq!(matched[0], Perplex::new(8.0, 0.0), epsilon = 1e-12);
+//! let smoothed = convolve(&chirp, &[Perplex::new(0.5, 0.0); 2], ConvolutionMode::Same);
+//! assert_eq!(smoothed.len(), 8);
+//! ```
+
+use super::{transform, HyperbolicSector, Perplex};
+use num_traits::{Float, FloatConst, Num};
+use std::ops::Neg;
+
+/// The part of the result of a convolution or cross-correlation of sequences with lengths `N` and `M`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ConvolutionMode {
+    /// The complete result of length `N + M - 1`.
+    Full,
+    /// The centered part of the complete result of length `max(N, M)`.
+    Same,
+    /// The part of length `max(N, M) - min(N, M) + 1` to which both sequences contribute completely.
+    Valid,
+}
+
+impl ConvolutionMode {
+    /// Returns the range of the complete result of sequences with lengths `n` and `m` which belongs to the mode.
+    fn range(&self, n: usize, m: usize) -> std::ops::Range<usize> {
+        let (short, long) = (n.min(m), n.max(m));
+        match self {
+            Self::Full => 0..n + m - 1,
+            Self::Same => {
+                let start = (short - 1) / 2;
+                start..start + long
+            }
+            Self::Valid => short - 1..long,
+        }
+    }
+}
+
+/// Returns the convolution `c_k = sum_j a_j b_{k - j}` of two perplex sequences with the perplex product, restricted to `mode`.
+///
+/// The sums are evaluated directly. The result is empty if a sequence is empty.
+pub fn convolve<T: Copy + Num>(
+    a: &[Perplex<T>],
+    b: &[Perplex<T>],
+    mode: ConvolutionMode,
+) -> Vec<Perplex<T>> {
+    if a.is_empty() || b.is_empty() {
+        return Vec::new();
+    }
+    let mut full = vec![Perplex::new(T::zero(), T::zero()); a.len() + b.len() - 1];
+    for (i, &a_i) in a.iter().enumerate() {
+        for (j, &b_j) in b.iter().enumerate() {
+            full[i + j] = full[i + j] + a_i * b_j;
+        }
+    }
+    full[mode.range(a.len(), b.len())].to_vec()
+}
+
+/// Returns the cross-correlation `c_k = sum_n a_{n + k} conj(b_n)` of two perplex sequences with the perplex product, restricted to `mode`.
+///
+/// The complete result starts at the lag `k = 1 - M` for a sequence `b` of length `M`. The sums are evaluated directly. The result is empty if a sequence is empty.
+pub fn correlate<T: Copy + Num + Neg<Output = T>>(
+    a: &[Perplex<T>],
+    b: &[Perplex<T>],
+    mode: ConvolutionMode,
+) -> Vec<Perplex<T>> {
+    convolve(a, &reversed_conj(b), mode)
+}
+
+/// Returns the convolution of two perplex sequences like `convolve`, but evaluated by the convolution theorem of `transform::circular_convolution`.
+pub fn fast_convolve<T: Copy + Float + FloatConst>(
+    a: &[Perplex<T>],
+    b: &[Perplex<T>],
+    mode: ConvolutionMode,
+) -> Vec<Perplex<T>> {
+    if a.is_empty() || b.is_empty() {
+        return Vec::new();
+    }
+    // zero padding to a power of two avoids the wrap-around and enables the radix-2 algorithm
+    let n = (a.len() + b.len() - 1).next_power_of_two();
+    let pad = |s: &[Perplex<T>]| {
+        let mut padded = s.to_vec();
+        padded.resize(n, Perplex::new(T::zero(), T::zero()));
+        padded
+    };
+    let full = transform::circular_convolution(&pad(a), &pad(b));
+    full[mode.range(a.len(), b.len())].to_vec()
+}
+
+/// Returns the cross-correlation of two perplex sequences like `correlate`, but evaluated by the convolution theorem of `transform::circular_convolution`.
+pub fn fast_correlate<T: Copy + Float + FloatConst>(
+    a: &[Perplex<T>],
+    b: &[Perplex<T>],
+    mode: ConvolutionMode,
+) -> Vec<Perplex<T>> {
+    fast_convolve(a, &reversed_conj(b), mode)
+}
+
+/// The window functions which weight the samples of a frame in the `short_time_analysis`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Window {
+    /// Equal weights for all samples.
+    Rectangular,
+    /// The symmetric Hann window `0.5 - 0.5 cos(2 pi n / (L - 1))`.
+    Hann,
+    /// The symmetric Hamming window `0.54 - 0.46 cos(2 pi n / (L - 1))`.
+    Hamming,
+}
+
+impl Window {
+    /// Returns the `len` weights of the window.
+    pub fn coefficients<T: Copy + Float + FloatConst>(&self, len: usize) -> Vec<T> {
+        let cosine = |a: f64, b: f64| {
+            let a = T::from(a).unwrap();
+            let b = T::from(b).unwrap();
+            let step = T::TAU() / T::from(len.saturating_sub(1).max(1)).unwrap();
+            (0..len)
+                .map(|n| a - b * (step * T::from(n).unwrap()).cos())
+                .collect()
+        };
+        match self {
+            Self::Rectangular => vec![T::one(); len],
+            Self::Hann => cosine(0.5, 0.5),
+            Self::Hamming => cosine(0.54, 0.46),
+        }
+    }
+}
+
+/// The tracks of the `short_time_analysis` of a perplex signal, with one entry per frame.
+#[derive(Clone, Debug, PartialEq)]
+pub struct InstantaneousTrack<T> {
+    /// The center of each frame as (fractional) sample position.
+    pub centers: Vec<T>,
+    /// The weighted mean of the moduli of the samples.
+    pub modulus: Vec<T>,
+    /// The rapidity (hyperbolic argument) of the weighted mean of the samples.
+    pub rapidity: Vec<T>,
+    /// The change of the rapidity per sample, i.e., the instantaneous hyperbolic frequency.
+    pub frequency: Vec<T>,
+}
+
+/// Estimates the instantaneous modulus, rapidity and frequency of `signal` within frames of `frame_len` samples, which start every `hop` samples and are weighted by `window`.
+///
+/// The rapidity of a frame is the hyperbolic argument of the weighted mean of its samples. The frequency is the argument of the weighted lag-one product `sum_n w_n z_{n+1} conj(z_n)`, whose rapidity is the weighted difference of consecutive rapidities.
+/// Both are exact at the frame center for a signal `rho e^(h (omega n + phi))` and a symmetric window, and they are independent of the Klein group element of the sector, as long as all samples of a frame lie in the same sector.
+///
+/// Returns `None` if `frame_len < 2`, `hop == 0` or the signal is shorter than a frame.
+///
+/// # Examples
+///
+/// ```
+/// use approx::assert_abs_diff_eq;
+/// use perplex_num::signal::{short_time_analysis, Window};
+/// use perplex_num::Perplex;
+/// let chirp: Vec<_> = (0..64)
+///     .map(|n| Perplex::cis(0.001 * (n * n) as f64).scale(2.0))
+///     .collect();
+/// let track = short_time_analysis(&chirp, Window::Hann, 16, 8).unwrap();
+/// assert_eq!(track.centers[1], 15.5);
+/// assert_abs_diff_eq!(track.frequency[1], 0.002 * 15.5, epsilon = 1e-12);
+/// assert_abs_diff_eq!(track.modulus[1], 2.0, epsilon = 1e-12);
+/// ```
+pub fn short_time_analysis<T: Copy + Float + FloatConst>(
+    signal: &[Perplex<T>],
+    window: Window,
+    frame_len: usize,
+    hop: usize,
+) -> Option<InstantaneousTrack<T>> {
+    if frame_len < 2 || hop == 0 || signal.len() < frame_len {
+        return None;
+    }
+    let weights: Vec<T> = window.coefficients(frame_len);
+    let two = T::one() + T::one();
+    let weight_sum = weights.iter().fold(T::zero(), |sum, &w| sum + w);
+    let half_len = T::from(frame_len - 1).unwrap() / two;
+    let frames = (signal.len() - frame_len) / hop + 1;
+    let mut track = InstantaneousTrack {
+        centers: Vec::with_capacity(frames),
+        modulus: Vec::with_capacity(frames),
+        rapidity: Vec::with_capacity(frames),
+        frequency: Vec::with_capacity(frames),
+    };
+    for start in (0..frames).map(|i| i * hop) {
+        let frame = &signal[start..start + frame_len];
+        let zero = Perplex::new(T::zero(), T::zero());
+        let (mean, modulus) = frame
+            .iter()
+            .zip(&weights)
+            .fold((zero, T::zero()), |(mean, modulus), (&z, &w)| {
+                (mean + z.scale(w), modulus + w * z.modulus())
+            });
+        // each lag-one product is weighted by the mean weight of its samples
+        let lag_product = frame
+            .windows(2)
+            .zip(weights.windows(2))
+            .fold(zero, |sum, (z, w)| {
+                sum + (z[1] * z[0].conj()).scale((w[0] + w[1]) / two)
+            });
+        track.centers.push(T::from(start).unwrap() + half_len);
+        track.modulus.push(modulus / weight_sum);
+        track.rapidity.push(mean.arg());
+        track.frequency.push(lag_product.arg());
+    }
+    Some(track)
+}
+
+/// Returns a continuous track of the hyperbolic arguments (rapidities) along the path `z`, analogous to phase unwrapping.
+///
+/// Within a sector, the track follows the argument `arg`. Crossing a light cone line, the rapidity diverges and returns from infinity in the adjacent sector, where the argument traverses its branch in the opposite direction.
+/// Hence, the track accumulates the differences of consecutive arguments with a sign that flips at each transition between the time-like sectors (`Right`, `Left`) and the space-like sectors (`Up`, `Down`), such that the track continues in the same direction.
+/// The step crossing the light cone contributes the absolute difference of the arguments in the direction of the previous step.
+/// Transitions between the `Right` and the `Left` sector, e.g., through the origin, do not flip the sign, since the argument is invariant under the negation.
+/// Light-like samples have infinite arguments and repeat the previous value of the track, or the first finite value for leading light-like samples. Consecutive samples must not cross both light cone lines.
+/// See `LnBranch` for the incremental evaluation along a path.
+///
+/// # Examples
+///
+/// ```
+/// use perplex_num::signal::unwrap_args;
+/// use perplex_num::Perplex;
+/// // from the right sector over the line x = t into the up sector
+/// let path = [Perplex::new(1.0, 0.5), Perplex::new(1.0, 0.9), Perplex::new(0.8, 1.0), Perplex::new(0.5, 1.0)];
+/// let track = unwrap_args(&path);
+/// assert!(track.windows(2).all(|w| w[0] < w[1]), "The track is monotonic!");
+/// approx::assert_abs_diff_eq!(track[3] - track[2], path[2].arg() - path[3].arg(), epsilon = 1e-12);
+/// ```
+pub fn unwrap_args<T: Copy + Float>(z: &[Perplex<T>]) -> Vec<T> {
+    let mut branch = LnBranch::new();
+    let track: Vec<_> = z.iter().map(|&z| branch.arg(z)).collect();
+    // leading light-like samples repeat the first finite value
+    let first = track.iter().flatten().next().copied().unwrap_or(T::zero());
+    track.into_iter().map(|v| v.unwrap_or(first)).collect()
+}
+
+/// A branch of the hyperbolic argument and the logarithm which is followed continuously along a path, i.e., the incremental form of `unwrap_args`.
+///
+/// The logarithm of the `Perplex` type is extended to all sectors by the Klein index, hence its components jump when a path crosses the light cone from one sector into another, e.g., from the `Right` into the `Up` sector.
+/// Since the perplex exponential function is not periodic, there are no other branches of the logarithm within a sector, and the divergence of the modulus at the light cone is inherent. The branch instead replaces the argument by its continuous track, such that `ln` returns the hyperbolic polar coordinates `ln|z| + h theta` with a rapidity `theta` which does not jump at sector transitions.
+/// Within the right sector and before the first transition, `ln` agrees with `Perplex::ln`.
+///
+/// # Examples
+///
+/// ```
+/// use perplex_num::signal::LnBranch;
+/// use perplex_num::Perplex;
+/// let mut branch = LnBranch::new();
+/// let before = branch.ln(Perplex::new(1.0, 0.8)).unwrap();
+/// assert_eq!(Some(before), Perplex::new(1.0, 0.8).ln());
+/// // the rapidity continues to grow in the up sector, whereas the argument decreases
+/// let z = Perplex::new(0.5, 1.0);
+/// let after = branch.ln(z).unwrap();
+/// assert!(after.x > before.x && z.arg() < before.x);
+/// assert_eq!(after.t, z.ln_modulus());
+/// assert_eq!(branch.sector(), Some(perplex_num::HyperbolicSector::Up));
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct LnBranch<T> {
+    /// The previous finite sample with its argument.
+    previous: Option<(Perplex<T>, T)>,
+    /// The sign of the increments of the arguments, which flips at each transition between time-like and space-like sectors.
+    sign: T,
+    /// The sign of the last non-zero increment of the track.
+    direction: Option<T>,
+    /// The current value of the track.
+    value: T,
+}
+
+impl<T: Copy + Float> Default for LnBranch<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Copy + Float> LnBranch<T> {
+    /// Creates the branch at the start of a path.
+    #[inline]
+    pub fn new() -> Self {
+        Self {
+            previous: None,
+            sign: T::one(),
+            direction: None,
+            value: T::zero(),
+        }
+    }
+    /// Advances the branch to the next sample `z` of the path and returns the continuous track of the argument, see `unwrap_args`.
+    ///
+    /// Light-like samples repeat the previous value, and `None` is returned while no sample of the path has been finite.
+    pub fn arg(&mut self, z: Perplex<T>) -> Option<T> {
+        let time_like = |z: &Perplex<T>| z.t.abs() > z.x.abs();
+        let arg = z.arg();
+        if arg.is_finite() {
+            match self.previous {
+                Some((last, last_arg)) => {
+                    let increment = if time_like(&last) != time_like(&z) {
+                        self.sign = -self.sign;
+                        // the crossing continues in the direction of the previous increment
+                        let magnitude = (arg - last_arg).abs();
+                        self.direction
+                            .map_or(self.sign * (arg - last_arg), |d| d * magnitude)
+                    } else {
+                        self.sign * (arg - last_arg)
+                    };
+                    if !increment.is_zero() {
+                        self.direction = Some(increment.signum());
+                    }
+                    self.value = self.value + increment;
+                }
+                None => self.value = arg,
+            }
+            self.previous = Some((z, arg));
+        }
+        self.previous.map(|_| self.value)
+    }
+    /// Advances the branch to the next sample `z` of the path and returns the logarithm `ln|z| + h theta` with the continuous track `theta` of the argument, or `None` if `z` is light-like.
+    #[inline]
+    pub fn ln(&mut self, z: Perplex<T>) -> Option<Perplex<T>> {
+        let theta = self.arg(z)?;
+        let ln_modulus = z.ln_modulus();
+        ln_modulus
+            .is_finite()
+            .then(|| Perplex::new(ln_modulus, theta))
+    }
+    /// Returns the sector of the last sample which is not light-like, whose Klein index `k` reconstructs the sample as `z = k |z| cis(arg(z))`.
+    #[inline]
+    pub fn sector(&self) -> Option<HyperbolicSector<T>> {
+        self.previous.map(|(z, _)| z.sector())
+    }
+}
+
+/// Returns the hyperbolic conjugates of `s` in reversed order.
+fn reversed_conj<T: Copy + Num + Neg<Output = T>>(s: &[Perplex<T>]) -> Vec<Perplex<T>> {
+    s.iter().rev().map(|z| z.conj()).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_abs_diff_eq;
+    const MODES: [ConvolutionMode; 3] = [
+        ConvolutionMode::Full,
+        ConvolutionMode::Same,
+        ConvolutionMode::Valid,
+    ];
+    fn sequence(n: usize, offset: f64) -> Vec<Perplex<f64>> {
+        (0..n)
+            .map(|i| {
+                let s = i as f64 + offset;
+                Perplex::new(s.sin(), 0.5 * s.cos())
+            })
+            .collect()
+    }
+    #[test]
+    fn test_convolve() {
+        let a = [Perplex::new(1, 0), Perplex::new(2, 0), Perplex::new(3, 0)];
+        let b = [Perplex::new(0, 0), Perplex::new(1, 0), Perplex::new(0, 1)];
+        let full = convolve(&a, &b, ConvolutionMode::Full);
+        assert_eq!(
+            full,
+            vec![
+                Perplex::new(0, 0),
+                Perplex::new(1, 0),
+                Perplex::new(2, 1),
+                Perplex::new(3, 2),
+                Perplex::new(0, 3)
+            ]
+        );
+        assert_eq!(convolve(&a, &b, ConvolutionMode::Same), full[1..4].to_vec());
+        assert_eq!(
+            convolve(&a, &b, ConvolutionMode::Valid),
+            full[2..3].to_vec()
+        );
+        assert_eq!(
+            convolve(&b, &a, ConvolutionMode::Full),
+            full,
+            "Convolution is commutative!"
+        );
+        assert!(convolve(&a, &[], ConvolutionMode::Full).is_empty());
+    }
+    #[test]
+    fn test_modes() {
+        // lengths as in the examples of numpy.convolve
+        assert_eq!(ConvolutionMode::Same.range(5, 2), 0..5);
+        assert_eq!(ConvolutionMode::Same.range(5, 4), 1..6);
+        assert_eq!(ConvolutionMode::Valid.range(2, 5), 1..5);
+        assert_eq!(ConvolutionMode::Full.range(1, 1), 0..1);
+    }
+    #[test]
+    fn test_correlate() {
+        let a = sequence(6, 0.0);
+        let b = sequence(3, 0.7);
+        let full = correlate(&a, &b, ConvolutionMode::Full);
+        assert_eq!(full.len(), 8);
+        // lag k = 0 is at index M - 1
+        let lag_zero = (0..3).fold(Perplex::new(0.0, 0.0), |sum, n| sum + a[n] * b[n].conj());
+        assert_abs_diff_eq!(full[2], lag_zero, epsilon = 1e-15);
+        let lag_minus_two = a[0] * b[2].conj();
+        assert_abs_diff_eq!(full[0], lag_minus_two, epsilon = 1e-15);
+    }
+    #[test]
+    fn test_fast() {
+        for (n, m) in [(7, 3), (3, 7), (8, 8), (1, 5), (13, 1)] {
+            let (a, b) = (sequence(n, 0.0), sequence(m, 1.3));
+            for mode in MODES {
+                let direct = convolve(&a, &b, mode);
+                let fast = fast_convolve(&a, &b, mode);
+                assert_eq!(direct.len(), fast.len());
+                for (d, f) in direct.into_iter().zip(fast) {
+                    assert_abs_diff_eq!(d, f, epsilon = 1e-12);
+                }
+                let direct = correlate(&a, &b, mode);
+                let fast = fast_correlate(&a, &b, mode);
+                assert_eq!(direct.len(), fast.len());
+                for (d, f) in direct.into_iter().zip(fast) {
+                    assert_abs_diff_eq!(d, f, epsilon = 1e-12);
+                }
+            }
+        }
+        assert!(fast_correlate(&[], &sequence(2, 0.0), ConvolutionMode::Same).is_empty());
+    }
+    #[test]
+    fn test_window() {
+        assert_eq!(Window::Rectangular.coefficients::<f64>(3), vec![1.0; 3]);
+        let hann: Vec<f64> = Window::Hann.coefficients(5);
+        for (w, expected) in hann.into_iter().zip([0.0, 0.5, 1.0, 0.5, 0.0]) {
+            assert_abs_diff_eq!(w, expected, epsilon = 1e-15);
+        }
+        let hamming: Vec<f64> = Window::Hamming.coefficients(3);
+        for (w, expected) in hamming.into_iter().zip([0.08, 1.0, 0.08]) {
+            assert_abs_diff_eq!(w, expected, epsilon = 1e-15);
+        }
+        assert_eq!(Window::Hann.coefficients::<f32>(1).len(), 1);
+    }
+    #[test]
+    fn test_unwrap_args() {
+        // counterclockwise around the origin through all sectors
+        let path: Vec<_> = (0..64)
+            .map(|n| {
+                let phi = 0.1 + n as f64 * std::f64::consts::TAU / 64.0;
+                Perplex::new(phi.cos(), phi.sin())
+            })
+            .collect();
+        let track = unwrap_args(&path);
+        assert_eq!(track.len(), path.len());
+        assert_eq!(track[0], path[0].arg());
+        assert!(track.windows(2).all(|w| w[0] < w[1]), "Track is monotonic!");
+        // within a sector, the differences are the differences of the arguments
+        assert_abs_diff_eq!(
+            track[2] - track[1],
+            path[2].arg() - path[1].arg(),
+            epsilon = 1e-12
+        );
+        // light-like samples repeat the previous value
+        let path = [
+            Perplex::new(1.0, 1.0),
+            Perplex::new(2.0, 1.0),
+            Perplex::new(1.0, 1.0),
+            Perplex::new(2.0, -1.0),
+        ];
+        let track = unwrap_args(&path);
+        let theta = path[1].arg();
+        assert_eq!(track, vec![theta, theta, theta, -theta]);
+        // the negation through the origin does not flip the direction
+        let path = [
+            Perplex::new(2.0, 0.5),
+            Perplex::new(-2.0, -1.0),
+            Perplex::new(-2.0, -1.5),
+        ];
+        let track = unwrap_args(&path);
+        assert_abs_diff_eq!(track[2], path[2].arg(), epsilon = 1e-12);
+        assert!(unwrap_args::<f64>(&[]).is_empty());
+        assert_eq!(unwrap_args(&[Perplex::new(1.0, -1.0)]), vec![0.0]);
+    }
+    #[test]
+    fn test_ln_branch() {
+        // from the right sector over the line x = t into the up sector, with a light-like sample
+        let path: Vec<_> = (0..=40)
+            .map(|n| Perplex::new(1.0, 0.5 + n as f64 / 40.0))
+            .collect();
+        let mut branch = LnBranch::new();
+        let logs: Vec<_> = path.iter().map(|&z| branch.ln(z)).collect();
+        assert!(logs[20].is_none(), "Light-like!");
+        let logs: Vec<_> = logs.into_iter().flatten().collect();
+        assert!(
+            logs.windows(2).all(|w| w[0].x < w[1].x),
+            "Rapidity without jump!"
+        );
+        for (z, log) in path.iter().zip(&logs).take(20) {
+            assert_abs_diff_eq!(*log, z.ln().unwrap(), epsilon = 1e-14);
+        }
+        // the Klein-extended logarithm jumps at the transition
+        assert!(path[21].ln().unwrap().x < path[19].ln().unwrap().x);
+        let mut branch = LnBranch::new();
+        for z in &path {
+            if branch.ln(*z).is_some() {
+                let k = branch.sector().unwrap().canonical_representative();
+                let polar = Perplex::cis(z.arg()).scale(z.modulus());
+                assert_abs_diff_eq!(k * polar, *z, epsilon = 1e-12);
+            }
+        }
+        assert_eq!(branch.sector(), Some(HyperbolicSector::Up));
+        assert_eq!(LnBranch::new().arg(Perplex::new(2.0, 2.0)), None);
+        assert_eq!(LnBranch::<f64>::default().sector(), None, "No sample yet!");
+    }
+    #[test]
+    fn test_short_time_analysis() {
+        let (rho, omega, phi) = (3.0, 0.05, -0.4);
+        let tone: Vec<_> = (0..50)
+            .map(|n| Perplex::cis(omega * n as f64 + phi).scale(rho))
+            .collect();
+        for window in [Window::Rectangular, Window::Hann, Window::Hamming] {
+            let track = short_time_analysis(&tone, window, 10, 5).unwrap();
+            assert_eq!(track.centers.len(), 9);
+            for (i, &center) in track.centers.iter().enumerate() {
+                assert_eq!(center, 5.0 * i as f64 + 4.5);
+                assert_abs_diff_eq!(track.modulus[i], rho, epsilon = 1e-12);
+                assert_abs_diff_eq!(track.rapidity[i], omega * center + phi, epsilon = 1e-12);
+                assert_abs_diff_eq!(track.frequency[i], omega, epsilon = 1e-12);
+            }
+            // the Klein group element of the sector is irrelevant
+            let up: Vec<_> = tone.iter().map(|&z| z * Perplex::h()).collect();
+            assert_eq!(short_time_analysis(&up, window, 10, 5).unwrap(), track);
+        }
+        assert!(short_time_analysis(&tone, Window::Hann, 1, 1).is_none());
+        assert!(short_time_analysis(&tone, Window::Hann, 10, 0).is_none());
+        assert!(short_time_analysis(&tone, Window::Hann, 51, 1).is_none());
+    }
+}
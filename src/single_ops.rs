@@ -11,40 +11,152 @@
 //! The module defines methods for exponentiation:
 //! - `powu`: Method for exponentiation with an unsigned integer exponent.
 //! - `powi`: Method for exponentiation with a signed integer exponent, returning an `Option` to handle cases where the perplex number cannot be inverted.
+//! - `powi_unchecked`: Method for exponentiation with a signed integer exponent for callers that guarantee the invertibility.
+//! - `pow_const`: Method for exponentiation with a constant unsigned exponent, whose squaring chain is written out for the exponents up to 16.
 
 use super::Perplex;
+use core::ops::Neg;
 use num_traits::{Inv, Num, One, Pow};
-use std::ops::Neg;
 
-impl<T: Copy + Num + Neg<Output = T>> Neg for Perplex<T> {
+impl<T: Clone + Num + Neg<Output = T>> Neg for Perplex<T> {
     type Output = Self;
     #[inline]
     fn neg(self) -> Self::Output {
         Self::Output::new(-self.t, -self.x)
     }
 }
-impl<T: Copy + Num + Neg<Output = T>> Inv for Perplex<T> {
+impl<T: Clone + Num + Neg<Output = T>> Neg for &Perplex<T> {
+    type Output = Perplex<T>;
+    #[inline]
+    fn neg(self) -> Self::Output {
+        -self.clone()
+    }
+}
+impl<T: Clone + Num + Neg<Output = T>> Inv for Perplex<T> {
     type Output = Option<Self>;
     #[inline]
     fn inv(self) -> Self::Output {
         self.try_inverse()
     }
 }
+impl<T: Clone + Num + Neg<Output = T>> Inv for &Perplex<T> {
+    type Output = Option<Perplex<T>>;
+    #[inline]
+    fn inv(self) -> Self::Output {
+        self.try_inverse()
+    }
+}
 
-impl<T: Copy + Num + Neg<Output = T>> Perplex<T> {
+impl<T: Clone + Num + Neg<Output = T>> Perplex<T> {
     /// Raises `self` to an unsigned integer power.
     #[inline]
     pub fn powu(&self, exp: u32) -> Self {
-        Pow::pow(*self, exp)
+        Pow::pow(self.clone(), exp)
     }
 
-    /// Raises `self` to a signed integer power.
+    /// Raises `self` to a signed integer power, or returns `None` if the exponent is negative and `self` is light-like.
+    ///
+    /// The invertibility is checked once before the exponentiation by squaring, which is shared with `powu`, such that the loop does not wrap its intermediate results in `Option`.
     #[inline]
     pub fn powi(&self, exp: i32) -> Option<Self> {
-        Pow::pow(*self, exp)
+        Pow::pow(self.clone(), exp)
+    }
+
+    /// Raises `self` to a signed integer power without checking the invertibility for a negative exponent.
+    ///
+    /// The caller guarantees that `self` is not light-like if `exp` is negative. Otherwise, the inverse divides by the zero squared distance, which yields infinite or NaN components for floating point types and panics for integer types.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use perplex_num::Perplex;
+    ///
+    /// let z = Perplex::new(2.0, 1.0);
+    /// assert_eq!(z.powi_unchecked(-2), z.powi(-2).unwrap());
+    /// ```
+    #[inline]
+    pub fn powi_unchecked(&self, exp: i32) -> Self {
+        let base = if exp < 0 {
+            let squared_distance = self.squared_distance();
+            Self::new(
+                self.t.clone() / squared_distance.clone(),
+                -self.x.clone() / squared_distance,
+            )
+        } else {
+            self.clone()
+        };
+        pow_by_squaring(base, exp.unsigned_abs())
     }
 }
-impl<T: Copy + Num> Pow<u32> for Perplex<T> {
+impl<T: Clone + Num> Perplex<T> {
+    /// Raises `self` to the constant unsigned integer power `N`.
+    ///
+    /// For the exponents up to 16, the squarings and multiplications of `powu` are written out for each exponent without a loop, and only the selection of the chain depends on the constant `N`, which the optimizer removes.
+    /// This is beneficial in hot loops with a statically known small exponent (e.g., 2 to 16). Larger exponents use the loop of `powu`.
+    /// The multiplications are performed in the order of `powu`, hence both methods yield identical results.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use perplex_num::Perplex;
+    ///
+    /// let z = Perplex::new(1.0, 0.5);
+    /// assert_eq!(z.pow_const::<3>(), z * z * z);
+    /// ```
+    #[inline(always)]
+    pub fn pow_const<const N: u32>(&self) -> Self {
+        let x = self;
+        let sq = |z: &Self| z.clone() * z.clone();
+        match N {
+            0 => Perplex::one(),
+            1 => x.clone(),
+            2 => sq(x),
+            3 => x.clone() * sq(x),
+            4 => sq(&sq(x)),
+            5 => x.clone() * sq(&sq(x)),
+            6 => {
+                let x2 = sq(x);
+                x2.clone() * sq(&x2)
+            }
+            7 => {
+                let x2 = sq(x);
+                x.clone() * x2.clone() * sq(&x2)
+            }
+            8 => sq(&sq(&sq(x))),
+            9 => x.clone() * sq(&sq(&sq(x))),
+            10 => {
+                let x2 = sq(x);
+                x2.clone() * sq(&sq(&x2))
+            }
+            11 => {
+                let x2 = sq(x);
+                x.clone() * x2.clone() * sq(&sq(&x2))
+            }
+            12 => {
+                let x4 = sq(&sq(x));
+                x4.clone() * sq(&x4)
+            }
+            13 => {
+                let x4 = sq(&sq(x));
+                x.clone() * x4.clone() * sq(&x4)
+            }
+            14 => {
+                let x2 = sq(x);
+                let x4 = sq(&x2);
+                x2 * x4.clone() * sq(&x4)
+            }
+            15 => {
+                let x2 = sq(x);
+                let x4 = sq(&x2);
+                x.clone() * x2 * x4.clone() * sq(&x4)
+            }
+            16 => sq(&sq(&sq(&sq(x)))),
+            _ => pow_by_squaring(x.clone(), N),
+        }
+    }
+}
+
+impl<T: Clone + Num> Pow<u32> for Perplex<T> {
     type Output = Perplex<T>;
 
     /// Performs exponentiation by squaring, an efficient algorithm for raising numbers to a power.
@@ -56,26 +168,12 @@ impl<T: Copy + Num> Pow<u32> for Perplex<T> {
     /// # Returns
     /// The result of raising the perplex number to the power of `exp`.
     #[inline]
-    fn pow(self, mut exp: u32) -> Self::Output {
-        // Initialize the result as the multiplicative identity, which is the result if the exponent is zero.
-        let mut result = Perplex::one();
-        if exp == 0 {
-            return result;
-        }
-        // Set the base for exponentiation and iterate until the exponent is reduced to 1.
-        let mut base = self;
-        while exp > 1 {
-            if exp % 2 == 1 {
-                result = result * base;
-            }
-            exp /= 2;
-            base = base * base;
-        }
-        result * base
+    fn pow(self, exp: u32) -> Self::Output {
+        pow_by_squaring(self, exp)
     }
 }
 
-impl<T: Copy + Num + Neg<Output = T>> Pow<i32> for Perplex<T> {
+impl<T: Clone + Num + Neg<Output = T>> Pow<i32> for Perplex<T> {
     type Output = Option<Perplex<T>>;
 
     /// Performs exponentiation for both positive and negative integer exponents.
@@ -89,15 +187,30 @@ impl<T: Copy + Num + Neg<Output = T>> Pow<i32> for Perplex<T> {
     /// Returns `None` if the perplex number cannot be inverted (i.e., it is light-like).
     #[inline]
     fn pow(self, exp: i32) -> Self::Output {
-        // If the exponent is negative, calculate the multiplicative inverse first.
-        if exp < 0 {
-            // Use the wrapping_neg method to safely handle potential overflow.
-            self.inv().map(|z| z.pow(exp.wrapping_neg() as u32))
-        } else {
-            // For non-negative exponents, delegate to the u32 implementation.
-            Some(Pow::pow(self, exp as u32))
+        // If the exponent is negative, check the invertibility once and calculate the multiplicative inverse first.
+        let base = if exp < 0 { self.inv()? } else { self };
+        // unsigned_abs handles i32::MIN without overflow
+        Some(pow_by_squaring(base, exp.unsigned_abs()))
+    }
+}
+
+/// Raises `base` to the power `exp` by squaring, which is the shared loop of `powu`, `powi` and `powi_unchecked`.
+#[inline(always)]
+fn pow_by_squaring<T: Clone + Num>(mut base: Perplex<T>, mut exp: u32) -> Perplex<T> {
+    // Initialize the result as the multiplicative identity, which is the result if the exponent is zero.
+    let mut result = Perplex::one();
+    if exp == 0 {
+        return result;
+    }
+    // Iterate until the exponent is reduced to 1.
+    while exp > 1 {
+        if exp % 2 == 1 {
+            result = result * base.clone();
         }
+        exp /= 2;
+        base = base.clone() * base;
     }
+    result * base
 }
 
 #[cfg(test)]
@@ -146,6 +259,24 @@ mod tests {
         assert_abs_diff_eq!(z.powu(7), z * z * z * z * z * z * z, epsilon = 0.0000001);
     }
     #[test]
+    fn test_power_const() {
+        let z = Perplex::new(f64::PI(), -0.123);
+        assert_eq!(z.pow_const::<0>(), Perplex::one(), "Power 0 yields one!");
+        assert_eq!(z.pow_const::<1>(), z, "Power 1 yields the same number!");
+        assert_eq!(z.pow_const::<2>(), z.powu(2));
+        assert_eq!(z.pow_const::<3>(), z.powu(3));
+        assert_eq!(z.pow_const::<7>(), z.powu(7));
+        assert_eq!(z.pow_const::<8>(), z.powu(8));
+        macro_rules! assert_pow_const {
+            ($($n:literal),*) => {$(
+                assert_eq!(z.pow_const::<$n>(), z.powu($n), "Same order as powu!");
+            )*};
+        }
+        assert_pow_const!(4, 5, 6, 9, 10, 11, 12, 13, 14, 15, 16, 17, 31);
+        let z = Perplex::new(2, -1);
+        assert_eq!(z.pow_const::<5>(), z.powu(5), "Integer components!");
+    }
+    #[test]
     fn test_power_i32() {
         let z = Perplex::new(1.0, -1.0);
         assert!(z.powi(-2).is_none(), " 1 - h is not invertibe!");
@@ -182,4 +313,18 @@ mod tests {
             z_inv * z_inv * z_inv * z_inv * z_inv * z_inv * z_inv,
         );
     }
+    #[test]
+    fn test_power_unchecked() {
+        let z = Perplex::new(f64::PI(), -0.123);
+        for exp in [-7, -2, 0, 1, 5] {
+            assert_eq!(z.powi_unchecked(exp), z.powi(exp).unwrap());
+        }
+        assert_eq!(Perplex::new(1.0, 1.0).powi(i32::MIN), None);
+        assert_eq!(Perplex::new(1.0, 0.0).powi(i32::MIN), Some(Perplex::one()));
+        let light_like = Perplex::new(1.0, -1.0).powi_unchecked(-1);
+        assert!(
+            !f64::is_finite(light_like.t),
+            "Division by the zero squared distance!"
+        );
+    }
 }
@@ -0,0 +1,283 @@
+//! # Snapshot Module
+//!
+//! This module provides a tiny self-describing binary format for the checkpoints of a `PerplexBuffer`, e.g., the state of a long-running simulation, which is independent of `serde`.
+//! A snapshot consists of a header of 16 bytes and the payload of the little-endian time components followed by the little-endian space components, i.e., the structure of arrays layout of the buffer:
+//!
+//! | Offset | Bytes | Content |
+//! |--------|-------|---------|
+//! | 0 | 4 | The magic bytes `PPLX`. |
+//! | 4 | 2 | The little-endian format version `SNAPSHOT_VERSION`. |
+//! | 6 | 1 | The tag of the scalar type, see `SnapshotComponent::TAG`. |
+//! | 7 | 1 | Reserved, zero. |
+//! | 8 | 8 | The little-endian number of perplex numbers `count`. |
+//! | 16 | `count * BYTES` | The time components. |
+//! | `16 + count * BYTES` | `count * BYTES` | The space components. |
+//!
+//! The header is a multiple of the alignment of all scalar types, hence both component arrays of a memory-mapped snapshot are aligned and may be reinterpreted in place on little-endian targets, see `SnapshotHeader::payload_ranges`.
+//!
+//! ## Features
+//! - `save_snapshot` and `load_snapshot`: The writer and reader of a snapshot, whereby the reader fails with an `io::Error` of the kind `InvalidData` for a wrong magic, version or scalar type and `UnexpectedEof` for a truncated payload.
+//! - `SnapshotHeader`: The parsed header, which locates the components in the bytes of a snapshot without copying them.
+//! - `SnapshotComponent`: The scalar types of the format with their tags.
+//!
+//! ## Example
+//! ```
+//! use perplex_num::snapshot::{load_snapshot, save_snapshot, SnapshotHeader};
+//! use perplex_num::{Perplex, PerplexBuffer};
+//! let state: PerplexBuffer<f64> = (0..4).map(|i| Perplex::new(i as f64, 1.0)).collect();
+//! let mut bytes = Vec::new();
+//! save_snapshot(&mut bytes, &state).unwrap();
+//! assert_eq!(SnapshotHeader::from_bytes(&bytes).unwrap().count, 4);
+//! assert_eq!(load_snapshot::<_, f64>(bytes.as_slice()).unwrap(), state);
+//! // the scalar type is part of the format
+//! assert!(load_snapshot::<_, f32>(bytes.as_slice()).is_err());
+//! ```
+
+use super::stream::BinaryComponent;
+use super::PerplexBuffer;
+use std::io::{self, BufWriter, ErrorKind, Read, Write};
+use std::ops::Range;
+
+/// The magic bytes at the start of a snapshot.
+pub const SNAPSHOT_MAGIC: [u8; 4] = *b"PPLX";
+/// The version of the snapshot format written by `save_snapshot`.
+pub const SNAPSHOT_VERSION: u16 = 1;
+/// The number of bytes of the header, which precedes the payload.
+pub const SNAPSHOT_HEADER_BYTES: usize = 16;
+
+/// A scalar type of the snapshot format.
+pub trait SnapshotComponent: BinaryComponent {
+    /// The tag of the scalar type in the header, whose high nibble is the kind, i.e., `1` for unsigned integers, `2` for signed integers and `3` for floating-point numbers, and whose low nibble is the binary logarithm of `BYTES`.
+    const TAG: u8;
+}
+
+macro_rules! impl_snapshot_component {
+    ($($component:ty => $tag:expr),*) => {$(
+        impl SnapshotComponent for $component {
+            const TAG: u8 = $tag;
+        }
+    )*};
+}
+
+impl_snapshot_component!(
+    u8 => 0x10, u16 => 0x11, u32 => 0x12, u64 => 0x13, u128 => 0x14,
+    i8 => 0x20, i16 => 0x21, i32 => 0x22, i64 => 0x23, i128 => 0x24,
+    f32 => 0x32, f64 => 0x33
+);
+
+/// The header of a snapshot.
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct SnapshotHeader {
+    /// The format version.
+    pub version: u16,
+    /// The tag of the scalar type, see `SnapshotComponent::TAG`.
+    pub tag: u8,
+    /// The number of perplex numbers.
+    pub count: u64,
+}
+
+impl SnapshotHeader {
+    /// Creates the header of the current version for `count` perplex numbers with components of type `T`.
+    #[inline]
+    pub fn new<T: SnapshotComponent>(count: u64) -> Self {
+        Self {
+            version: SNAPSHOT_VERSION,
+            tag: T::TAG,
+            count,
+        }
+    }
+    /// Returns the bytes of the header.
+    #[inline]
+    pub fn to_bytes(&self) -> [u8; SNAPSHOT_HEADER_BYTES] {
+        let mut bytes = [0; SNAPSHOT_HEADER_BYTES];
+        bytes[..4].copy_from_slice(&SNAPSHOT_MAGIC);
+        bytes[4..6].copy_from_slice(&self.version.to_le_bytes());
+        bytes[6] = self.tag;
+        bytes[8..].copy_from_slice(&self.count.to_le_bytes());
+        bytes
+    }
+    /// Parses the header at the start of `bytes`, which fails with `InvalidData` for a wrong magic or an unsupported version and with `UnexpectedEof` if `bytes` is shorter than the header.
+    pub fn from_bytes(bytes: &[u8]) -> io::Result<Self> {
+        let bytes = bytes
+            .get(..SNAPSHOT_HEADER_BYTES)
+            .ok_or_else(|| io::Error::new(ErrorKind::UnexpectedEof, "truncated snapshot header"))?;
+        if bytes[..4] != SNAPSHOT_MAGIC {
+            return Err(io::Error::new(
+                ErrorKind::InvalidData,
+                "missing magic bytes of a snapshot",
+            ));
+        }
+        let version = u16::from_le_bytes([bytes[4], bytes[5]]);
+        if version != SNAPSHOT_VERSION {
+            return Err(io::Error::new(
+                ErrorKind::InvalidData,
+                format!("unsupported snapshot version {version}"),
+            ));
+        }
+        let mut count = [0; 8];
+        count.copy_from_slice(&bytes[8..]);
+        Ok(Self {
+            version,
+            tag: bytes[6],
+            count: u64::from_le_bytes(count),
+        })
+    }
+    /// Returns the byte ranges of the time and space components in the snapshot, or an `InvalidData` error if the tag differs from `T::TAG` or the payload exceeds the address space.
+    ///
+    /// The ranges do not depend on the length of the bytes, which the caller checks before slicing, e.g., of a memory-mapped file.
+    pub fn payload_ranges<T: SnapshotComponent>(&self) -> io::Result<(Range<usize>, Range<usize>)> {
+        if self.tag != T::TAG {
+            return Err(io::Error::new(
+                ErrorKind::InvalidData,
+                format!(
+                    "snapshot has the scalar tag {:#04x} instead of {:#04x}",
+                    self.tag,
+                    T::TAG
+                ),
+            ));
+        }
+        let component_bytes = usize::try_from(self.count)
+            .ok()
+            .and_then(|count| count.checked_mul(T::BYTES))
+            .filter(|&n| n <= (usize::MAX - SNAPSHOT_HEADER_BYTES) / 2)
+            .ok_or_else(|| io::Error::new(ErrorKind::InvalidData, "snapshot count is too large"))?;
+        let middle = SNAPSHOT_HEADER_BYTES + component_bytes;
+        Ok((
+            SNAPSHOT_HEADER_BYTES..middle,
+            middle..middle + component_bytes,
+        ))
+    }
+}
+
+/// Writes `buffer` as a snapshot of the current version.
+///
+/// The components are streamed through a `BufWriter`, such that the payload is not held in memory as a whole.
+pub fn save_snapshot<W: Write, T: SnapshotComponent>(
+    writer: W,
+    buffer: &PerplexBuffer<T>,
+) -> io::Result<()> {
+    let mut writer = BufWriter::new(writer);
+    let header = SnapshotHeader::new::<T>(buffer.len() as u64);
+    writer.write_all(&header.to_bytes())?;
+    let mut bytes = Vec::with_capacity(T::BYTES);
+    for &c in buffer.times().iter().chain(buffer.spaces()) {
+        bytes.clear();
+        c.extend_le_bytes(&mut bytes);
+        writer.write_all(&bytes)?;
+    }
+    writer.flush()
+}
+
+/// Reads a snapshot with components of type `T` from `reader`.
+///
+/// The payload is read up to the length stated in the header, such that a corrupted count allocates no more memory than the source provides.
+pub fn load_snapshot<R: Read, T: SnapshotComponent>(mut reader: R) -> io::Result<PerplexBuffer<T>> {
+    let mut header = [0; SNAPSHOT_HEADER_BYTES];
+    reader.read_exact(&mut header)?;
+    let header = SnapshotHeader::from_bytes(&header)?;
+    let (t_range, x_range) = header.payload_ranges::<T>()?;
+    let payload_bytes = x_range.end - t_range.start;
+    let mut payload = Vec::new();
+    reader
+        .take(payload_bytes as u64)
+        .read_to_end(&mut payload)?;
+    if payload.len() < payload_bytes {
+        return Err(io::Error::new(
+            ErrorKind::UnexpectedEof,
+            "truncated snapshot payload",
+        ));
+    }
+    let (t, x) = payload.split_at(t_range.len());
+    let components =
+        |bytes: &[u8]| -> Vec<T> { bytes.chunks_exact(T::BYTES).map(T::from_le_slice).collect() };
+    Ok(PerplexBuffer::from_components(components(t), components(x))
+        .expect("Component arrays of a snapshot have equal lengths!"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Perplex;
+    #[test]
+    fn test_snapshot_roundtrip() {
+        let state: PerplexBuffer<i32> = (0..5).map(|i| Perplex::new(i, -2 * i)).collect();
+        let mut bytes = Vec::new();
+        save_snapshot(&mut bytes, &state).unwrap();
+        assert_eq!(bytes.len(), SNAPSHOT_HEADER_BYTES + 2 * 5 * 4);
+        assert_eq!(&bytes[..4], b"PPLX");
+        assert_eq!(bytes[6], i32::TAG);
+        assert_eq!(load_snapshot::<_, i32>(bytes.as_slice()).unwrap(), state);
+        // the payload is in structure of arrays layout
+        let header = SnapshotHeader::from_bytes(&bytes).unwrap();
+        assert_eq!(header, SnapshotHeader::new::<i32>(5));
+        let (t, x) = header.payload_ranges::<i32>().unwrap();
+        assert_eq!(bytes[t][4..8], 1i32.to_le_bytes());
+        assert_eq!(bytes[x][4..8], (-2i32).to_le_bytes());
+        let empty = PerplexBuffer::<f64>::new();
+        bytes.clear();
+        save_snapshot(&mut bytes, &empty).unwrap();
+        assert_eq!(load_snapshot::<_, f64>(bytes.as_slice()).unwrap(), empty);
+        let nan = PerplexBuffer::from(vec![Perplex::new(f32::NAN, f32::INFINITY)]);
+        bytes.clear();
+        save_snapshot(&mut bytes, &nan).unwrap();
+        let loaded = load_snapshot::<_, f32>(bytes.as_slice()).unwrap();
+        assert!(loaded.times()[0].is_nan(), "NaN is preserved!");
+        assert_eq!(loaded.spaces(), &[f32::INFINITY]);
+    }
+    #[test]
+    fn test_snapshot_errors() {
+        let state = PerplexBuffer::from(vec![Perplex::new(1.0, 2.0), Perplex::new(3.0, 4.0)]);
+        let mut bytes = Vec::new();
+        save_snapshot(&mut bytes, &state).unwrap();
+        let kind = |bytes: &[u8]| load_snapshot::<_, f64>(bytes).unwrap_err().kind();
+        assert_eq!(kind(&bytes[..bytes.len() - 1]), ErrorKind::UnexpectedEof);
+        assert_eq!(kind(&bytes[..8]), ErrorKind::UnexpectedEof);
+        assert_eq!(
+            load_snapshot::<_, i64>(bytes.as_slice())
+                .unwrap_err()
+                .kind(),
+            ErrorKind::InvalidData,
+            "Scalar type mismatch!"
+        );
+        let mut corrupted = bytes.clone();
+        corrupted[0] = b'Q';
+        assert_eq!(kind(&corrupted), ErrorKind::InvalidData);
+        let mut corrupted = bytes.clone();
+        corrupted[4] = 2;
+        assert_eq!(kind(&corrupted), ErrorKind::InvalidData);
+        let mut corrupted = bytes;
+        corrupted[8..16].copy_from_slice(&u64::MAX.to_le_bytes());
+        assert_eq!(kind(&corrupted), ErrorKind::InvalidData);
+        corrupted[8..16].copy_from_slice(&(1u64 << 40).to_le_bytes());
+        assert_eq!(kind(&corrupted), ErrorKind::UnexpectedEof);
+    }
+    #[test]
+    fn test_snapshot_streaming() {
+        /// Counts the written bytes and records the largest single write.
+        struct Sink {
+            written: usize,
+            largest: usize,
+        }
+        impl Write for Sink {
+            fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+                self.written += buf.len();
+                self.largest = self.largest.max(buf.len());
+                Ok(buf.len())
+            }
+            fn flush(&mut self) -> io::Result<()> {
+                Ok(())
+            }
+        }
+        let state: PerplexBuffer<f64> = (0..100_000).map(|i| Perplex::new(i as f64, 0.5)).collect();
+        let mut sink = Sink {
+            written: 0,
+            largest: 0,
+        };
+        save_snapshot(&mut sink, &state).unwrap();
+        assert_eq!(sink.written, SNAPSHOT_HEADER_BYTES + 2 * 100_000 * 8);
+        assert!(
+            sink.largest <= 8 * 1024,
+            "The payload is streamed instead of buffered as a whole!"
+        );
+    }
+}
@@ -0,0 +1,214 @@
+//! # Structure of Arrays Module
+//!
+//! This module converts between the array of structures (AoS) layout `Vec<Perplex<T>>` and the structure of arrays (SoA) layout with separate buffers for the time and space components.
+//! The SoA layout is required by many external libraries, GPU uploads and vectorized kernels that operate on contiguous arrays of one component.
+//!
+//! ## Features
+//! - `split` and `join`: Owned conversions between `Vec<Perplex<T>>` and a pair of component vectors.
+//! - `times`, `spaces` and `zip`: Borrowed iterators over the components of a slice and over perplex numbers built from two component slices.
+//! - `PerplexBuffer`: A SoA container for perplex numbers, which the `snapshot` module saves and loads in a binary format.
+//!
+//! ## Example
+//! ```
+//! use perplex_num::{soa, Perplex};
+//! let z = vec![Perplex::new(1.0, 2.0), Perplex::new(3.0, 4.0)];
+//! let (t, x) = soa::split(z.clone());
+//! assert_eq!((t.as_slice(), x.as_slice()), ([1.0, 3.0].as_slice(), [2.0, 4.0].as_slice()));
+//! assert_eq!(soa::join(t, x), z);
+//! ```
+
+use super::Perplex;
+
+/// Splits a vector of perplex numbers into a vector of time components and a vector of space components.
+#[inline]
+pub fn split<T>(values: Vec<Perplex<T>>) -> (Vec<T>, Vec<T>) {
+    values.into_iter().map(|Perplex { t, x }| (t, x)).unzip()
+}
+
+/// Joins a vector of time components and a vector of space components into a vector of perplex numbers.
+///
+/// # Panics
+/// Panics if the vectors differ in length.
+#[inline]
+pub fn join<T>(t: Vec<T>, x: Vec<T>) -> Vec<Perplex<T>> {
+    assert_eq!(t.len(), x.len(), "Component vectors differ in length!");
+    t.into_iter()
+        .zip(x)
+        .map(|(t, x)| Perplex::new(t, x))
+        .collect()
+}
+
+/// Returns an iterator over the time components of a slice of perplex numbers.
+#[inline]
+pub fn times<T: Copy>(values: &[Perplex<T>]) -> impl Iterator<Item = T> + '_ {
+    values.iter().map(|z| z.t)
+}
+
+/// Returns an iterator over the space components of a slice of perplex numbers.
+#[inline]
+pub fn spaces<T: Copy>(values: &[Perplex<T>]) -> impl Iterator<Item = T> + '_ {
+    values.iter().map(|z| z.x)
+}
+
+/// Returns an iterator over the perplex numbers built from a slice of time components and a slice of space components.
+///
+/// # Panics
+/// Panics if the slices differ in length.
+#[inline]
+pub fn zip<'a, T: Copy>(t: &'a [T], x: &'a [T]) -> impl Iterator<Item = Perplex<T>> + 'a {
+    assert_eq!(t.len(), x.len(), "Component slices differ in length!");
+    t.iter().zip(x).map(|(&t, &x)| Perplex::new(t, x))
+}
+
+/// A buffer of perplex numbers in structure of arrays layout, i.e., with contiguous time and space components.
+#[derive(Clone, PartialEq, Eq, Hash, Debug, Default)]
+pub struct PerplexBuffer<T> {
+    t: Vec<T>,
+    x: Vec<T>,
+}
+
+impl<T> PerplexBuffer<T> {
+    /// Creates a new empty buffer.
+    #[inline]
+    pub fn new() -> Self {
+        Self {
+            t: Vec::new(),
+            x: Vec::new(),
+        }
+    }
+    /// Creates a new empty buffer with space for at least `capacity` perplex numbers.
+    #[inline]
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            t: Vec::with_capacity(capacity),
+            x: Vec::with_capacity(capacity),
+        }
+    }
+    /// Creates a buffer from separate component vectors, or returns `None` if they differ in length.
+    #[inline]
+    pub fn from_components(t: Vec<T>, x: Vec<T>) -> Option<Self> {
+        if t.len() == x.len() {
+            Some(Self { t, x })
+        } else {
+            None
+        }
+    }
+    /// Returns the number of perplex numbers in the buffer.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.t.len()
+    }
+    /// Checks if the buffer is empty.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.t.is_empty()
+    }
+    /// Appends a perplex number to the buffer.
+    #[inline]
+    pub fn push(&mut self, z: Perplex<T>) {
+        self.t.push(z.t);
+        self.x.push(z.x);
+    }
+    /// Returns the contiguous time components.
+    #[inline]
+    pub fn times(&self) -> &[T] {
+        &self.t
+    }
+    /// Returns the contiguous space components.
+    #[inline]
+    pub fn spaces(&self) -> &[T] {
+        &self.x
+    }
+    /// Returns mutable references to the contiguous time and space components.
+    #[inline]
+    pub fn components_mut(&mut self) -> (&mut [T], &mut [T]) {
+        (&mut self.t, &mut self.x)
+    }
+    /// Consumes the buffer and returns the component vectors.
+    #[inline]
+    pub fn into_components(self) -> (Vec<T>, Vec<T>) {
+        (self.t, self.x)
+    }
+}
+
+impl<T: Copy> PerplexBuffer<T> {
+    /// Returns the perplex number at `index`, or `None` if out of bounds.
+    #[inline]
+    pub fn get(&self, index: usize) -> Option<Perplex<T>> {
+        Some(Perplex::new(*self.t.get(index)?, self.x[index]))
+    }
+    /// Returns an iterator over the perplex numbers in the buffer.
+    #[inline]
+    pub fn iter(&self) -> impl Iterator<Item = Perplex<T>> + '_ {
+        zip(&self.t, &self.x)
+    }
+}
+
+impl<T> From<Vec<Perplex<T>>> for PerplexBuffer<T> {
+    /// Converts a vector of perplex numbers into a buffer in structure of arrays layout.
+    #[inline]
+    fn from(values: Vec<Perplex<T>>) -> Self {
+        let (t, x) = split(values);
+        Self { t, x }
+    }
+}
+
+impl<T> From<PerplexBuffer<T>> for Vec<Perplex<T>> {
+    /// Converts a buffer in structure of arrays layout into a vector of perplex numbers.
+    #[inline]
+    fn from(buffer: PerplexBuffer<T>) -> Self {
+        join(buffer.t, buffer.x)
+    }
+}
+
+impl<T> FromIterator<Perplex<T>> for PerplexBuffer<T> {
+    #[inline]
+    fn from_iter<I: IntoIterator<Item = Perplex<T>>>(iter: I) -> Self {
+        let (t, x) = iter.into_iter().map(|Perplex { t, x }| (t, x)).unzip();
+        Self { t, x }
+    }
+}
+
+impl<T> Extend<Perplex<T>> for PerplexBuffer<T> {
+    #[inline]
+    fn extend<I: IntoIterator<Item = Perplex<T>>>(&mut self, iter: I) {
+        for z in iter {
+            self.push(z);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[test]
+    fn test_split_join() {
+        let z = vec![Perplex::new(1, 2), Perplex::new(3, 4), Perplex::new(5, 6)];
+        let (t, x) = split(z.clone());
+        assert_eq!(t, vec![1, 3, 5]);
+        assert_eq!(x, vec![2, 4, 6]);
+        assert_eq!(times(&z).collect::<Vec<_>>(), t);
+        assert_eq!(spaces(&z).collect::<Vec<_>>(), x);
+        assert_eq!(zip(&t, &x).collect::<Vec<_>>(), z);
+        assert_eq!(join(t, x), z, "Join is the inverse of split!");
+    }
+    #[test]
+    fn test_buffer() {
+        let z = vec![Perplex::new(1.0, 2.0), Perplex::new(3.0, 4.0)];
+        let mut buffer = PerplexBuffer::from(z.clone());
+        assert_eq!(buffer.len(), 2);
+        assert_eq!(buffer.times(), &[1.0, 3.0]);
+        assert_eq!(buffer.spaces(), &[2.0, 4.0]);
+        assert_eq!(buffer.get(1), Some(Perplex::new(3.0, 4.0)));
+        assert_eq!(buffer.get(2), None);
+        buffer.push(Perplex::new(5.0, 6.0));
+        let (t, _) = buffer.components_mut();
+        t[0] = -1.0;
+        let values: Vec<Perplex<f64>> = buffer.clone().into();
+        assert_eq!(values, buffer.iter().collect::<Vec<_>>());
+        assert_eq!(values[0], Perplex::new(-1.0, 2.0));
+        assert_eq!(buffer, values.into_iter().collect());
+        assert!(PerplexBuffer::from_components(vec![1.0], vec![]).is_none());
+        assert!(PerplexBuffer::<f64>::new().is_empty());
+    }
+}
@@ -0,0 +1,416 @@
+//! # Spatial Module
+//!
+//! This module provides the `NeighborIndex`, a k-d tree over perplex numbers (points in the hyperbolic plane) which supports range and nearest neighbor queries under a configurable `PlaneMetric`.
+//!
+//! Besides the Euclidean and maximum distance of the cartesian coordinate plane, the index supports the modulus of the Minkowski interval `|z_1 - z_2| = sqrt(|D(z_1 - z_2)|)`.
+//! The interval is not a metric, since all points on the light cone of the query have distance zero. Hence, a range query with the interval selects a region bounded by four hyperbolas, which extends along the light cone of the query (e.g., for event matching within light-cone windows).
+//! The subtrees are pruned by a lower bound of the distance to their bounding box, which is exact for all three distance functions.
+//!
+//! ## Example
+//! ```
+//! use perplex_num::{NeighborIndex, Perplex, PlaneMetric};
+//! let events = vec![
+//!     Perplex::new(0.0, 0.0),
+//!     Perplex::new(10.0, 9.9),
+//!     Perplex::new(1.0, 0.0),
+//! ];
+//! let index = NeighborIndex::new(events, PlaneMetric::Interval);
+//! // the distant event close to the light cone matches
+//! assert_eq!(index.within(Perplex::new(0.0, 0.0), 1.5), vec![0, 1, 2]);
+//! let index = NeighborIndex::new(index.into_points(), PlaneMetric::Euclidean);
+//! assert_eq!(index.within(Perplex::new(0.0, 0.0), 1.5), vec![0, 2]);
+//! ```
+
+use super::Perplex;
+use num_traits::Float;
+use std::cmp::Ordering;
+
+/// Maximum number of points in a leaf of the k-d tree.
+const LEAF_SIZE: usize = 8;
+
+/// The distance functions of the hyperbolic plane supported by the `NeighborIndex`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum PlaneMetric {
+    /// The Euclidean distance `sqrt(Δt^2 + Δx^2)` in the cartesian coordinate plane, see `Perplex::l2_norm`.
+    Euclidean,
+    /// The maximum distance `max(|Δt|, |Δx|)` in the cartesian coordinate plane, see `Perplex::max_norm`.
+    Max,
+    /// The modulus of the Minkowski interval `sqrt(|Δt^2 - Δx^2|)`, see `Perplex::modulus`.
+    Interval,
+}
+
+impl PlaneMetric {
+    /// Returns the distance between `z1` and `z2`.
+    #[inline]
+    pub fn distance<T: Copy + Float>(&self, z1: Perplex<T>, z2: Perplex<T>) -> T {
+        let d = z1 - z2;
+        match self {
+            Self::Euclidean => d.l2_norm(),
+            Self::Max => d.max_norm(),
+            Self::Interval => d.modulus(),
+        }
+    }
+    /// Returns the minimal distance between `z` and the points of the axis-aligned box spanned by `lower` and `upper`.
+    fn box_distance<T: Copy + Float>(
+        &self,
+        z: Perplex<T>,
+        lower: Perplex<T>,
+        upper: Perplex<T>,
+    ) -> T {
+        // component ranges of the differences between the points of the box and z
+        let (dt_min, dt_max) = (lower.t - z.t, upper.t - z.t);
+        let (dx_min, dx_max) = (lower.x - z.x, upper.x - z.x);
+        match self {
+            Self::Euclidean | Self::Max => {
+                let clamp = |min: T, max: T| min.max(T::zero()).max(-max);
+                let d = Perplex::new(clamp(dt_min, dt_max), clamp(dx_min, dx_max));
+                if *self == Self::Euclidean {
+                    d.l2_norm()
+                } else {
+                    d.max_norm()
+                }
+            }
+            Self::Interval => {
+                // the box intersects a light-like line of z if a null coordinate changes its sign
+                let spans_zero = |min: T, max: T| min <= T::zero() && T::zero() <= max;
+                if spans_zero(dt_min + dx_min, dt_max + dx_max)
+                    || spans_zero(dt_min - dx_max, dt_max - dx_min)
+                {
+                    return T::zero();
+                }
+                // |D| is quasi-concave within an open quadrant of the null coordinates, hence minimal at a corner
+                [
+                    Perplex::new(dt_min, dx_min),
+                    Perplex::new(dt_min, dx_max),
+                    Perplex::new(dt_max, dx_min),
+                    Perplex::new(dt_max, dx_max),
+                ]
+                .iter()
+                .map(|d| d.modulus())
+                .fold(T::infinity(), T::min)
+            }
+        }
+    }
+}
+
+/// A node of the k-d tree covering the points `order[start..end]` within the bounding box spanned by `lower` and `upper`.
+#[derive(Clone, Debug)]
+struct Node<T> {
+    lower: Perplex<T>,
+    upper: Perplex<T>,
+    start: usize,
+    end: usize,
+    children: Option<(usize, usize)>,
+}
+
+/// A spatial index over perplex numbers for range and nearest neighbor queries with respect to a `PlaneMetric`.
+///
+/// The query results refer to the points by their position in the vector given to `new`. Points with non-finite components are never reported.
+#[derive(Clone, Debug)]
+pub struct NeighborIndex<T> {
+    points: Vec<Perplex<T>>,
+    metric: PlaneMetric,
+    order: Vec<usize>,
+    nodes: Vec<Node<T>>,
+}
+
+impl<T: Copy + Float> NeighborIndex<T> {
+    /// Builds the index over `points` with the distance function `metric`.
+    pub fn new(points: Vec<Perplex<T>>, metric: PlaneMetric) -> Self {
+        let order: Vec<usize> = (0..points.len())
+            .filter(|&i| points[i].t.is_finite() && points[i].x.is_finite())
+            .collect();
+        let mut index = Self {
+            points,
+            metric,
+            order,
+            nodes: Vec::new(),
+        };
+        if !index.order.is_empty() {
+            index.build(0, index.order.len());
+        }
+        index
+    }
+    /// Returns the indexed points.
+    #[inline]
+    pub fn points(&self) -> &[Perplex<T>] {
+        &self.points
+    }
+    /// Consumes the index and returns the indexed points.
+    #[inline]
+    pub fn into_points(self) -> Vec<Perplex<T>> {
+        self.points
+    }
+    /// Returns the distance function of the index.
+    #[inline]
+    pub fn metric(&self) -> PlaneMetric {
+        self.metric
+    }
+    /// Returns the number of indexed points.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.points.len()
+    }
+    /// Returns `true` if no points are indexed.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.points.is_empty()
+    }
+
+    /// Builds the subtree of the points `order[start..end]` and returns the index of its root node.
+    fn build(&mut self, start: usize, end: usize) -> usize {
+        let (lower, upper) = self.order[start..end].iter().fold(
+            (
+                Perplex::new(T::infinity(), T::infinity()),
+                Perplex::new(T::neg_infinity(), T::neg_infinity()),
+            ),
+            |(lower, upper), &i| {
+                let z = self.points[i];
+                (
+                    Perplex::new(lower.t.min(z.t), lower.x.min(z.x)),
+                    Perplex::new(upper.t.max(z.t), upper.x.max(z.x)),
+                )
+            },
+        );
+        let node = self.nodes.len();
+        self.nodes.push(Node {
+            lower,
+            upper,
+            start,
+            end,
+            children: None,
+        });
+        if end - start > LEAF_SIZE {
+            // split at the median of the longer side of the bounding box
+            let split_t = upper.t - lower.t >= upper.x - lower.x;
+            let points = &self.points;
+            let key = |i: &usize| if split_t { points[*i].t } else { points[*i].x };
+            let mid = (start + end) / 2;
+            self.order[start..end].select_nth_unstable_by(mid - start, |a, b| {
+                key(a).partial_cmp(&key(b)).unwrap_or(Ordering::Equal)
+            });
+            let left = self.build(start, mid);
+            let right = self.build(mid, end);
+            self.nodes[node].children = Some((left, right));
+        }
+        node
+    }
+
+    /// Returns the minimal distance between `z` and the bounding box of `node`.
+    #[inline]
+    fn node_distance(&self, z: Perplex<T>, node: &Node<T>) -> T {
+        self.metric.box_distance(z, node.lower, node.upper)
+    }
+
+    /// Returns the indices of all points with a distance of at most `radius` to `z` in ascending order.
+    pub fn within(&self, z: Perplex<T>, radius: T) -> Vec<usize> {
+        let mut found = Vec::new();
+        let mut stack = Vec::new();
+        if !self.nodes.is_empty() {
+            stack.push(0);
+        }
+        while let Some(node) = stack.pop() {
+            let node = &self.nodes[node];
+            if self.node_distance(z, node) > radius {
+                continue;
+            }
+            match node.children {
+                Some((left, right)) => stack.extend([left, right]),
+                None => found.extend(
+                    self.order[node.start..node.end]
+                        .iter()
+                        .filter(|&&i| self.metric.distance(self.points[i], z) <= radius),
+                ),
+            }
+        }
+        found.sort_unstable();
+        found
+    }
+
+    /// Returns the index of a point with minimal distance to `z` together with the distance, or `None` if no finite points are indexed.
+    #[inline]
+    pub fn nearest(&self, z: Perplex<T>) -> Option<(usize, T)> {
+        self.k_nearest(z, 1).pop()
+    }
+
+    /// Returns the indices of the `k` points with minimal distance to `z` together with their distances, ordered by ascending distance.
+    ///
+    /// Fewer than `k` results are returned if fewer finite points are indexed. Ties are broken arbitrarily.
+    pub fn k_nearest(&self, z: Perplex<T>, k: usize) -> Vec<(usize, T)> {
+        let mut best: Vec<(usize, T)> = Vec::with_capacity(k + 1);
+        if k == 0 || self.nodes.is_empty() {
+            return best;
+        }
+        let by_distance =
+            |a: &(usize, T), b: &(usize, T)| a.1.partial_cmp(&b.1).unwrap_or(Ordering::Equal);
+        let mut stack = vec![(0, self.node_distance(z, &self.nodes[0]))];
+        while let Some((node, node_distance)) = stack.pop() {
+            if best.len() == k && node_distance >= best[k - 1].1 {
+                continue;
+            }
+            let node = &self.nodes[node];
+            match node.children {
+                Some((left, right)) => {
+                    let left = (left, self.node_distance(z, &self.nodes[left]));
+                    let right = (right, self.node_distance(z, &self.nodes[right]));
+                    // visit the closer child first
+                    if by_distance(&left, &right) == Ordering::Greater {
+                        stack.extend([left, right]);
+                    } else {
+                        stack.extend([right, left]);
+                    }
+                }
+                None => {
+                    for &i in &self.order[node.start..node.end] {
+                        let candidate = (i, self.metric.distance(self.points[i], z));
+                        if best.len() < k || by_distance(&candidate, &best[k - 1]) == Ordering::Less
+                        {
+                            let position = best.partition_point(|b| {
+                                by_distance(b, &candidate) != Ordering::Greater
+                            });
+                            best.insert(position, candidate);
+                            best.truncate(k);
+                        }
+                    }
+                }
+            }
+        }
+        best
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_abs_diff_eq;
+    /// Deterministic pseudo-random points in the square [-10, 10]^2.
+    fn points(n: usize) -> Vec<Perplex<f64>> {
+        let mut state: u64 = 42;
+        let mut next = || {
+            state = state
+                .wrapping_mul(6364136223846793005)
+                .wrapping_add(1442695040888963407);
+            (state >> 11) as f64 / (1u64 << 53) as f64 * 20.0 - 10.0
+        };
+        (0..n).map(|_| Perplex::new(next(), next())).collect()
+    }
+    fn brute_force_within(
+        points: &[Perplex<f64>],
+        metric: PlaneMetric,
+        z: Perplex<f64>,
+        radius: f64,
+    ) -> Vec<usize> {
+        (0..points.len())
+            .filter(|&i| metric.distance(points[i], z) <= radius)
+            .collect()
+    }
+    #[test]
+    fn test_distance() {
+        let (z1, z2) = (Perplex::new(1.0, 2.0), Perplex::new(4.0, -2.0));
+        assert_eq!(PlaneMetric::Euclidean.distance(z1, z2), 5.0);
+        assert_eq!(PlaneMetric::Max.distance(z1, z2), 4.0);
+        assert_abs_diff_eq!(PlaneMetric::Interval.distance(z1, z2), f64::sqrt(7.0));
+        assert_eq!(
+            PlaneMetric::Interval.distance(z1, z1 + Perplex::new(3.0, 3.0)),
+            0.0,
+            "Light-like separation!"
+        );
+    }
+    #[test]
+    fn test_box_distance() {
+        let (lower, upper) = (Perplex::new(2.0, -1.0), Perplex::new(3.0, 1.0));
+        let z = Perplex::new(0.0, 0.0);
+        assert_eq!(PlaneMetric::Euclidean.box_distance(z, lower, upper), 2.0);
+        assert_eq!(PlaneMetric::Max.box_distance(z, lower, upper), 2.0);
+        assert_abs_diff_eq!(
+            PlaneMetric::Interval.box_distance(z, lower, upper),
+            f64::sqrt(3.0),
+            epsilon = 1e-15
+        );
+        assert_eq!(
+            PlaneMetric::Interval.box_distance(Perplex::new(0.0, 2.5), lower, upper),
+            0.0,
+            "Box intersects the light cone!"
+        );
+        assert_eq!(
+            PlaneMetric::Max.box_distance(Perplex::new(2.5, 0.0), lower, upper),
+            0.0
+        );
+    }
+    #[test]
+    fn test_within() {
+        let points = points(500);
+        for metric in [
+            PlaneMetric::Euclidean,
+            PlaneMetric::Max,
+            PlaneMetric::Interval,
+        ] {
+            let index = NeighborIndex::new(points.clone(), metric);
+            assert_eq!(index.len(), 500);
+            for (z, radius) in [
+                (Perplex::new(0.0, 0.0), 1.0),
+                (Perplex::new(5.0, -3.0), 2.5),
+                (Perplex::new(-20.0, 11.0), 0.5),
+                (Perplex::new(1.0, 1.0), 0.0),
+            ] {
+                assert_eq!(
+                    index.within(z, radius),
+                    brute_force_within(&points, metric, z, radius),
+                    "Range query equals brute force search for {metric:?}!"
+                );
+            }
+        }
+    }
+    #[test]
+    fn test_nearest() {
+        let points = points(300);
+        for metric in [
+            PlaneMetric::Euclidean,
+            PlaneMetric::Max,
+            PlaneMetric::Interval,
+        ] {
+            let index = NeighborIndex::new(points.clone(), metric);
+            for z in [Perplex::new(0.3, -0.7), Perplex::new(30.0, 2.0)] {
+                let mut distances: Vec<f64> =
+                    points.iter().map(|&p| metric.distance(p, z)).collect();
+                distances.sort_by(|a, b| a.partial_cmp(b).unwrap());
+                let k_nearest = index.k_nearest(z, 5);
+                assert_eq!(k_nearest.len(), 5);
+                for (j, &(i, d)) in k_nearest.iter().enumerate() {
+                    assert_eq!(d, metric.distance(points[i], z));
+                    assert_eq!(
+                        d, distances[j],
+                        "k nearest equal brute force for {metric:?}!"
+                    );
+                }
+                assert_eq!(index.nearest(z).unwrap().1, distances[0]);
+            }
+        }
+    }
+    #[test]
+    fn test_degenerate() {
+        let index = NeighborIndex::new(Vec::new(), PlaneMetric::Euclidean);
+        assert!(index.is_empty());
+        assert_eq!(index.nearest(Perplex::new(0.0, 0.0)), None);
+        assert!(index.within(Perplex::new(0.0, 0.0), 1.0).is_empty());
+        let points = vec![
+            Perplex::new(f64::NAN, 0.0),
+            Perplex::new(1.0, 1.0),
+            Perplex::new(1.0, 1.0),
+            Perplex::new(f64::INFINITY, 1.0),
+        ];
+        let index = NeighborIndex::new(points, PlaneMetric::Max);
+        assert_eq!(index.metric(), PlaneMetric::Max);
+        assert_eq!(
+            index.within(Perplex::new(1.0, 1.0), 0.0),
+            vec![1, 2],
+            "Duplicates are reported!"
+        );
+        assert_eq!(
+            index.k_nearest(Perplex::new(0.0, 0.0), 10).len(),
+            2,
+            "Non-finite points are skipped!"
+        );
+        assert!(index.k_nearest(Perplex::new(0.0, 0.0), 0).is_empty());
+    }
+}
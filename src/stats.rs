@@ -0,0 +1,252 @@
+//! # Statistics Module
+//!
+//! This module provides descriptive statistics for samples of perplex numbers, based on their hyperbolic polar form.
+//!
+//! ## Features
+//! - `histogram_polar`: Two-dimensional histogram over the modulus `rho` and the argument `theta`, counted separately for each sector of the hyperbolic plane.
+//! - `rapidity_kde`: Kernel density estimation with a Gaussian kernel over the argument (rapidity) of the samples.
+//!
+//! ## Example
+//! ```
+//! use perplex_num::{stats, HyperbolicSector, Perplex};
+//! let samples = [Perplex::new(2.0, 1.0), Perplex::new(1.0, 2.0), Perplex::new(1.0, 1.0)];
+//! let histogram = stats::histogram_polar(&samples, &[0.0, 1.0, 2.0], &[-1.0, 0.0, 1.0]);
+//! assert_eq!(histogram.count(HyperbolicSector::Right, 1, 1), 1);
+//! assert_eq!(histogram.count(HyperbolicSector::Up, 1, 1), 1);
+//! assert_eq!(histogram.light_like, 1);
+//! ```
+
+use super::{HyperbolicSector, Perplex};
+use num_traits::{Float, FloatConst};
+
+/// Two-dimensional histogram over the hyperbolic polar coordinates `(rho, theta)` of perplex numbers, with one table of counts per sector.
+///
+/// A sample falls into bin `(i, j)` if `rho_edges[i] <= rho < rho_edges[i + 1]` and `theta_edges[j] <= theta < theta_edges[j + 1]`.
+#[derive(Clone, PartialEq, Debug)]
+pub struct PolarHistogram<T> {
+    /// The sorted bin edges of the modulus `rho`.
+    pub rho_edges: Vec<T>,
+    /// The sorted bin edges of the argument `theta`.
+    pub theta_edges: Vec<T>,
+    /// Row-major counts `counts[s][i * n_theta + j]` for the sectors `s` in the order Right, Up, Left, Down.
+    pub counts: [Vec<usize>; 4],
+    /// Number of light-like samples, which have an infinite argument.
+    pub light_like: usize,
+    /// Number of samples outside of the bin edges (including NaN samples).
+    pub outliers: usize,
+}
+
+impl<T: Copy + Float> PolarHistogram<T> {
+    /// Returns the number of bins of the modulus `rho`.
+    #[inline]
+    pub fn rho_bins(&self) -> usize {
+        self.rho_edges.len().saturating_sub(1)
+    }
+    /// Returns the number of bins of the argument `theta`.
+    #[inline]
+    pub fn theta_bins(&self) -> usize {
+        self.theta_edges.len().saturating_sub(1)
+    }
+    /// Returns the count of `sector` in bin `(rho_bin, theta_bin)`. The `Diagonal` sector has no bins and yields `light_like`.
+    ///
+    /// # Panics
+    /// Panics if a bin index is out of range.
+    #[inline]
+    pub fn count(&self, sector: HyperbolicSector<T>, rho_bin: usize, theta_bin: usize) -> usize {
+        assert!(
+            rho_bin < self.rho_bins() && theta_bin < self.theta_bins(),
+            "Bin index out of range!"
+        );
+        match sector_index(sector) {
+            Some(s) => self.counts[s][rho_bin * self.theta_bins() + theta_bin],
+            None => self.light_like,
+        }
+    }
+    /// Returns the total count of all samples inside of the bins of `sector`.
+    #[inline]
+    pub fn sector_total(&self, sector: HyperbolicSector<T>) -> usize {
+        match sector_index(sector) {
+            Some(s) => self.counts[s].iter().sum(),
+            None => self.light_like,
+        }
+    }
+}
+
+/// Index of a sector in the tables of `PolarHistogram`, or `None` for the diagonal.
+#[inline]
+fn sector_index<T>(sector: HyperbolicSector<T>) -> Option<usize> {
+    match sector {
+        HyperbolicSector::Right => Some(0),
+        HyperbolicSector::Up => Some(1),
+        HyperbolicSector::Left => Some(2),
+        HyperbolicSector::Down => Some(3),
+        HyperbolicSector::Diagonal(_) => None,
+    }
+}
+
+/// Index of the bin `[edges[i], edges[i + 1])` containing `value`, or `None` if `value` is outside of the edges.
+#[inline]
+fn bin_index<T: Copy + Float>(edges: &[T], value: T) -> Option<usize> {
+    if edges.len() < 2 || !(value >= edges[0] && value < edges[edges.len() - 1]) {
+        return None;
+    }
+    // first edge that is greater than value, which exists and is not the first edge
+    let upper = edges.partition_point(|&edge| edge <= value);
+    Some(upper - 1)
+}
+
+/// Counts the `samples` in a two-dimensional histogram over their hyperbolic polar coordinates `(rho, theta)` per sector.
+///
+/// The bin edges `rho_edges` and `theta_edges` must be sorted in ascending order. Light-like samples are counted separately since their argument is infinite.
+pub fn histogram_polar<T: Copy + Float>(
+    samples: &[Perplex<T>],
+    rho_edges: &[T],
+    theta_edges: &[T],
+) -> PolarHistogram<T> {
+    let n_rho = rho_edges.len().saturating_sub(1);
+    let n_theta = theta_edges.len().saturating_sub(1);
+    let mut histogram = PolarHistogram {
+        rho_edges: rho_edges.to_vec(),
+        theta_edges: theta_edges.to_vec(),
+        counts: std::array::from_fn(|_| vec![0; n_rho * n_theta]),
+        light_like: 0,
+        outliers: 0,
+    };
+    for z in samples {
+        let polar = z.polar();
+        let Some(s) = sector_index(polar.sector) else {
+            histogram.light_like += 1;
+            continue;
+        };
+        match (
+            bin_index(rho_edges, polar.rho),
+            bin_index(theta_edges, polar.theta),
+        ) {
+            (Some(i), Some(j)) => histogram.counts[s][i * n_theta + j] += 1,
+            _ => histogram.outliers += 1,
+        }
+    }
+    histogram
+}
+
+/// Returns the bandwidth for a Gaussian kernel density estimation of the arguments (rapidities) of `samples` according to Silverman's rule of thumb `0.9 min(sigma, IQR / 1.34) n^(-1/5)`.
+///
+/// Light-like samples are ignored. Returns `None` if there are less than two samples with a finite argument.
+pub fn silverman_bandwidth<T: Copy + Float>(samples: &[Perplex<T>]) -> Option<T> {
+    let mut thetas = finite_args(samples);
+    let n = thetas.len();
+    if n < 2 {
+        return None;
+    }
+    thetas.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    let n_t = T::from(n).unwrap();
+    let mean = thetas.iter().fold(T::zero(), |acc, &theta| acc + theta) / n_t;
+    let variance = thetas
+        .iter()
+        .fold(T::zero(), |acc, &theta| acc + (theta - mean).powi(2))
+        / (n_t - T::one());
+    let quantile = |q: f64| {
+        let position = q * (n - 1) as f64;
+        let (lower, fraction) = (position.floor() as usize, position.fract());
+        let upper = (lower + 1).min(n - 1);
+        let fraction = T::from(fraction).unwrap();
+        thetas[lower] + (thetas[upper] - thetas[lower]) * fraction
+    };
+    let iqr = quantile(0.75) - quantile(0.25);
+    let sigma = variance.sqrt();
+    let spread = if iqr > T::zero() {
+        sigma.min(iqr / T::from(1.34).unwrap())
+    } else {
+        sigma
+    };
+    Some(T::from(0.9).unwrap() * spread * n_t.powf(T::from(-0.2).unwrap()))
+}
+
+/// Estimates the probability density of the arguments (rapidities) of `samples` at each of the `points` by a Gaussian kernel with the given `bandwidth`.
+///
+/// Light-like samples are ignored. If no sample has a finite argument, the density is zero everywhere.
+pub fn rapidity_kde<T: Copy + Float + FloatConst>(
+    samples: &[Perplex<T>],
+    bandwidth: T,
+    points: &[T],
+) -> Vec<T> {
+    let thetas = finite_args(samples);
+    if thetas.is_empty() {
+        return vec![T::zero(); points.len()];
+    }
+    let two = T::one() + T::one();
+    let normalization =
+        T::one() / (T::from(thetas.len()).unwrap() * bandwidth * (two * T::PI()).sqrt());
+    points
+        .iter()
+        .map(|&point| {
+            let sum = thetas.iter().fold(T::zero(), |acc, &theta| {
+                let u = (point - theta) / bandwidth;
+                acc + (-u * u / two).exp()
+            });
+            sum * normalization
+        })
+        .collect()
+}
+
+/// Arguments of the samples that are not light-like.
+#[inline]
+fn finite_args<T: Copy + Float>(samples: &[Perplex<T>]) -> Vec<T> {
+    samples
+        .iter()
+        .map(|z| z.arg())
+        .filter(|theta| theta.is_finite())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_abs_diff_eq;
+    #[test]
+    fn test_histogram_polar() {
+        let samples = [
+            Perplex::cis(0.5).scale(1.5),      // Right, rho=1.5, theta=0.5
+            -Perplex::cis(-0.5).scale(0.5),    // Left, rho=0.5, theta=-0.5
+            Perplex::new(0.0, 1.0).scale(1.5), // Up, rho=1.5, theta=0
+            Perplex::new(1.0, -1.0),           // light-like
+            Perplex::cis(2.0),                 // theta out of range
+            Perplex::new(f64::NAN, 1.0),       // NaN
+        ];
+        let histogram = histogram_polar(&samples, &[0.0, 1.0, 2.0], &[-1.0, 0.0, 1.0]);
+        assert_eq!(histogram.rho_bins(), 2);
+        assert_eq!(histogram.theta_bins(), 2);
+        assert_eq!(histogram.count(HyperbolicSector::Right, 1, 1), 1);
+        assert_eq!(histogram.count(HyperbolicSector::Left, 0, 0), 1);
+        assert_eq!(
+            histogram.count(HyperbolicSector::Up, 1, 1),
+            1,
+            "theta=0 is in the upper bin!"
+        );
+        assert_eq!(histogram.sector_total(HyperbolicSector::Down), 0);
+        assert_eq!(histogram.light_like, 1);
+        assert_eq!(histogram.sector_total(HyperbolicSector::Diagonal(1.0)), 1);
+        assert_eq!(histogram.outliers, 2);
+    }
+    #[test]
+    fn test_rapidity_kde() {
+        let samples: Vec<_> = (-50..=50)
+            .map(|k| Perplex::cis(k as f64 / 25.0))
+            .chain(std::iter::once(Perplex::new(1.0, 1.0)))
+            .collect();
+        let bandwidth = silverman_bandwidth(&samples).unwrap();
+        assert!(bandwidth > 0.0 && bandwidth < 1.0);
+        let points: Vec<f64> = (-100..=100).map(|k| k as f64 / 10.0).collect();
+        let density = rapidity_kde(&samples, bandwidth, &points);
+        let integral: f64 = density.iter().sum::<f64>() * 0.1;
+        assert_abs_diff_eq!(integral, 1.0, epsilon = 1e-3);
+        assert!(
+            density[100] > density[130],
+            "Density is highest at the center!"
+        );
+        assert_eq!(
+            rapidity_kde(&[Perplex::new(1.0, 1.0)], 1.0, &points[..2]),
+            vec![0.0; 2]
+        );
+    }
+}
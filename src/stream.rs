@@ -0,0 +1,373 @@
+//! # Stream Module
+//!
+//! This module provides chunked adapters for datasets of perplex numbers which are larger than the memory: the values are read lazily from a `Read` source in chunks of a fixed number of values, transformed chunk by chunk and written to a `Write` sink, such that the memory is bounded by the chunk size.
+//! Two formats are supported: the binary format of the interleaved little-endian components `t0 x0 t1 x1 ...`, which is the layout of `Perplex::as_interleaved` on little-endian targets, and CSV with one value `t,x` per line.
+//!
+//! ## Features
+//! - `binary_chunks` and `csv_chunks`: Iterators over the chunks of a source, which yield an `io::Error` of the kind `InvalidData` for malformed CSV lines and `UnexpectedEof` for a truncated binary value.
+//! - `write_binary` and `write_csv`: The writers of both formats.
+//! - `pipeline`: The transformation of the values of a chunk iterator by a function, which may drop values, into a sink, with a `StreamSummary` of the counts.
+//!
+//! ## Example
+//! ```
+//! use perplex_num::stream::{csv_chunks, pipeline, write_binary, binary_chunks};
+//! use perplex_num::Perplex;
+//! let csv = "t,x\n2,1\n1,1\n3,-2\n";
+//! let mut output = Vec::new();
+//! // the inverses of the non-light-like values, read in chunks of two values
+//! let summary = pipeline(
+//!     csv_chunks::<_, f64>(csv.as_bytes(), 2, true),
+//!     |z| z.try_inverse(),
+//!     |chunk| write_binary(&mut output, chunk),
+//! )
+//! .unwrap();
+//! assert_eq!((summary.chunks, summary.read, summary.written), (2, 3, 2));
+//! let inverses: Vec<_> = binary_chunks::<_, f64>(output.as_slice(), 16).flatten().flatten().collect();
+//! assert_eq!(inverses, vec![Perplex::new(2.0, 1.0).try_inverse().unwrap(), Perplex::new(3.0, -2.0).try_inverse().unwrap()]);
+//! ```
+
+use super::Perplex;
+use std::fmt::Display;
+use std::io::{self, BufRead, ErrorKind, Read, Write};
+use std::marker::PhantomData;
+use std::str::FromStr;
+
+/// A primitive number with a little-endian byte representation for the binary format.
+pub trait BinaryComponent: Copy {
+    /// The number of bytes of a component.
+    const BYTES: usize;
+    /// Creates the component from the first `BYTES` little-endian bytes of `bytes`.
+    fn from_le_slice(bytes: &[u8]) -> Self;
+    /// Appends the little-endian bytes of the component to `bytes`.
+    fn extend_le_bytes(self, bytes: &mut Vec<u8>);
+}
+
+macro_rules! impl_binary_component {
+    ($($component:ty),*) => {$(
+        impl BinaryComponent for $component {
+            const BYTES: usize = core::mem::size_of::<$component>();
+            #[inline]
+            fn from_le_slice(bytes: &[u8]) -> Self {
+                let mut array = [0; core::mem::size_of::<$component>()];
+                array.copy_from_slice(&bytes[..Self::BYTES]);
+                <$component>::from_le_bytes(array)
+            }
+            #[inline]
+            fn extend_le_bytes(self, bytes: &mut Vec<u8>) {
+                bytes.extend_from_slice(&self.to_le_bytes());
+            }
+        }
+    )*};
+}
+
+impl_binary_component!(f32, f64, i8, i16, i32, i64, i128, u8, u16, u32, u64, u128);
+
+/// The iterator of `binary_chunks`.
+#[derive(Debug)]
+pub struct BinaryChunks<R, T> {
+    reader: R,
+    chunk_size: usize,
+    buffer: Vec<u8>,
+    done: bool,
+    _component: PhantomData<T>,
+}
+
+/// Returns an iterator over the chunks of at most `chunk_size` values of the binary format in `reader`, whereby only the last chunk may be shorter.
+///
+/// A chunk size of zero is treated as one. After an error, the iterator is exhausted.
+pub fn binary_chunks<R: Read, T: BinaryComponent>(
+    reader: R,
+    chunk_size: usize,
+) -> BinaryChunks<R, T> {
+    BinaryChunks {
+        reader,
+        chunk_size: chunk_size.max(1),
+        buffer: Vec::new(),
+        done: false,
+        _component: PhantomData,
+    }
+}
+
+impl<R: Read, T: BinaryComponent> Iterator for BinaryChunks<R, T> {
+    type Item = io::Result<Vec<Perplex<T>>>;
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+        let value_bytes = 2 * T::BYTES;
+        self.buffer.resize(self.chunk_size * value_bytes, 0);
+        let mut filled = 0;
+        while filled < self.buffer.len() {
+            match self.reader.read(&mut self.buffer[filled..]) {
+                Ok(0) => {
+                    self.done = true;
+                    break;
+                }
+                Ok(n) => filled += n,
+                Err(e) if e.kind() == ErrorKind::Interrupted => {}
+                Err(e) => {
+                    self.done = true;
+                    return Some(Err(e));
+                }
+            }
+        }
+        if filled % value_bytes != 0 {
+            self.done = true;
+            return Some(Err(io::Error::new(
+                ErrorKind::UnexpectedEof,
+                "truncated perplex value at the end of the binary stream",
+            )));
+        }
+        if filled == 0 {
+            return None;
+        }
+        let chunk = self.buffer[..filled]
+            .chunks_exact(value_bytes)
+            .map(|bytes| {
+                Perplex::new(
+                    T::from_le_slice(bytes),
+                    T::from_le_slice(&bytes[T::BYTES..]),
+                )
+            })
+            .collect();
+        Some(Ok(chunk))
+    }
+}
+
+/// The iterator of `csv_chunks`.
+#[derive(Debug)]
+pub struct CsvChunks<R, T> {
+    reader: R,
+    chunk_size: usize,
+    skip_header: bool,
+    line: String,
+    line_number: usize,
+    done: bool,
+    _component: PhantomData<T>,
+}
+
+/// Returns an iterator over the chunks of at most `chunk_size` values of the CSV lines `t,x` in `reader`, whereby only the last chunk may be shorter.
+///
+/// The first line is skipped if `header` is true, and empty lines are skipped. A chunk size of zero is treated as one. After an error, the iterator is exhausted.
+pub fn csv_chunks<R: BufRead, T: FromStr>(
+    reader: R,
+    chunk_size: usize,
+    header: bool,
+) -> CsvChunks<R, T> {
+    CsvChunks {
+        reader,
+        chunk_size: chunk_size.max(1),
+        skip_header: header,
+        line: String::new(),
+        line_number: 0,
+        done: false,
+        _component: PhantomData,
+    }
+}
+
+impl<R: BufRead, T: FromStr> CsvChunks<R, T> {
+    /// Parses the current line, whose number is used in the error message.
+    fn parse_line(&self) -> io::Result<Perplex<T>> {
+        let invalid = || {
+            io::Error::new(
+                ErrorKind::InvalidData,
+                format!(
+                    "line {} is not a perplex value t,x: {:?}",
+                    self.line_number,
+                    self.line.trim_end()
+                ),
+            )
+        };
+        let mut fields = self.line.trim().split(',');
+        let (Some(t), Some(x), None) = (fields.next(), fields.next(), fields.next()) else {
+            return Err(invalid());
+        };
+        let parse = |field: &str| field.trim().parse::<T>().map_err(|_| invalid());
+        Ok(Perplex::new(parse(t)?, parse(x)?))
+    }
+}
+
+impl<R: BufRead, T: FromStr> Iterator for CsvChunks<R, T> {
+    type Item = io::Result<Vec<Perplex<T>>>;
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut chunk = Vec::new();
+        while !self.done && chunk.len() < self.chunk_size {
+            self.line.clear();
+            match self.reader.read_line(&mut self.line) {
+                Ok(0) => self.done = true,
+                Ok(_) => {
+                    self.line_number += 1;
+                    if self.skip_header {
+                        self.skip_header = false;
+                    } else if !self.line.trim().is_empty() {
+                        match self.parse_line() {
+                            Ok(z) => chunk.push(z),
+                            Err(e) => {
+                                self.done = true;
+                                return Some(Err(e));
+                            }
+                        }
+                    }
+                }
+                Err(e) if e.kind() == ErrorKind::Interrupted => {}
+                Err(e) => {
+                    self.done = true;
+                    return Some(Err(e));
+                }
+            }
+        }
+        (!chunk.is_empty()).then_some(Ok(chunk))
+    }
+}
+
+/// Writes `values` in the binary format of interleaved little-endian components.
+pub fn write_binary<W: Write, T: BinaryComponent>(
+    mut writer: W,
+    values: &[Perplex<T>],
+) -> io::Result<()> {
+    let mut bytes = Vec::with_capacity(values.len() * 2 * T::BYTES);
+    for z in values {
+        z.t.extend_le_bytes(&mut bytes);
+        z.x.extend_le_bytes(&mut bytes);
+    }
+    writer.write_all(&bytes)
+}
+
+/// Writes `values` as CSV lines `t,x` without a header, whereby the components are formatted by `Display`, which roundtrips for floating-point numbers.
+pub fn write_csv<W: Write, T: Display>(mut writer: W, values: &[Perplex<T>]) -> io::Result<()> {
+    for z in values {
+        writeln!(writer, "{},{}", z.t, z.x)?;
+    }
+    Ok(())
+}
+
+/// The counts of a `pipeline`.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug, Default)]
+pub struct StreamSummary {
+    /// The number of processed chunks.
+    pub chunks: usize,
+    /// The number of read values.
+    pub read: usize,
+    /// The number of written values, which excludes the values dropped by the transformation.
+    pub written: usize,
+}
+
+/// Applies `f` to the values of each chunk of `chunks` and passes the results, without the values for which `f` returns `None`, to `sink`, e.g., a closure calling `write_binary`.
+///
+/// Only one chunk of input and output values is held in memory at a time. The first error of the chunks or the sink is returned.
+pub fn pipeline<I, T, U, F, S>(chunks: I, mut f: F, mut sink: S) -> io::Result<StreamSummary>
+where
+    I: IntoIterator<Item = io::Result<Vec<Perplex<T>>>>,
+    F: FnMut(Perplex<T>) -> Option<Perplex<U>>,
+    S: FnMut(&[Perplex<U>]) -> io::Result<()>,
+{
+    let mut summary = StreamSummary::default();
+    let mut output = Vec::new();
+    for chunk in chunks {
+        let chunk = chunk?;
+        summary.chunks += 1;
+        summary.read += chunk.len();
+        output.clear();
+        output.extend(chunk.into_iter().filter_map(&mut f));
+        summary.written += output.len();
+        sink(&output)?;
+    }
+    Ok(summary)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[test]
+    fn test_binary() {
+        let values: Vec<_> = (0..10)
+            .map(|i| Perplex::new(i as f32, -0.5 * i as f32))
+            .collect();
+        let mut bytes = Vec::new();
+        write_binary(&mut bytes, &values).unwrap();
+        assert_eq!(bytes.len(), 80);
+        assert_eq!(&bytes[8..12], &1f32.to_le_bytes());
+        let chunks: Vec<_> = binary_chunks::<_, f32>(bytes.as_slice(), 4)
+            .map(Result::unwrap)
+            .collect();
+        assert_eq!(
+            chunks.iter().map(Vec::len).collect::<Vec<_>>(),
+            vec![4, 4, 2]
+        );
+        assert_eq!(chunks.concat(), values);
+        // a truncated value is an error, after which the iterator is exhausted
+        let mut chunks = binary_chunks::<_, f32>(&bytes[..78], 8);
+        assert_eq!(chunks.next().unwrap().unwrap().len(), 8);
+        let error = chunks.next().unwrap().unwrap_err();
+        assert_eq!(error.kind(), ErrorKind::UnexpectedEof);
+        assert!(chunks.next().is_none());
+        assert!(binary_chunks::<_, f64>(&[][..], 4).next().is_none());
+        let integers = [Perplex::new(-3i16, 7)];
+        let mut bytes = Vec::new();
+        write_binary(&mut bytes, &integers).unwrap();
+        let read: Vec<_> = binary_chunks::<_, i16>(bytes.as_slice(), 0)
+            .flatten()
+            .collect();
+        assert_eq!(read, vec![integers.to_vec()]);
+    }
+    #[test]
+    fn test_csv() {
+        let values = vec![
+            Perplex::new(0.1, 1e-300),
+            Perplex::new(-2.5, 3.0),
+            Perplex::new(f64::MAX, -0.0),
+        ];
+        let mut csv = b"t,x\n".to_vec();
+        write_csv(&mut csv, &values).unwrap();
+        csv.extend_from_slice(b"\n  4 , 5 \n");
+        let chunks: Vec<_> = csv_chunks::<_, f64>(csv.as_slice(), 3, true)
+            .map(Result::unwrap)
+            .collect();
+        assert_eq!(chunks.len(), 2);
+        assert_eq!(&chunks[0], &values, "Display roundtrips!");
+        assert_eq!(chunks[1], vec![Perplex::new(4.0, 5.0)]);
+        for malformed in ["1,2\n3\n", "1,2\n3,4,5\n", "1,2\nx,4\n"] {
+            let mut chunks = csv_chunks::<_, f64>(malformed.as_bytes(), 8, false);
+            let error = chunks.next().unwrap().unwrap_err();
+            assert_eq!(error.kind(), ErrorKind::InvalidData);
+            assert!(error.to_string().starts_with("line 2"), "{error}");
+            assert!(chunks.next().is_none());
+        }
+    }
+    #[test]
+    fn test_pipeline() {
+        let values: Vec<_> = (0..1000).map(|i| Perplex::new(i as f64, 1.0)).collect();
+        let mut bytes = Vec::new();
+        write_binary(&mut bytes, &values).unwrap();
+        let mut largest_chunk = 0;
+        let mut output = Vec::new();
+        let summary = pipeline(
+            binary_chunks::<_, f64>(bytes.as_slice(), 64),
+            |z| z.sqrt(),
+            |chunk| {
+                largest_chunk = largest_chunk.max(chunk.len());
+                write_csv(&mut output, chunk)
+            },
+        )
+        .unwrap();
+        assert_eq!(summary.chunks, 16);
+        assert_eq!((summary.read, summary.written), (1000, 999));
+        assert!(largest_chunk <= 64, "Bounded memory!");
+        let roots: Vec<Perplex<f64>> = csv_chunks(output.as_slice(), 100, false)
+            .flatten()
+            .flatten()
+            .collect();
+        assert_eq!(
+            roots,
+            values[1..]
+                .iter()
+                .map(|z| z.sqrt().unwrap())
+                .collect::<Vec<_>>()
+        );
+        // errors of the sink are forwarded
+        let failing = pipeline(binary_chunks::<_, f64>(bytes.as_slice(), 64), Some, |_| {
+            Err(io::Error::other("full"))
+        });
+        assert_eq!(failing.unwrap_err().to_string(), "full");
+    }
+}
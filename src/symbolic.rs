@@ -0,0 +1,202 @@
+//! # Symbolic Module
+//!
+//! This module provides the `SymbolicSafe` marker trait for scalar types which represent exact or symbolic expressions, e.g., multivariate polynomials or expression trees of a computer algebra system.
+//! Such types implement the ring operations of `Num`, but in general neither `Copy` nor `PartialOrd` nor `Float`, since an expression is neither cheap to duplicate nor ordered nor approximated by a finite precision.
+//! The algebraic methods of the crate therefore only require `Clone + Num + Neg`, which is exactly the bound of `SymbolicSafe`, and a generic function with the bound `T: SymbolicSafe` may use all methods listed below to derive perplex identities exactly.
+//!
+//! The tests for light-like numbers, e.g., in the division, `try_inverse` and `Mobius::apply`, call `Zero::is_zero` on the squared distance, which detects zero only if the scalar type simplifies its expressions to a normal form.
+//! The causal classification like `is_time_like`, the `Display` implementation and the transcendental functions require `PartialOrd` or `Float` and are not symbolic-safe.
+//!
+//! ## Features
+//! - `Perplex`: `new`, `h`, `real`, `hyperbolic`, `squared_distance`, `minkowski_inner`, `scale`, `lift`, `map_null_basis`, `annihilator`, `conj`, `try_inverse`, `powu`, `powi`, `pow_const`, `div_checked`, `inverse_checked`, `powi_checked` and the operators `Add`, `Sub`, `Mul`, `Div` and `Neg` with the scalar and perplex operands, including references.
+//! - `NullBasis`: All methods, operators and the `From` conversions, whereby the conversion to `Perplex` divides by two.
+//! - `Mobius`: All methods except `blaschke`, which checks the unit square by comparisons.
+//! - `PerplexPolynomial`: `new`, `coefficients`, `degree`, `eval`, `derivative` and `null_components`.
+//!
+//! ## Example
+//! ```
+//! use perplex_num::symbolic::SymbolicSafe;
+//! use perplex_num::Perplex;
+//! // the identity holds for every scalar type, hence a symbolic type proves it
+//! fn conj_is_multiplicative<T: SymbolicSafe>(z: Perplex<T>, w: Perplex<T>) -> bool {
+//!     (&z * &w).conj() == z.conj() * w.conj()
+//! }
+//! assert!(conj_is_multiplicative(Perplex::new(2, -3), Perplex::new(5, 7)));
+//! ```
+
+use core::ops::Neg;
+use num_traits::Num;
+
+/// A marker for scalar types whose perplex numbers support the exact algebraic methods of the crate, see the module documentation.
+///
+/// It is implemented for all types with `Clone + Num + Neg`, e.g., the signed integers, the floating-point numbers, `num_rational::Ratio` and symbolic expression types.
+pub trait SymbolicSafe: Clone + Num + Neg<Output = Self> {}
+
+impl<T: Clone + Num + Neg<Output = T>> SymbolicSafe for T {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::null_basis::NullBasis;
+    use crate::{Mobius, Perplex};
+    use core::ops::{Add, Div, Mul, Rem, Sub};
+    use num_traits::{One, Zero};
+    use std::collections::BTreeMap;
+
+    /// A polynomial with integer coefficients in the symbols `a`, `b`, `c` and `d`, which is neither `Copy` nor ordered.
+    #[derive(Clone, Debug, PartialEq)]
+    struct Poly(BTreeMap<[u32; 4], i64>);
+
+    impl Poly {
+        fn symbol(i: usize) -> Self {
+            let mut exponents = [0; 4];
+            exponents[i] = 1;
+            Self(BTreeMap::from([(exponents, 1)]))
+        }
+        fn constant(&self) -> Option<i64> {
+            match self.0.len() {
+                0 => Some(0),
+                1 => self.0.get(&[0; 4]).copied(),
+                _ => None,
+            }
+        }
+        fn normalized(mut self) -> Self {
+            self.0.retain(|_, c| *c != 0);
+            self
+        }
+    }
+    impl Zero for Poly {
+        fn zero() -> Self {
+            Self(BTreeMap::new())
+        }
+        fn is_zero(&self) -> bool {
+            self.0.is_empty()
+        }
+    }
+    impl One for Poly {
+        fn one() -> Self {
+            Self(BTreeMap::from([([0; 4], 1)]))
+        }
+    }
+    impl Add for Poly {
+        type Output = Self;
+        fn add(mut self, rhs: Self) -> Self {
+            for (m, c) in rhs.0 {
+                *self.0.entry(m).or_insert(0) += c;
+            }
+            self.normalized()
+        }
+    }
+    impl Neg for Poly {
+        type Output = Self;
+        fn neg(self) -> Self {
+            Self(self.0.into_iter().map(|(m, c)| (m, -c)).collect())
+        }
+    }
+    impl Sub for Poly {
+        type Output = Self;
+        fn sub(self, rhs: Self) -> Self {
+            self + -rhs
+        }
+    }
+    impl Mul for Poly {
+        type Output = Self;
+        fn mul(self, rhs: Self) -> Self {
+            let mut product = BTreeMap::new();
+            for (m, c) in &self.0 {
+                for (n, d) in &rhs.0 {
+                    let exponents = core::array::from_fn(|i| m[i] + n[i]);
+                    *product.entry(exponents).or_insert(0) += c * d;
+                }
+            }
+            Self(product).normalized()
+        }
+    }
+    // exact division by non-zero integer constants, which suffices for the conversion from the null basis
+    impl Div for Poly {
+        type Output = Self;
+        fn div(self, rhs: Self) -> Self {
+            let d = rhs.constant().expect("Division by a constant!");
+            Self(self.0.into_iter().map(|(m, c)| (m, c / d)).collect()).normalized()
+        }
+    }
+    impl Rem for Poly {
+        type Output = Self;
+        fn rem(self, rhs: Self) -> Self {
+            let d = rhs.constant().expect("Division by a constant!");
+            Self(self.0.into_iter().map(|(m, c)| (m, c % d)).collect()).normalized()
+        }
+    }
+    impl Num for Poly {
+        type FromStrRadixErr = core::num::ParseIntError;
+        fn from_str_radix(s: &str, radix: u32) -> Result<Self, Self::FromStrRadixErr> {
+            let c = i64::from_str_radix(s, radix)?;
+            Ok(Self(BTreeMap::from([([0; 4], c)])).normalized())
+        }
+    }
+
+    fn symbols() -> (Perplex<Poly>, Perplex<Poly>) {
+        let [a, b, c, d] = core::array::from_fn(Poly::symbol);
+        (Perplex::new(a, b), Perplex::new(c, d))
+    }
+    fn inverse_identity<T: SymbolicSafe>(z: &Perplex<T>) -> Perplex<T> {
+        // z conj(z) = t^2 - x^2, hence conj(z) is the inverse up to the squared distance
+        z * &z.conj()
+    }
+
+    #[test]
+    fn test_perplex_identities() {
+        let (z, w) = symbols();
+        assert_eq!(inverse_identity(&z), Perplex::from(z.squared_distance()));
+        assert_eq!(inverse_identity(&Perplex::new(3, 2)), Perplex::new(5, 0));
+        let product = &z * &w;
+        assert_eq!(product.conj(), z.conj() * w.conj(), "Conjugate!");
+        assert_eq!(
+            product.squared_distance(),
+            z.squared_distance() * w.squared_distance(),
+            "Multiplicative squared distance!"
+        );
+        assert_eq!(
+            z.minkowski_inner(&z),
+            z.squared_distance(),
+            "Minkowski inner product!"
+        );
+        assert_eq!(z.powu(3), &z * &z * z.clone());
+        assert_eq!(z.pow_const::<2>(), &z * &z);
+        let h = Perplex::<Poly>::h();
+        assert_eq!(&h * &h, Perplex::one());
+    }
+    #[test]
+    fn test_null_basis_and_mobius() {
+        let (z, w) = symbols();
+        let (u, v) = (NullBasis::from(z.clone()), NullBasis::from(w.clone()));
+        assert_eq!(NullBasis::from(&z * &w), u.clone() * v.clone());
+        assert_eq!(u.squared_distance(), z.squared_distance());
+        assert!(!u.is_light_like(), "Symbolic coefficients are not zero!");
+        assert_eq!(Perplex::from(u.conj()), z.conj());
+        let m = Mobius::new(z.clone(), w.clone(), w.conj(), z.conj());
+        let n = Mobius::new(w.clone(), Perplex::one(), Perplex::zero(), z.clone());
+        assert_eq!(Mobius::identity().compose(&m), m);
+        assert_eq!(
+            m.compose(&n).determinant(),
+            m.determinant() * n.determinant(),
+            "Multiplicative determinant!"
+        );
+        // the denominator is the constant one, hence the transformation applies exactly
+        let translation = Mobius::new(Perplex::one(), w.clone(), Perplex::zero(), Perplex::one());
+        assert_eq!(translation.apply(z.clone()), Some(z + w));
+    }
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_polynomial() {
+        use crate::PerplexPolynomial;
+        let (z, w) = symbols();
+        let p = PerplexPolynomial::new(vec![w.clone(), Perplex::zero(), z.clone()]);
+        assert_eq!(p.eval(w.clone()), &z * &w * w.clone() + w.clone());
+        assert_eq!(
+            p.derivative().eval(w.clone()),
+            z.scale(Poly::one() + Poly::one()) * w
+        );
+        assert_eq!(p.degree(), Some(2));
+    }
+}
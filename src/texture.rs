@@ -0,0 +1,177 @@
+//! # Texture Module
+//!
+//! This module (optional feature `bytemuck`) exports fields of perplex numbers as RG float textures for shader pipelines. A texel of the format `Rg32Float` holds the time component in the red channel and the space component in the green channel, which is exactly the memory layout of `Perplex<f32>`.
+//!
+//! ## Features
+//! - `Pod` and `Zeroable` traits from the `bytemuck` crate for `Perplex<T>`.
+//! - `as_rg32f`, `as_rg32f_mut` and `as_rg32f_bytes`: Zero-copy reinterpretation of a slice of perplex numbers as interleaved texel data.
+//! - `from_rg32f`: Zero-copy reinterpretation of interleaved texel data as a slice of perplex numbers.
+//! - `fill_rg32f`: Conversion of perplex numbers of any primitive component type into texel data.
+//! - `padded_bytes_per_row` and `pack_rg32f`: Row-major packing of a field into a texture upload buffer with padded rows, as required e.g. by the 256 byte row alignment of WebGPU, whereby `pack_rg32f` allocates the buffer and requires the feature `std`.
+//!
+//! ## Example
+//! ```
+//! use perplex_num::{texture, Perplex};
+//! let field = vec![Perplex::new(1.0f32, 2.0), Perplex::new(3.0, 4.0)];
+//! assert_eq!(texture::as_rg32f(&field), &[1.0, 2.0, 3.0, 4.0]);
+//! let bytes_per_row = texture::padded_bytes_per_row(1, 256);
+//! let buffer = texture::pack_rg32f(&field, 1, bytes_per_row).unwrap();
+//! assert_eq!(buffer.len(), 2 * 256);
+//! ```
+
+use super::Perplex;
+use bytemuck::{Pod, Zeroable};
+use num_traits::ToPrimitive;
+
+// SAFETY: `Perplex` is `repr(C)` with two fields of the same type `T`, hence it has no padding and every bit pattern of two `T` is valid.
+unsafe impl<T: Zeroable> Zeroable for Perplex<T> {}
+unsafe impl<T: Pod> Pod for Perplex<T> {}
+
+/// The size in bytes of a single `Rg32Float` texel, i.e., of a `Perplex<f32>`.
+pub const RG32F_TEXEL_SIZE: usize = core::mem::size_of::<Perplex<f32>>();
+
+/// Reinterprets a slice of perplex numbers as interleaved `[t0, x0, t1, x1, ...]` texel data.
+#[inline]
+pub fn as_rg32f(values: &[Perplex<f32>]) -> &[f32] {
+    bytemuck::cast_slice(values)
+}
+
+/// Reinterprets a mutable slice of perplex numbers as interleaved `[t0, x0, t1, x1, ...]` texel data.
+#[inline]
+pub fn as_rg32f_mut(values: &mut [Perplex<f32>]) -> &mut [f32] {
+    bytemuck::cast_slice_mut(values)
+}
+
+/// Reinterprets a slice of perplex numbers as the raw bytes of `Rg32Float` texel data in native byte order.
+#[inline]
+pub fn as_rg32f_bytes(values: &[Perplex<f32>]) -> &[u8] {
+    bytemuck::cast_slice(values)
+}
+
+/// Reinterprets interleaved `[t0, x0, t1, x1, ...]` texel data as a slice of perplex numbers, or returns `None` if the number of channels is odd.
+#[inline]
+pub fn from_rg32f(texels: &[f32]) -> Option<&[Perplex<f32>]> {
+    bytemuck::try_cast_slice(texels).ok()
+}
+
+/// Converts perplex numbers of any primitive component type into interleaved texel data and writes them to the front of `dst`.
+///
+/// Returns the number of written texels, which is limited by the length of `dst`. Components which are not representable as `f32` are written as NaN.
+#[inline]
+pub fn fill_rg32f<T: ToPrimitive>(
+    dst: &mut [f32],
+    values: impl IntoIterator<Item = Perplex<T>>,
+) -> usize {
+    let mut written = 0;
+    for (texel, z) in dst.chunks_exact_mut(2).zip(values) {
+        texel[0] = z.t.to_f32().unwrap_or(f32::NAN);
+        texel[1] = z.x.to_f32().unwrap_or(f32::NAN);
+        written += 1;
+    }
+    written
+}
+
+/// Returns the number of bytes of a row of `width` texels, rounded up to a multiple of `alignment` bytes.
+///
+/// # Panics
+/// Panics if `alignment` is zero.
+#[inline]
+pub fn padded_bytes_per_row(width: usize, alignment: usize) -> usize {
+    assert!(alignment > 0, "Row alignment must be positive!");
+    (width * RG32F_TEXEL_SIZE).div_ceil(alignment) * alignment
+}
+
+/// Packs a row-major field of `width` columns into a texture upload buffer with `bytes_per_row` bytes per row, whereby the padding at the end of each row is zeroed.
+///
+/// Returns `None` if `width` is zero, the length of the field is not a multiple of `width`, or a row of `width` texels does not fit into `bytes_per_row` bytes.
+#[cfg(feature = "std")]
+pub fn pack_rg32f(field: &[Perplex<f32>], width: usize, bytes_per_row: usize) -> Option<Vec<u8>> {
+    let row_bytes = width.checked_mul(RG32F_TEXEL_SIZE)?;
+    if width == 0 || field.len() % width != 0 || bytes_per_row < row_bytes {
+        return None;
+    }
+    let height = field.len() / width;
+    let mut buffer = vec![0; height * bytes_per_row];
+    for (dst, row) in buffer
+        .chunks_exact_mut(bytes_per_row)
+        .zip(field.chunks_exact(width))
+    {
+        dst[..row_bytes].copy_from_slice(as_rg32f_bytes(row));
+    }
+    Some(buffer)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[test]
+    fn test_cast() {
+        let mut field = vec![Perplex::new(1.0f32, -2.0), Perplex::new(0.5, 0.25)];
+        assert_eq!(RG32F_TEXEL_SIZE, 8);
+        assert_eq!(
+            as_rg32f(&field),
+            &[1.0, -2.0, 0.5, 0.25],
+            "Time then space!"
+        );
+        as_rg32f_mut(&mut field)[3] = 4.0;
+        assert_eq!(field[1], Perplex::new(0.5, 4.0));
+        assert_eq!(as_rg32f_bytes(&field).len(), 16);
+        assert_eq!(
+            from_rg32f(&[1.0, -2.0, 0.5, 4.0]).unwrap(),
+            field.as_slice()
+        );
+        assert!(
+            from_rg32f(&[1.0, 2.0, 3.0]).is_none(),
+            "Odd number of channels!"
+        );
+    }
+    #[test]
+    fn test_pod() {
+        let values = [Perplex::new(1.0f64, -2.0), Perplex::new(0.5, 0.25)];
+        let flat: &[f64] = bytemuck::cast_slice(&values);
+        assert_eq!(flat, Perplex::as_interleaved(&values));
+        let bytes: &[u8] = bytemuck::cast_slice(&values);
+        assert_eq!(bytes.len(), 32);
+        let read: Perplex<f64> = bytemuck::pod_read_unaligned(&bytes[16..]);
+        assert_eq!(read, values[1], "Zero-copy I/O of the bytes!");
+        assert_eq!(
+            <Perplex<i32> as Zeroable>::zeroed(),
+            Perplex::new(0, 0),
+            "Zeroed is the origin!"
+        );
+        let mut buffer = [0u16; 4];
+        bytemuck::cast_slice_mut::<u16, Perplex<u16>>(&mut buffer)[1].x = 7;
+        assert_eq!(buffer, [0, 0, 0, 7]);
+    }
+    #[test]
+    fn test_fill() {
+        let mut dst = [0.0f32; 5];
+        let values = [Perplex::new(1.5f64, -0.5), Perplex::new(1e300, 2.0)];
+        assert_eq!(fill_rg32f(&mut dst, values), 2);
+        assert_eq!(&dst[..3], &[1.5, -0.5, f32::INFINITY]);
+        assert_eq!(dst[4], 0.0, "Remaining channels are untouched!");
+        let mut dst = [0.0f32; 2];
+        assert_eq!(fill_rg32f(&mut dst, values), 1, "Limited by destination!");
+        let mut dst = [0.0f32; 2];
+        assert_eq!(fill_rg32f(&mut dst, [Perplex::new(3u8, 7)]), 1);
+        assert_eq!(dst, [3.0, 7.0]);
+    }
+    #[test]
+    fn test_pack() {
+        assert_eq!(padded_bytes_per_row(3, 256), 256);
+        assert_eq!(padded_bytes_per_row(33, 256), 512);
+        assert_eq!(padded_bytes_per_row(3, 1), 24);
+        let field: Vec<_> = (0..6)
+            .map(|i| Perplex::new(i as f32, -(i as f32)))
+            .collect();
+        let buffer = pack_rg32f(&field, 3, 32).unwrap();
+        assert_eq!(buffer.len(), 64);
+        assert_eq!(&buffer[..24], as_rg32f_bytes(&field[..3]));
+        assert_eq!(&buffer[24..32], &[0; 8], "Padding is zeroed!");
+        assert_eq!(&buffer[32..56], as_rg32f_bytes(&field[3..]));
+        assert_eq!(pack_rg32f(&field, 3, 24).unwrap(), as_rg32f_bytes(&field));
+        assert!(pack_rg32f(&field, 4, 32).is_none(), "Incomplete row!");
+        assert!(pack_rg32f(&field, 3, 16).is_none(), "Row exceeds stride!");
+        assert!(pack_rg32f(&field, 0, 16).is_none(), "Zero width!");
+    }
+}
@@ -0,0 +1,214 @@
+//! # Transform Module
+//!
+//! This module provides a hyperbolic analogue of the discrete Fourier transform for sequences of perplex numbers.
+//! The perplex product acts componentwise on the null components `u = t + x` and `v = t - x` (see the diagonal basis in [Wikipedia](https://wikipedia.org/wiki/Split-complex_number)), hence a sequence of perplex samples is transformed by transforming the real sequences of its null components independently and recombining the results.
+//! The real transform is the discrete Hartley transform (DHT) `H_k = sum_n s_n cas(2 pi k n / N)` with `cas = cos + sin`, see [Discrete Hartley transform](https://doi.org/10.1364/JOSA.73.001832). In contrast to the discrete Fourier transform, it maps real sequences to real sequences, such that the spectrum of a perplex signal is again a sequence of perplex numbers.
+//! The DHT is linear, hence the transform in the null basis equals the transform of the time and space components.
+//!
+//! ## Features
+//! - `hartley`: The DHT of a real sequence, computed by a radix-2 fast Fourier transform for lengths that are a power of two and by the direct sum otherwise.
+//! - `forward` and `inverse`: The transform of a perplex sequence and its inversion.
+//! - `circular_convolution`: The circular convolution with the perplex product, computed by the convolution theorem of the DHT.
+//!
+//! ## Example
+//! ```
+//! use approx::assert_abs_diff_eq;
+//! use perplex_num::{transform, Perplex};
+//! let signal = vec![Perplex::new(1.0, 0.5), Perplex::new(-2.0, 0.0), Perplex::new(0.0, 3.0)];
+//! let spectrum = transform::forward(&signal);
+//! assert_abs_diff_eq!(spectrum[0], Perplex::new(-1.0, 3.5), epsilon = 1e-12);
+//! for (z, z_inv) in signal.iter().zip(transform::inverse(&spectrum)) {
+//!     assert_abs_diff_eq!(*z, z_inv, epsilon = 1e-12);
+//! }
+//! ```
+
+use super::Perplex;
+use num_traits::{Float, FloatConst};
+
+/// Returns the discrete Hartley transform `H_k = sum_n s_n cas(2 pi k n / N)` of the real sequence `signal`.
+///
+/// The transform is its own inverse up to the factor `1/N`.
+pub fn hartley<T: Copy + Float + FloatConst>(signal: &[T]) -> Vec<T> {
+    let n = signal.len();
+    if n.is_power_of_two() {
+        // H_k = Re(F_k) - Im(F_k) with the discrete Fourier transform F
+        fft(signal).into_iter().map(|(re, im)| re - im).collect()
+    } else {
+        let step = T::TAU() / T::from(n).unwrap();
+        (0..n)
+            .map(|k| {
+                signal.iter().enumerate().fold(T::zero(), |sum, (j, &s)| {
+                    // reduce the index product modulo n for an accurate angle
+                    let angle = step * T::from((k * j) % n).unwrap();
+                    sum + s * (angle.cos() + angle.sin())
+                })
+            })
+            .collect()
+    }
+}
+
+/// Computes the discrete Fourier transform of a real sequence whose length is a power of two by the iterative radix-2 algorithm of Cooley and Tukey.
+fn fft<T: Copy + Float + FloatConst>(signal: &[T]) -> Vec<(T, T)> {
+    let n = signal.len();
+    let bits = n.trailing_zeros();
+    // bit reversal permutation
+    let mut values: Vec<(T, T)> = (0..n)
+        .map(|i| {
+            let j = if bits == 0 {
+                0
+            } else {
+                i.reverse_bits() >> (usize::BITS - bits)
+            };
+            (signal[j], T::zero())
+        })
+        .collect();
+    let mut len = 2;
+    while len <= n {
+        let step = -T::TAU() / T::from(len).unwrap();
+        for chunk in values.chunks_exact_mut(len) {
+            let (even, odd) = chunk.split_at_mut(len / 2);
+            for (j, (e, o)) in even.iter_mut().zip(odd.iter_mut()).enumerate() {
+                let (sin, cos) = (step * T::from(j).unwrap()).sin_cos();
+                let w = (o.0 * cos - o.1 * sin, o.0 * sin + o.1 * cos);
+                *o = (e.0 - w.0, e.1 - w.1);
+                *e = (e.0 + w.0, e.1 + w.1);
+            }
+        }
+        len *= 2;
+    }
+    values
+}
+
+/// Transforms the sequence `signal` by the DHT of its null components.
+///
+/// The spectrum is unnormalized, i.e., the component `0` is the sum of the samples.
+pub fn forward<T: Copy + Float + FloatConst>(signal: &[Perplex<T>]) -> Vec<Perplex<T>> {
+    let two = T::one() + T::one();
+    let u: Vec<T> = signal.iter().map(|z| z.t + z.x).collect();
+    let v: Vec<T> = signal.iter().map(|z| z.t - z.x).collect();
+    hartley(&u)
+        .into_iter()
+        .zip(hartley(&v))
+        .map(|(u, v)| Perplex::new((u + v) / two, (u - v) / two))
+        .collect()
+}
+
+/// Inverts `forward`, i.e., returns the signal of the unnormalized `spectrum`.
+pub fn inverse<T: Copy + Float + FloatConst>(spectrum: &[Perplex<T>]) -> Vec<Perplex<T>> {
+    let n = T::from(spectrum.len()).unwrap();
+    forward(spectrum)
+        .into_iter()
+        .map(|z| Perplex::new(z.t / n, z.x / n))
+        .collect()
+}
+
+/// Returns the circular convolution `c_k = sum_j a_j b_{(k - j) mod N}` of two perplex sequences of length `N` with the perplex product.
+///
+/// The convolution is computed by the convolution theorem of the DHT in `O(N log N)` operations if `N` is a power of two.
+///
+/// # Panics
+/// Panics if the sequences differ in length.
+pub fn circular_convolution<T: Copy + Float + FloatConst>(
+    a: &[Perplex<T>],
+    b: &[Perplex<T>],
+) -> Vec<Perplex<T>> {
+    assert_eq!(a.len(), b.len(), "Sequences differ in length!");
+    let n = a.len();
+    let two = T::one() + T::one();
+    let (a_hat, b_hat) = (forward(a), forward(b));
+    // the product acts componentwise on the null components of the spectra
+    let null = |z: Perplex<T>| (z.t + z.x, z.t - z.x);
+    let product: Vec<Perplex<T>> = (0..n)
+        .map(|k| {
+            let (a_u, a_v) = null(a_hat[k]);
+            let (a_u_rev, a_v_rev) = null(a_hat[(n - k) % n]);
+            let (b_u, b_v) = null(b_hat[k]);
+            let (b_u_rev, b_v_rev) = null(b_hat[(n - k) % n]);
+            // convolution theorem of the DHT
+            let u = (a_u * (b_u + b_u_rev) + a_u_rev * (b_u - b_u_rev)) / two;
+            let v = (a_v * (b_v + b_v_rev) + a_v_rev * (b_v - b_v_rev)) / two;
+            Perplex::new((u + v) / two, (u - v) / two)
+        })
+        .collect();
+    inverse(&product)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_abs_diff_eq;
+    fn signal(n: usize) -> Vec<Perplex<f64>> {
+        (0..n)
+            .map(|i| {
+                let s = i as f64;
+                Perplex::new((0.7 * s).sin() + 0.1 * s, (1.3 * s).cos() - 0.5)
+            })
+            .collect()
+    }
+    fn direct_hartley(signal: &[f64]) -> Vec<f64> {
+        let n = signal.len() as f64;
+        (0..signal.len())
+            .map(|k| {
+                signal
+                    .iter()
+                    .enumerate()
+                    .map(|(j, s)| {
+                        let angle = std::f64::consts::TAU * (k * j) as f64 / n;
+                        s * (angle.cos() + angle.sin())
+                    })
+                    .sum()
+            })
+            .collect()
+    }
+    #[test]
+    fn test_hartley() {
+        for n in [1, 2, 4, 16, 6, 7] {
+            let s: Vec<f64> = signal(n).iter().map(|z| z.t).collect();
+            for (h, h_direct) in hartley(&s).into_iter().zip(direct_hartley(&s)) {
+                assert_abs_diff_eq!(h, h_direct, epsilon = 1e-12);
+            }
+        }
+        assert!(hartley::<f64>(&[]).is_empty());
+        let delta = [1.0, 0.0, 0.0, 0.0];
+        assert_eq!(hartley(&delta), vec![1.0; 4], "Delta has a flat spectrum!");
+    }
+    #[test]
+    fn test_forward_inverse() {
+        for n in [8, 5] {
+            let signal = signal(n);
+            let spectrum = forward(&signal);
+            let sum = signal.iter().fold(Perplex::new(0.0, 0.0), |s, z| s + *z);
+            assert_abs_diff_eq!(spectrum[0], sum, epsilon = 1e-12);
+            for (z, z_inv) in signal.iter().zip(inverse(&spectrum)) {
+                assert_abs_diff_eq!(*z, z_inv, epsilon = 1e-12);
+            }
+        }
+    }
+    #[test]
+    fn test_circular_convolution() {
+        for n in [8, 6] {
+            let (a, b) = (signal(n), signal(2 * n).split_off(n));
+            let direct: Vec<_> = (0..n)
+                .map(|k| {
+                    (0..n).fold(Perplex::new(0.0, 0.0), |sum, j| {
+                        sum + a[j] * b[(n + k - j) % n]
+                    })
+                })
+                .collect();
+            for (c, c_direct) in circular_convolution(&a, &b).into_iter().zip(direct) {
+                assert_abs_diff_eq!(c, c_direct, epsilon = 1e-10);
+            }
+        }
+        let mut identity = vec![Perplex::new(0.0, 0.0); 4];
+        identity[0] = Perplex::new(1.0, 0.0);
+        let a = signal(4);
+        for (c, z) in circular_convolution(&a, &identity).into_iter().zip(a) {
+            assert_abs_diff_eq!(c, z, epsilon = 1e-12);
+        }
+    }
+    #[test]
+    #[should_panic(expected = "Sequences differ in length!")]
+    fn test_circular_convolution_panics() {
+        let _ = circular_convolution(&signal(3), &signal(4));
+    }
+}
@@ -0,0 +1,122 @@
+//! # Unit Hyperbola Module
+//!
+//! This module provides the geodesic geometry of the right branch of the unit hyperbola `t^2 - x^2 = 1, t > 0` for `UnitPerplex` values, which is the one-dimensional hyperbolic space of the boosts.
+//! The rapidity is an arc length parameter of the branch: the Minkowski length of the arc between two boosts is the difference of their rapidities, and multiplication by a boost translates the rapidities, which makes it an isometry.
+//! Hence, distances, midpoints and geodesics are computed from the rapidities, consistent with the composition of boosts and with `boost::hyperbolic_mean`.
+//!
+//! ## Features
+//! - `distance`: The geodesic distance `|theta_b - theta_a|`.
+//! - `midpoint`: The boost with the mean rapidity, which is equidistant to both boosts.
+//! - `interpolate`: The geodesic `s -> a (b / a)^s`, which moves with constant speed from `a` at `s = 0` to `b` at `s = 1`.
+//!
+//! ## Example
+//! ```
+//! use approx::assert_abs_diff_eq;
+//! use perplex_num::unit_hyperbola::{distance, interpolate, midpoint};
+//! use perplex_num::UnitPerplex;
+//! let (a, b) = (UnitPerplex::from_rapidity(-0.5), UnitPerplex::from_velocity(0.6).unwrap());
+//! assert_abs_diff_eq!(distance(a, b), 0.5 + 0.6f64.atanh(), epsilon = 1e-15);
+//! let m = midpoint(a, b);
+//! assert_abs_diff_eq!(distance(a, m), distance(m, b), epsilon = 1e-15);
+//! assert_abs_diff_eq!(interpolate(a, b, 0.5).perplex(), m.perplex(), epsilon = 1e-15);
+//! ```
+
+use super::UnitPerplex;
+use num_traits::Float;
+
+/// Returns the geodesic distance `|theta_b - theta_a|` of the boosts `a` and `b`, i.e., the Minkowski arc length between them on the unit hyperbola, which is invariant under boosts of both.
+#[inline]
+pub fn distance<T: Copy + Float>(a: UnitPerplex<T>, b: UnitPerplex<T>) -> T {
+    (b.rapidity() - a.rapidity()).abs()
+}
+
+/// Returns the geodesic midpoint of the boosts `a` and `b`, i.e., the boost with the mean rapidity, which is also the normalization of `a + b` to the unit hyperbola.
+#[inline]
+pub fn midpoint<T: Copy + Float>(a: UnitPerplex<T>, b: UnitPerplex<T>) -> UnitPerplex<T> {
+    let two = T::one() + T::one();
+    UnitPerplex::from_rapidity((a.rapidity() + b.rapidity()) / two)
+}
+
+/// Returns the point at the parameter `s` of the geodesic from `a` to `b`, i.e., the boost with the rapidity `theta_a + s (theta_b - theta_a)`, which has the distance `|s| distance(a, b)` from `a`.
+///
+/// The geodesic extends beyond the boosts for `s` outside of `[0, 1]`.
+#[inline]
+pub fn interpolate<T: Copy + Float>(a: UnitPerplex<T>, b: UnitPerplex<T>, s: T) -> UnitPerplex<T> {
+    let theta = a.rapidity();
+    UnitPerplex::from_rapidity(theta + s * (b.rapidity() - theta))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_abs_diff_eq;
+    #[test]
+    fn test_distance() {
+        let (a, b, c) = (
+            UnitPerplex::from_rapidity(0.3),
+            UnitPerplex::from_rapidity(-1.2),
+            UnitPerplex::from_rapidity(2.0),
+        );
+        assert_eq!(distance(a, a), 0.0);
+        assert_abs_diff_eq!(distance(a, b), 1.5, epsilon = 1e-15);
+        assert_eq!(distance(a, b), distance(b, a), "Symmetry!");
+        // the distances add along the branch and are invariant under boosts
+        assert_abs_diff_eq!(
+            distance(b, c),
+            distance(b, a) + distance(a, c),
+            epsilon = 1e-14
+        );
+        let boost = UnitPerplex::from_velocity(0.8).unwrap();
+        assert_abs_diff_eq!(distance(boost * a, boost * b), 1.5, epsilon = 1e-14);
+        // the distance of the identity is the rapidity, even for velocities close to one
+        let fast = UnitPerplex::from_velocity(1.0 - 1e-12).unwrap();
+        assert_abs_diff_eq!(
+            distance(UnitPerplex::identity(), fast),
+            (1.0 - 1e-12f64).atanh(),
+            epsilon = 1e-9
+        );
+    }
+    #[test]
+    fn test_midpoint() {
+        let (a, b) = (
+            UnitPerplex::from_rapidity(-0.4),
+            UnitPerplex::from_rapidity(1.8),
+        );
+        let m = midpoint(a, b);
+        assert_abs_diff_eq!(m.rapidity(), 0.7, epsilon = 1e-15);
+        assert_eq!(midpoint(a, b), midpoint(b, a));
+        let sum = UnitPerplex::from_perplex(a.perplex() + b.perplex()).unwrap();
+        assert_abs_diff_eq!(m.perplex(), sum.perplex(), epsilon = 1e-14);
+        assert_abs_diff_eq!(
+            m.perplex(),
+            interpolate(a, b, 0.5).perplex(),
+            epsilon = 1e-15
+        );
+    }
+    #[test]
+    fn test_interpolate() {
+        let (a, b) = (
+            UnitPerplex::from_rapidity(0.5),
+            UnitPerplex::from_rapidity(-2.5),
+        );
+        assert_abs_diff_eq!(
+            interpolate(a, b, 0.0).perplex(),
+            a.perplex(),
+            epsilon = 1e-15
+        );
+        assert_abs_diff_eq!(
+            interpolate(a, b, 1.0).perplex(),
+            b.perplex(),
+            epsilon = 1e-15
+        );
+        for s in [-0.5, 0.25, 0.75, 2.0] {
+            let z = interpolate(a, b, s);
+            assert_abs_diff_eq!(distance(a, z), s.abs() * 3.0, epsilon = 1e-14);
+            assert_abs_diff_eq!(z.perplex().squared_distance(), 1.0, epsilon = 1e-14);
+        }
+        // the geodesic is the power a (b / a)^s of the relative boost
+        let relative = (b * a.inverse()).perplex();
+        let power = a.perplex() * relative.powf(0.25).unwrap();
+        assert_abs_diff_eq!(interpolate(a, b, 0.25).perplex(), power, epsilon = 1e-14);
+    }
+}
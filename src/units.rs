@@ -0,0 +1,186 @@
+//! # Units Module
+//!
+//! This module (optional feature `uom`) provides the `Event` type for points of the Minkowski plane whose components carry units of the [uom](https://docs.rs/uom) crate, i.e., a `Time` and a `Length`.
+//! Adding a time to a length is a compile error, hence unit errors are caught at compile time.
+//!
+//! A perplex number uses natural units with the speed of light `c = 1`, i.e., both components are lengths. The conversions `to_perplex` and `from_perplex` map the time to the length `c t` in meters and back with an explicit speed `c`, usually `speed_of_light()`.
+//!
+//! ## Features
+//! - Addition, subtraction, negation and scaling of events.
+//! - `interval`: The squared Minkowski interval `(c t)^2 - x^2` as an `Area`.
+//! - `proper_time` and `proper_length` of time- and space-like separations.
+//!
+//! The submodules `f64` and `f32` provide the types for the respective storage type, analogous to `uom::si::f64` and `uom::si::f32`.
+//!
+//! ## Example
+//! ```
+//! use perplex_num::units::f64::{speed_of_light, Event};
+//! use uom::si::f64::{Length, Time};
+//! use uom::si::{length::meter, time::second};
+//! let c = speed_of_light();
+//! let a = Event::new(Time::new::<second>(2.0), Length::new::<meter>(1.0e8));
+//! let b = Event::new(Time::new::<second>(1.0), Length::new::<meter>(0.0));
+//! let separation = a - b;
+//! assert!(separation.to_perplex(c).is_time_like());
+//! assert!(separation.proper_time(c).unwrap() < Time::new::<second>(1.0));
+//! ```
+
+macro_rules! units_impl {
+    ($float:ident) => {
+        use crate::Perplex;
+        use std::ops::{Add, Neg, Sub};
+        use uom::si::$float::{Area, Length, Time, Velocity};
+        use uom::si::{length::meter, velocity::meter_per_second};
+
+        /// Returns the speed of light in vacuum `c = 299 792 458 m/s`.
+        #[inline]
+        pub fn speed_of_light() -> Velocity {
+            Velocity::new::<meter_per_second>(299_792_458.0)
+        }
+
+        /// An event (or a separation of events) of the Minkowski plane with a time and a position component carrying units.
+        #[derive(Copy, Clone, PartialEq, Debug)]
+        pub struct Event {
+            /// The time component.
+            pub time: Time,
+            /// The space component.
+            pub position: Length,
+        }
+
+        impl Event {
+            /// Creates a new event from its time and position.
+            #[inline]
+            pub fn new(time: Time, position: Length) -> Self {
+                Self { time, position }
+            }
+            /// Creates an event from a perplex number in natural units, whose components are interpreted as the lengths `c t` and `x` in meters.
+            #[inline]
+            pub fn from_perplex(z: Perplex<$float>, c: Velocity) -> Self {
+                let time = Length::new::<meter>(z.t) / c;
+                Self::new(time, Length::new::<meter>(z.x))
+            }
+            /// Returns the perplex number in natural units, whose components are the lengths `c t` and `x` in meters.
+            #[inline]
+            pub fn to_perplex(&self, c: Velocity) -> Perplex<$float> {
+                let ct: Length = self.time * c;
+                Perplex::new(ct.get::<meter>(), self.position.get::<meter>())
+            }
+            /// Multiplies both components by the dimensionless `factor`.
+            #[inline]
+            pub fn scale(&self, factor: $float) -> Self {
+                Self::new(self.time * factor, self.position * factor)
+            }
+            /// Returns the squared Minkowski interval `(c t)^2 - x^2`, which is positive for time-like and negative for space-like separations.
+            #[inline]
+            pub fn interval(&self, c: Velocity) -> Area {
+                let ct: Length = self.time * c;
+                ct * ct - self.position * self.position
+            }
+            /// Returns the proper time `sqrt((c t)^2 - x^2) / c` of a time-like separation, or `None` otherwise.
+            #[inline]
+            pub fn proper_time(&self, c: Velocity) -> Option<Time> {
+                let interval = self.interval(c);
+                if interval.value > 0.0 {
+                    Some(interval.sqrt() / c)
+                } else {
+                    None
+                }
+            }
+            /// Returns the proper length `sqrt(x^2 - (c t)^2)` of a space-like separation, or `None` otherwise.
+            #[inline]
+            pub fn proper_length(&self, c: Velocity) -> Option<Length> {
+                let interval = self.interval(c);
+                if interval.value < 0.0 {
+                    Some((-interval).sqrt())
+                } else {
+                    None
+                }
+            }
+        }
+
+        impl Add for Event {
+            type Output = Self;
+            #[inline]
+            fn add(self, rhs: Self) -> Self::Output {
+                Self::new(self.time + rhs.time, self.position + rhs.position)
+            }
+        }
+
+        impl Sub for Event {
+            type Output = Self;
+            #[inline]
+            fn sub(self, rhs: Self) -> Self::Output {
+                Self::new(self.time - rhs.time, self.position - rhs.position)
+            }
+        }
+
+        impl Neg for Event {
+            type Output = Self;
+            #[inline]
+            fn neg(self) -> Self::Output {
+                Self::new(-self.time, -self.position)
+            }
+        }
+    };
+}
+
+/// Events with `f64` storage.
+pub mod f64 {
+    units_impl!(f64);
+}
+
+/// Events with `f32` storage.
+pub mod f32 {
+    units_impl!(f32);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::f64::*;
+    use crate::Perplex;
+    use approx::assert_abs_diff_eq;
+    use uom::si::f64::{Length, Time, Velocity};
+    use uom::si::{area::square_meter, length::meter, time::second, velocity::meter_per_second};
+    #[test]
+    fn test_event() {
+        let a = Event::new(Time::new::<second>(1.0), Length::new::<meter>(2.0));
+        let b = Event::new(Time::new::<second>(0.5), Length::new::<meter>(-1.0));
+        assert_eq!((a + b).time, Time::new::<second>(1.5));
+        assert_eq!((a - b).position, Length::new::<meter>(3.0));
+        assert_eq!(
+            -a + a,
+            Event::new(Time::new::<second>(0.0), Length::new::<meter>(0.0))
+        );
+        assert_eq!(a.scale(2.0).position, Length::new::<meter>(4.0));
+    }
+    #[test]
+    fn test_natural_units() {
+        // c = 2 m/s for readable numbers
+        let c = Velocity::new::<meter_per_second>(2.0);
+        let z = Perplex::new(3.0, 1.0);
+        let event = Event::from_perplex(z, c);
+        assert_abs_diff_eq!(event.time.get::<second>(), 1.5);
+        assert_eq!(event.to_perplex(c), z);
+        assert_abs_diff_eq!(
+            event.interval(c).get::<square_meter>(),
+            z.squared_distance()
+        );
+        assert_abs_diff_eq!(
+            event.proper_time(c).unwrap().get::<second>(),
+            z.modulus() / 2.0
+        );
+        assert!(event.proper_length(c).is_none());
+        let space_like = Event::from_perplex(Perplex::new(1.0, -3.0), c);
+        assert!(space_like.proper_time(c).is_none());
+        assert_abs_diff_eq!(
+            space_like.proper_length(c).unwrap().get::<meter>(),
+            f64::sqrt(8.0)
+        );
+        let light_like = Event::new(
+            Time::new::<second>(1.0),
+            speed_of_light() * Time::new::<second>(1.0),
+        );
+        assert!(light_like.proper_time(speed_of_light()).is_none());
+        assert!(light_like.proper_length(speed_of_light()).is_none());
+    }
+}
@@ -0,0 +1,329 @@
+//! # Worldline Module
+//!
+//! This module treats sequences of perplex numbers as streams of events in the Minkowski plane, whose time component is the coordinate time and whose space component is the position in natural units (`c = 1`). Events carrying units, like `units::Event`, are converted by their `to_perplex` method.
+//!
+//! ## Features
+//! - `merge_by_time`: Merges several streams sorted by coordinate time into one stream, whereby simultaneous events keep the order of the streams and within each stream.
+//! - `causality_violation` and `is_causal_worldline`: Check that a stream is a causal worldline, i.e., that each event lies in the open future light cone of its predecessor.
+//! - `orthogonalize`: The Gram–Schmidt orthonormalization of a basis under the Minkowski inner product, e.g., to construct adapted frames along worldlines, which reports the signature of the basis and fails for null vectors.
+//! - `frame_field`: The orthonormal frames of the instantaneous rest frames along a time-like worldline, the backbone of accelerated-observer visualizations.
+//!
+//! ## Example
+//! ```
+//! use perplex_num::worldline::{is_causal_worldline, merge_by_time};
+//! use perplex_num::Perplex;
+//! let a = [Perplex::new(0.0, 0.0), Perplex::new(1.0, 0.5), Perplex::new(3.0, 1.0)];
+//! let b = [Perplex::new(1.0, 4.0), Perplex::new(2.0, 4.0)];
+//! assert!(is_causal_worldline(&a) && is_causal_worldline(&b));
+//! let merged = merge_by_time(&[&a, &b]).unwrap();
+//! let streams: Vec<_> = merged.iter().map(|(stream, _)| *stream).collect();
+//! assert_eq!(streams, vec![0, 0, 1, 1, 0]);
+//! ```
+
+use super::Perplex;
+use num_traits::Float;
+use std::fmt;
+
+/// Merges the streams of events sorted by coordinate time into one stream sorted by coordinate time, in which each event is paired with the index of its stream.
+///
+/// The merge is stable: simultaneous events of different streams are ordered by the stream index, and simultaneous events of one stream keep their order.
+/// Returns `None` if a stream is not sorted by coordinate time or contains a NaN time component.
+pub fn merge_by_time<T: Copy + Float>(
+    streams: &[&[Perplex<T>]],
+) -> Option<Vec<(usize, Perplex<T>)>> {
+    let sorted = |stream: &&[Perplex<T>]| {
+        stream.iter().all(|z| !z.t.is_nan()) && stream.windows(2).all(|w| w[0].t <= w[1].t)
+    };
+    if !streams.iter().all(sorted) {
+        return None;
+    }
+    let total = streams.iter().map(|stream| stream.len()).sum();
+    let mut merged = Vec::with_capacity(total);
+    let mut heads = vec![0; streams.len()];
+    while merged.len() < total {
+        // the first stream with the earliest head wins ties
+        let (stream, _) = streams
+            .iter()
+            .zip(&heads)
+            .enumerate()
+            .filter_map(|(i, (stream, &head))| stream.get(head).map(|z| (i, z.t)))
+            .fold(
+                None,
+                |earliest: Option<(usize, T)>, (i, t)| match earliest {
+                    Some((_, earliest_t)) if earliest_t <= t => earliest,
+                    _ => Some((i, t)),
+                },
+            )?;
+        merged.push((stream, streams[stream][heads[stream]]));
+        heads[stream] += 1;
+    }
+    Some(merged)
+}
+
+/// Returns the index of the first event which does not lie in the open future light cone of its predecessor, i.e., whose difference `d` to the predecessor violates `d.t > |d.x|`, or `None` for a causal worldline.
+#[inline]
+pub fn causality_violation<T: Copy + Float>(events: &[Perplex<T>]) -> Option<usize> {
+    events
+        .windows(2)
+        .position(|w| {
+            let d = w[1] - w[0];
+            // NaN components are incomparable and thus violations
+            d.t.partial_cmp(&d.x.abs()) != Some(std::cmp::Ordering::Greater)
+        })
+        .map(|i| i + 1)
+}
+
+/// Checks if the events form a causal worldline, i.e., each difference of consecutive events is time-like and future-directed, see `causality_violation`.
+#[inline]
+pub fn is_causal_worldline<T: Copy + Float>(events: &[Perplex<T>]) -> bool {
+    causality_violation(events).is_none()
+}
+
+/// The signature of a basis orthonormalized by `orthogonalize`.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug, Default)]
+pub struct Signature {
+    /// The number of time-like vectors with the squared norm `1`.
+    pub time_like: usize,
+    /// The number of space-like vectors with the squared norm `-1`.
+    pub space_like: usize,
+}
+
+/// The error of `orthogonalize` for a vector, whose index is given, without a normalization.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub enum OrthogonalizationError {
+    /// The vector lies in the span of its predecessors, i.e., its orthogonal part vanishes.
+    LinearlyDependent(usize),
+    /// The orthogonal part of the vector is light-like, i.e., a non-zero null vector.
+    LightLike(usize),
+}
+
+impl fmt::Display for OrthogonalizationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::LinearlyDependent(i) => write!(f, "vector {i} is linearly dependent"),
+            Self::LightLike(i) => write!(f, "vector {i} becomes light-like"),
+        }
+    }
+}
+
+impl std::error::Error for OrthogonalizationError {}
+
+/// Orthonormalizes `basis` in place by the Gram–Schmidt process under the indefinite Minkowski inner product `t1 t2 - x1 x2`, see `Perplex::minkowski_inner`, and returns its signature.
+///
+/// Each vector is projected onto the orthogonal complement of its predecessors by `v - <v, e> / <e, e> e` and scaled to the squared norm `1` (time-like) or `-1` (space-like).
+/// Since the quadratic form is indefinite, a non-zero vector may be null: the process fails with `OrthogonalizationError::LightLike` if the squared norm of the orthogonal part is within the relative tolerance `sqrt(epsilon)` of zero, and with `OrthogonalizationError::LinearlyDependent` if the orthogonal part itself is within this tolerance of zero.
+/// The vectors from the failing index on are left unchanged.
+///
+/// # Examples
+///
+/// ```
+/// use perplex_num::worldline::{orthogonalize, OrthogonalizationError, Signature};
+/// use perplex_num::Perplex;
+/// // the four-velocity of an observer and a spatial direction
+/// let mut frame = [Perplex::new(2.0, 1.0), Perplex::new(0.0, 1.0)];
+/// let signature = orthogonalize(&mut frame).unwrap();
+/// assert_eq!(signature, Signature { time_like: 1, space_like: 1 });
+/// approx::assert_abs_diff_eq!(frame[0].minkowski_inner(&frame[1]), 0.0, epsilon = 1e-15);
+/// let mut null = [Perplex::new(1.0, 1.0)];
+/// assert_eq!(orthogonalize(&mut null), Err(OrthogonalizationError::LightLike(0)));
+/// ```
+pub fn orthogonalize<T: Copy + Float>(
+    basis: &mut [Perplex<T>],
+) -> Result<Signature, OrthogonalizationError> {
+    let tolerance = T::epsilon().sqrt();
+    let mut signature = Signature::default();
+    for i in 0..basis.len() {
+        let (done, rest) = basis.split_at_mut(i);
+        let mut v = rest[0];
+        let scale = v.max_norm();
+        // the predecessors are orthonormal with the squared norms +-1
+        for e in done.iter() {
+            let coefficient = v.mul_fused(e.conj()).t / e.squared_distance_fused();
+            v = v - e.scale(coefficient);
+        }
+        let norm = v.max_norm();
+        if norm.is_nan() || norm <= tolerance * scale {
+            return Err(OrthogonalizationError::LinearlyDependent(i));
+        }
+        let squared_norm = v.squared_distance_fused();
+        if squared_norm.is_nan() || squared_norm.abs() <= tolerance * norm * norm {
+            return Err(OrthogonalizationError::LightLike(i));
+        }
+        rest[0] = v.scale(squared_norm.abs().sqrt().recip());
+        if squared_norm > T::zero() {
+            signature.time_like += 1;
+        } else {
+            signature.space_like += 1;
+        }
+    }
+    Ok(signature)
+}
+
+/// The orthonormal frame of an observer, i.e., its instantaneous rest frame.
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct Frame<T> {
+    /// The unit tangent, i.e., the four-velocity with the squared norm `1` and a positive time component.
+    pub tangent: Perplex<T>,
+    /// The spatial axis, which is Minkowski-orthogonal to the tangent with the squared norm `-1` and a positive space component.
+    pub spatial: Perplex<T>,
+}
+
+/// Returns the frame at each event of a time-like worldline, whose tangent is estimated by the difference of the neighboring events, i.e., by central differences inside and one-sided differences at the ends.
+///
+/// The frames are orthonormalized by `orthogonalize` from the tangent and the space axis `h`, which yields the spatial axis `h u` of the unit tangent `u`. In the plane, this is the Fermi–Walker transport of the spatial axis, since a frame cannot rotate.
+/// The central difference of events on a hyperbola of uniform acceleration is parallel to its tangent, such that such worldlines yield exact frames up to rounding.
+/// Returns `None` if there are less than two events, the events do not form a causal worldline, see `is_causal_worldline`, or a tangent is light-like within the tolerance of `orthogonalize`.
+///
+/// # Examples
+///
+/// ```
+/// use perplex_num::worldline::frame_field;
+/// use perplex_num::Perplex;
+/// let events = [Perplex::new(0.0, 0.0), Perplex::new(1.0, 0.6), Perplex::new(2.0, 1.2)];
+/// let frames = frame_field(&events).unwrap();
+/// approx::assert_abs_diff_eq!(frames[1].tangent, Perplex::new(1.25, 0.75), epsilon = 1e-15);
+/// approx::assert_abs_diff_eq!(frames[1].spatial, Perplex::new(0.75, 1.25), epsilon = 1e-15);
+/// ```
+pub fn frame_field<T: Copy + Float>(events: &[Perplex<T>]) -> Option<Vec<Frame<T>>> {
+    if events.len() < 2 || !is_causal_worldline(events) {
+        return None;
+    }
+    let last = events.len() - 1;
+    (0..=last)
+        .map(|i| {
+            let velocity = events[(i + 1).min(last)] - events[i.saturating_sub(1)];
+            let mut basis = [velocity, Perplex::h()];
+            orthogonalize(&mut basis).ok()?;
+            Some(Frame {
+                tangent: basis[0],
+                spatial: basis[1],
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_abs_diff_eq;
+    #[test]
+    fn test_merge() {
+        let a = [
+            Perplex::new(0.0, 1.0),
+            Perplex::new(2.0, 2.0),
+            Perplex::new(2.0, 3.0),
+        ];
+        let b = [Perplex::new(-1.0, 0.0), Perplex::new(2.0, -2.0)];
+        let c: [Perplex<f64>; 0] = [];
+        let merged = merge_by_time(&[&a, &c, &b]).unwrap();
+        assert_eq!(
+            merged,
+            vec![(2, b[0]), (0, a[0]), (0, a[1]), (0, a[2]), (2, b[1])],
+            "Simultaneous events are ordered by stream and position!"
+        );
+        assert_eq!(merge_by_time::<f64>(&[]), Some(Vec::new()));
+        let unsorted = [Perplex::new(1.0, 0.0), Perplex::new(0.0, 0.0)];
+        assert!(merge_by_time(&[&a, &unsorted]).is_none());
+        let nan = [Perplex::new(f64::NAN, 0.0)];
+        assert!(merge_by_time(&[&nan]).is_none());
+    }
+    #[test]
+    fn test_causality() {
+        let worldline = [
+            Perplex::new(0.0, 0.0),
+            Perplex::new(1.0, 0.5),
+            Perplex::new(2.0, 0.0),
+        ];
+        assert!(is_causal_worldline(&worldline));
+        assert!(is_causal_worldline::<f64>(&[]));
+        let light_like = [Perplex::new(0.0, 0.0), Perplex::new(1.0, 1.0)];
+        assert_eq!(causality_violation(&light_like), Some(1));
+        let past = [
+            Perplex::new(0.0, 0.0),
+            Perplex::new(1.0, 0.0),
+            Perplex::new(0.5, 0.0),
+        ];
+        assert_eq!(causality_violation(&past), Some(2), "Past-directed!");
+        let nan = [Perplex::new(0.0, 0.0), Perplex::new(1.0, f64::NAN)];
+        assert!(!is_causal_worldline(&nan));
+    }
+    #[test]
+    fn test_orthogonalize() {
+        let mut basis = [Perplex::new(1.0, 3.0), Perplex::new(1.0, 0.0)];
+        let signature = orthogonalize(&mut basis).unwrap();
+        assert_eq!(
+            signature,
+            Signature {
+                time_like: 1,
+                space_like: 1
+            }
+        );
+        assert_abs_diff_eq!(basis[0].squared_distance(), -1.0, epsilon = 1e-14);
+        assert_abs_diff_eq!(basis[1].squared_distance(), 1.0, epsilon = 1e-14);
+        assert_abs_diff_eq!(basis[0].minkowski_inner(&basis[1]), 0.0, epsilon = 1e-14);
+        // the Minkowski complement of a space-like vector is its mirror at the light cone
+        assert_abs_diff_eq!(
+            basis[1],
+            Perplex::new(3.0, 1.0).scale(8f64.sqrt().recip()),
+            epsilon = 1e-14
+        );
+        let mut dependent = [
+            Perplex::new(2.0, 0.5),
+            Perplex::new(0.0, 1.0),
+            Perplex::new(-1.0, 2.0),
+        ];
+        assert_eq!(
+            orthogonalize(&mut dependent),
+            Err(OrthogonalizationError::LinearlyDependent(2)),
+            "At most two independent vectors in the plane!"
+        );
+        assert_eq!(
+            dependent[2],
+            Perplex::new(-1.0, 2.0),
+            "Failing vector unchanged!"
+        );
+        // in the plane, the complement of a non-null vector is non-null
+        let mut light_like = [Perplex::new(3.0, -3.0), Perplex::new(1.0, 0.0)];
+        assert_eq!(
+            orthogonalize(&mut light_like),
+            Err(OrthogonalizationError::LightLike(0))
+        );
+        let mut nearly_light_like = [Perplex::new(1.0, 1.0 - 1e-12)];
+        assert_eq!(
+            orthogonalize(&mut nearly_light_like),
+            Err(OrthogonalizationError::LightLike(0)),
+            "Within the tolerance!"
+        );
+        assert_eq!(orthogonalize::<f64>(&mut []), Ok(Signature::default()));
+    }
+    #[test]
+    fn test_frame_field() {
+        let rho = 2.0;
+        let events: Vec<_> = (-10..=10)
+            .map(|k| Perplex::from_rindler(rho, 0.1 * k as f64).unwrap())
+            .collect();
+        let frames = frame_field(&events).unwrap();
+        assert_eq!(frames.len(), events.len());
+        for frame in &frames {
+            assert_abs_diff_eq!(frame.tangent.squared_distance(), 1.0, epsilon = 1e-14);
+            assert_abs_diff_eq!(frame.spatial.squared_distance(), -1.0, epsilon = 1e-14);
+            assert_abs_diff_eq!(
+                frame.tangent.minkowski_inner(&frame.spatial),
+                0.0,
+                epsilon = 1e-14
+            );
+            assert!(frame.tangent.t > 0.0 && frame.spatial.x > 0.0, "Oriented!");
+        }
+        for i in 1..events.len() - 1 {
+            // the spatial axis of a uniformly accelerated observer points away from the event horizon
+            assert_abs_diff_eq!(
+                frames[i].spatial,
+                events[i].scale(1.0 / rho),
+                epsilon = 1e-13
+            );
+        }
+        assert!(frame_field(&events[..1]).is_none());
+        assert!(frame_field(&[Perplex::new(0.0, 0.0), Perplex::new(1.0, 2.0)]).is_none());
+        assert!(frame_field::<f64>(&[]).is_none());
+    }
+}